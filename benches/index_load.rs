@@ -0,0 +1,62 @@
+//! Compares loading a saved index with building one from its items.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use spatial_narrative::core::{Location, Timestamp};
+use spatial_narrative::index::{SpatialIndex, TemporalIndex};
+
+fn locations(n: usize) -> Vec<Location> {
+    (0..n)
+        .map(|i| {
+            let lat = (i as f64 * 0.7919) % 170.0 - 85.0;
+            let lon = (i as f64 * 1.3733) % 358.0 - 179.0;
+            Location::new(lat, lon)
+        })
+        .collect()
+}
+
+fn timestamps(n: usize) -> Vec<Timestamp> {
+    (0..n as i64)
+        .map(|i| Timestamp::from_unix(1_700_000_000 + (i * 7_919) % 31_536_000).unwrap())
+        .collect()
+}
+
+fn spatial_load(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spatial_load");
+    for n in [10_000, 100_000] {
+        let items = locations(n);
+        let mut bytes = Vec::new();
+        SpatialIndex::from_iter(items.clone(), |l| l)
+            .write_to(&mut bytes)
+            .unwrap();
+
+        group.bench_with_input(BenchmarkId::new("from_iter", n), &items, |b, items| {
+            b.iter(|| SpatialIndex::from_iter(black_box(items.clone()), |l| l))
+        });
+        group.bench_with_input(BenchmarkId::new("read_from", n), &bytes, |b, bytes| {
+            b.iter(|| SpatialIndex::<Location>::read_from(black_box(bytes.as_slice())).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn temporal_load(c: &mut Criterion) {
+    let mut group = c.benchmark_group("temporal_load");
+    for n in [10_000, 100_000] {
+        let items = timestamps(n);
+        let mut bytes = Vec::new();
+        TemporalIndex::from_iter(items.clone(), |t| t)
+            .write_to(&mut bytes)
+            .unwrap();
+
+        group.bench_with_input(BenchmarkId::new("from_iter", n), &items, |b, items| {
+            b.iter(|| TemporalIndex::from_iter(black_box(items.clone()), |t| t))
+        });
+        group.bench_with_input(BenchmarkId::new("read_from", n), &bytes, |b, bytes| {
+            b.iter(|| TemporalIndex::<Timestamp>::read_from(black_box(bytes.as_slice())).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, spatial_load, temporal_load);
+criterion_main!(benches);
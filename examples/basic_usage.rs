@@ -3,8 +3,8 @@
 //! Run with: `cargo run --example basic_usage`
 
 use spatial_narrative::core::{
-    EventBuilder, GeoBounds, Location, NarrativeBuilder, SourceRef, SourceType, TimeRange,
-    Timestamp,
+    EventBuilder, GeoBounds, Location, NarrativeBuilder, SourceRef, SourceType, TemporalMatch,
+    TimeRange, Timestamp,
 };
 
 fn main() {
@@ -176,7 +176,7 @@ fn main() {
     println!("=== Temporal Filtering ===\n");
 
     let range_1918 = TimeRange::year(1918);
-    let events_1918 = narrative.filter_temporal(&range_1918);
+    let events_1918 = narrative.filter_temporal(&range_1918, TemporalMatch::Possibly);
     println!("Events in 1918: {}", events_1918.len());
     for event in events_1918 {
         println!(
@@ -2,7 +2,7 @@
 //!
 //! Run with: `cargo run --example indexing`
 
-use spatial_narrative::core::{GeoBounds, Location, TimeRange, Timestamp};
+use spatial_narrative::core::{GeoBounds, Location, TemporalMatch, TimeRange, Timestamp};
 use spatial_narrative::index::{GridSpec, SpatialIndex, SpatiotemporalIndex, TemporalIndex};
 
 fn main() {
@@ -102,7 +102,7 @@ fn demonstrate_temporal_index() {
     let work_end = Timestamp::parse("2024-03-20T17:00:00Z").unwrap();
     let work_range = TimeRange::new(work_start, work_end);
 
-    let work_events = index.query_range(&work_range);
+    let work_events = index.query_range(&work_range, TemporalMatch::Possibly);
     println!("\nEvents during work hours (9 AM - 5 PM):");
     for event in work_events {
         println!("  - {}", event);
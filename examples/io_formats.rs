@@ -2,7 +2,9 @@
 //!
 //! Run with: `cargo run --example io_formats`
 
-use spatial_narrative::core::{EventBuilder, Location, Narrative, NarrativeBuilder, Timestamp};
+use spatial_narrative::core::{
+    EventBuilder, IdStrategy, Location, Narrative, NarrativeBuilder, Timestamp,
+};
 use spatial_narrative::io::{
     CsvFormat, CsvOptions, Format, GeoJsonFormat, GeoJsonOptions, JsonFormat,
 };
@@ -89,8 +91,12 @@ fn demonstrate_geojson_format(narrative: &Narrative) {
         include_ids: true,
         include_tags: true,
         include_sources: true,
+        include_metadata: true,
         timestamp_property: "timestamp".to_string(),
+        end_timestamp_property: "end_timestamp".to_string(),
         text_property: "description".to_string(),
+        id_strategy: IdStrategy::Random,
+        skip_invalid_geometry: false,
     });
 
     let mut output = Vec::new();
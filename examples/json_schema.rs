@@ -0,0 +1,13 @@
+//! Prints the JSON Schema for the native JSON narrative format.
+//!
+//! Run with: `cargo run --example json_schema > schema/narrative.schema.json`
+
+use spatial_narrative::io::JsonFormat;
+
+fn main() {
+    let schema = JsonFormat::schema();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&schema).expect("schema serializes")
+    );
+}
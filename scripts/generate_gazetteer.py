@@ -0,0 +1,175 @@
+#!/usr/bin/env python3
+"""Generate the built-in gazetteer from the GeoNames dumps.
+
+Writes the city table in src/parser/gazetteer.rs (the most populous
+places in cities1000.txt) and src/parser/gazetteer_admin.rs, which names
+each city's first- and second-level administrative divisions. cities1000
+only holds division codes (country "FR", admin1 "84"); admin1CodesASCII.txt
+and admin2Codes.txt resolve them to names ("Auvergne-Rhône-Alpes").
+
+Dump files missing from the data directory are downloaded from
+https://download.geonames.org/export/dump/. Run scripts/generate_time_zones.py
+afterwards, since the time zone table is built from the gazetteer cities.
+
+Usage:
+    python3 scripts/generate_gazetteer.py [data-dir]
+"""
+
+import io
+import sys
+import urllib.request
+import zipfile
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+GAZETTEER = ROOT / "src" / "parser" / "gazetteer.rs"
+ADMIN_OUTPUT = ROOT / "src" / "parser" / "gazetteer_admin.rs"
+DUMP_URL = "https://download.geonames.org/export/dump/"
+
+CITY_COUNT = 2500
+MAX_ALIASES = 3
+
+# Historical, abandoned and destroyed places, and sections of other places
+SKIP_FEATURES = {"PPLH", "PPLQ", "PPLW", "PPLX"}
+
+# Country names that differ from countryInfo.txt
+COUNTRY_NAMES = {
+    "CD": "Democratic Republic of the Congo",
+    "TR": "Turkey",
+}
+
+TABLE_START = "        let cities = [\n"
+TABLE_END = "        ];\n\n        for (name, country, lat, lon, pop, aliases) in cities {"
+
+
+def fetch(data_dir, name):
+    """Returns the path of a dump file, downloading it if missing."""
+    path = data_dir / name
+    if path.exists():
+        return path
+    data_dir.mkdir(parents=True, exist_ok=True)
+    if name == "cities1000.txt":
+        with urllib.request.urlopen(DUMP_URL + "cities1000.zip") as response:
+            with zipfile.ZipFile(io.BytesIO(response.read())) as archive:
+                archive.extract(name, data_dir)
+    else:
+        urllib.request.urlretrieve(DUMP_URL + name, path)
+    return path
+
+
+def read_rows(path):
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            if line.startswith("#") or not line.strip():
+                continue
+            yield line.rstrip("\n").split("\t")
+
+
+def country_names(path):
+    names = {row[0]: row[4] for row in read_rows(path)}
+    names.update(COUNTRY_NAMES)
+    return names
+
+
+def division_names(path):
+    """Maps a dotted division code ("FR.84") to its name."""
+    return {row[0]: row[1] for row in read_rows(path)}
+
+
+def is_latin(name):
+    return all(
+        ord(c) < 0x250 or 0x1E00 <= ord(c) < 0x1F00 or 0x300 <= ord(c) < 0x370
+        for c in name
+    )
+
+
+def aliases(name, alternate_names):
+    """Up to MAX_ALIASES Latin-script alternate names, sorted."""
+    names = {a for a in alternate_names.split(",") if a and a != name and is_latin(a)}
+    return sorted(names)[:MAX_ALIASES]
+
+
+def load_cities(data_dir):
+    countries = country_names(fetch(data_dir, "countryInfo.txt"))
+    admin1 = division_names(fetch(data_dir, "admin1CodesASCII.txt"))
+    admin2 = division_names(fetch(data_dir, "admin2Codes.txt"))
+
+    cities = []
+    for row in read_rows(fetch(data_dir, "cities1000.txt")):
+        if row[7] in SKIP_FEATURES or row[8] not in countries:
+            continue
+        code = row[8]
+        cities.append(
+            {
+                "name": row[1],
+                "country": countries[code],
+                "admin1": admin1.get(f"{code}.{row[10]}", ""),
+                "admin2": admin2.get(f"{code}.{row[10]}.{row[11]}", ""),
+                "lat": float(row[4]),
+                "lon": float(row[5]),
+                "population": int(row[14] or 0),
+                "aliases": aliases(row[1], row[3]),
+            }
+        )
+
+    cities.sort(key=lambda c: -c["population"])
+    return cities[:CITY_COUNT]
+
+
+def rust_str(s):
+    return '"' + s.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def city_row(city):
+    aliases = ", ".join(rust_str(a) for a in city["aliases"])
+    return (
+        "            (\n"
+        f"                {rust_str(city['name'])},\n"
+        f"                {rust_str(city['country'])},\n"
+        f"                {city['lat']:.4f},\n"
+        f"                {city['lon']:.4f},\n"
+        f"                {city['population']},\n"
+        f"                vec![{aliases}],\n"
+        "            ),\n"
+    )
+
+
+def write_city_table(cities):
+    source = GAZETTEER.read_text(encoding="utf-8")
+    start = source.index(TABLE_START) + len(TABLE_START)
+    end = source.index(TABLE_END, start)
+    rows = "".join(city_row(c) for c in cities)
+    GAZETTEER.write_text(source[:start] + rows + source[end:], encoding="utf-8")
+
+
+def write_admin_table(cities):
+    lines = [
+        "// Auto-generated administrative divisions of the built-in gazetteer's",
+        "// cities, from GeoNames (https://www.geonames.org) cities1000.txt,",
+        "// admin1CodesASCII.txt and admin2Codes.txt.",
+        "// Licensed under Creative Commons Attribution 4.0 International (CC BY 4.0)",
+        "// DO NOT EDIT MANUALLY - regenerate with scripts/generate_gazetteer.py",
+        "",
+        "/// Divisions as `(name, country, admin1, admin2)`; empty when unknown.",
+        "#[rustfmt::skip]",
+        "pub(super) const ADMIN: &[(&str, &str, &str, &str)] = &[",
+    ]
+    for c in cities:
+        if c["admin1"] or c["admin2"]:
+            fields = (c["name"], c["country"], c["admin1"], c["admin2"])
+            lines.append("    (" + ", ".join(rust_str(f) for f in fields) + "),")
+    lines.append("];")
+    ADMIN_OUTPUT.write_text("\n".join(lines) + "\n", encoding="utf-8")
+
+
+def main():
+    data_dir = Path(sys.argv[1]) if len(sys.argv) > 1 else ROOT / "target" / "geonames"
+    cities = load_cities(data_dir)
+    write_city_table(cities)
+    write_admin_table(cities)
+    print(f"wrote {len(cities)} cities to {GAZETTEER.relative_to(ROOT)}")
+    print(f"wrote divisions to {ADMIN_OUTPUT.relative_to(ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,147 @@
+#!/usr/bin/env python3
+"""Generate src/core/time_zone_boundaries.rs, the time zone boundary table.
+
+Reads the combined GeoJSON of timezone-boundary-builder
+(https://github.com/evansiroky/timezone-boundary-builder), simplifies each
+ring with Douglas-Peucker and drops rings that collapse, so the table stays
+small enough to compile in. Polygons include territorial waters; anything
+outside them falls back to the nearest-place table and the nautical zones
+(see src/core/time_zone.rs).
+
+The release archive is downloaded into the data directory when missing.
+
+Usage:
+    python3 scripts/generate_time_zone_boundaries.py [data-dir] [tolerance]
+"""
+
+import io
+import json
+import sys
+import urllib.request
+import zipfile
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+OUTPUT = ROOT / "src" / "core" / "time_zone_boundaries.rs"
+RELEASE_URL = (
+    "https://github.com/evansiroky/timezone-boundary-builder/"
+    "releases/latest/download/timezones.geojson.zip"
+)
+COMBINED = "combined.json"
+
+# Simplification tolerance in degrees (about 2 km at the equator)
+TOLERANCE = 0.02
+
+
+def fetch(data_dir):
+    """Returns the path of the combined GeoJSON, downloading it if missing."""
+    path = data_dir / COMBINED
+    if path.exists():
+        return path
+    data_dir.mkdir(parents=True, exist_ok=True)
+    with urllib.request.urlopen(RELEASE_URL) as response:
+        with zipfile.ZipFile(io.BytesIO(response.read())) as archive:
+            name = next(n for n in archive.namelist() if n.endswith(".json"))
+            path.write_bytes(archive.read(name))
+    return path
+
+
+def perpendicular_distance(point, start, end):
+    (x, y), (x1, y1), (x2, y2) = point, start, end
+    dx, dy = x2 - x1, y2 - y1
+    if dx == 0 and dy == 0:
+        return ((x - x1) ** 2 + (y - y1) ** 2) ** 0.5
+    return abs(dy * x - dx * y + x2 * y1 - y2 * x1) / (dx * dx + dy * dy) ** 0.5
+
+
+def simplify(points, tolerance):
+    """Douglas-Peucker, iteratively so long rings don't hit the recursion limit."""
+    keep = [False] * len(points)
+    keep[0] = keep[-1] = True
+    stack = [(0, len(points) - 1)]
+    while stack:
+        first, last = stack.pop()
+        index, distance = first, 0.0
+        for i in range(first + 1, last):
+            d = perpendicular_distance(points[i], points[first], points[last])
+            if d > distance:
+                index, distance = i, d
+        if distance > tolerance:
+            keep[index] = True
+            stack.append((first, index))
+            stack.append((index, last))
+    return [p for p, k in zip(points, keep) if k]
+
+
+def simplify_ring(ring, tolerance):
+    """A closed simplified ring, or None when fewer than 4 positions remain."""
+    ring = simplify([tuple(p[:2]) for p in ring], tolerance)
+    if ring[0] != ring[-1]:
+        ring.append(ring[0])
+    return ring if len(ring) >= 4 else None
+
+
+def polygons(geometry):
+    if geometry["type"] == "Polygon":
+        return [geometry["coordinates"]]
+    if geometry["type"] == "MultiPolygon":
+        return geometry["coordinates"]
+    return []
+
+
+def simplify_polygon(rings, tolerance):
+    exterior = simplify_ring(rings[0], tolerance)
+    if exterior is None:
+        return None
+    holes = (simplify_ring(ring, tolerance) for ring in rings[1:])
+    return [exterior] + [hole for hole in holes if hole is not None]
+
+
+def rust_ring(ring):
+    vertices = [f"({lon:.4f}, {lat:.4f})" for lon, lat in ring]
+    lines = [", ".join(vertices[i : i + 3]) for i in range(0, len(vertices), 3)]
+    return "&[\n" + "".join(f"        {line},\n" for line in lines) + "    ]"
+
+
+def main():
+    data_dir = Path(sys.argv[1]) if len(sys.argv) > 1 else ROOT / "target" / "tz-boundaries"
+    tolerance = float(sys.argv[2]) if len(sys.argv) > 2 else TOLERANCE
+    with open(fetch(data_dir), encoding="utf-8") as f:
+        features = json.load(f)["features"]
+
+    zones = []
+    for feature in sorted(features, key=lambda f: f["properties"]["tzid"]):
+        simplified = [simplify_polygon(p, tolerance) for p in polygons(feature["geometry"])]
+        simplified = [p for p in simplified if p is not None]
+        if simplified:
+            zones.append((feature["properties"]["tzid"], simplified))
+
+    lines = [
+        "// Auto-generated time zone boundaries from timezone-boundary-builder",
+        "// (https://github.com/evansiroky/timezone-boundary-builder),",
+        f"// simplified to a tolerance of {tolerance} degrees.",
+        "// DO NOT EDIT MANUALLY - regenerate with scripts/generate_time_zone_boundaries.py",
+        "// Licensed under the Open Data Commons Open Database License (ODbL)",
+        "",
+        "/// A closed ring of `(lon, lat)` vertices.",
+        "pub(super) type Ring = &'static [(f64, f64)];",
+        "",
+        "/// Boundaries as `(zone, polygons)`; each polygon is a list of rings,",
+        "/// exterior ring first.",
+        "#[rustfmt::skip]",
+        "pub(super) const BOUNDARIES: &[(&str, &[&[Ring]])] = &[",
+    ]
+    for zone, zone_polygons in zones:
+        polygon_text = ", ".join(
+            "&[" + ", ".join(rust_ring(ring) for ring in rings) + "]" for rings in zone_polygons
+        )
+        lines.append(f'    ("{zone}", &[{polygon_text}]),')
+    lines.append("];")
+
+    OUTPUT.write_text("\n".join(lines) + "\n", encoding="utf-8")
+    vertices = sum(len(ring) for _, ps in zones for rings in ps for ring in rings)
+    print(f"Wrote {len(zones)} zones ({vertices} vertices) to {OUTPUT.relative_to(ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
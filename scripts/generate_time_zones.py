@@ -0,0 +1,271 @@
+#!/usr/bin/env python3
+"""Generate src/core/time_zone_data.rs, the nearest-place time zone table.
+
+Each built-in gazetteer city (GeoNames data, see src/parser/gazetteer.rs)
+is assigned an IANA zone from its country using the tz database's
+zone.tab, so a city can never pick up a neighbouring country's zone.
+Countries with several zones take the zone whose reference point is
+nearest, corrected by OVERRIDES where that is wrong. The zone.tab
+reference points themselves are added as extra places, which covers
+islands and small territories missing from the gazetteer.
+
+Usage:
+    python3 scripts/generate_time_zones.py [path/to/zone.tab]
+"""
+
+import math
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+GAZETTEER = ROOT / "src" / "parser" / "gazetteer.rs"
+OUTPUT = ROOT / "src" / "core" / "time_zone_data.rs"
+ZONEINFO = Path("/usr/share/zoneinfo")
+
+# Gazetteer country names that differ from iso3166.tab
+COUNTRY_CODES = {
+    "Bosnia and Herzegovina": "BA",
+    "Central African Republic": "CF",
+    "Czechia": "CZ",
+    "Democratic Republic of the Congo": "CD",
+    "Ivory Coast": "CI",
+    "Kosovo": "XK",
+    "Macao": "MO",
+    "Myanmar": "MM",
+    "North Korea": "KP",
+    "Palestinian Territory": "PS",
+    "Republic of the Congo": "CG",
+    "South Korea": "KR",
+    "The Netherlands": "NL",
+    "United Kingdom": "GB",
+}
+
+# Kosovo has no zone.tab entry of its own
+EXTRA_ZONES = {"XK": ["Europe/Belgrade"]}
+
+# Countries whose other zones only cover places absent from the gazetteer
+# (or, for China, the cities listed in OVERRIDES); every other gazetteer
+# city takes the main zone.
+MAIN_ZONE = {
+    "CN": "Asia/Shanghai",
+    "DE": "Europe/Berlin",
+    "EC": "America/Guayaquil",
+    "NZ": "Pacific/Auckland",
+    "PG": "Pacific/Port_Moresby",
+}
+
+# Cities whose zone differs from the nearest zone.tab reference point,
+# by country and zone, following the region comments in zone.tab
+OVERRIDES = {
+    "Argentina": {
+        "America/Argentina/Buenos_Aires": [
+            "Mar del Plata", "Bahía Blanca", "Merlo", "Quilmes",
+        ],
+        "America/Argentina/Cordoba": [
+            "Rosario", "Santa Fe", "Corrientes", "Posadas", "Resistencia",
+            "Santiago del Estero", "Paraná",
+        ],
+    },
+    "Brazil": {
+        "America/Belem": ["Parauapebas"],
+        "America/Fortaleza": [
+            "São Luís", "São José de Ribamar", "Natal", "Parnamirim",
+            "João Pessoa", "Campina Grande",
+        ],
+        "America/Recife": ["Petrolina"],
+        "America/Sao_Paulo": [
+            "Montes Claros", "Goiânia", "Aparecida de Goiânia", "Anápolis",
+            "Maringá", "Foz do Iguaçu", "Cascavel", "Ceilândia",
+        ],
+    },
+    "Canada": {
+        "America/Edmonton": ["Calgary"],
+        "America/Regina": ["Saskatoon"],
+        "America/Toronto": ["Québec"],
+    },
+    "Chile": {"America/Santiago": ["Puerto Montt"]},
+    "China": {
+        "Asia/Urumqi": [
+            "Ürümqi", "Shihezi", "Korla", "Aqsu", "Kashgar", "Gujangbagh",
+            "Huocheng", "Artux", "Xinyuan", "Turpan", "Ghulja", "Karamay",
+            "Hami", "Bole",
+        ],
+    },
+    "Democratic Republic of the Congo": {
+        "Africa/Lubumbashi": ["Kananga", "Kisangani", "Tshikapa", "Isiro"],
+    },
+    "Indonesia": {
+        "Asia/Jakarta": [
+            "Medan", "Batam", "Pekanbaru", "Dumai", "Percut", "Binjai",
+            "Pematangsiantar", "Banda Aceh", "Padangsidempuan", "Situbondo",
+            "Jember",
+        ],
+        "Asia/Jayapura": ["Ambon"],
+    },
+    "Kazakhstan": {
+        "Asia/Almaty": ["Karagandy", "Astana", "Pavlodar", "Shymkent"],
+    },
+    "Mexico": {
+        "America/Matamoros": ["Nuevo Laredo"],
+        "America/Mazatlan": ["Tepic"],
+        "America/Mexico_City": [
+            "Zapopan", "Guadalajara", "Aguascalientes", "Tlaquepaque", "Tonalá",
+            "Tuxtla", "Tapachula", "Villahermosa",
+        ],
+        "America/Monterrey": ["Victoria de Durango", "Tampico"],
+    },
+    "Russia": {
+        "Asia/Novokuznetsk": ["Kemerovo"],
+        "Asia/Vladivostok": [
+            "Khabarovsk", "Khabarovsk Vtoroy", "Komsomolsk-on-Amur",
+        ],
+        "Asia/Yekaterinburg": [
+            "Surgut", "Nizhnevartovsk", "Orenburg", "Sterlitamak", "Orsk",
+        ],
+        "Europe/Moscow": [
+            "Arkhangel’sk", "Murmansk", "Yoshkar-Ola", "Syktyvkar",
+            "Makhachkala", "Vladikavkaz", "Grozny", "Nalchik",
+            "Nizhniy Novgorod", "Kazan", "Naberezhnyye Chelny", "Cheboksary",
+            "Saransk", "Rostov-na-Donu", "Krasnodar", "Stavropol’", "Sochi",
+            "Taganrog", "Novorossiysk", "Penza", "Tambov",
+        ],
+        "Europe/Samara": ["Izhevsk"],
+    },
+    "Spain": {"Europe/Madrid": ["Sevilla", "Málaga", "Córdoba"]},
+    "Ukraine": {
+        "Europe/Kyiv": [
+            "Odesa", "Donetsk", "Zaporizhzhya", "Kryvyy Rih", "Mykolayiv",
+            "Luhansk", "Makiivka", "Kherson", "Horlivka",
+        ],
+    },
+    "United States": {
+        "America/New_York": [
+            "Jacksonville", "Charlotte", "Atlanta", "Miami", "Raleigh", "Tampa",
+            "West Raleigh", "Orlando", "Greensboro", "Durham", "St. Petersburg",
+            "Winston-Salem", "Hialeah", "Columbus", "Lexington",
+            "Lexington-Fayette", "Cincinnati", "Meads", "Cleveland",
+            "Pittsburgh", "Toledo", "Buffalo",
+        ],
+        "America/Indiana/Indianapolis": ["Fort Wayne"],
+        "America/Chicago": [
+            "Houston", "New South Memphis", "Memphis", "Nashville",
+            "New Orleans", "Dallas", "Kansas City", "Tulsa", "Plano",
+            "St. Louis", "Garland", "San Antonio", "Fort Worth", "Austin",
+            "Oklahoma City", "Wichita", "Arlington", "Corpus Christi", "Lincoln",
+            "Lubbock", "Irving", "Laredo", "Minneapolis", "Saint Paul",
+        ],
+        "America/Denver": ["El Paso", "Albuquerque"],
+        "America/Los_Angeles": ["Seattle", "Portland", "Reno", "Tri-Cities"],
+    },
+}
+
+
+def load_cities():
+    src = GAZETTEER.read_text(encoding="utf-8")
+    start = src.index("let cities = [")
+    body = src[start : src.index("];", start)]
+    pattern = re.compile(
+        r'\(\s*"((?:[^"\\]|\\.)*)",\s*"((?:[^"\\]|\\.)*)",\s*(-?[\d.]+),\s*(-?[\d.]+),'
+    )
+    return [
+        (m.group(1), m.group(2), float(m.group(3)), float(m.group(4)))
+        for m in pattern.finditer(body)
+    ]
+
+
+def parse_iso6709(coord):
+    """Parse zone.tab's +DDMM[SS]+DDDMM[SS] coordinates."""
+    m = re.match(r"([+-]\d+)([+-]\d+)$", coord)
+
+    def part(text, degree_digits):
+        sign = -1 if text[0] == "-" else 1
+        digits = text[1:]
+        deg = int(digits[:degree_digits])
+        minutes = int(digits[degree_digits : degree_digits + 2])
+        seconds = int(digits[degree_digits + 2 :] or 0)
+        return sign * (deg + minutes / 60 + seconds / 3600)
+
+    return part(m.group(1), 2), part(m.group(2), 3)
+
+
+def load_zones(path):
+    zones = {}
+    for line in path.read_text(encoding="utf-8").splitlines():
+        if line.startswith("#") or not line.strip():
+            continue
+        fields = line.split("\t")
+        lat, lon = parse_iso6709(fields[1])
+        zones.setdefault(fields[0], []).append((fields[2], lat, lon))
+    return zones
+
+
+def load_country_codes():
+    codes = dict(COUNTRY_CODES)
+    for line in (ZONEINFO / "iso3166.tab").read_text(encoding="utf-8").splitlines():
+        if line.startswith("#"):
+            continue
+        code, name = line.split("\t")
+        codes.setdefault(name, code)
+    return codes
+
+
+def haversine(lat1, lon1, lat2, lon2):
+    p1, p2 = math.radians(lat1), math.radians(lat2)
+    dp, dl = p2 - p1, math.radians(lon2 - lon1)
+    a = math.sin(dp / 2) ** 2 + math.cos(p1) * math.cos(p2) * math.sin(dl / 2) ** 2
+    return 2 * 6_371_008.8 * math.asin(math.sqrt(a))
+
+
+def assign(name, country, lat, lon, code, zones):
+    for zone, cities in OVERRIDES.get(country, {}).items():
+        if name in cities:
+            return zone
+    if code in MAIN_ZONE:
+        return MAIN_ZONE[code]
+    candidates = zones.get(code) or [(z, lat, lon) for z in EXTRA_ZONES[code]]
+    return min(candidates, key=lambda z: haversine(lat, lon, z[1], z[2]))[0]
+
+
+def rust_str(text):
+    return '"' + text.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def main():
+    zone_tab = Path(sys.argv[1]) if len(sys.argv) > 1 else ZONEINFO / "zone.tab"
+    zones = load_zones(zone_tab)
+    codes = load_country_codes()
+    cities_list = load_cities()
+
+    for country, zones_by_city in OVERRIDES.items():
+        for cities in zones_by_city.values():
+            for city in cities:
+                if not any(c[0] == city and c[1] == country for c in cities_list):
+                    sys.exit(f"override for unknown city {city}, {country}")
+
+    places = []
+    for name, country, lat, lon in cities_list:
+        zone = assign(name, country, lat, lon, codes[country], zones)
+        places.append((name, lat, lon, zone))
+    for entries in zones.values():
+        for zone, lat, lon in entries:
+            places.append((zone.rsplit("/", 1)[-1].replace("_", " "), lat, lon, zone))
+
+    lines = [
+        "// Auto-generated time zone table from GeoNames cities (via the built-in",
+        "// gazetteer) and the IANA tz database's zone.tab.",
+        "// DO NOT EDIT MANUALLY - regenerate with scripts/generate_time_zones.py",
+        "",
+        "/// Reference places as `(name, lat, lon, zone)`.",
+        "#[rustfmt::skip]",
+        "pub(super) const PLACES: &[(&str, f64, f64, &str)] = &[",
+    ]
+    for name, lat, lon, zone in places:
+        lines.append(f"    ({rust_str(name)}, {lat:.4f}, {lon:.4f}, {rust_str(zone)}),")
+    lines.append("];")
+    OUTPUT.write_text("\n".join(lines) + "\n", encoding="utf-8")
+    print(f"Wrote {len(places)} places to {OUTPUT.relative_to(ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
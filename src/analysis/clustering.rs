@@ -4,7 +4,7 @@
 //! for grouping events by geographic location.
 
 use crate::analysis::haversine_distance;
-use crate::core::{Event, GeoBounds, Location};
+use crate::core::{circle_overlap_fraction, Event, GeoBounds, Location};
 use std::collections::HashSet;
 
 /// A cluster of events.
@@ -111,6 +111,92 @@ impl DBSCAN {
     /// assert_eq!(result.noise.len(), 1); // One noise point
     /// ```
     pub fn cluster(&self, events: &[Event]) -> ClusteringResult {
+        self.cluster_weighted(events, |_| 1.0)
+    }
+
+    /// Cluster events with a weight per event.
+    ///
+    /// A point is a core point when the summed weight of its neighbours
+    /// reaches `min_points`. Weighting by source confidence means a dense
+    /// group of poorly sourced claims does not form a cluster on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::{Event, Location, ReliabilityModel, Timestamp};
+    /// use spatial_narrative::analysis::DBSCAN;
+    ///
+    /// let events: Vec<Event> = (0..3)
+    ///     .map(|i| Event::new(Location::new(40.0 + i as f64 * 0.001, -74.0), Timestamp::now(), "Unsourced"))
+    ///     .collect();
+    ///
+    /// let model = ReliabilityModel::new();
+    /// let dbscan = DBSCAN::new(1000.0, 2);
+    /// assert_eq!(dbscan.cluster(&events).num_clusters(), 1);
+    /// assert_eq!(dbscan.cluster_weighted(&events, |e| model.confidence(e)).num_clusters(), 0);
+    /// ```
+    pub fn cluster_weighted<F>(&self, events: &[Event], weight: F) -> ClusteringResult
+    where
+        F: Fn(&Event) -> f64,
+    {
+        self.run(events, weight, false)
+    }
+
+    /// Cluster events, taking each location's uncertainty into account.
+    ///
+    /// Two events are neighbours with the probability that their true
+    /// positions lie within `eps` of each other, treating each position
+    /// as uniform over its uncertainty circle. A point is a core point when
+    /// the expected number of neighbours reaches `min_points`, and clusters
+    /// only grow through neighbours that are more likely than not within
+    /// `eps`. Events without uncertainty behave as in [`DBSCAN::cluster`].
+    ///
+    /// This keeps many events geoparsed to the same city centroid from
+    /// forming a tight cluster when `eps` is much smaller than the city.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::{Event, Location, Timestamp};
+    /// use spatial_narrative::analysis::DBSCAN;
+    ///
+    /// // Three events resolved to the same city centroid, +/- 10 km
+    /// let events: Vec<Event> = (0..3)
+    ///     .map(|_| {
+    ///         let location = Location::builder()
+    ///             .coordinates(48.8566, 2.3522)
+    ///             .uncertainty_meters(10_000.0)
+    ///             .build()
+    ///             .unwrap();
+    ///         Event::new(location, Timestamp::now(), "Somewhere in Paris")
+    ///     })
+    ///     .collect();
+    ///
+    /// let dbscan = DBSCAN::new(500.0, 2);
+    /// assert_eq!(dbscan.cluster(&events).num_clusters(), 1);
+    /// assert_eq!(dbscan.cluster_uncertain(&events).num_clusters(), 0);
+    /// ```
+    pub fn cluster_uncertain(&self, events: &[Event]) -> ClusteringResult {
+        self.cluster_uncertain_weighted(events, |_| 1.0)
+    }
+
+    /// Cluster events with a weight per event, taking location
+    /// uncertainty into account.
+    ///
+    /// Combines [`DBSCAN::cluster_weighted`] and [`DBSCAN::cluster_uncertain`]:
+    /// each neighbour contributes its weight times the probability that it
+    /// is within `eps`.
+    pub fn cluster_uncertain_weighted<F>(&self, events: &[Event], weight: F) -> ClusteringResult
+    where
+        F: Fn(&Event) -> f64,
+    {
+        self.run(events, weight, true)
+    }
+
+    fn run<F>(&self, events: &[Event], weight: F, uncertain: bool) -> ClusteringResult
+    where
+        F: Fn(&Event) -> f64,
+    {
         let n = events.len();
         if n == 0 {
             return ClusteringResult {
@@ -122,6 +208,7 @@ impl DBSCAN {
 
         // Build distance cache (for efficiency)
         let locations: Vec<_> = events.iter().map(|e| &e.location).collect();
+        let weights: Vec<f64> = events.iter().map(weight).collect();
 
         // Labels: -1 = unvisited, -2 = noise, >= 0 = cluster id
         let mut labels: Vec<i32> = vec![-1; n];
@@ -132,13 +219,21 @@ impl DBSCAN {
                 continue; // Already processed
             }
 
-            let neighbors = self.range_query(&locations, i);
+            let neighbors = self.range_query(&locations, i, uncertain);
 
-            if neighbors.len() < self.min_points {
+            if !self.is_dense(&neighbors, &weights) {
                 labels[i] = -2; // Mark as noise
             } else {
                 // Expand cluster
-                self.expand_cluster(&locations, i, &neighbors, current_cluster, &mut labels);
+                self.expand_cluster(
+                    &locations,
+                    &weights,
+                    uncertain,
+                    i,
+                    &neighbors,
+                    current_cluster,
+                    &mut labels,
+                );
                 current_cluster += 1;
             }
         }
@@ -153,30 +248,60 @@ impl DBSCAN {
         self.build_result(events, labels)
     }
 
-    fn range_query(&self, locations: &[&Location], point_idx: usize) -> Vec<usize> {
+    /// Returns the neighbours of a point with the probability that each is
+    /// within `eps`; without uncertainty the probability is always 1.
+    fn range_query(
+        &self,
+        locations: &[&Location],
+        point_idx: usize,
+        uncertain: bool,
+    ) -> Vec<(usize, f64)> {
         let point = locations[point_idx];
         locations
             .iter()
             .enumerate()
-            .filter(|(i, loc)| {
-                *i != point_idx
-                    && haversine_distance(point.lat, point.lon, loc.lat, loc.lon) <= self.eps
+            .filter(|(i, _)| *i != point_idx)
+            .filter_map(|(i, loc)| {
+                let d = haversine_distance(point.lat, point.lon, loc.lat, loc.lon);
+                let p = if uncertain {
+                    // The offset between two uniform positions spreads over
+                    // roughly the combined radius.
+                    let spread = point.uncertainty_radius().hypot(loc.uncertainty_radius());
+                    circle_overlap_fraction(d, spread, self.eps)
+                } else if d <= self.eps {
+                    1.0
+                } else {
+                    0.0
+                };
+                (p > 0.0).then_some((i, p))
             })
-            .map(|(i, _)| i)
             .collect()
     }
 
+    /// Whether the expected neighbour weight makes a core point.
+    fn is_dense(&self, neighbors: &[(usize, f64)], weights: &[f64]) -> bool {
+        neighbors.iter().map(|&(i, p)| weights[i] * p).sum::<f64>() >= self.min_points as f64
+    }
+
+    /// Neighbours a cluster may grow through.
+    fn reachable(neighbors: &[(usize, f64)]) -> impl Iterator<Item = usize> + '_ {
+        neighbors.iter().filter(|(_, p)| *p >= 0.5).map(|(i, _)| *i)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn expand_cluster(
         &self,
         locations: &[&Location],
+        weights: &[f64],
+        uncertain: bool,
         seed_idx: usize,
-        seed_neighbors: &[usize],
+        seed_neighbors: &[(usize, f64)],
         cluster_id: i32,
         labels: &mut [i32],
     ) {
         labels[seed_idx] = cluster_id;
 
-        let mut seeds: Vec<usize> = seed_neighbors.to_vec();
+        let mut seeds: Vec<usize> = Self::reachable(seed_neighbors).collect();
         let mut processed: HashSet<usize> = HashSet::new();
 
         while let Some(current_idx) = seeds.pop() {
@@ -195,10 +320,10 @@ impl DBSCAN {
 
             labels[current_idx] = cluster_id;
 
-            let neighbors = self.range_query(locations, current_idx);
+            let neighbors = self.range_query(locations, current_idx, uncertain);
 
-            if neighbors.len() >= self.min_points {
-                seeds.extend(neighbors);
+            if self.is_dense(&neighbors, weights) {
+                seeds.extend(Self::reachable(&neighbors));
             }
         }
     }
@@ -437,23 +562,8 @@ fn compute_centroid_from_locations(locations: &[&&Location]) -> Location {
 }
 
 fn compute_bounds(events: &[&Event]) -> GeoBounds {
-    if events.is_empty() {
-        return GeoBounds::new(0.0, 0.0, 0.0, 0.0);
-    }
-
-    let mut min_lat = f64::MAX;
-    let mut max_lat = f64::MIN;
-    let mut min_lon = f64::MAX;
-    let mut max_lon = f64::MIN;
-
-    for event in events {
-        min_lat = min_lat.min(event.location.lat);
-        max_lat = max_lat.max(event.location.lat);
-        min_lon = min_lon.min(event.location.lon);
-        max_lon = max_lon.max(event.location.lon);
-    }
-
-    GeoBounds::new(min_lat, max_lat, min_lon, max_lon)
+    GeoBounds::from_locations(events.iter().map(|e| &e.location))
+        .unwrap_or_else(|| GeoBounds::new(0.0, 0.0, 0.0, 0.0))
 }
 
 #[cfg(test)]
@@ -485,6 +595,10 @@ mod tests {
 
         assert_eq!(result.num_clusters(), 1);
         assert_eq!(result.clusters[0].len(), 3);
+        assert_eq!(
+            result.clusters[0].bounds,
+            GeoBounds::new(40.0, -74.002, 40.002, -74.0)
+        );
     }
 
     #[test]
@@ -504,6 +618,33 @@ mod tests {
         assert_eq!(result.labels[3], -1); // Last point is noise
     }
 
+    #[test]
+    fn test_dbscan_uncertain() {
+        let vague = |lat: f64, lon: f64, meters: f64| {
+            let mut event = make_event(lat, lon);
+            event.location.uncertainty_meters = Some(meters);
+            event
+        };
+
+        // A tight group of precise points plus city-centroid points
+        let mut events: Vec<Event> = (0..4)
+            .map(|i| make_event(40.0 + i as f64 * 0.001, -74.0))
+            .collect();
+        events.extend((0..4).map(|_| vague(41.0, -75.0, 20_000.0)));
+
+        let dbscan = DBSCAN::new(500.0, 2);
+        assert_eq!(dbscan.cluster(&events).num_clusters(), 2);
+
+        let result = dbscan.cluster_uncertain(&events);
+        assert_eq!(result.num_clusters(), 1);
+        assert_eq!(result.clusters[0].event_indices, vec![0, 1, 2, 3]);
+        assert_eq!(result.noise, vec![4, 5, 6, 7]);
+
+        // Modest uncertainty relative to eps still clusters
+        let close: Vec<Event> = (0..3).map(|_| vague(41.0, -75.0, 50.0)).collect();
+        assert_eq!(dbscan.cluster_uncertain(&close).num_clusters(), 1);
+    }
+
     #[test]
     fn test_kmeans_basic() {
         let events = vec![
@@ -258,35 +258,14 @@ pub fn spatial_union(n1: &Narrative, n2: &Narrative) -> Option<GeoBounds> {
     let bounds2 = compute_bounds(n2.events());
 
     match (bounds1, bounds2) {
-        (Some(b1), Some(b2)) => Some(GeoBounds::new(
-            b1.min_lat.min(b2.min_lat),
-            b1.max_lat.max(b2.max_lat),
-            b1.min_lon.min(b2.min_lon),
-            b1.max_lon.max(b2.max_lon),
-        )),
+        (Some(b1), Some(b2)) => Some(b1.union(&b2)),
         (Some(b), None) | (None, Some(b)) => Some(b),
         (None, None) => None,
     }
 }
 
 fn compute_bounds(events: &[Event]) -> Option<GeoBounds> {
-    if events.is_empty() {
-        return None;
-    }
-
-    let mut min_lat = f64::MAX;
-    let mut max_lat = f64::MIN;
-    let mut min_lon = f64::MAX;
-    let mut max_lon = f64::MIN;
-
-    for event in events {
-        min_lat = min_lat.min(event.location.lat);
-        max_lat = max_lat.max(event.location.lat);
-        min_lon = min_lon.min(event.location.lon);
-        max_lon = max_lon.max(event.location.lon);
-    }
-
-    Some(GeoBounds::new(min_lat, max_lat, min_lon, max_lon))
+    GeoBounds::from_locations(events.iter().map(|e| &e.location))
 }
 
 #[cfg(test)]
@@ -420,4 +399,22 @@ mod tests {
         let intersection = spatial_intersection(&n1, &n2, 1000.0);
         assert_eq!(intersection.len(), 1);
     }
+
+    #[test]
+    fn test_spatial_union() {
+        let n1 = NarrativeBuilder::new()
+            .event(make_event(40.0, -74.0, "2024-01-01T10:00:00Z", &[]))
+            .event(make_event(42.0, -71.0, "2024-01-01T11:00:00Z", &[]))
+            .build();
+        let n2 = NarrativeBuilder::new()
+            .event(make_event(38.9, -77.0, "2024-01-01T12:00:00Z", &[]))
+            .build();
+
+        let union = spatial_union(&n1, &n2).unwrap();
+        assert_eq!(union, GeoBounds::new(38.9, -77.0, 42.0, -71.0));
+        assert_eq!(
+            spatial_union(&n1, &Narrative::builder().build()),
+            Some(GeoBounds::new(40.0, -74.0, 42.0, -71.0))
+        );
+    }
 }
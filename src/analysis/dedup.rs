@@ -0,0 +1,566 @@
+//! Event deduplication and entity resolution.
+//!
+//! Scores candidate duplicate pairs by spatial distance, time
+//! difference and text similarity, then collapses matches into a single
+//! event while keeping an audit trail of the merged IDs.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::haversine_distance;
+use crate::core::{Event, EventId, Narrative, Timestamp};
+
+/// Configuration for duplicate detection.
+#[derive(Debug, Clone)]
+pub struct DedupConfig {
+    /// Weight for spatial similarity in the overall score.
+    pub spatial_weight: f64,
+    /// Weight for temporal similarity in the overall score.
+    pub temporal_weight: f64,
+    /// Weight for text similarity in the overall score.
+    pub text_weight: f64,
+    /// Events farther apart than this (meters) are never duplicates.
+    pub max_distance_m: f64,
+    /// Events further apart in time than this (seconds) are never duplicates.
+    pub max_time_diff_secs: f64,
+    /// Minimum overall score for a pair to count as a duplicate.
+    pub match_threshold: f64,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self {
+            spatial_weight: 0.35,
+            temporal_weight: 0.35,
+            text_weight: 0.3,
+            max_distance_m: 1000.0,     // 1 km
+            max_time_diff_secs: 3600.0, // 1 hour
+            match_threshold: 0.75,
+        }
+    }
+}
+
+/// Similarity scores for a candidate duplicate pair.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateScore {
+    /// Weighted overall score (0.0 to 1.0).
+    pub overall: f64,
+    /// Spatial similarity (0.0 to 1.0).
+    pub spatial: f64,
+    /// Temporal similarity (0.0 to 1.0).
+    pub temporal: f64,
+    /// Text similarity (0.0 to 1.0).
+    pub text: f64,
+}
+
+impl DuplicateScore {
+    /// Returns true if the pair is within the hard limits and above the threshold.
+    pub fn is_match(&self, config: &DedupConfig) -> bool {
+        self.spatial > 0.0 && self.temporal > 0.0 && self.overall >= config.match_threshold
+    }
+}
+
+/// A pair of events judged to be duplicates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicatePair {
+    /// First event of the pair.
+    pub first: EventId,
+    /// Second event of the pair.
+    pub second: EventId,
+    /// Similarity scores for the pair.
+    pub score: DuplicateScore,
+}
+
+/// Audit record of events collapsed into one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeRecord {
+    /// ID of the event that was kept.
+    pub kept: EventId,
+    /// IDs of the events merged into the kept event.
+    pub collapsed: Vec<EventId>,
+    /// Lowest score among the matched pairs that linked the group.
+    pub min_score: f64,
+}
+
+/// Result of deduplicating a set of events.
+#[derive(Debug, Clone)]
+pub struct DedupResult {
+    /// Events after duplicates were merged, in original order.
+    pub events: Vec<Event>,
+    /// One record per group of collapsed events.
+    pub merges: Vec<MergeRecord>,
+}
+
+impl DedupResult {
+    /// Returns the number of events removed by merging.
+    pub fn removed_count(&self) -> usize {
+        self.merges.iter().map(|m| m.collapsed.len()).sum()
+    }
+}
+
+/// Score how likely two events describe the same occurrence.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Timestamp};
+/// use spatial_narrative::analysis::{score_pair, DedupConfig};
+///
+/// let a = Event::new(
+///     Location::new(40.7128, -74.0060),
+///     Timestamp::parse("2024-01-01T10:00:00Z").unwrap(),
+///     "Fire breaks out downtown",
+/// );
+/// let b = Event::new(
+///     Location::new(40.7130, -74.0062),
+///     Timestamp::parse("2024-01-01T10:05:00Z").unwrap(),
+///     "Fire breaks out in downtown Manhattan",
+/// );
+///
+/// let config = DedupConfig::default();
+/// assert!(score_pair(&a, &b, &config).is_match(&config));
+/// ```
+pub fn score_pair(a: &Event, b: &Event, config: &DedupConfig) -> DuplicateScore {
+    let distance = haversine_distance(
+        a.location.lat,
+        a.location.lon,
+        b.location.lat,
+        b.location.lon,
+    );
+    let spatial = linear_similarity(distance, config.max_distance_m);
+
+    let gap_secs = time_gap_millis(&a.timestamp, &b.timestamp) as f64 / 1000.0;
+    let temporal = linear_similarity(gap_secs, config.max_time_diff_secs);
+
+    let text = text_similarity(&a.text, &b.text);
+
+    let total_weight = config.spatial_weight + config.temporal_weight + config.text_weight;
+    let overall = if total_weight > 0.0 {
+        (spatial * config.spatial_weight
+            + temporal * config.temporal_weight
+            + text * config.text_weight)
+            / total_weight
+    } else {
+        0.0
+    };
+
+    DuplicateScore {
+        overall,
+        spatial,
+        temporal,
+        text,
+    }
+}
+
+/// Compute text similarity as the Jaccard index of lowercased words.
+pub fn text_similarity(a: &str, b: &str) -> f64 {
+    let words1 = words(a);
+    let words2 = words(b);
+
+    if words1.is_empty() && words2.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = words1.intersection(&words2).count();
+    let union = words1.union(&words2).count();
+    intersection as f64 / union as f64
+}
+
+/// Find all pairs of events that look like duplicates.
+pub fn find_duplicates(events: &[Event], config: &DedupConfig) -> Vec<DuplicatePair> {
+    matching_pairs(events, config)
+        .into_iter()
+        .map(|(first, second, score)| DuplicatePair {
+            first: events[first].id.clone(),
+            second: events[second].id.clone(),
+            score,
+        })
+        .collect()
+}
+
+/// Merge duplicate events.
+///
+/// Matched pairs are grouped transitively. Each group keeps its earliest
+/// event in input order, with the sources, tags and metadata of the
+/// other members unioned into it. Existing metadata values win on
+/// conflict.
+pub fn deduplicate(events: Vec<Event>, config: &DedupConfig) -> DedupResult {
+    let pairs = matching_pairs(&events, config);
+    if pairs.is_empty() {
+        return DedupResult {
+            events,
+            merges: Vec::new(),
+        };
+    }
+
+    // Union-find over event positions, with the lowest position as root
+    let mut parent: Vec<usize> = (0..events.len()).collect();
+    let mut min_score = vec![f64::INFINITY; events.len()];
+    for &(first, second, score) in &pairs {
+        let a = find_root(&mut parent, first);
+        let b = find_root(&mut parent, second);
+        let (root, child) = if a < b { (a, b) } else { (b, a) };
+        if root != child {
+            parent[child] = root;
+            min_score[root] = min_score[root].min(min_score[child]);
+        }
+        min_score[root] = min_score[root].min(score.overall);
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..events.len() {
+        let root = find_root(&mut parent, i);
+        if root != i {
+            groups.entry(root).or_default().push(i);
+        }
+    }
+
+    let mut slots: Vec<Option<Event>> = events.into_iter().map(Some).collect();
+    let mut merges = Vec::new();
+    let mut roots: Vec<_> = groups.keys().copied().collect();
+    roots.sort_unstable();
+
+    for root in roots {
+        let mut kept = slots[root].take().expect("group root is unique");
+        let mut collapsed = Vec::new();
+        for &member in &groups[&root] {
+            let other = slots[member].take().expect("group member is unique");
+            collapsed.push(other.id.clone());
+            absorb(&mut kept, other);
+        }
+        merges.push(MergeRecord {
+            kept: kept.id.clone(),
+            collapsed,
+            min_score: min_score[root],
+        });
+        slots[root] = Some(kept);
+    }
+
+    DedupResult {
+        events: slots.into_iter().flatten().collect(),
+        merges,
+    }
+}
+
+/// Merge another narrative into a target, collapsing duplicate events.
+///
+/// Unlike [`Narrative::merge`], which simply appends, this resolves
+/// events that describe the same occurrence and returns the audit
+/// records of what was collapsed. The other narrative's sections and
+/// telling order are carried over, and references to collapsed events
+/// point at the event that was kept.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, SourceRef, Timestamp};
+/// use spatial_narrative::analysis::{merge_narratives, DedupConfig};
+///
+/// let ts = Timestamp::parse("2024-01-01T10:00:00Z").unwrap();
+/// let mut wire = Narrative::new("Wire feed");
+/// let mut a = Event::new(Location::new(51.5074, -0.1278), ts.clone(), "Bridge closed after crash");
+/// a.add_source(SourceRef::article("https://wire.example.com/1"));
+/// wire.add_event(a);
+///
+/// let mut local = Narrative::new("Local news");
+/// let mut b = Event::new(Location::new(51.5075, -0.1279), ts, "Bridge closed after crash");
+/// b.add_source(SourceRef::article("https://local.example.com/2"));
+/// local.add_event(b);
+///
+/// let merges = merge_narratives(&mut wire, local, &DedupConfig::default());
+/// assert_eq!(wire.len(), 1);
+/// assert_eq!(wire.events()[0].sources.len(), 2);
+/// assert_eq!(merges[0].collapsed.len(), 1);
+/// ```
+pub fn merge_narratives(
+    target: &mut Narrative,
+    other: Narrative,
+    config: &DedupConfig,
+) -> Vec<MergeRecord> {
+    // Tell the target's events first, then the other's, as Narrative::merge does
+    let discourse: Vec<EventId> = if target.discourse.is_empty() && other.discourse.is_empty() {
+        Vec::new()
+    } else {
+        let mut ids: Vec<EventId> = target.discourse_ids().into_iter().cloned().collect();
+        ids.extend(other.discourse_ids().into_iter().cloned());
+        ids
+    };
+
+    let mut events = std::mem::take(&mut target.events);
+    events.extend(other.events);
+    target.actors.merge(other.actors);
+    target.sections.extend(other.sections);
+
+    let result = deduplicate(events, config);
+    target.events = result.events;
+
+    // Point section and discourse references at the events that were kept
+    let duplicates: HashMap<EventId, EventId> = result
+        .merges
+        .iter()
+        .flat_map(|m| m.collapsed.iter().map(|c| (c.clone(), m.kept.clone())))
+        .collect();
+    for section in &mut target.sections {
+        section.remap_events(&duplicates);
+    }
+    let mut told = HashSet::new();
+    target.discourse = discourse
+        .into_iter()
+        .map(|id| duplicates.get(&id).cloned().unwrap_or(id))
+        .filter(|id| told.insert(id.clone()))
+        .collect();
+    target.metadata.modified = Some(Timestamp::now());
+    result.merges
+}
+
+/// Positions of matching pairs, each as (lower, higher, score).
+fn matching_pairs(events: &[Event], config: &DedupConfig) -> Vec<(usize, usize, DuplicateScore)> {
+    // Sort by time so only pairs inside the time window are compared
+    let mut order: Vec<usize> = (0..events.len()).collect();
+    order.sort_by_key(|&i| events[i].timestamp.earliest().to_unix_millis());
+
+    let window_ms = (config.max_time_diff_secs * 1000.0) as i64;
+    let mut pairs = Vec::new();
+
+    for (pos, &i) in order.iter().enumerate() {
+        let latest_i = events[i].timestamp.latest().to_unix_millis();
+        for &j in &order[pos + 1..] {
+            if events[j].timestamp.earliest().to_unix_millis() - latest_i > window_ms {
+                break;
+            }
+            let score = score_pair(&events[i], &events[j], config);
+            if score.is_match(config) {
+                pairs.push((i.min(j), i.max(j), score));
+            }
+        }
+    }
+
+    pairs
+}
+
+fn absorb(kept: &mut Event, other: Event) {
+    for source in other.sources {
+        if !kept.sources.contains(&source) {
+            kept.sources.push(source);
+        }
+    }
+    for tag in other.tags {
+        kept.add_tag(tag);
+    }
+    for actor in other.actors {
+        kept.add_actor(actor);
+    }
+    for (key, value) in other.metadata {
+        kept.metadata.entry(key).or_insert(value);
+    }
+}
+
+fn find_root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// Similarity falling linearly from 1 at zero to 0 at the limit.
+fn linear_similarity(value: f64, limit: f64) -> f64 {
+    if value > limit {
+        0.0
+    } else if limit > 0.0 {
+        (1.0 - value / limit).max(f64::MIN_POSITIVE)
+    } else {
+        1.0
+    }
+}
+
+/// Gap between the implied ranges of two timestamps, zero if they overlap.
+fn time_gap_millis(a: &Timestamp, b: &Timestamp) -> i64 {
+    let start = a
+        .earliest()
+        .to_unix_millis()
+        .max(b.earliest().to_unix_millis());
+    let end = a.latest().to_unix_millis().min(b.latest().to_unix_millis());
+    (start - end).max(0)
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, MetadataValue, Section, SourceRef};
+
+    fn event(lat: f64, lon: f64, time: &str, text: &str) -> Event {
+        Event::new(
+            Location::new(lat, lon),
+            Timestamp::parse(time).unwrap(),
+            text,
+        )
+    }
+
+    #[test]
+    fn test_score_pair() {
+        let config = DedupConfig::default();
+        let a = event(40.0, -74.0, "2024-01-01T10:00:00Z", "Flood on Main Street");
+
+        let same = event(40.0, -74.0, "2024-01-01T10:00:00Z", "flood on main street");
+        let score = score_pair(&a, &same, &config);
+        assert!((score.overall - 1.0).abs() < 1e-9);
+
+        let far = event(41.0, -74.0, "2024-01-01T10:00:00Z", "Flood on Main Street");
+        let score = score_pair(&a, &far, &config);
+        assert_eq!(score.spatial, 0.0);
+        assert!(!score.is_match(&config));
+
+        let later = event(40.0, -74.0, "2024-01-02T10:00:00Z", "Flood on Main Street");
+        assert!(!score_pair(&a, &later, &config).is_match(&config));
+    }
+
+    #[test]
+    fn test_score_pair_coarse_precision() {
+        // A day-precision report overlaps the exact time, so there is no gap
+        let config = DedupConfig::default();
+        let a = event(40.0, -74.0, "2024-01-01T18:00:00Z", "Flood");
+        let b = event(40.0, -74.0, "2024-01-01", "Flood");
+        assert_eq!(score_pair(&a, &b, &config).temporal, 1.0);
+    }
+
+    #[test]
+    fn test_text_similarity() {
+        assert_eq!(text_similarity("a b", "A, B!"), 1.0);
+        assert_eq!(text_similarity("a b", "c d"), 0.0);
+        assert!((text_similarity("a b c", "a b d") - 0.5).abs() < 1e-9);
+        assert_eq!(text_similarity("", ""), 0.0);
+    }
+
+    #[test]
+    fn test_deduplicate_transitive() {
+        let config = DedupConfig::default();
+        let mut a = event(40.0, -74.0, "2024-01-01T10:00:00Z", "Protest at city hall");
+        a.add_tag("protest");
+        a.set_metadata("reporter", "wire");
+        a.add_source(SourceRef::article("https://a.example.com"));
+
+        let mut b = event(
+            40.0001,
+            -74.0,
+            "2024-01-01T10:10:00Z",
+            "Protest at city hall",
+        );
+        b.add_tag("Politics");
+        b.set_metadata("reporter", "local");
+        b.set_metadata("crowd", 500);
+        b.add_source(SourceRef::article("https://a.example.com"));
+        b.add_source(SourceRef::article("https://b.example.com"));
+
+        let c = event(
+            40.0002,
+            -74.0,
+            "2024-01-01T10:20:00Z",
+            "Protest at city hall",
+        );
+        let unrelated = event(10.0, 10.0, "2024-01-01T10:00:00Z", "Earthquake");
+
+        let ids = [a.id.clone(), b.id.clone(), c.id.clone()];
+        let result = deduplicate(vec![a, unrelated.clone(), b, c], &config);
+
+        assert_eq!(result.events.len(), 2);
+        assert_eq!(result.removed_count(), 2);
+        assert_eq!(result.events[1].id, unrelated.id);
+
+        let merged = &result.events[0];
+        assert_eq!(merged.id, ids[0]);
+        assert_eq!(merged.sources.len(), 2);
+        assert_eq!(merged.tags, vec!["protest", "Politics"]);
+        assert_eq!(
+            merged
+                .get_metadata("reporter")
+                .and_then(MetadataValue::as_str),
+            Some("wire")
+        );
+        assert_eq!(merged.metadata_f64("crowd"), Some(500.0));
+
+        assert_eq!(result.merges.len(), 1);
+        assert_eq!(result.merges[0].kept, ids[0]);
+        assert_eq!(
+            result.merges[0].collapsed,
+            vec![ids[1].clone(), ids[2].clone()]
+        );
+        assert!(result.merges[0].min_score >= config.match_threshold);
+    }
+
+    #[test]
+    fn test_merge_narratives() {
+        let mut n1 = Narrative::new("One");
+        n1.add_event(event(0.0, 0.0, "2024-01-01T00:00:00Z", "Launch"));
+        let mut n2 = Narrative::new("Two");
+        n2.add_event(event(0.0, 0.0, "2024-01-01T00:01:00Z", "Launch"));
+        n2.add_event(event(0.0, 0.0, "2024-03-01T00:00:00Z", "Landing"));
+
+        let merges = merge_narratives(&mut n1, n2, &DedupConfig::default());
+        assert_eq!(n1.len(), 2);
+        assert_eq!(merges.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_narratives_sections() {
+        let launch = event(0.0, 0.0, "2024-01-01T00:00:00Z", "Launch");
+        let mut n1 = Narrative::new("One");
+        n1.add_section(Section::new("Wire").with_event(launch.id.clone()));
+        n1.add_event(launch.clone());
+
+        let repeat = event(0.0, 0.0, "2024-01-01T00:01:00Z", "Launch");
+        let landing = event(0.0, 0.0, "2024-03-01T00:00:00Z", "Landing");
+        let mut n2 = Narrative::new("Two");
+        n2.add_section(Section::new("Local").with_events([repeat.id.clone(), landing.id.clone()]));
+        n2.add_event(repeat);
+        n2.add_event(landing.clone());
+
+        let merges = merge_narratives(&mut n1, n2, &DedupConfig::default());
+        assert_eq!(merges[0].kept, launch.id);
+        assert_eq!(n1.sections.len(), 2);
+        assert_eq!(n1.sections[0].events, vec![launch.id.clone()]);
+        assert_eq!(n1.sections[1].events, vec![launch.id, landing.id]);
+    }
+
+    #[test]
+    fn test_merge_narratives_discourse() {
+        let launch = event(0.0, 0.0, "2024-01-01T00:00:00Z", "Launch");
+        let orbit = event(0.0, 0.0, "2024-02-01T00:00:00Z", "Orbit");
+        let mut n1 = Narrative::new("One");
+        n1.add_event(launch.clone());
+        n1.add_event(orbit.clone());
+        n1.set_discourse_order([orbit.id.clone(), launch.id.clone()])
+            .unwrap();
+
+        let landing = event(0.0, 0.0, "2024-03-01T00:00:00Z", "Landing");
+        let repeat = event(0.0, 0.0, "2024-01-01T00:01:00Z", "Launch");
+        let mut n2 = Narrative::new("Two");
+        n2.add_event(repeat.clone());
+        n2.add_event(landing.clone());
+        n2.set_discourse_order([landing.id.clone(), repeat.id.clone()])
+            .unwrap();
+
+        merge_narratives(&mut n1, n2, &DedupConfig::default());
+        assert_eq!(n1.discourse, vec![orbit.id, launch.id, landing.id]);
+        assert!(!n1.discourse.contains(&repeat.id));
+    }
+
+    #[test]
+    fn test_merge_record_serialization() {
+        let record = MergeRecord {
+            kept: EventId::new(),
+            collapsed: vec![EventId::new()],
+            min_score: 0.9,
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: MergeRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(record, parsed);
+    }
+}
@@ -8,9 +8,11 @@
 //!
 //! - **Spatial Metrics** - Geographic extent, distance, dispersion ([`SpatialMetrics`])
 //! - **Temporal Metrics** - Duration, event rate, gaps, bursts ([`TemporalMetrics`])
+//! - **Discourse** - Flashbacks, flash-forwards and non-linearity ([`DiscourseMetrics`])
 //! - **Movement** - Trajectory extraction and analysis ([`Trajectory`], [`detect_stops`])
 //! - **Clustering** - DBSCAN, k-means clustering ([`DBSCAN`], [`KMeans`])
 //! - **Comparison** - Narrative similarity and comparison ([`compare_narratives`])
+//! - **Deduplication** - Duplicate detection and merging ([`deduplicate`], [`merge_narratives`])
 //!
 //! # Examples
 //!
@@ -48,6 +50,7 @@
 
 mod clustering;
 mod comparison;
+mod dedup;
 mod movement;
 mod spatial_metrics;
 mod temporal_metrics;
@@ -58,10 +61,17 @@ pub use comparison::{
     common_locations, compare_narratives, spatial_intersection, spatial_similarity, spatial_union,
     temporal_similarity, thematic_similarity, ComparisonConfig, NarrativeSimilarity,
 };
+pub use dedup::{
+    deduplicate, find_duplicates, merge_narratives, score_pair, text_similarity, DedupConfig,
+    DedupResult, DuplicatePair, DuplicateScore, MergeRecord,
+};
 pub use movement::{detect_stops, MovementAnalyzer, Stop, StopThreshold, Trajectory};
 pub use spatial_metrics::{
-    bearing, density_map, destination_point, haversine_distance, DensityCell, SpatialMetrics,
+    bearing, density_map, density_map_weighted, destination_point, distance_3d, haversine_distance,
+    rollup_by_admin, rollup_by_admin_weighted, rollup_by_cell, rollup_by_cell_weighted,
+    AdminRollup, CellRollup, DensityCell, ElevationStats, SpatialMetrics,
 };
 pub use temporal_metrics::{
-    detect_bursts, detect_gaps, event_rate, TemporalMetrics, TimeBin, TimeBinCount,
+    detect_bursts, detect_gaps, event_rate, event_rate_weighted, Anachrony, AnachronyKind,
+    DiscourseMetrics, TemporalMetrics, TimeBin, TimeBinCount,
 };
@@ -3,8 +3,9 @@
 //! Provides tools for analyzing movement patterns including
 //! trajectory extraction, velocity profiles, and stop detection.
 
-use crate::analysis::haversine_distance;
-use crate::core::{Event, GeoBounds, Location, TimeRange, Timestamp};
+use crate::analysis::spatial_metrics::elevation_change;
+use crate::analysis::{distance_3d, haversine_distance};
+use crate::core::{ActorId, Event, GeoBounds, Location, Narrative, TimeRange, Timestamp};
 
 /// A trajectory representing movement through space and time.
 #[derive(Debug, Clone)]
@@ -29,6 +30,24 @@ impl Trajectory {
         }
     }
 
+    /// Create the trajectory of one actor through a narrative.
+    ///
+    /// The trajectory is named after the actor when it is in the
+    /// narrative's registry, and after its ID otherwise.
+    pub fn for_actor(narrative: &Narrative, actor: &ActorId) -> Self {
+        let id = narrative
+            .actors
+            .get(actor)
+            .map(|a| a.name.clone())
+            .unwrap_or_else(|| actor.to_string());
+        let events = narrative
+            .events_involving(actor)
+            .into_iter()
+            .cloned()
+            .collect();
+        Self::new(id, events)
+    }
+
     /// Get the events in this trajectory.
     pub fn events(&self) -> &[Event] {
         &self.events
@@ -58,23 +77,7 @@ impl Trajectory {
 
     /// Get the geographic bounds of this trajectory.
     pub fn bounds(&self) -> Option<GeoBounds> {
-        if self.events.is_empty() {
-            return None;
-        }
-
-        let mut min_lat = f64::MAX;
-        let mut max_lat = f64::MIN;
-        let mut min_lon = f64::MAX;
-        let mut max_lon = f64::MIN;
-
-        for event in &self.events {
-            min_lat = min_lat.min(event.location.lat);
-            max_lat = max_lat.max(event.location.lat);
-            min_lon = min_lon.min(event.location.lon);
-            max_lon = max_lon.max(event.location.lon);
-        }
-
-        Some(GeoBounds::new(min_lat, max_lat, min_lon, max_lon))
+        GeoBounds::from_locations(self.events.iter().map(|e| &e.location))
     }
 
     /// Get total distance traveled in meters.
@@ -96,6 +99,76 @@ impl Trajectory {
             .sum()
     }
 
+    /// Get total distance traveled in meters, including elevation change.
+    ///
+    /// Segments where either end has no elevation count their horizontal
+    /// distance only.
+    pub fn total_distance_3d(&self) -> f64 {
+        self.events
+            .windows(2)
+            .map(|w| distance_3d(&w[0].location, &w[1].location))
+            .sum()
+    }
+
+    /// Get total climb in meters.
+    ///
+    /// Events without an elevation are skipped.
+    pub fn elevation_gain(&self) -> f64 {
+        elevation_change(self.events.iter().map(|e| e.location.elevation)).0
+    }
+
+    /// Get total descent in meters, as a positive number.
+    ///
+    /// Events without an elevation are skipped.
+    pub fn elevation_loss(&self) -> f64 {
+        elevation_change(self.events.iter().map(|e| e.location.elevation)).1
+    }
+
+    /// Compute the elevation profile.
+    ///
+    /// Returns tuples of (horizontal distance along the trajectory in
+    /// meters, elevation in meters) for each event with an elevation.
+    pub fn elevation_profile(&self) -> Vec<(f64, f64)> {
+        let mut along = 0.0;
+        let mut profile = Vec::new();
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                let prev = &self.events[i - 1].location;
+                along +=
+                    haversine_distance(prev.lat, prev.lon, event.location.lat, event.location.lon);
+            }
+            if let Some(elevation) = event.location.elevation {
+                profile.push((along, elevation));
+            }
+        }
+        profile
+    }
+
+    /// Compute vertical speed between consecutive events with elevations.
+    ///
+    /// Returns tuples of (timestamp, vertical speed in m/s), positive when
+    /// climbing. Events without an elevation are skipped.
+    pub fn vertical_speed_profile(&self) -> Vec<(Timestamp, f64)> {
+        let points: Vec<(&Timestamp, f64)> = self
+            .events
+            .iter()
+            .filter_map(|e| e.location.elevation.map(|elev| (&e.timestamp, elev)))
+            .collect();
+
+        points
+            .windows(2)
+            .map(|w| {
+                let time_diff = (w[1].0.to_unix_millis() - w[0].0.to_unix_millis()) as f64 / 1000.0;
+                let speed = if time_diff > 0.0 {
+                    (w[1].1 - w[0].1) / time_diff
+                } else {
+                    0.0
+                };
+                (w[0].0.clone(), speed)
+            })
+            .collect()
+    }
+
     /// Get total duration in seconds.
     pub fn duration_secs(&self) -> f64 {
         if self.events.len() < 2 {
@@ -122,6 +195,20 @@ impl Trajectory {
     ///
     /// Returns tuples of (timestamp, speed in m/s).
     pub fn velocity_profile(&self) -> Vec<(Timestamp, f64)> {
+        self.segment_speeds(|a, b| haversine_distance(a.lat, a.lon, b.lat, b.lon))
+    }
+
+    /// Compute velocity profile including elevation change.
+    ///
+    /// Returns tuples of (timestamp, speed in m/s) using [`distance_3d`].
+    pub fn velocity_profile_3d(&self) -> Vec<(Timestamp, f64)> {
+        self.segment_speeds(distance_3d)
+    }
+
+    fn segment_speeds<F>(&self, distance: F) -> Vec<(Timestamp, f64)>
+    where
+        F: Fn(&Location, &Location) -> f64,
+    {
         if self.events.len() < 2 {
             return Vec::new();
         }
@@ -129,12 +216,7 @@ impl Trajectory {
         self.events
             .windows(2)
             .map(|w| {
-                let dist = haversine_distance(
-                    w[0].location.lat,
-                    w[0].location.lon,
-                    w[1].location.lat,
-                    w[1].location.lon,
-                );
+                let dist = distance(&w[0].location, &w[1].location);
                 let time_diff = (w[1].timestamp.to_unix_millis() - w[0].timestamp.to_unix_millis())
                     as f64
                     / 1000.0;
@@ -478,6 +560,34 @@ mod tests {
         assert_eq!(traj.len(), 2);
         assert!(traj.total_distance() > 0.0);
         assert_eq!(traj.duration_secs(), 3600.0);
+        assert_eq!(
+            traj.bounds(),
+            Some(GeoBounds::new(40.0, -74.0, 41.0, -73.0))
+        );
+    }
+
+    #[test]
+    fn test_trajectory_for_actor() {
+        use crate::core::{Actor, ActorKind, NarrativeBuilder};
+
+        let scott = Actor::new("Robert Falcon Scott", ActorKind::Person);
+        let id = scott.id.clone();
+        let mut depot = make_event(-77.6, 166.4, "1911-01-24T00:00:00Z");
+        depot.add_actor(id.clone());
+        let mut pole = make_event(-90.0, 0.0, "1912-01-17T00:00:00Z");
+        pole.add_actor(id.clone());
+
+        let narrative = NarrativeBuilder::new()
+            .actor(scott)
+            .event(pole)
+            .event(make_event(-78.0, 167.0, "1911-02-01T00:00:00Z"))
+            .event(depot)
+            .build();
+
+        let traj = Trajectory::for_actor(&narrative, &id);
+        assert_eq!(traj.id, "Robert Falcon Scott");
+        assert_eq!(traj.len(), 2);
+        assert_eq!(traj.events()[1].location.lat, -90.0);
     }
 
     #[test]
@@ -494,6 +604,43 @@ mod tests {
         assert!(profile[0].1 > 0.0);
     }
 
+    #[test]
+    fn test_elevation_profile() {
+        let at = |lat: f64, elevation: Option<f64>, time: &str| {
+            let mut event = make_event(lat, 86.925, time);
+            event.location.elevation = elevation;
+            event
+        };
+        let traj = Trajectory::new(
+            "ascent",
+            vec![
+                at(27.98, Some(5364.0), "2024-05-20T00:00:00Z"),
+                at(27.99, None, "2024-05-20T03:00:00Z"),
+                at(28.00, Some(6400.0), "2024-05-20T06:00:00Z"),
+                at(28.01, Some(6100.0), "2024-05-20T08:00:00Z"),
+            ],
+        );
+
+        assert_eq!(traj.elevation_gain(), 1036.0);
+        assert_eq!(traj.elevation_loss(), 300.0);
+        assert!(traj.total_distance_3d() > traj.total_distance());
+
+        let profile = traj.elevation_profile();
+        assert_eq!(profile.len(), 3);
+        assert_eq!(profile[0], (0.0, 5364.0));
+        assert!(profile[1].0 > 2000.0 && profile[2].0 > profile[1].0);
+
+        let vertical = traj.vertical_speed_profile();
+        assert_eq!(vertical.len(), 2);
+        assert!((vertical[0].1 - 1036.0 / 21_600.0).abs() < 1e-9);
+        assert!(vertical[1].1 < 0.0);
+
+        let speeds_3d = traj.velocity_profile_3d();
+        let speeds = traj.velocity_profile();
+        assert!(speeds_3d[2].1 > speeds[2].1);
+        assert_eq!(speeds_3d[0].1, speeds[0].1);
+    }
+
     #[test]
     fn test_detect_stops() {
         let events = vec![
@@ -3,7 +3,11 @@
 //! Provides tools for computing geographic extent, distances,
 //! dispersion, and density of events in a narrative.
 
-use crate::core::{Event, GeoBounds, Location};
+use std::collections::HashMap;
+
+use crate::core::{AdminLevel, Event, GeoBounds, Location, PlaceHierarchy};
+use crate::error::Result;
+use crate::index::{Cell, CellSystem};
 
 /// Spatial metrics computed from a collection of events.
 #[derive(Debug, Clone)]
@@ -24,6 +28,36 @@ pub struct SpatialMetrics {
     pub dispersion: f64,
     /// Convex hull area (in square meters), if computable.
     pub area: Option<f64>,
+    /// Total distance between consecutive events including elevation change (in meters).
+    ///
+    /// Equal to `total_distance` when no consecutive pair has elevations.
+    pub total_distance_3d: f64,
+    /// Elevation statistics, if any event has an elevation.
+    pub elevation: Option<ElevationStats>,
+}
+
+/// Elevation statistics for a collection of locations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationStats {
+    /// Number of locations with an elevation.
+    pub count: usize,
+    /// Lowest elevation (in meters).
+    pub min: f64,
+    /// Highest elevation (in meters).
+    pub max: f64,
+    /// Mean elevation (in meters).
+    pub mean: f64,
+    /// Total climb between consecutive locations with elevations (in meters).
+    pub gain: f64,
+    /// Total descent between consecutive locations with elevations (in meters).
+    pub loss: f64,
+}
+
+impl ElevationStats {
+    /// Difference between the highest and lowest elevation (in meters).
+    pub fn range(&self) -> f64 {
+        self.max - self.min
+    }
 }
 
 impl Default for SpatialMetrics {
@@ -37,6 +71,8 @@ impl Default for SpatialMetrics {
             max_distance: 0.0,
             dispersion: 0.0,
             area: None,
+            total_distance_3d: 0.0,
+            elevation: None,
         }
     }
 }
@@ -70,8 +106,45 @@ impl SpatialMetrics {
         Self::from_locations(&locations)
     }
 
+    /// Compute spatial metrics with a weight per event.
+    ///
+    /// The centroid and dispersion are weighted means; events with a
+    /// weight of zero or less are left out entirely. Weighting by source
+    /// confidence keeps poorly sourced claims from pulling the centre.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::{Event, Location, ReliabilityModel, SourceRef, Timestamp};
+    /// use spatial_narrative::analysis::SpatialMetrics;
+    ///
+    /// let mut sourced = Event::new(Location::new(10.0, 10.0), Timestamp::now(), "Sourced");
+    /// sourced.add_source(SourceRef::report("https://example.org/report"));
+    /// let rumour = Event::new(Location::new(20.0, 20.0), Timestamp::now(), "Rumour");
+    ///
+    /// let model = ReliabilityModel::new();
+    /// let metrics = SpatialMetrics::from_events_weighted(&[sourced, rumour], |e| model.confidence(e));
+    /// assert_eq!(metrics.event_count, 1);
+    /// assert!((metrics.centroid.unwrap().lat - 10.0).abs() < 1e-6);
+    /// ```
+    pub fn from_events_weighted<F>(events: &[Event], weight: F) -> Self
+    where
+        F: Fn(&Event) -> f64,
+    {
+        let (locations, weights): (Vec<&Location>, Vec<f64>) = events
+            .iter()
+            .map(|e| (&e.location, weight(e)))
+            .filter(|(_, w)| *w > 0.0)
+            .unzip();
+        Self::from_weighted_locations(&locations, &weights)
+    }
+
     /// Compute spatial metrics from a slice of locations.
     pub fn from_locations(locations: &[&Location]) -> Self {
+        Self::from_weighted_locations(locations, &vec![1.0; locations.len()])
+    }
+
+    fn from_weighted_locations(locations: &[&Location], weights: &[f64]) -> Self {
         if locations.is_empty() {
             return Self::default();
         }
@@ -82,7 +155,7 @@ impl SpatialMetrics {
         let bounds = Self::compute_bounds(locations);
 
         // Compute centroid
-        let centroid = Self::compute_centroid(locations);
+        let centroid = Self::compute_centroid(locations, weights);
 
         // Compute distances between consecutive locations
         let (total_distance, avg_distance, max_distance) =
@@ -91,7 +164,7 @@ impl SpatialMetrics {
         // Compute dispersion from centroid
         let dispersion = centroid
             .as_ref()
-            .map(|c| Self::compute_dispersion(locations, c))
+            .map(|c| Self::compute_dispersion(locations, weights, c))
             .unwrap_or(0.0);
 
         // Approximate area using bounding box (simplified)
@@ -101,6 +174,8 @@ impl SpatialMetrics {
             width_m * height_m
         });
 
+        let total_distance_3d = locations.windows(2).map(|w| distance_3d(w[0], w[1])).sum();
+
         Self {
             event_count,
             bounds,
@@ -110,30 +185,44 @@ impl SpatialMetrics {
             max_distance,
             dispersion,
             area,
+            total_distance_3d,
+            elevation: Self::compute_elevation_stats(locations, weights),
         }
     }
 
-    fn compute_bounds(locations: &[&Location]) -> Option<GeoBounds> {
-        if locations.is_empty() {
+    fn compute_elevation_stats(locations: &[&Location], weights: &[f64]) -> Option<ElevationStats> {
+        let samples: Vec<(f64, f64)> = locations
+            .iter()
+            .zip(weights)
+            .filter_map(|(l, w)| l.elevation.map(|e| (e, *w)))
+            .collect();
+        if samples.is_empty() {
             return None;
         }
 
-        let mut min_lat = f64::MAX;
-        let mut max_lat = f64::MIN;
-        let mut min_lon = f64::MAX;
-        let mut max_lon = f64::MIN;
-
-        for loc in locations {
-            min_lat = min_lat.min(loc.lat);
-            max_lat = max_lat.max(loc.lat);
-            min_lon = min_lon.min(loc.lon);
-            max_lon = max_lon.max(loc.lon);
-        }
+        let total_weight: f64 = samples.iter().map(|(_, w)| w).sum();
+        let (gain, loss) = elevation_change(locations.iter().map(|l| l.elevation));
+        Some(ElevationStats {
+            count: samples.len(),
+            min: samples
+                .iter()
+                .map(|(e, _)| *e)
+                .fold(f64::INFINITY, f64::min),
+            max: samples
+                .iter()
+                .map(|(e, _)| *e)
+                .fold(f64::NEG_INFINITY, f64::max),
+            mean: samples.iter().map(|(e, w)| e * w).sum::<f64>() / total_weight,
+            gain,
+            loss,
+        })
+    }
 
-        Some(GeoBounds::new(min_lat, max_lat, min_lon, max_lon))
+    fn compute_bounds(locations: &[&Location]) -> Option<GeoBounds> {
+        GeoBounds::from_locations(locations.iter().copied())
     }
 
-    fn compute_centroid(locations: &[&Location]) -> Option<Location> {
+    fn compute_centroid(locations: &[&Location], weights: &[f64]) -> Option<Location> {
         if locations.is_empty() {
             return None;
         }
@@ -143,16 +232,16 @@ impl SpatialMetrics {
         let mut y = 0.0;
         let mut z = 0.0;
 
-        for loc in locations {
+        for (loc, w) in locations.iter().zip(weights) {
             let lat_rad = loc.lat.to_radians();
             let lon_rad = loc.lon.to_radians();
 
-            x += lat_rad.cos() * lon_rad.cos();
-            y += lat_rad.cos() * lon_rad.sin();
-            z += lat_rad.sin();
+            x += w * lat_rad.cos() * lon_rad.cos();
+            y += w * lat_rad.cos() * lon_rad.sin();
+            z += w * lat_rad.sin();
         }
 
-        let n = locations.len() as f64;
+        let n: f64 = weights.iter().sum();
         x /= n;
         y /= n;
         z /= n;
@@ -162,10 +251,13 @@ impl SpatialMetrics {
         let lat = z.atan2(hyp).to_degrees();
 
         // Average elevation if any have it
-        let total_elev: f64 = locations.iter().filter_map(|l| l.elevation).sum();
-        let elev_count = locations.iter().filter(|l| l.elevation.is_some()).count();
-        let elevation = if elev_count > 0 {
-            Some(total_elev / elev_count as f64)
+        let (total_elev, elev_weight) = locations
+            .iter()
+            .zip(weights)
+            .filter_map(|(l, w)| l.elevation.map(|e| (e * w, *w)))
+            .fold((0.0, 0.0), |(te, tw), (e, w)| (te + e, tw + w));
+        let elevation = if elev_weight > 0.0 {
+            Some(total_elev / elev_weight)
         } else {
             None
         };
@@ -176,6 +268,7 @@ impl SpatialMetrics {
             elevation,
             uncertainty_meters: None,
             name: None,
+            hierarchy: None,
         })
     }
 
@@ -198,17 +291,18 @@ impl SpatialMetrics {
         (total, avg, max)
     }
 
-    fn compute_dispersion(locations: &[&Location], centroid: &Location) -> f64 {
+    fn compute_dispersion(locations: &[&Location], weights: &[f64], centroid: &Location) -> f64 {
         if locations.is_empty() {
             return 0.0;
         }
 
         let total_dist: f64 = locations
             .iter()
-            .map(|loc| haversine_distance(loc.lat, loc.lon, centroid.lat, centroid.lon))
+            .zip(weights)
+            .map(|(loc, w)| w * haversine_distance(loc.lat, loc.lon, centroid.lat, centroid.lon))
             .sum();
 
-        total_dist / locations.len() as f64
+        total_dist / weights.iter().sum::<f64>()
     }
 }
 
@@ -239,6 +333,51 @@ pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     EARTH_RADIUS_M * c
 }
 
+/// Compute the distance between two locations in meters, including elevation change.
+///
+/// Combines the Haversine distance with the difference in elevation.
+/// If either location has no elevation, this is the Haversine distance.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::analysis::distance_3d;
+/// use spatial_narrative::core::Location;
+///
+/// let base = Location::with_elevation(27.9881, 86.9250, 5364.0);
+/// let summit = Location::with_elevation(27.9881, 86.9250, 8848.86);
+/// assert!((distance_3d(&base, &summit) - 3484.86).abs() < 1e-6);
+/// ```
+pub fn distance_3d(a: &Location, b: &Location) -> f64 {
+    let horizontal = haversine_distance(a.lat, a.lon, b.lat, b.lon);
+    match (a.elevation, b.elevation) {
+        (Some(ea), Some(eb)) => horizontal.hypot(eb - ea),
+        _ => horizontal,
+    }
+}
+
+/// Sums climb and descent over a sequence of elevations.
+///
+/// Missing elevations are skipped, so each change is measured from the
+/// last known elevation. Returns (gain, loss), both non-negative.
+pub(crate) fn elevation_change(elevations: impl IntoIterator<Item = Option<f64>>) -> (f64, f64) {
+    let mut gain = 0.0;
+    let mut loss = 0.0;
+    let mut last: Option<f64> = None;
+    for elevation in elevations.into_iter().flatten() {
+        if let Some(prev) = last {
+            let delta = elevation - prev;
+            if delta > 0.0 {
+                gain += delta;
+            } else {
+                loss -= delta;
+            }
+        }
+        last = Some(elevation);
+    }
+    (gain, loss)
+}
+
 /// Compute the initial bearing from point 1 to point 2 in degrees.
 ///
 /// Returns a value between 0 and 360 degrees.
@@ -290,15 +429,24 @@ pub struct DensityCell {
     pub lat: f64,
     /// Cell center longitude.
     pub lon: f64,
-    /// Event count in this cell.
+    /// Number of events centred in this cell.
     pub count: usize,
-    /// Density (events per square km).
+    /// Sum of event weights in this cell.
+    ///
+    /// An event with location uncertainty spreads its weight over the
+    /// cells its uncertainty circle covers, so this can differ from
+    /// `count` even when unweighted.
+    pub weight: f64,
+    /// Density (weight per square km).
     pub density: f64,
 }
 
 /// Compute a density map for the given events.
 ///
 /// Divides the bounding box into a grid and counts events per cell.
+/// Events with location uncertainty spread their mass over the cells
+/// their uncertainty circle covers, and the grid grows to cover those
+/// circles.
 ///
 /// # Arguments
 ///
@@ -310,30 +458,93 @@ pub struct DensityCell {
 ///
 /// Vector of density cells, row-major order.
 pub fn density_map(events: &[Event], rows: usize, cols: usize) -> Vec<DensityCell> {
+    density_map_weighted(events, rows, cols, |_| 1.0)
+}
+
+/// Compute a density map where each event contributes a weight.
+///
+/// Useful for weighting by a numeric metadata field, such as casualties
+/// or magnitude. Events without a weight can return 0.0.
+///
+/// An event with location uncertainty contributes its weight spread
+/// evenly over its uncertainty circle, so an event geoparsed to a city
+/// centroid does not pile up in a single cell. `count` still reports
+/// where event centres fall.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Timestamp};
+/// use spatial_narrative::analysis::density_map_weighted;
+///
+/// let mut a = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "A");
+/// a.set_metadata("casualties", 12);
+/// let mut b = Event::new(Location::new(1.0, 1.0), Timestamp::now(), "B");
+/// b.set_metadata("casualties", 3);
+///
+/// let cells = density_map_weighted(&[a, b], 1, 1, |e| {
+///     e.metadata_f64("casualties").unwrap_or(0.0)
+/// });
+/// assert_eq!(cells[0].count, 2);
+/// assert_eq!(cells[0].weight, 15.0);
+/// ```
+pub fn density_map_weighted<F>(
+    events: &[Event],
+    rows: usize,
+    cols: usize,
+    weight: F,
+) -> Vec<DensityCell>
+where
+    F: Fn(&Event) -> f64,
+{
     if events.is_empty() || rows == 0 || cols == 0 {
         return Vec::new();
     }
 
-    let locations: Vec<&Location> = events.iter().map(|e| &e.location).collect();
-    let bounds = match SpatialMetrics::compute_bounds(&locations) {
+    // Spread each event over its uncertainty circle; exact events are a single sample
+    let samples: Vec<Vec<(f64, f64)>> = events
+        .iter()
+        .map(|e| e.location.uncertainty_samples())
+        .collect();
+    let bounds = match samples
+        .iter()
+        .flatten()
+        .copied()
+        .fold(None, |acc, (lat, lon)| {
+            let point = Location::new(lat, lon);
+            match acc {
+                None => Some(GeoBounds::from_corners(&point, &point)),
+                Some(mut b) => {
+                    b.expand_to_include(&point);
+                    Some(b)
+                },
+            }
+        }) {
         Some(b) => b,
         None => return Vec::new(),
     };
 
     let lat_step = (bounds.max_lat - bounds.min_lat) / rows as f64;
-    let lon_step = (bounds.max_lon - bounds.min_lon) / cols as f64;
+    let lon_step = bounds.width() / cols as f64;
+    let cell_of = |lat: f64, lon: f64| {
+        let row = ((lat - bounds.min_lat) / lat_step).floor() as usize;
+        let col = (bounds.lon_offset(lon) / lon_step).floor() as usize;
+        (row.min(rows - 1), col.min(cols - 1))
+    };
 
-    // Count events per cell
+    // Count events and sum weights per cell
     let mut counts = vec![vec![0usize; cols]; rows];
+    let mut weights = vec![vec![0.0f64; cols]; rows];
 
-    for loc in &locations {
-        let row = ((loc.lat - bounds.min_lat) / lat_step).floor() as usize;
-        let col = ((loc.lon - bounds.min_lon) / lon_step).floor() as usize;
-
-        let row = row.min(rows - 1);
-        let col = col.min(cols - 1);
-
+    for (event, event_samples) in events.iter().zip(&samples) {
+        let (row, col) = cell_of(event.location.lat, event.location.lon);
         counts[row][col] += 1;
+
+        let share = weight(event) / event_samples.len() as f64;
+        for &(lat, lon) in event_samples {
+            let (row, col) = cell_of(lat, lon);
+            weights[row][col] += share;
+        }
     }
 
     // Compute cell area in square km (approximate)
@@ -342,7 +553,11 @@ pub fn density_map(events: &[Event], rows: usize, cols: usize) -> Vec<DensityCel
     for (row, count_row) in counts.iter().enumerate() {
         for (col, &count) in count_row.iter().enumerate() {
             let cell_lat = bounds.min_lat + (row as f64 + 0.5) * lat_step;
-            let cell_lon = bounds.min_lon + (col as f64 + 0.5) * lon_step;
+            let mut cell_lon = bounds.min_lon + (col as f64 + 0.5) * lon_step;
+            if cell_lon > 180.0 {
+                // Grids over bounds that wrap continue past the antimeridian
+                cell_lon -= 360.0;
+            }
 
             // Approximate cell area
             let width_m = haversine_distance(
@@ -359,8 +574,9 @@ pub fn density_map(events: &[Event], rows: usize, cols: usize) -> Vec<DensityCel
             );
             let area_km2 = (width_m * height_m) / 1_000_000.0;
 
+            let cell_weight = weights[row][col];
             let density = if area_km2 > 0.0 {
-                count as f64 / area_km2
+                cell_weight / area_km2
             } else {
                 0.0
             };
@@ -369,6 +585,7 @@ pub fn density_map(events: &[Event], rows: usize, cols: usize) -> Vec<DensityCel
                 lat: cell_lat,
                 lon: cell_lon,
                 count,
+                weight: cell_weight,
                 density,
             });
         }
@@ -377,6 +594,165 @@ pub fn density_map(events: &[Event], rows: usize, cols: usize) -> Vec<DensityCel
     cells
 }
 
+/// Event totals for one administrative unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdminRollup {
+    /// The unit, keeping only the roll-up level and the levels above it.
+    pub place: PlaceHierarchy,
+    /// Event count in this unit.
+    pub count: usize,
+    /// Sum of event weights in this unit (equal to `count` when unweighted).
+    pub weight: f64,
+}
+
+/// Count events per administrative unit, such as per country or province.
+///
+/// The place-based counterpart of [`density_map`]. Units are keyed by
+/// their full path, so provinces of the same name in different countries
+/// stay separate. Events whose location has no unit at `level` are skipped.
+/// Results are sorted by count, largest first.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::analysis::rollup_by_admin;
+/// use spatial_narrative::core::{AdminLevel, Event, Location, PlaceHierarchy, Timestamp};
+///
+/// let at = |admin1: &str| {
+///     let place = PlaceHierarchy::new().with_country("Canada").with_admin1(admin1);
+///     Event::new(Location::new(45.0, -75.0).with_hierarchy(place), Timestamp::now(), "")
+/// };
+/// let events = [at("Ontario"), at("Ontario"), at("Quebec")];
+///
+/// let countries = rollup_by_admin(&events, AdminLevel::Country);
+/// assert_eq!(countries.len(), 1);
+/// assert_eq!(countries[0].count, 3);
+///
+/// let provinces = rollup_by_admin(&events, AdminLevel::Admin1);
+/// assert_eq!(provinces[0].place.admin1.as_deref(), Some("Ontario"));
+/// ```
+pub fn rollup_by_admin(events: &[Event], level: AdminLevel) -> Vec<AdminRollup> {
+    rollup_by_admin_weighted(events, level, |_| 1.0)
+}
+
+/// Sum event weights per administrative unit.
+///
+/// Like [`rollup_by_admin`], but each event contributes a weight.
+/// Results are sorted by total weight, largest first.
+pub fn rollup_by_admin_weighted<F>(
+    events: &[Event],
+    level: AdminLevel,
+    weight: F,
+) -> Vec<AdminRollup>
+where
+    F: Fn(&Event) -> f64,
+{
+    let mut units: HashMap<PlaceHierarchy, (usize, f64)> = HashMap::new();
+    for event in events {
+        let Some(hierarchy) = &event.location.hierarchy else {
+            continue;
+        };
+        if hierarchy.get(level).is_none() {
+            continue;
+        }
+        let totals = units.entry(hierarchy.truncate(level)).or_default();
+        totals.0 += 1;
+        totals.1 += weight(event);
+    }
+
+    let mut rollups: Vec<AdminRollup> = units
+        .into_iter()
+        .map(|(place, (count, weight))| AdminRollup {
+            place,
+            count,
+            weight,
+        })
+        .collect();
+    rollups.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then(b.count.cmp(&a.count))
+            .then_with(|| a.place.cmp(&b.place))
+    });
+    rollups
+}
+
+/// Event totals for one grid cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellRollup {
+    /// The cell.
+    pub cell: Cell,
+    /// Event count in this cell.
+    pub count: usize,
+    /// Sum of event weights in this cell (equal to `count` when unweighted).
+    pub weight: f64,
+}
+
+/// Count events per cell of a hierarchical grid, such as per geohash.
+///
+/// Unlike [`density_map`], cells do not depend on the events' extent, so
+/// counts from different datasets line up cell for cell. Fails if the
+/// level is out of range for the system. Results are sorted by count,
+/// largest first.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::analysis::rollup_by_cell;
+/// use spatial_narrative::core::{Event, Location, Timestamp};
+/// use spatial_narrative::index::CellSystem;
+///
+/// let at = |lat, lon| Event::new(Location::new(lat, lon), Timestamp::now(), "");
+/// let events = [at(48.8566, 2.3522), at(48.8600, 2.3400), at(51.5074, -0.1278)];
+///
+/// let cells = rollup_by_cell(&events, CellSystem::Geohash, 4).unwrap();
+/// assert_eq!(cells.len(), 2);
+/// assert_eq!(cells[0].cell.id(), "u09t");
+/// assert_eq!(cells[0].count, 2);
+/// ```
+pub fn rollup_by_cell(events: &[Event], system: CellSystem, level: u8) -> Result<Vec<CellRollup>> {
+    rollup_by_cell_weighted(events, system, level, |_| 1.0)
+}
+
+/// Sum event weights per cell of a hierarchical grid.
+///
+/// Like [`rollup_by_cell`], but each event contributes a weight.
+/// Results are sorted by total weight, largest first.
+pub fn rollup_by_cell_weighted<F>(
+    events: &[Event],
+    system: CellSystem,
+    level: u8,
+    weight: F,
+) -> Result<Vec<CellRollup>>
+where
+    F: Fn(&Event) -> f64,
+{
+    let mut cells: HashMap<Cell, (usize, f64)> = HashMap::new();
+    for event in events {
+        let totals = cells
+            .entry(system.cell(&event.location, level)?)
+            .or_default();
+        totals.0 += 1;
+        totals.1 += weight(event);
+    }
+
+    let mut rollups: Vec<CellRollup> = cells
+        .into_iter()
+        .map(|(cell, (count, weight))| CellRollup {
+            cell,
+            count,
+            weight,
+        })
+        .collect();
+    rollups.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then(b.count.cmp(&a.count))
+            .then_with(|| a.cell.cmp(&b.cell))
+    });
+    Ok(rollups)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +762,31 @@ mod tests {
         Event::new(Location::new(lat, lon), Timestamp::now(), "test")
     }
 
+    #[test]
+    fn test_weighted_metrics() {
+        let events = vec![
+            make_event(0.0, 0.0),
+            make_event(0.0, 1.0),
+            make_event(5.0, 5.0),
+        ];
+
+        let unit = SpatialMetrics::from_events_weighted(&events, |_| 1.0);
+        let plain = SpatialMetrics::from_events(&events);
+        assert!((unit.dispersion - plain.dispersion).abs() < 1e-6);
+
+        // Heavily weighting the first event pulls the centroid towards it
+        let weighted = SpatialMetrics::from_events_weighted(&events, |e| {
+            if e.location.lon == 0.0 {
+                8.0
+            } else {
+                1.0
+            }
+        });
+        let centroid = weighted.centroid.unwrap();
+        assert!(centroid.lat < plain.centroid.unwrap().lat);
+        assert_eq!(weighted.event_count, 3);
+    }
+
     #[test]
     fn test_haversine_distance() {
         // NYC to LA
@@ -444,6 +845,11 @@ mod tests {
         let centroid = metrics.centroid.unwrap();
         assert!((centroid.lat - 40.0).abs() < 1.0);
         assert!((centroid.lon - (-74.0)).abs() < 1.0);
+
+        assert_eq!(
+            metrics.bounds,
+            Some(GeoBounds::new(39.0, -75.0, 41.0, -73.0))
+        );
     }
 
     #[test]
@@ -461,4 +867,127 @@ mod tests {
         let total: usize = cells.iter().map(|c| c.count).sum();
         assert_eq!(total, 3);
     }
+
+    #[test]
+    fn test_density_map_spreads_uncertainty() {
+        let mut city = make_event(0.5, 0.5);
+        city.location.uncertainty_meters = Some(50_000.0);
+        let events = vec![make_event(0.0, 0.0), make_event(1.0, 1.0), city];
+
+        let cells = density_map(&events, 4, 4);
+        let total_weight: f64 = cells.iter().map(|c| c.weight).sum();
+        assert!((total_weight - 3.0).abs() < 1e-9);
+        assert_eq!(cells.iter().map(|c| c.count).sum::<usize>(), 3);
+
+        // The centroid's mass covers several cells rather than one
+        let spread = cells
+            .iter()
+            .filter(|c| c.weight > 0.0 && c.weight < 1.0)
+            .count();
+        assert!(spread >= 4);
+    }
+
+    #[test]
+    fn test_rollup_by_admin() {
+        let at = |country: &str, admin1: Option<&str>, deaths: i64| {
+            let mut place = PlaceHierarchy::new().with_country(country);
+            if let Some(admin1) = admin1 {
+                place = place.with_admin1(admin1);
+            }
+            let mut event = make_event(0.0, 0.0);
+            event.location.hierarchy = Some(place);
+            event.set_metadata("deaths", deaths);
+            event
+        };
+        let events = vec![
+            at("United States", Some("Georgia"), 1),
+            at("Georgia", None, 5),
+            at("Georgia", Some("Tbilisi"), 2),
+            make_event(1.0, 1.0),
+        ];
+
+        let countries = rollup_by_admin(&events, AdminLevel::Country);
+        assert_eq!(countries.len(), 2);
+        assert_eq!(countries[0].place.country.as_deref(), Some("Georgia"));
+        assert_eq!(countries[0].count, 2);
+
+        // Same-named units in different countries stay apart
+        let regions = rollup_by_admin(&events, AdminLevel::Admin1);
+        assert_eq!(regions.len(), 2);
+        assert!(regions.iter().all(|r| r.count == 1));
+
+        let weighted = rollup_by_admin_weighted(&events, AdminLevel::Country, |e| {
+            e.metadata_f64("deaths").unwrap_or(0.0)
+        });
+        assert_eq!(weighted[0].weight, 7.0);
+        assert_eq!(weighted[1].weight, 1.0);
+    }
+
+    #[test]
+    fn test_rollup_by_cell() {
+        let events = vec![
+            make_event(10.0, 10.0),
+            make_event(10.0001, 10.0001),
+            make_event(10.3, 10.3),
+            make_event(-30.0, 150.0),
+        ];
+
+        for system in CellSystem::ALL {
+            let fine = rollup_by_cell(&events, system, 6).unwrap();
+            let coarse = rollup_by_cell(&events, system, 4).unwrap();
+            assert!(fine.len() >= coarse.len(), "{system}");
+            assert_eq!(fine.iter().map(|c| c.count).sum::<usize>(), 4);
+            assert!(fine[0].cell.contains(&events[0].location));
+            assert!(fine[0].count >= 2);
+        }
+
+        // One heavy event outweighs the cluster
+        let weighted = rollup_by_cell_weighted(&events, CellSystem::Quadkey, 3, |e| {
+            if e.location.lat < 0.0 {
+                10.0
+            } else {
+                1.0
+            }
+        })
+        .unwrap();
+        assert_eq!(weighted.len(), 2);
+        assert_eq!(weighted[0].count, 1);
+        assert!(rollup_by_cell(&events, CellSystem::Geohash, 0).is_err());
+    }
+
+    #[test]
+    fn test_elevation_metrics() {
+        let events = vec![
+            Event::new(
+                Location::with_elevation(0.0, 0.0, 100.0),
+                Timestamp::now(),
+                "a",
+            ),
+            make_event(0.0, 0.001),
+            Event::new(
+                Location::with_elevation(0.0, 0.002, 400.0),
+                Timestamp::now(),
+                "b",
+            ),
+            Event::new(
+                Location::with_elevation(0.0, 0.003, 250.0),
+                Timestamp::now(),
+                "c",
+            ),
+        ];
+
+        let metrics = SpatialMetrics::from_events(&events);
+        let elevation = metrics.elevation.unwrap();
+        assert_eq!(elevation.count, 3);
+        assert_eq!((elevation.min, elevation.max), (100.0, 400.0));
+        assert_eq!(elevation.range(), 300.0);
+        assert!((elevation.mean - 250.0).abs() < 1e-9);
+        assert_eq!((elevation.gain, elevation.loss), (300.0, 150.0));
+
+        // Only the last pair has elevations at both ends
+        assert!(metrics.total_distance_3d > metrics.total_distance);
+        let flat = SpatialMetrics::from_events(&[make_event(0.0, 0.0), make_event(1.0, 1.0)]);
+        assert!(flat.elevation.is_none());
+        assert_eq!(flat.total_distance_3d, flat.total_distance);
+    }
 }
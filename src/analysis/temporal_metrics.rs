@@ -2,8 +2,12 @@
 //!
 //! Provides tools for analyzing the temporal characteristics
 //! of narratives including duration, event rates, gaps, and clustering.
+//!
+//! Durations, rates and gaps are measured between the instants timestamps
+//! store, so a year-precision date counts as its first instant. Select
+//! events with [`Narrative::filter_temporal`] to account for precision.
 
-use crate::core::{Event, TimeRange, Timestamp};
+use crate::core::{Event, EventId, Narrative, TimeRange, Timestamp};
 use std::collections::HashMap;
 
 /// Temporal metrics computed from a collection of events.
@@ -125,6 +129,214 @@ impl TemporalMetrics {
     }
 }
 
+/// Direction of a departure from chronological order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnachronyKind {
+    /// The event is told after events that happened later.
+    Flashback,
+    /// The event is told before events that happened earlier.
+    FlashForward,
+}
+
+/// An event told out of chronological order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anachrony {
+    /// The displaced event.
+    pub event_id: EventId,
+    /// Position of the event in telling order.
+    pub position: usize,
+    /// Whether the event looks back or ahead.
+    pub kind: AnachronyKind,
+    /// How far, in seconds, the event lies from the main storyline around it.
+    pub reach_secs: f64,
+}
+
+/// Comparison of a narrative's telling order with its chronology.
+///
+/// The main storyline is the longest run of events, in telling order,
+/// whose timestamps never go backwards. Every other event is an
+/// anachrony: a flashback if it happened before the storyline event told
+/// just before it, otherwise a flash-forward.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, Timestamp};
+/// use spatial_narrative::analysis::{AnachronyKind, DiscourseMetrics};
+///
+/// let event = |time: &str, text: &str| {
+///     Event::new(Location::new(0.0, 0.0), Timestamp::parse(time).unwrap(), text)
+/// };
+/// // Told as: arrival, childhood memory, departure
+/// let narrative = Narrative::builder()
+///     .event(event("2024-05-01", "Arrival"))
+///     .event(event("1990-07-01", "Childhood"))
+///     .event(event("2024-05-03", "Departure"))
+///     .build();
+///
+/// let metrics = DiscourseMetrics::from_narrative(&narrative);
+/// assert_eq!(metrics.anachronies.len(), 1);
+/// assert_eq!(metrics.anachronies[0].kind, AnachronyKind::Flashback);
+/// assert!(metrics.non_linearity > 0.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DiscourseMetrics {
+    /// Number of events analyzed.
+    pub event_count: usize,
+    /// Events told out of chronological order, in telling order.
+    pub anachronies: Vec<Anachrony>,
+    /// Share of event pairs told in the opposite order to when they
+    /// happened: 0.0 is strictly chronological, 1.0 fully reversed.
+    pub non_linearity: f64,
+}
+
+impl DiscourseMetrics {
+    /// Analyzes a narrative in its [discourse order](Narrative::events_in_discourse_order).
+    pub fn from_narrative(narrative: &Narrative) -> Self {
+        Self::from_events(&narrative.events_in_discourse_order())
+    }
+
+    /// Analyzes events given in telling order.
+    pub fn from_events(events: &[&Event]) -> Self {
+        let times: Vec<i64> = events
+            .iter()
+            .map(|e| e.timestamp.to_unix_millis())
+            .collect();
+        let n = times.len();
+        if n < 2 {
+            return Self {
+                event_count: n,
+                ..Self::default()
+            };
+        }
+
+        let main = main_storyline(&times);
+        // The first main-storyline event after each position
+        let mut next_main: Vec<Option<usize>> = vec![None; n];
+        for i in (0..n - 1).rev() {
+            next_main[i] = if main[i + 1] {
+                Some(i + 1)
+            } else {
+                next_main[i + 1]
+            };
+        }
+
+        let mut anachronies = Vec::new();
+        let mut prev_main: Option<usize> = None;
+        for i in 0..n {
+            if main[i] {
+                prev_main = Some(i);
+                continue;
+            }
+            let (kind, reach_ms) = match (prev_main, next_main[i]) {
+                (Some(p), _) if times[p] > times[i] => {
+                    (AnachronyKind::Flashback, times[p] - times[i])
+                },
+                (_, Some(q)) => (AnachronyKind::FlashForward, times[i] - times[q]),
+                // Unreachable for a longest storyline; treat as looking back
+                _ => (AnachronyKind::Flashback, 0),
+            };
+            anachronies.push(Anachrony {
+                event_id: events[i].id.clone(),
+                position: i,
+                kind,
+                reach_secs: reach_ms.max(0) as f64 / 1000.0,
+            });
+        }
+
+        let pairs = (n * (n - 1) / 2) as f64;
+        let non_linearity = count_inversions(&mut times.clone()) as f64 / pairs;
+
+        Self {
+            event_count: n,
+            anachronies,
+            non_linearity,
+        }
+    }
+
+    /// Returns true if no event is told out of chronological order.
+    pub fn is_linear(&self) -> bool {
+        self.anachronies.is_empty()
+    }
+
+    /// Returns the flashbacks.
+    pub fn flashbacks(&self) -> impl Iterator<Item = &Anachrony> {
+        self.anachronies
+            .iter()
+            .filter(|a| a.kind == AnachronyKind::Flashback)
+    }
+
+    /// Returns the flash-forwards.
+    pub fn flash_forwards(&self) -> impl Iterator<Item = &Anachrony> {
+        self.anachronies
+            .iter()
+            .filter(|a| a.kind == AnachronyKind::FlashForward)
+    }
+}
+
+/// Marks the longest non-decreasing subsequence of times.
+///
+/// Among equally long subsequences, the one told earliest wins, so an
+/// opening scene anchors the storyline and later digressions are the
+/// anachronies.
+fn main_storyline(times: &[i64]) -> Vec<bool> {
+    let n = times.len();
+
+    // run[i] = length of the longest non-decreasing run starting at i,
+    // found by scanning backwards with patience sorting on negated times
+    let mut run = vec![0; n];
+    let mut tails: Vec<i64> = Vec::new();
+    for i in (0..n).rev() {
+        let v = -times[i];
+        let k = tails.partition_point(|&t| t <= v);
+        run[i] = k + 1;
+        if k == tails.len() {
+            tails.push(v);
+        } else {
+            tails[k] = v;
+        }
+    }
+
+    let mut main = vec![false; n];
+    let mut remaining = tails.len();
+    let mut last: Option<i64> = None;
+    for i in 0..n {
+        if remaining > 0 && run[i] == remaining && last.map_or(true, |t| times[i] >= t) {
+            main[i] = true;
+            last = Some(times[i]);
+            remaining -= 1;
+        }
+    }
+    main
+}
+
+/// Counts strictly inverted pairs by merge sort.
+fn count_inversions(values: &mut [i64]) -> usize {
+    let n = values.len();
+    if n < 2 {
+        return 0;
+    }
+    let mid = n / 2;
+    let mut count = count_inversions(&mut values[..mid]) + count_inversions(&mut values[mid..]);
+
+    let mut merged = Vec::with_capacity(n);
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < n {
+        if values[i] <= values[j] {
+            merged.push(values[i]);
+            i += 1;
+        } else {
+            count += mid - i;
+            merged.push(values[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&values[i..mid]);
+    merged.extend_from_slice(&values[j..]);
+    values.copy_from_slice(&merged);
+    count
+}
+
 /// Time bin for temporal aggregation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TimeBin {
@@ -149,6 +361,8 @@ pub struct TimeBinCount {
     pub end: Timestamp,
     /// Number of events in this bin.
     pub count: usize,
+    /// Sum of event weights in this bin (equal to `count` when unweighted).
+    pub weight: f64,
 }
 
 /// Compute event counts per time bin.
@@ -180,6 +394,34 @@ pub struct TimeBinCount {
 /// assert_eq!(rates[1].count, 1); // 1 event in second hour
 /// ```
 pub fn event_rate(events: &[Event], bin_size: TimeBin) -> Vec<TimeBinCount> {
+    event_rate_weighted(events, bin_size, |_| 1.0)
+}
+
+/// Compute summed event weights per time bin.
+///
+/// Useful for weighting by a numeric metadata field, such as casualties
+/// or magnitude. Events without a weight can return 0.0.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Timestamp};
+/// use spatial_narrative::analysis::{event_rate_weighted, TimeBin};
+///
+/// let mut a = Event::new(Location::new(0.0, 0.0), Timestamp::parse("2024-01-01T10:00:00Z").unwrap(), "A");
+/// a.set_metadata("casualties", 4);
+/// let b = Event::new(Location::new(0.0, 0.0), Timestamp::parse("2024-01-01T10:30:00Z").unwrap(), "B");
+///
+/// let rates = event_rate_weighted(&[a, b], TimeBin::Hour, |e| {
+///     e.metadata_f64("casualties").unwrap_or(0.0)
+/// });
+/// assert_eq!(rates[0].count, 2);
+/// assert_eq!(rates[0].weight, 4.0);
+/// ```
+pub fn event_rate_weighted<F>(events: &[Event], bin_size: TimeBin, weight: F) -> Vec<TimeBinCount>
+where
+    F: Fn(&Event) -> f64,
+{
     if events.is_empty() {
         return Vec::new();
     }
@@ -200,12 +442,14 @@ pub fn event_rate(events: &[Event], bin_size: TimeBin) -> Vec<TimeBinCount> {
     };
 
     // Compute bin counts
-    let mut bins: HashMap<i64, usize> = HashMap::new();
+    let mut bins: HashMap<i64, (usize, f64)> = HashMap::new();
 
     for event in &sorted {
         let ts = event.timestamp.to_unix_millis();
         let bin_start = (ts / bin_millis) * bin_millis;
-        *bins.entry(bin_start).or_insert(0) += 1;
+        let entry = bins.entry(bin_start).or_insert((0, 0.0));
+        entry.0 += 1;
+        entry.1 += weight(event);
     }
 
     // Generate continuous bins from first to last
@@ -216,11 +460,16 @@ pub fn event_rate(events: &[Event], bin_size: TimeBin) -> Vec<TimeBinCount> {
     let mut bin_start = first_bin;
 
     while bin_start <= last_bin {
-        let count = bins.get(&bin_start).copied().unwrap_or(0);
+        let (count, weight) = bins.get(&bin_start).copied().unwrap_or((0, 0.0));
         let start = Timestamp::from_unix_millis(bin_start).unwrap();
         let end = Timestamp::from_unix_millis(bin_start + bin_millis).unwrap();
 
-        result.push(TimeBinCount { start, end, count });
+        result.push(TimeBinCount {
+            start,
+            end,
+            count,
+            weight,
+        });
         bin_start += bin_millis;
     }
 
@@ -351,6 +600,61 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_discourse_linear_and_reversed() {
+        let events: Vec<Event> = ["2024-01-01", "2024-01-02", "2024-01-03", "2024-01-04"]
+            .iter()
+            .map(|t| make_event(t))
+            .collect();
+
+        let told: Vec<&Event> = events.iter().collect();
+        let linear = DiscourseMetrics::from_events(&told);
+        assert!(linear.is_linear());
+        assert_eq!(linear.non_linearity, 0.0);
+
+        let reversed: Vec<&Event> = events.iter().rev().collect();
+        let metrics = DiscourseMetrics::from_events(&reversed);
+        assert_eq!(metrics.non_linearity, 1.0);
+        assert_eq!(metrics.anachronies.len(), 3);
+    }
+
+    #[test]
+    fn test_discourse_flash_forward() {
+        // Told: day 1, day 9 (prophecy), day 2, day 3
+        let events: Vec<Event> = ["2024-01-01", "2024-01-09", "2024-01-02", "2024-01-03"]
+            .iter()
+            .map(|t| make_event(t))
+            .collect();
+        let told: Vec<&Event> = events.iter().collect();
+        let metrics = DiscourseMetrics::from_events(&told);
+
+        assert_eq!(metrics.anachronies.len(), 1);
+        let anachrony = &metrics.anachronies[0];
+        assert_eq!(anachrony.kind, AnachronyKind::FlashForward);
+        assert_eq!(anachrony.position, 1);
+        assert_eq!(anachrony.reach_secs, 7.0 * 86_400.0);
+        assert_eq!(metrics.flash_forwards().count(), 1);
+        assert!((metrics.non_linearity - 2.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_discourse_follows_narrative_order() {
+        let events = [
+            make_event("2024-01-01"),
+            make_event("2024-01-02"),
+            make_event("2024-01-03"),
+        ];
+        let last_id = events[2].id.clone();
+        let mut narrative = Narrative::builder().events(events).build();
+        assert!(DiscourseMetrics::from_narrative(&narrative).is_linear());
+
+        // Opening with the ending is a flash-forward before the main storyline
+        narrative.set_discourse_order([last_id.clone()]).unwrap();
+        let metrics = DiscourseMetrics::from_narrative(&narrative);
+        assert_eq!(metrics.flash_forwards().count(), 1);
+        assert_eq!(metrics.anachronies[0].event_id, last_id);
+    }
+
     #[test]
     fn test_temporal_metrics_empty() {
         let metrics = TemporalMetrics::from_events(&[]);
@@ -0,0 +1,328 @@
+//! Actors - the people, organisations and groups involved in events.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::core::MetadataValue;
+use crate::error::{Error, Result};
+
+/// Unique identifier for an actor.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct ActorId(pub Uuid);
+
+impl ActorId {
+    /// Creates a new random ActorId.
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates an ActorId from a UUID.
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses an ActorId from a string.
+    pub fn parse(s: &str) -> Result<Self> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| Error::ParseError(format!("invalid actor ID: {}", s)))
+    }
+
+    /// Returns the inner UUID.
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ActorId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ActorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Uuid> for ActorId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Kind of actor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ActorKind {
+    /// An individual person.
+    #[default]
+    Person,
+    /// A formal organisation (company, agency, party).
+    Organization,
+    /// An informal group (crowd, unit, movement).
+    Group,
+}
+
+impl std::fmt::Display for ActorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActorKind::Person => write!(f, "person"),
+            ActorKind::Organization => write!(f, "organization"),
+            ActorKind::Group => write!(f, "group"),
+        }
+    }
+}
+
+/// A person, organisation or group that takes part in events.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Actor, ActorKind};
+///
+/// let actor = Actor::new("Ernest Shackleton", ActorKind::Person)
+///     .with_alias("Shackleton")
+///     .with_alias("The Boss");
+///
+/// assert!(actor.matches_name("the boss"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "A person, organisation or group that takes part in events.")]
+pub struct Actor {
+    /// Unique identifier.
+    pub id: ActorId,
+    /// Canonical name.
+    pub name: String,
+    /// Kind of actor.
+    pub kind: ActorKind,
+    /// Alternative names.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Key-value metadata.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, MetadataValue>,
+}
+
+impl Actor {
+    /// Creates a new actor with a fresh ID.
+    pub fn new(name: impl Into<String>, kind: ActorKind) -> Self {
+        Self {
+            id: ActorId::new(),
+            name: name.into(),
+            kind,
+            aliases: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Adds an alias, returning the actor.
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.add_alias(alias);
+        self
+    }
+
+    /// Adds an alias if it is not already known.
+    pub fn add_alias(&mut self, alias: impl Into<String>) {
+        let alias = alias.into();
+        if !self.matches_name(&alias) {
+            self.aliases.push(alias);
+        }
+    }
+
+    /// Returns true if the name or any alias matches, ignoring case.
+    pub fn matches_name(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .any(|n| n.to_lowercase() == name)
+    }
+
+    /// Sets a metadata value.
+    pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<MetadataValue>) {
+        self.metadata.insert(key.into(), value.into());
+    }
+}
+
+/// Reference from an event to an actor, with the actor's role.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct ActorRef {
+    /// The referenced actor.
+    pub actor: ActorId,
+    /// Role the actor plays in the event (e.g. "organizer", "victim").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+impl ActorRef {
+    /// Creates a reference without a role.
+    pub fn new(actor: ActorId) -> Self {
+        Self { actor, role: None }
+    }
+
+    /// Creates a reference with a role.
+    pub fn with_role(actor: ActorId, role: impl Into<String>) -> Self {
+        Self {
+            actor,
+            role: Some(role.into()),
+        }
+    }
+}
+
+impl From<ActorId> for ActorRef {
+    fn from(actor: ActorId) -> Self {
+        Self::new(actor)
+    }
+}
+
+/// Registry of the actors in a narrative.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Actor, ActorKind, ActorRegistry};
+///
+/// let mut registry = ActorRegistry::new();
+/// let id = registry.add(Actor::new("United Nations", ActorKind::Organization).with_alias("UN"));
+///
+/// assert_eq!(registry.find_by_name("UN").unwrap().id, id);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct ActorRegistry {
+    actors: Vec<Actor>,
+}
+
+impl ActorRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an actor, replacing any actor with the same ID, and returns its ID.
+    pub fn add(&mut self, actor: Actor) -> ActorId {
+        let id = actor.id.clone();
+        match self.get_mut(&id) {
+            Some(existing) => *existing = actor,
+            None => self.actors.push(actor),
+        }
+        id
+    }
+
+    /// Returns the ID of the actor with this name or alias, adding a new actor if none matches.
+    pub fn resolve(&mut self, name: &str, kind: ActorKind) -> ActorId {
+        match self.find_by_name(name) {
+            Some(actor) => actor.id.clone(),
+            None => self.add(Actor::new(name.trim(), kind)),
+        }
+    }
+
+    /// Adds the actors of another registry whose IDs are not already present.
+    pub fn merge(&mut self, other: ActorRegistry) {
+        for actor in other.actors {
+            if self.get(&actor.id).is_none() {
+                self.actors.push(actor);
+            }
+        }
+    }
+
+    /// Removes an actor by ID.
+    pub fn remove(&mut self, id: &ActorId) -> Option<Actor> {
+        let pos = self.actors.iter().position(|a| &a.id == id)?;
+        Some(self.actors.remove(pos))
+    }
+
+    /// Finds an actor by ID.
+    pub fn get(&self, id: &ActorId) -> Option<&Actor> {
+        self.actors.iter().find(|a| &a.id == id)
+    }
+
+    /// Finds an actor by ID (mutable).
+    pub fn get_mut(&mut self, id: &ActorId) -> Option<&mut Actor> {
+        self.actors.iter_mut().find(|a| &a.id == id)
+    }
+
+    /// Finds an actor by name or alias, ignoring case.
+    pub fn find_by_name(&self, name: &str) -> Option<&Actor> {
+        self.actors.iter().find(|a| a.matches_name(name))
+    }
+
+    /// Returns all actors of a kind.
+    pub fn of_kind(&self, kind: ActorKind) -> Vec<&Actor> {
+        self.actors.iter().filter(|a| a.kind == kind).collect()
+    }
+
+    /// Iterates over all actors.
+    pub fn iter(&self) -> std::slice::Iter<'_, Actor> {
+        self.actors.iter()
+    }
+
+    /// Returns the number of actors.
+    pub fn len(&self) -> usize {
+        self.actors.len()
+    }
+
+    /// Returns true if there are no actors.
+    pub fn is_empty(&self) -> bool {
+        self.actors.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a ActorRegistry {
+    type Item = &'a Actor;
+    type IntoIter = std::slice::Iter<'a, Actor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actor_aliases() {
+        let mut actor = Actor::new("Médecins Sans Frontières", ActorKind::Organization)
+            .with_alias("MSF")
+            .with_alias("Doctors Without Borders");
+        actor.add_alias("msf");
+
+        assert_eq!(actor.aliases.len(), 2);
+        assert!(actor.matches_name("doctors without borders"));
+        assert!(!actor.matches_name("Red Cross"));
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = ActorRegistry::new();
+        let un =
+            registry.add(Actor::new("United Nations", ActorKind::Organization).with_alias("UN"));
+
+        assert_eq!(registry.resolve("un", ActorKind::Organization), un);
+        let crowd = registry.resolve("Protesters", ActorKind::Group);
+        assert_ne!(crowd, un);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.of_kind(ActorKind::Group).len(), 1);
+
+        assert!(registry.remove(&crowd).is_some());
+        assert!(registry.get(&crowd).is_none());
+    }
+
+    #[test]
+    fn test_registry_serialization() {
+        let mut registry = ActorRegistry::new();
+        registry
+            .add(Actor::new("Ada Lovelace", ActorKind::Person).with_alias("Countess of Lovelace"));
+
+        let json = serde_json::to_value(&registry).unwrap();
+        assert_eq!(json[0]["kind"], "person");
+
+        let parsed: ActorRegistry = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, registry);
+    }
+}
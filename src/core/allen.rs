@@ -0,0 +1,181 @@
+//! Allen's interval algebra for temporal reasoning.
+
+use serde::{Deserialize, Serialize};
+
+/// One of the thirteen relations of Allen's interval algebra.
+///
+/// Relations describe how two time spans are positioned relative to
+/// each other. Spans are treated as closed at millisecond resolution,
+/// so a span ending one millisecond before another begins *meets* it.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{AllenRelation, TimeRange};
+///
+/// let march = TimeRange::month(2024, 3);
+/// let april = TimeRange::month(2024, 4);
+///
+/// assert_eq!(march.relation(&april), AllenRelation::Meets);
+/// assert_eq!(april.relation(&march), AllenRelation::MetBy);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllenRelation {
+    /// Ends before the other starts, with a gap between them.
+    Before,
+    /// Ends exactly where the other starts.
+    Meets,
+    /// Starts first and ends inside the other.
+    Overlaps,
+    /// Starts together with the other and ends first.
+    Starts,
+    /// Lies strictly inside the other.
+    During,
+    /// Ends together with the other and starts later.
+    Finishes,
+    /// Starts and ends together with the other.
+    Equals,
+    /// Inverse of [`Finishes`](Self::Finishes).
+    FinishedBy,
+    /// Inverse of [`During`](Self::During).
+    Contains,
+    /// Inverse of [`Starts`](Self::Starts).
+    StartedBy,
+    /// Inverse of [`Overlaps`](Self::Overlaps).
+    OverlappedBy,
+    /// Inverse of [`Meets`](Self::Meets).
+    MetBy,
+    /// Inverse of [`Before`](Self::Before).
+    After,
+}
+
+impl AllenRelation {
+    /// Returns the relation seen from the other span.
+    pub fn inverse(self) -> Self {
+        match self {
+            AllenRelation::Before => AllenRelation::After,
+            AllenRelation::Meets => AllenRelation::MetBy,
+            AllenRelation::Overlaps => AllenRelation::OverlappedBy,
+            AllenRelation::Starts => AllenRelation::StartedBy,
+            AllenRelation::During => AllenRelation::Contains,
+            AllenRelation::Finishes => AllenRelation::FinishedBy,
+            AllenRelation::Equals => AllenRelation::Equals,
+            AllenRelation::FinishedBy => AllenRelation::Finishes,
+            AllenRelation::Contains => AllenRelation::During,
+            AllenRelation::StartedBy => AllenRelation::Starts,
+            AllenRelation::OverlappedBy => AllenRelation::Overlaps,
+            AllenRelation::MetBy => AllenRelation::Meets,
+            AllenRelation::After => AllenRelation::Before,
+        }
+    }
+
+    /// Returns true if the two spans share at least one instant.
+    pub fn is_overlapping(self) -> bool {
+        !matches!(
+            self,
+            AllenRelation::Before
+                | AllenRelation::Meets
+                | AllenRelation::MetBy
+                | AllenRelation::After
+        )
+    }
+
+    /// Computes the relation between two closed millisecond spans.
+    pub(crate) fn between(a: (i64, i64), b: (i64, i64)) -> Self {
+        // Work with half-open spans so that adjacent spans meet
+        let (s1, e1) = (a.0, a.1.saturating_add(1));
+        let (s2, e2) = (b.0, b.1.saturating_add(1));
+
+        if e1 < s2 {
+            AllenRelation::Before
+        } else if e2 < s1 {
+            AllenRelation::After
+        } else if e1 == s2 {
+            AllenRelation::Meets
+        } else if e2 == s1 {
+            AllenRelation::MetBy
+        } else if s1 == s2 && e1 == e2 {
+            AllenRelation::Equals
+        } else if s1 == s2 {
+            if e1 < e2 {
+                AllenRelation::Starts
+            } else {
+                AllenRelation::StartedBy
+            }
+        } else if e1 == e2 {
+            if s1 > s2 {
+                AllenRelation::Finishes
+            } else {
+                AllenRelation::FinishedBy
+            }
+        } else if s1 > s2 && e1 < e2 {
+            AllenRelation::During
+        } else if s1 < s2 && e1 > e2 {
+            AllenRelation::Contains
+        } else if s1 < s2 {
+            AllenRelation::Overlaps
+        } else {
+            AllenRelation::OverlappedBy
+        }
+    }
+}
+
+impl std::fmt::Display for AllenRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AllenRelation::Before => "before",
+            AllenRelation::Meets => "meets",
+            AllenRelation::Overlaps => "overlaps",
+            AllenRelation::Starts => "starts",
+            AllenRelation::During => "during",
+            AllenRelation::Finishes => "finishes",
+            AllenRelation::Equals => "equals",
+            AllenRelation::FinishedBy => "finished by",
+            AllenRelation::Contains => "contains",
+            AllenRelation::StartedBy => "started by",
+            AllenRelation::OverlappedBy => "overlapped by",
+            AllenRelation::MetBy => "met by",
+            AllenRelation::After => "after",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allen_all_relations() {
+        let cases = [
+            ((0, 9), (20, 29), AllenRelation::Before),
+            ((0, 9), (10, 19), AllenRelation::Meets),
+            ((0, 14), (10, 19), AllenRelation::Overlaps),
+            ((10, 14), (10, 19), AllenRelation::Starts),
+            ((12, 14), (10, 19), AllenRelation::During),
+            ((15, 19), (10, 19), AllenRelation::Finishes),
+            ((10, 19), (10, 19), AllenRelation::Equals),
+        ];
+
+        for (a, b, expected) in cases {
+            assert_eq!(AllenRelation::between(a, b), expected);
+            assert_eq!(AllenRelation::between(b, a), expected.inverse());
+        }
+    }
+
+    #[test]
+    fn test_allen_instants() {
+        assert_eq!(
+            AllenRelation::between((5, 5), (5, 5)),
+            AllenRelation::Equals
+        );
+        assert_eq!(AllenRelation::between((5, 5), (6, 6)), AllenRelation::Meets);
+        assert_eq!(
+            AllenRelation::between((5, 5), (0, 9)),
+            AllenRelation::During
+        );
+        assert!(!AllenRelation::Meets.is_overlapping());
+        assert!(AllenRelation::During.is_overlapping());
+    }
+}
@@ -1,15 +1,23 @@
 //! Geographic and temporal bounds for filtering and queries.
 
-use chrono::{Datelike, Duration, TimeZone};
+use chrono::{Duration, TimeZone};
+use geo::BoundingRect;
+use geo_types::Geometry;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Location, Timestamp};
+use crate::core::fuzzy_date::{self, ParsedDate};
+use crate::core::location::wrap_longitude;
+use crate::core::{AllenRelation, Location, Timestamp};
 
 /// Geographic bounding box.
 ///
 /// Represents a rectangular region defined by minimum and maximum
 /// latitude and longitude values.
 ///
+/// A box whose `min_lon` is greater than its `max_lon` wraps across the
+/// antimeridian, as in GeoJSON: it covers `min_lon..=180` and
+/// `-180..=max_lon`.
+///
 /// # Examples
 ///
 /// ```
@@ -21,6 +29,12 @@ use crate::core::{Location, Timestamp};
 /// // Check if a location is within bounds
 /// let sf = Location::new(37.7749, -122.4194);
 /// assert!(bay_area.contains(&sf));
+///
+/// // Fiji straddles the antimeridian
+/// let fiji = GeoBounds::new(-21.0, 176.0, -12.0, -178.0);
+/// assert!(fiji.crosses_antimeridian());
+/// assert!(fiji.contains(&Location::new(-16.8, 179.4)));
+/// assert!(fiji.contains(&Location::new(-17.1, -179.9)));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct GeoBounds {
@@ -57,40 +71,108 @@ impl GeoBounds {
         Self::new(sw.lat, sw.lon, ne.lat, ne.lon)
     }
 
-    /// Creates bounds that contain all given locations.
+    /// Creates the smallest bounds that contain all given locations.
+    ///
+    /// The bounds wrap across the antimeridian when that is narrower, so
+    /// locations at 179° and -179° give a 2° wide box.
     pub fn from_locations<'a>(locations: impl IntoIterator<Item = &'a Location>) -> Option<Self> {
-        let mut iter = locations.into_iter();
-        let first = iter.next()?;
-
-        let mut bounds = Self::new(first.lat, first.lon, first.lat, first.lon);
-
-        for loc in iter {
-            bounds.expand_to_include(loc);
-        }
-
-        Some(bounds)
+        let mut lat_range: Option<(f64, f64)> = None;
+        let spans: Vec<(f64, f64)> = locations
+            .into_iter()
+            .map(|loc| {
+                let (lo, hi) = lat_range.unwrap_or((loc.lat, loc.lat));
+                lat_range = Some((lo.min(loc.lat), hi.max(loc.lat)));
+                (loc.lon, loc.lon)
+            })
+            .collect();
+        let (min_lat, max_lat) = lat_range?;
+        let (min_lon, max_lon) = covering_lon_span(spans);
+        Some(Self::new(min_lat, min_lon, max_lat, max_lon))
+    }
+
+    /// Creates bounds that contain the given geometry.
+    ///
+    /// Each part of a multi-part geometry is measured on its own, and the
+    /// parts are then covered by the narrowest box, which wraps across the
+    /// antimeridian when that is narrower. A multi-polygon cut at ±180°
+    /// (as GeoJSON recommends) therefore gets a wrapping box rather than
+    /// one spanning the globe. Single parts are planar, as in `geo`.
+    ///
+    /// Returns `None` for empty geometries.
+    pub fn from_geometry(geometry: &Geometry<f64>) -> Option<Self> {
+        let mut rects = Vec::new();
+        collect_part_rects(geometry, &mut rects);
+        let min_lat = rects.iter().map(|r| r.min().y).reduce(f64::min)?;
+        let max_lat = rects.iter().map(|r| r.max().y).reduce(f64::max)?;
+        let (min_lon, max_lon) =
+            covering_lon_span(rects.iter().map(|r| (r.min().x, r.max().x)).collect());
+        Some(Self::new(min_lat, min_lon, max_lat, max_lon))
     }
 
     /// Creates bounds centered on a point with given radius in degrees.
+    ///
+    /// Latitudes are clamped to the poles. A box reaching past ±180°
+    /// longitude wraps across the antimeridian, and one 360° or wider
+    /// covers every longitude.
     pub fn from_center_degrees(center: &Location, lat_radius: f64, lon_radius: f64) -> Self {
+        let min_lat = (center.lat - lat_radius).max(-90.0);
+        let max_lat = (center.lat + lat_radius).min(90.0);
+        if lon_radius >= 180.0 {
+            return Self::new(min_lat, -180.0, max_lat, 180.0);
+        }
         Self::new(
-            center.lat - lat_radius,
-            center.lon - lon_radius,
-            center.lat + lat_radius,
-            center.lon + lon_radius,
+            min_lat,
+            wrap_longitude(center.lon - lon_radius),
+            max_lat,
+            wrap_longitude(center.lon + lon_radius),
         )
     }
 
+    /// Returns true if the box wraps across the antimeridian.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lon > self.max_lon
+    }
+
+    /// Splits a box that wraps across the antimeridian into its eastern
+    /// and western parts.
+    ///
+    /// Boxes that do not wrap are returned as they are.
+    pub fn split_antimeridian(&self) -> Vec<GeoBounds> {
+        if self.crosses_antimeridian() {
+            vec![
+                GeoBounds::new(self.min_lat, self.min_lon, self.max_lat, 180.0),
+                GeoBounds::new(self.min_lat, -180.0, self.max_lat, self.max_lon),
+            ]
+        } else {
+            vec![*self]
+        }
+    }
+
     /// Checks if a location is within these bounds.
     pub fn contains(&self, location: &Location) -> bool {
-        location.lat >= self.min_lat
-            && location.lat <= self.max_lat
-            && location.lon >= self.min_lon
-            && location.lon <= self.max_lon
+        self.contains_coords(location.lat, location.lon)
+    }
+
+    /// Checks if a coordinate pair is within these bounds.
+    pub(crate) fn contains_coords(&self, lat: f64, lon: f64) -> bool {
+        let in_lon = if self.crosses_antimeridian() {
+            lon >= self.min_lon || lon <= self.max_lon
+        } else {
+            lon >= self.min_lon && lon <= self.max_lon
+        };
+        in_lon && lat >= self.min_lat && lat <= self.max_lat
     }
 
     /// Checks if these bounds intersect with other bounds.
     pub fn intersects(&self, other: &GeoBounds) -> bool {
+        let parts = other.split_antimeridian();
+        self.split_antimeridian()
+            .iter()
+            .any(|a| parts.iter().any(|b| a.intersects_plain(b)))
+    }
+
+    /// Intersection test for boxes that do not wrap.
+    fn intersects_plain(&self, other: &GeoBounds) -> bool {
         self.min_lat <= other.max_lat
             && self.max_lat >= other.min_lat
             && self.min_lon <= other.max_lon
@@ -98,48 +180,95 @@ impl GeoBounds {
     }
 
     /// Returns the intersection of two bounds, if any.
+    ///
+    /// When the overlap falls in two separate pieces, which can happen
+    /// when one box wraps across the antimeridian, the result is the
+    /// smallest box containing both.
     pub fn intersection(&self, other: &GeoBounds) -> Option<GeoBounds> {
-        if !self.intersects(other) {
-            return None;
-        }
-
-        Some(GeoBounds::new(
-            self.min_lat.max(other.min_lat),
-            self.min_lon.max(other.min_lon),
-            self.max_lat.min(other.max_lat),
-            self.max_lon.min(other.max_lon),
-        ))
-    }
-
-    /// Returns bounds that contain both this and other bounds.
+        let parts = other.split_antimeridian();
+        let mut pieces = self.split_antimeridian().into_iter().flat_map(|a| {
+            parts
+                .iter()
+                .filter(move |b| a.intersects_plain(b))
+                .map(move |b| {
+                    GeoBounds::new(
+                        a.min_lat.max(b.min_lat),
+                        a.min_lon.max(b.min_lon),
+                        a.max_lat.min(b.max_lat),
+                        a.max_lon.min(b.max_lon),
+                    )
+                })
+        });
+
+        let first = pieces.next()?;
+        Some(match pieces.next() {
+            None => first,
+            // East and west pieces meeting at ±180° form a wrapping box
+            Some(second) if first.max_lon == 180.0 && second.min_lon == -180.0 => GeoBounds::new(
+                first.min_lat.min(second.min_lat),
+                first.min_lon,
+                first.max_lat.max(second.max_lat),
+                second.max_lon,
+            ),
+            Some(second) => first.union(&second),
+        })
+    }
+
+    /// Returns the smallest bounds that contain both this and other bounds.
+    ///
+    /// The result wraps across the antimeridian when that is narrower.
     pub fn union(&self, other: &GeoBounds) -> GeoBounds {
+        let spans = self
+            .split_antimeridian()
+            .into_iter()
+            .chain(other.split_antimeridian())
+            .map(|b| (b.min_lon, b.max_lon))
+            .collect();
+        let (min_lon, max_lon) = covering_lon_span(spans);
         GeoBounds::new(
             self.min_lat.min(other.min_lat),
-            self.min_lon.min(other.min_lon),
+            min_lon,
             self.max_lat.max(other.max_lat),
-            self.max_lon.max(other.max_lon),
+            max_lon,
         )
     }
 
     /// Expands bounds to include the given location.
+    ///
+    /// Grows whichever side, east or west, needs less widening, so bounds
+    /// may come to wrap across the antimeridian.
     pub fn expand_to_include(&mut self, location: &Location) {
-        self.min_lat = self.min_lat.min(location.lat);
-        self.max_lat = self.max_lat.max(location.lat);
-        self.min_lon = self.min_lon.min(location.lon);
-        self.max_lon = self.max_lon.max(location.lon);
+        *self = self.union(&GeoBounds::from_corners(location, location));
+    }
+
+    /// Returns how many degrees east of the western edge a longitude lies,
+    /// counting across the antimeridian for bounds that wrap.
+    ///
+    /// Longitudes inside the bounds give `0.0..=width()`, which makes
+    /// this the column coordinate for grids laid over the bounds.
+    pub fn lon_offset(&self, lon: f64) -> f64 {
+        if self.crosses_antimeridian() {
+            (lon - self.min_lon).rem_euclid(360.0)
+        } else {
+            lon - self.min_lon
+        }
     }
 
     /// Returns the center of the bounds.
     pub fn center(&self) -> Location {
         Location::new(
             (self.min_lat + self.max_lat) / 2.0,
-            (self.min_lon + self.max_lon) / 2.0,
+            wrap_longitude(self.min_lon + self.width() / 2.0),
         )
     }
 
     /// Returns the width in degrees (longitude span).
     pub fn width(&self) -> f64 {
-        self.max_lon - self.min_lon
+        if self.crosses_antimeridian() {
+            self.max_lon - self.min_lon + 360.0
+        } else {
+            self.max_lon - self.min_lon
+        }
     }
 
     /// Returns the height in degrees (latitude span).
@@ -168,6 +297,9 @@ impl GeoBounds {
     }
 
     /// Converts to a geo-types Rect.
+    ///
+    /// Split boxes that wrap across the antimeridian with
+    /// [`split_antimeridian`](Self::split_antimeridian) first.
     pub fn to_geo_rect(&self) -> geo_types::Rect<f64> {
         geo_types::Rect::new(
             geo_types::coord! { x: self.min_lon, y: self.min_lat },
@@ -176,6 +308,53 @@ impl GeoBounds {
     }
 }
 
+/// Bounding rectangles of the single parts of a geometry.
+fn collect_part_rects(geometry: &Geometry<f64>, rects: &mut Vec<geo_types::Rect<f64>>) {
+    match geometry {
+        Geometry::MultiPoint(mp) => rects.extend(mp.iter().map(|p| p.bounding_rect())),
+        Geometry::MultiLineString(mls) => {
+            rects.extend(mls.iter().filter_map(|l| l.bounding_rect()))
+        },
+        Geometry::MultiPolygon(mp) => rects.extend(mp.iter().filter_map(|p| p.bounding_rect())),
+        Geometry::GeometryCollection(gc) => {
+            for part in gc {
+                collect_part_rects(part, rects);
+            }
+        },
+        other => rects.extend(other.bounding_rect()),
+    }
+}
+
+/// The narrowest `(min_lon, max_lon)` covering some longitude spans.
+///
+/// Each span runs east from its first to its second longitude without
+/// wrapping. The result leaves out the widest gap between spans, which
+/// may be the one across the antimeridian (giving a plain span) or any
+/// other (giving a span that wraps). Ties keep the plain span.
+fn covering_lon_span(mut spans: Vec<(f64, f64)>) -> (f64, f64) {
+    spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(spans.len());
+    for (lo, hi) in spans {
+        match merged.last_mut() {
+            Some(last) if lo <= last.1 => last.1 = last.1.max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+
+    let (first, last) = (merged[0], merged[merged.len() - 1]);
+    let mut best = (first.0, last.1);
+    let mut widest_gap = first.0 + 360.0 - last.1;
+    for pair in merged.windows(2) {
+        let gap = pair[1].0 - pair[0].1;
+        if gap > widest_gap {
+            widest_gap = gap;
+            best = (pair[1].0, pair[0].1);
+        }
+    }
+    best
+}
+
 impl Default for GeoBounds {
     fn default() -> Self {
         // World bounds
@@ -183,10 +362,51 @@ impl Default for GeoBounds {
     }
 }
 
+/// How a temporal query treats the uncertainty of timestamp precision.
+///
+/// A timestamp stands for every instant its precision allows: `1943` at
+/// year precision could be any moment of that year. A query matches an
+/// entity [`Possibly`](Self::Possibly) if some reading of its timestamps
+/// overlaps the range, and [`Definitely`](Self::Definitely) if every
+/// reading does. An instantaneous entity therefore definitely matches
+/// only a range that covers its whole implied interval.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{
+///     Event, Location, TemporalEntity, TemporalMatch, TimeRange, Timestamp,
+/// };
+///
+/// let event = Event::new(Location::new(0.0, 0.0), Timestamp::parse("1943").unwrap(), "Raid");
+/// let march = TimeRange::month(1943, 3);
+///
+/// assert!(event.matches_time_range(&march, TemporalMatch::Possibly));
+/// assert!(!event.matches_time_range(&march, TemporalMatch::Definitely));
+/// assert!(event.matches_time_range(&TimeRange::year(1943), TemporalMatch::Definitely));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemporalMatch {
+    /// Some reading of the timestamps overlaps the range.
+    #[default]
+    Possibly,
+    /// Every reading of the timestamps overlaps the range.
+    Definitely,
+}
+
 /// Time range for temporal queries.
 ///
 /// Represents a span of time with start and end timestamps.
 ///
+/// [`contains`](Self::contains) and [`overlaps`](Self::overlaps) compare
+/// the instants the timestamps store, so `1943` is read as
+/// 1943-01-01T00:00. [`contains_definitely`](Self::contains_definitely)
+/// and [`contains_possibly`](Self::contains_possibly) expand a timestamp
+/// to the interval its precision implies (see
+/// [`Timestamp::implied_range`]), as do narrative filters and temporal
+/// index queries, which take a [`TemporalMatch`].
+///
 /// # Examples
 ///
 /// ```
@@ -213,20 +433,47 @@ impl TimeRange {
         Self { start, end }
     }
 
+    /// Parses a time range from a date expression.
+    ///
+    /// Accepts everything [`Timestamp::parse`] does. Span notations such
+    /// as `spring 1943`, `early March 1916` or `between 1840 and 1845`
+    /// give their exact span; single dates give the interval implied by
+    /// their precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::TimeRange;
+    ///
+    /// let spring = TimeRange::parse("spring 1943").unwrap();
+    /// assert_eq!(spring.start.to_rfc3339(), "1943-03-01T00:00:00+00:00");
+    /// ```
+    pub fn parse(s: &str) -> crate::error::Result<Self> {
+        match fuzzy_date::parse(s)? {
+            ParsedDate::Instant(ts) => {
+                let range = ts.implied_range();
+                let mark = |t: Timestamp| {
+                    t.with_calendar(ts.calendar)
+                        .with_approximate(ts.approximate)
+                };
+                Ok(Self::new(mark(range.start), mark(range.end)))
+            },
+            ParsedDate::Range(range) | ParsedDate::Between(range) => Ok(range),
+        }
+    }
+
     /// Creates a time range for a specific year.
     pub fn year(year: i32) -> Self {
-        let start = Timestamp::parse(&format!("{}", year)).unwrap();
-        let end_dt = chrono::NaiveDate::from_ymd_opt(year, 12, 31)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
-            .unwrap();
-        let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
-        Self::new(start, end)
+        let start = chrono::NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+        Self::days(
+            start,
+            chrono::NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
+        )
     }
 
     /// Creates a time range for a specific month.
     pub fn month(year: i32, month: u32) -> Self {
-        let start = Timestamp::parse(&format!("{}-{:02}", year, month)).unwrap();
+        let start = chrono::NaiveDate::from_ymd_opt(year, month, 1).unwrap();
 
         // Calculate last day of month
         let next_month = if month == 12 { 1 } else { month + 1 };
@@ -234,25 +481,22 @@ impl TimeRange {
         let last_day = chrono::NaiveDate::from_ymd_opt(next_year, next_month, 1)
             .unwrap()
             .pred_opt()
-            .unwrap()
-            .day();
-
-        let end_dt = chrono::NaiveDate::from_ymd_opt(year, month, last_day)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
             .unwrap();
-        let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
 
-        Self::new(start, end)
+        Self::days(start, last_day)
     }
 
     /// Creates a time range for a specific day.
     pub fn day(year: i32, month: u32, day: u32) -> Self {
-        let start = Timestamp::parse(&format!("{}-{:02}-{:02}", year, month, day)).unwrap();
-        let end_dt = chrono::NaiveDate::from_ymd_opt(year, month, day)
-            .unwrap()
-            .and_hms_opt(23, 59, 59)
-            .unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        Self::days(date, date)
+    }
+
+    /// Creates a range from the start of one day to the end of another.
+    fn days(first: chrono::NaiveDate, last: chrono::NaiveDate) -> Self {
+        let start =
+            Timestamp::new(chrono::Utc.from_utc_datetime(&first.and_hms_opt(0, 0, 0).unwrap()));
+        let end_dt = last.and_hms_milli_opt(23, 59, 59, 999).unwrap();
         let end = Timestamp::new(chrono::Utc.from_utc_datetime(&end_dt));
         Self::new(start, end)
     }
@@ -272,13 +516,38 @@ impl TimeRange {
     }
 
     /// Checks if a timestamp is within this range.
+    ///
+    /// This compares the timestamp's datetime only. Use
+    /// [`contains_definitely`](Self::contains_definitely) or
+    /// [`contains_possibly`](Self::contains_possibly) to take its
+    /// precision into account.
     pub fn contains(&self, timestamp: &Timestamp) -> bool {
-        timestamp >= &self.start && timestamp <= &self.end
+        timestamp.datetime >= self.start.datetime && timestamp.datetime <= self.end.datetime
+    }
+
+    /// Checks if every instant a timestamp could refer to lies in this range.
+    pub fn contains_definitely(&self, timestamp: &Timestamp) -> bool {
+        self.contains(&timestamp.earliest()) && self.contains(&timestamp.latest())
+    }
+
+    /// Checks if some instant a timestamp could refer to lies in this range.
+    pub fn contains_possibly(&self, timestamp: &Timestamp) -> bool {
+        self.overlaps(&timestamp.implied_range())
     }
 
     /// Checks if this range overlaps with another.
+    ///
+    /// Compares the stored instants, like [`contains`](Self::contains).
     pub fn overlaps(&self, other: &TimeRange) -> bool {
-        self.start <= other.end && self.end >= other.start
+        self.start.datetime <= other.end.datetime && self.end.datetime >= other.start.datetime
+    }
+
+    /// Returns the Allen relation between this range and another.
+    pub fn relation(&self, other: &TimeRange) -> AllenRelation {
+        AllenRelation::between(
+            (self.start.to_unix_millis(), self.end.to_unix_millis()),
+            (other.start.to_unix_millis(), other.end.to_unix_millis()),
+        )
     }
 
     /// Returns the intersection of two ranges, if any.
@@ -348,6 +617,8 @@ impl TimeRange {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Datelike;
+    use geo_types::{line_string, polygon, MultiPolygon};
 
     #[test]
     fn test_geobounds_contains() {
@@ -385,6 +656,21 @@ mod tests {
         assert_eq!(bounds.max_lon, 40.0);
     }
 
+    #[test]
+    fn test_geobounds_from_geometry() {
+        let line = line_string![
+            (x: -74.0, y: 40.0),
+            (x: -73.0, y: 41.5),
+            (x: -72.5, y: 40.5),
+        ];
+
+        let bounds = GeoBounds::from_geometry(&line.into()).unwrap();
+        assert_eq!(bounds.min_lat, 40.0);
+        assert_eq!(bounds.max_lat, 41.5);
+        assert_eq!(bounds.min_lon, -74.0);
+        assert_eq!(bounds.max_lon, -72.5);
+    }
+
     #[test]
     fn test_geobounds_center() {
         let bounds = GeoBounds::new(0.0, 0.0, 10.0, 10.0);
@@ -393,6 +679,119 @@ mod tests {
         assert_eq!(center.lon, 5.0);
     }
 
+    #[test]
+    fn test_geobounds_antimeridian() {
+        // 170°E to 170°W, across the antimeridian
+        let pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        assert!(pacific.crosses_antimeridian());
+        assert_eq!(pacific.width(), 20.0);
+        assert_eq!(pacific.center().lon.abs(), 180.0);
+        assert!(pacific.contains(&Location::new(0.0, 175.0)));
+        assert!(pacific.contains(&Location::new(0.0, -175.0)));
+        assert!(!pacific.contains(&Location::new(0.0, 0.0)));
+
+        let parts = pacific.split_antimeridian();
+        assert_eq!(parts.len(), 2);
+        assert_eq!((parts[0].min_lon, parts[0].max_lon), (170.0, 180.0));
+        assert_eq!((parts[1].min_lon, parts[1].max_lon), (-180.0, -170.0));
+
+        let east = GeoBounds::new(-5.0, -175.0, 5.0, -160.0);
+        assert!(pacific.intersects(&east));
+        assert!(!pacific.intersects(&GeoBounds::new(-5.0, 0.0, 5.0, 10.0)));
+        let overlap = pacific.intersection(&east).unwrap();
+        assert_eq!((overlap.min_lon, overlap.max_lon), (-175.0, -170.0));
+
+        let wider = GeoBounds::new(-5.0, 175.0, 5.0, -175.0);
+        assert_eq!(pacific.intersection(&wider), Some(wider));
+
+        let around = GeoBounds::from_center_degrees(&Location::new(0.0, 178.0), 1.0, 5.0);
+        assert_eq!((around.min_lon, around.max_lon), (173.0, -177.0));
+    }
+
+    #[test]
+    fn test_geobounds_expand_across_antimeridian() {
+        let mut pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        pacific.expand_to_include(&Location::new(0.0, 175.0));
+        assert_eq!(pacific, GeoBounds::new(-10.0, 170.0, 10.0, -170.0));
+
+        pacific.expand_to_include(&Location::new(20.0, -160.0));
+        assert_eq!(pacific, GeoBounds::new(-10.0, 170.0, 20.0, -160.0));
+
+        let mut fiji = GeoBounds::new(-18.0, 178.0, -18.0, 178.0);
+        fiji.expand_to_include(&Location::new(-17.0, -179.0));
+        assert!(fiji.crosses_antimeridian());
+        assert_eq!(fiji.width(), 3.0);
+    }
+
+    #[test]
+    fn test_geobounds_from_locations_across_antimeridian() {
+        let locations = [Location::new(-1.0, 179.0), Location::new(1.0, -179.0)];
+        let bounds = GeoBounds::from_locations(&locations).unwrap();
+        assert_eq!(bounds, GeoBounds::new(-1.0, 179.0, 1.0, -179.0));
+        assert_eq!(bounds.width(), 2.0);
+
+        // The widest gap decides, wherever it lies
+        let spread = [
+            Location::new(0.0, 170.0),
+            Location::new(0.0, -170.0),
+            Location::new(0.0, -100.0),
+        ];
+        let bounds = GeoBounds::from_locations(&spread).unwrap();
+        assert_eq!((bounds.min_lon, bounds.max_lon), (170.0, -100.0));
+
+        // Without a shorter way round, bounds stay plain
+        let atlantic = [Location::new(0.0, -60.0), Location::new(0.0, 10.0)];
+        let bounds = GeoBounds::from_locations(&atlantic).unwrap();
+        assert_eq!((bounds.min_lon, bounds.max_lon), (-60.0, 10.0));
+    }
+
+    #[test]
+    fn test_geobounds_from_geometry_across_antimeridian() {
+        // A polygon cut at the antimeridian, as GeoJSON recommends
+        let east = polygon![(x: 175.0, y: -20.0), (x: 180.0, y: -20.0), (x: 180.0, y: -15.0)];
+        let west = polygon![(x: -180.0, y: -20.0), (x: -178.0, y: -20.0), (x: -180.0, y: -15.0)];
+        let fiji = Geometry::MultiPolygon(MultiPolygon(vec![east, west]));
+
+        let bounds = GeoBounds::from_geometry(&fiji).unwrap();
+        assert_eq!(bounds, GeoBounds::new(-20.0, 175.0, -15.0, -178.0));
+        assert!(bounds.contains(&Location::new(-17.0, 179.0)));
+        assert!(!bounds.contains(&Location::new(-17.0, 0.0)));
+
+        // A single part is planar, so a line from 179° to -179° crosses 0°
+        let line = line_string![(x: 179.0, y: 0.0), (x: -179.0, y: 0.0)];
+        let bounds = GeoBounds::from_geometry(&line.into()).unwrap();
+        assert_eq!((bounds.min_lon, bounds.max_lon), (-179.0, 179.0));
+    }
+
+    #[test]
+    fn test_geobounds_union_across_antimeridian() {
+        let west_of = GeoBounds::new(0.0, 170.0, 10.0, 175.0);
+        let east_of = GeoBounds::new(-10.0, -175.0, 0.0, -170.0);
+        assert_eq!(
+            west_of.union(&east_of),
+            GeoBounds::new(-10.0, 170.0, 10.0, -170.0)
+        );
+
+        let pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        let wider = pacific.union(&GeoBounds::new(0.0, 160.0, 5.0, 165.0));
+        assert_eq!(wider, GeoBounds::new(-10.0, 160.0, 10.0, -170.0));
+
+        let world = GeoBounds::default();
+        assert_eq!(pacific.union(&world), world);
+
+        let apart =
+            GeoBounds::new(0.0, -10.0, 1.0, 0.0).union(&GeoBounds::new(0.0, 20.0, 1.0, 30.0));
+        assert_eq!((apart.min_lon, apart.max_lon), (-10.0, 30.0));
+    }
+
+    #[test]
+    fn test_geobounds_lon_offset() {
+        let pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        assert_eq!(pacific.lon_offset(170.0), 0.0);
+        assert_eq!(pacific.lon_offset(-175.0), 15.0);
+        assert_eq!(GeoBounds::new(0.0, -10.0, 1.0, 10.0).lon_offset(5.0), 15.0);
+    }
+
     #[test]
     fn test_timerange_year() {
         let range = TimeRange::year(2024);
@@ -431,7 +830,49 @@ mod tests {
     fn test_timerange_duration() {
         let range = TimeRange::day(2024, 3, 15);
         let duration = range.duration();
-        // Should be approximately 24 hours minus 1 second
-        assert!(duration.num_hours() >= 23);
+        // Should be 24 hours minus 1 millisecond
+        assert_eq!(duration.num_milliseconds(), 24 * 60 * 60 * 1000 - 1);
+    }
+
+    #[test]
+    fn test_timerange_precision_aware_contains() {
+        let year = Timestamp::parse("1943").unwrap();
+        let march = TimeRange::month(1943, 3);
+        let war = TimeRange::new(
+            Timestamp::parse("1939-09-01").unwrap(),
+            Timestamp::parse("1945-09-02").unwrap(),
+        );
+
+        // Plain containment reads "1943" as its first instant
+        assert!(!march.contains(&year));
+        assert!(TimeRange::month(1943, 1).contains(&year));
+        assert!(march.contains_possibly(&year));
+        assert!(!march.contains_definitely(&year));
+        assert!(war.contains_definitely(&year));
+    }
+
+    #[test]
+    fn test_timerange_parse() {
+        let spring = TimeRange::parse("spring 1943").unwrap();
+        assert_eq!(spring.end.datetime.month(), 5);
+
+        let decade = TimeRange::parse("c. 1840s").unwrap();
+        assert_eq!(decade.start.datetime.year(), 1840);
+        assert_eq!(decade.end.datetime.year(), 1849);
+        assert!(decade.start.approximate);
+
+        let bce = TimeRange::year(-43);
+        assert!(bce.contains(&Timestamp::parse("44 BCE").unwrap()));
+    }
+
+    #[test]
+    fn test_timerange_relation() {
+        let march = TimeRange::month(2024, 3);
+        let april = TimeRange::month(2024, 4);
+        let mid_march = TimeRange::day(2024, 3, 15);
+
+        assert_eq!(march.relation(&april), AllenRelation::Meets);
+        assert_eq!(mid_march.relation(&march), AllenRelation::During);
+        assert_eq!(march.relation(&march), AllenRelation::Equals);
     }
 }
@@ -0,0 +1,131 @@
+//! Calendar systems for historical dates.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Offset between a Julian Day Number and chrono's days from the common era.
+const JDN_CE_OFFSET: i64 = 1_721_425;
+
+/// Calendar in which a date was originally recorded.
+///
+/// Timestamps always store the instant in the proleptic Gregorian
+/// calendar. The calendar marker records how the date should be read
+/// and displayed, so a Julian date keeps its original day and month.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::Calendar;
+///
+/// // The last Julian day before the Gregorian reform
+/// let date = Calendar::Julian.to_gregorian(1582, 10, 4).unwrap();
+/// assert_eq!(date.to_string(), "1582-10-14");
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Calendar {
+    /// Proleptic Gregorian calendar.
+    #[default]
+    Gregorian,
+    /// Julian (Old Style) calendar.
+    Julian,
+}
+
+impl Calendar {
+    /// Returns true for the Gregorian calendar.
+    pub fn is_gregorian(&self) -> bool {
+        *self == Calendar::Gregorian
+    }
+
+    /// Returns the number of days in a month of this calendar.
+    pub fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        let leap = match self {
+            Calendar::Gregorian => NaiveDate::from_ymd_opt(year, 2, 29).is_some(),
+            Calendar::Julian => year.rem_euclid(4) == 0,
+        };
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        Some(days)
+    }
+
+    /// Converts a date in this calendar to the proleptic Gregorian calendar.
+    ///
+    /// Years are astronomical, so 1 BCE is year 0.
+    pub fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        match self {
+            Calendar::Gregorian => NaiveDate::from_ymd_opt(year, month, day),
+            Calendar::Julian => {
+                if day == 0 || day > self.days_in_month(year, month)? {
+                    return None;
+                }
+                let a = (14 - i64::from(month)) / 12;
+                let y = i64::from(year) + 4800 - a;
+                let m = i64::from(month) + 12 * a - 3;
+                let jdn = i64::from(day) + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+                let days = i32::try_from(jdn - JDN_CE_OFFSET).ok()?;
+                NaiveDate::from_num_days_from_ce_opt(days)
+            },
+        }
+    }
+
+    /// Converts a proleptic Gregorian date to (year, month, day) in this calendar.
+    pub fn from_gregorian(&self, date: NaiveDate) -> (i32, u32, u32) {
+        match self {
+            Calendar::Gregorian => (date.year(), date.month(), date.day()),
+            Calendar::Julian => {
+                let jdn = i64::from(date.num_days_from_ce()) + JDN_CE_OFFSET;
+                let c = jdn + 32082;
+                let d = (4 * c + 3).div_euclid(1461);
+                let e = c - (1461 * d).div_euclid(4);
+                let m = (5 * e + 2) / 153;
+                let day = e - (153 * m + 2) / 5 + 1;
+                let month = m + 3 - 12 * (m / 10);
+                let year = d - 4800 + m / 10;
+                (year as i32, month as u32, day as u32)
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Calendar::Gregorian => write!(f, "gregorian"),
+            Calendar::Julian => write!(f, "julian"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_julian_to_gregorian() {
+        // Russian February Revolution: 23 February 1917 O.S.
+        let date = Calendar::Julian.to_gregorian(1917, 2, 23).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(1917, 3, 8).unwrap());
+
+        // Julian leap day that does not exist in the Gregorian calendar
+        assert!(Calendar::Julian.to_gregorian(1700, 2, 29).is_some());
+        assert!(Calendar::Julian.to_gregorian(1701, 2, 29).is_none());
+    }
+
+    #[test]
+    fn test_julian_roundtrip() {
+        for (year, month, day) in [(1582, 10, 4), (-43, 3, 15), (1, 1, 1), (1700, 2, 29)] {
+            let gregorian = Calendar::Julian.to_gregorian(year, month, day).unwrap();
+            assert_eq!(
+                Calendar::Julian.from_gregorian(gregorian),
+                (year, month, day)
+            );
+        }
+    }
+}
@@ -0,0 +1,804 @@
+//! Narrative diffs, patches and edit history.
+
+use geo_types::Geometry;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::core::{
+    ActorRef, ActorRegistry, Event, EventId, Location, MetadataValue, Narrative, Section,
+    SourceRef, Timestamp,
+};
+use crate::error::{Error, Result};
+
+/// A change to a single field of an event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum FieldChange {
+    /// The location changed.
+    Location { old: Location, new: Location },
+    /// The extended geometry changed.
+    Geometry {
+        old: Option<Geometry<f64>>,
+        new: Option<Geometry<f64>>,
+    },
+    /// The (start) timestamp changed.
+    Timestamp { old: Timestamp, new: Timestamp },
+    /// The end timestamp changed.
+    EndTimestamp {
+        old: Option<Timestamp>,
+        new: Option<Timestamp>,
+    },
+    /// The text changed.
+    Text { old: String, new: String },
+    /// A tag was added.
+    TagAdded { tag: String },
+    /// A tag was removed.
+    TagRemoved { tag: String },
+    /// A metadata entry was set, changed or removed.
+    Metadata {
+        key: String,
+        old: Option<MetadataValue>,
+        new: Option<MetadataValue>,
+    },
+    /// The source list changed.
+    Sources {
+        old: Vec<SourceRef>,
+        new: Vec<SourceRef>,
+    },
+    /// The actor list changed.
+    Actors {
+        old: Vec<ActorRef>,
+        new: Vec<ActorRef>,
+    },
+}
+
+impl FieldChange {
+    /// Returns the change that reverts this one.
+    pub fn invert(&self) -> FieldChange {
+        match self.clone() {
+            FieldChange::Location { old, new } => FieldChange::Location { old: new, new: old },
+            FieldChange::Geometry { old, new } => FieldChange::Geometry { old: new, new: old },
+            FieldChange::Timestamp { old, new } => FieldChange::Timestamp { old: new, new: old },
+            FieldChange::EndTimestamp { old, new } => {
+                FieldChange::EndTimestamp { old: new, new: old }
+            },
+            FieldChange::Text { old, new } => FieldChange::Text { old: new, new: old },
+            FieldChange::TagAdded { tag } => FieldChange::TagRemoved { tag },
+            FieldChange::TagRemoved { tag } => FieldChange::TagAdded { tag },
+            FieldChange::Metadata { key, old, new } => FieldChange::Metadata {
+                key,
+                old: new,
+                new: old,
+            },
+            FieldChange::Sources { old, new } => FieldChange::Sources { old: new, new: old },
+            FieldChange::Actors { old, new } => FieldChange::Actors { old: new, new: old },
+        }
+    }
+
+    /// Applies the change to an event, checking the old value first.
+    pub fn apply(&self, event: &mut Event) -> Result<()> {
+        fn check<T: PartialEq>(current: &T, old: &T, event: &Event, field: &str) -> Result<()> {
+            if current == old {
+                Ok(())
+            } else {
+                Err(Error::PatchConflict(format!(
+                    "{} of event {} does not match the patch",
+                    field, event.id
+                )))
+            }
+        }
+
+        match self {
+            FieldChange::Location { old, new } => {
+                check(&event.location, old, event, "location")?;
+                event.location = new.clone();
+            },
+            FieldChange::Geometry { old, new } => {
+                check(&event.geometry, old, event, "geometry")?;
+                event.geometry = new.clone();
+            },
+            FieldChange::Timestamp { old, new } => {
+                check(&event.timestamp, old, event, "timestamp")?;
+                event.timestamp = new.clone();
+            },
+            FieldChange::EndTimestamp { old, new } => {
+                check(&event.end_timestamp, old, event, "end timestamp")?;
+                event.end_timestamp = new.clone();
+            },
+            FieldChange::Text { old, new } => {
+                check(&event.text, old, event, "text")?;
+                event.text = new.clone();
+            },
+            FieldChange::TagAdded { tag } => event.add_tag(tag.clone()),
+            FieldChange::TagRemoved { tag } => event.remove_tag(tag),
+            FieldChange::Metadata { key, old, new } => {
+                let current = event.metadata.get(key).cloned();
+                check(&current, old, event, &format!("metadata '{}'", key))?;
+                match new {
+                    Some(value) => event.set_metadata(key.clone(), value.clone()),
+                    None => {
+                        event.metadata.remove(key);
+                    },
+                }
+            },
+            FieldChange::Sources { old, new } => {
+                check(&event.sources, old, event, "sources")?;
+                event.sources = new.clone();
+            },
+            FieldChange::Actors { old, new } => {
+                check(&event.actors, old, event, "actors")?;
+                event.actors = new.clone();
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Field-level changes to one event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventChange {
+    /// ID of the modified event.
+    pub id: EventId,
+    /// Changes to the event's fields.
+    pub changes: Vec<FieldChange>,
+}
+
+impl EventChange {
+    /// Computes the field changes between two versions of an event.
+    pub fn between(old: &Event, new: &Event) -> Self {
+        let mut changes = Vec::new();
+
+        if old.location != new.location {
+            changes.push(FieldChange::Location {
+                old: old.location.clone(),
+                new: new.location.clone(),
+            });
+        }
+        if old.geometry != new.geometry {
+            changes.push(FieldChange::Geometry {
+                old: old.geometry.clone(),
+                new: new.geometry.clone(),
+            });
+        }
+        if old.timestamp != new.timestamp {
+            changes.push(FieldChange::Timestamp {
+                old: old.timestamp.clone(),
+                new: new.timestamp.clone(),
+            });
+        }
+        if old.end_timestamp != new.end_timestamp {
+            changes.push(FieldChange::EndTimestamp {
+                old: old.end_timestamp.clone(),
+                new: new.end_timestamp.clone(),
+            });
+        }
+        if old.text != new.text {
+            changes.push(FieldChange::Text {
+                old: old.text.clone(),
+                new: new.text.clone(),
+            });
+        }
+
+        for tag in &old.tags {
+            if !new.has_tag(tag) {
+                changes.push(FieldChange::TagRemoved { tag: tag.clone() });
+            }
+        }
+        for tag in &new.tags {
+            if !old.has_tag(tag) {
+                changes.push(FieldChange::TagAdded { tag: tag.clone() });
+            }
+        }
+
+        let keys: BTreeSet<_> = old.metadata.keys().chain(new.metadata.keys()).collect();
+        for key in keys {
+            let (before, after) = (old.metadata.get(key), new.metadata.get(key));
+            if before != after {
+                changes.push(FieldChange::Metadata {
+                    key: key.clone(),
+                    old: before.cloned(),
+                    new: after.cloned(),
+                });
+            }
+        }
+
+        if old.sources != new.sources {
+            changes.push(FieldChange::Sources {
+                old: old.sources.clone(),
+                new: new.sources.clone(),
+            });
+        }
+        if old.actors != new.actors {
+            changes.push(FieldChange::Actors {
+                old: old.actors.clone(),
+                new: new.actors.clone(),
+            });
+        }
+
+        Self {
+            id: new.id.clone(),
+            changes,
+        }
+    }
+
+    /// Returns true if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A change to a narrative-level value, such as its sections.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueChange<T> {
+    /// The value before the change.
+    pub old: T,
+    /// The value after the change.
+    pub new: T,
+}
+
+impl<T: Clone + PartialEq> ValueChange<T> {
+    /// Returns the change between two values, if they differ.
+    pub fn between(old: &T, new: &T) -> Option<Self> {
+        (old != new).then(|| Self {
+            old: old.clone(),
+            new: new.clone(),
+        })
+    }
+
+    /// Returns the change that reverts this one.
+    pub fn invert(&self) -> Self {
+        Self {
+            old: self.new.clone(),
+            new: self.old.clone(),
+        }
+    }
+
+    /// Checks that the current value is the one the change expects.
+    fn check(&self, current: &T, what: &str) -> Result<()> {
+        if *current == self.old {
+            Ok(())
+        } else {
+            Err(Error::PatchConflict(format!(
+                "{} does not match the patch",
+                what
+            )))
+        }
+    }
+}
+
+/// Differences between two versions of a narrative.
+///
+/// A diff is serializable and can be applied to the old version as a
+/// patch to produce the new one, or inverted to undo it. Besides event
+/// changes it records the event order, sections, telling order and
+/// actors, so undoing a removal puts the event back where it was,
+/// along with its section and discourse references.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, NarrativeDiff, Timestamp};
+///
+/// let mut before = Narrative::new("Draft");
+/// before.add_event(Event::new(Location::new(0.0, 0.0), Timestamp::now(), "Landing"));
+///
+/// let mut after = before.clone();
+/// after.events_mut()[0].text = "Landing at dawn".to_string();
+/// after.add_event(Event::new(Location::new(1.0, 1.0), Timestamp::now(), "Advance"));
+///
+/// let diff = NarrativeDiff::between(&before, &after);
+/// assert_eq!(diff.added.len(), 1);
+/// assert_eq!(diff.modified.len(), 1);
+///
+/// let mut patched = before.clone();
+/// diff.apply(&mut patched).unwrap();
+/// assert_eq!(patched.events(), after.events());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NarrativeDiff {
+    /// Events present only in the new version.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<Event>,
+    /// Events present only in the old version.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<Event>,
+    /// Events present in both versions with changed fields.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modified: Vec<EventChange>,
+    /// Event IDs in storage order, if events were added, removed or moved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<ValueChange<Vec<EventId>>>,
+    /// The sections, if they changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sections: Option<ValueChange<Vec<Section>>>,
+    /// The telling order, if it changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discourse: Option<ValueChange<Vec<EventId>>>,
+    /// The actor registry, if it changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actors: Option<ValueChange<ActorRegistry>>,
+}
+
+impl NarrativeDiff {
+    /// Computes the diff from `old` to `new`, matching events by ID.
+    pub fn between(old: &Narrative, new: &Narrative) -> Self {
+        let mut diff = NarrativeDiff::default();
+        let old_positions = positions(old.events());
+        let new_positions = positions(new.events());
+
+        for event in old.events() {
+            match new_positions.get(&event.id) {
+                Some(&i) => {
+                    let change = EventChange::between(event, &new.events()[i]);
+                    if !change.is_empty() {
+                        diff.modified.push(change);
+                    }
+                },
+                None => diff.removed.push(event.clone()),
+            }
+        }
+        for event in new.events() {
+            if !old_positions.contains_key(&event.id) {
+                diff.added.push(event.clone());
+            }
+        }
+
+        let ids = |n: &Narrative| n.events().iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        diff.order = ValueChange::between(&ids(old), &ids(new));
+        diff.sections = ValueChange::between(&old.sections, &new.sections);
+        diff.discourse = ValueChange::between(&old.discourse, &new.discourse);
+        diff.actors = ValueChange::between(&old.actors, &new.actors);
+
+        diff
+    }
+
+    /// Returns true if the two versions were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && self.order.is_none()
+            && self.sections.is_none()
+            && self.discourse.is_none()
+            && self.actors.is_none()
+    }
+
+    /// Returns the number of affected events.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.modified.len()
+    }
+
+    /// Returns the diff that reverts this one.
+    pub fn invert(&self) -> NarrativeDiff {
+        NarrativeDiff {
+            added: self.removed.clone(),
+            removed: self.added.clone(),
+            modified: self
+                .modified
+                .iter()
+                .map(|change| EventChange {
+                    id: change.id.clone(),
+                    changes: change
+                        .changes
+                        .iter()
+                        .rev()
+                        .map(FieldChange::invert)
+                        .collect(),
+                })
+                .collect(),
+            order: self.order.as_ref().map(ValueChange::invert),
+            sections: self.sections.as_ref().map(ValueChange::invert),
+            discourse: self.discourse.as_ref().map(ValueChange::invert),
+            actors: self.actors.as_ref().map(ValueChange::invert),
+        }
+    }
+
+    /// Applies the diff to a narrative as a patch.
+    ///
+    /// Fails without modifying the narrative if an event is missing, or a
+    /// field, the sections, the telling order or the actors no longer
+    /// hold the value the diff expects. Events are put in the recorded
+    /// order; events the diff does not list keep their relative order
+    /// after the listed ones.
+    pub fn apply(&self, narrative: &mut Narrative) -> Result<()> {
+        if let Some(sections) = &self.sections {
+            sections.check(&narrative.sections, "sections")?;
+        }
+        if let Some(discourse) = &self.discourse {
+            discourse.check(&narrative.discourse, "discourse order")?;
+        }
+        if let Some(actors) = &self.actors {
+            actors.check(&narrative.actors, "actor registry")?;
+        }
+
+        // Removed events become `None` so positions stay valid
+        let positions = positions(&narrative.events);
+        let mut events: Vec<Option<Event>> = narrative.events.iter().cloned().map(Some).collect();
+
+        for removed in &self.removed {
+            let slot = positions
+                .get(&removed.id)
+                .copied()
+                .filter(|&i| events[i].is_some())
+                .ok_or_else(|| Error::EventNotFound(removed.id.to_string()))?;
+            if events[slot].as_ref() != Some(removed) {
+                return Err(Error::PatchConflict(format!(
+                    "removed event {} has been modified",
+                    removed.id
+                )));
+            }
+            events[slot] = None;
+        }
+
+        for change in &self.modified {
+            let event = positions
+                .get(&change.id)
+                .and_then(|&i| events[i].as_mut())
+                .ok_or_else(|| Error::EventNotFound(change.id.to_string()))?;
+            for field in &change.changes {
+                field.apply(event)?;
+            }
+        }
+
+        let mut added_ids = HashSet::with_capacity(self.added.len());
+        for added in &self.added {
+            let exists = positions
+                .get(&added.id)
+                .is_some_and(|&i| events[i].is_some());
+            if exists || !added_ids.insert(&added.id) {
+                return Err(Error::PatchConflict(format!(
+                    "added event {} already exists",
+                    added.id
+                )));
+            }
+        }
+        let mut events: Vec<Event> = events.into_iter().flatten().collect();
+        events.extend(self.added.iter().cloned());
+
+        if let Some(order) = &self.order {
+            let rank: HashMap<&EventId, usize> = order
+                .new
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (id, i))
+                .collect();
+            events.sort_by_key(|e| rank.get(&e.id).copied().unwrap_or(usize::MAX));
+        }
+
+        narrative.events = events;
+        if let Some(sections) = &self.sections {
+            narrative.sections = sections.new.clone();
+        }
+        if let Some(discourse) = &self.discourse {
+            narrative.discourse = discourse.new.clone();
+        }
+        if let Some(actors) = &self.actors {
+            narrative.actors = actors.new.clone();
+        }
+        narrative.metadata.modified = Some(Timestamp::now());
+        Ok(())
+    }
+}
+
+/// Maps each event ID to the position of its first occurrence.
+fn positions(events: &[Event]) -> HashMap<&EventId, usize> {
+    let mut positions = HashMap::with_capacity(events.len());
+    for (i, event) in events.iter().enumerate() {
+        positions.entry(&event.id).or_insert(i);
+    }
+    positions
+}
+
+/// Undo/redo history of edits to a narrative.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{ChangeLog, Event, Location, Narrative, Timestamp};
+///
+/// let mut narrative = Narrative::new("Story");
+/// let mut log = ChangeLog::new();
+///
+/// log.edit(&mut narrative, |n| {
+///     n.add_event(Event::new(Location::new(0.0, 0.0), Timestamp::now(), "Start"));
+/// });
+/// assert_eq!(narrative.len(), 1);
+///
+/// log.undo(&mut narrative).unwrap();
+/// assert!(narrative.is_empty());
+///
+/// log.redo(&mut narrative).unwrap();
+/// assert_eq!(narrative.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChangeLog {
+    undo: Vec<NarrativeDiff>,
+    redo: Vec<NarrativeDiff>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl ChangeLog {
+    /// Creates an empty, unbounded change log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a change log that keeps at most `limit` undo steps.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Records a diff that has already been applied, clearing the redo stack.
+    pub fn record(&mut self, diff: NarrativeDiff) {
+        if diff.is_empty() {
+            return;
+        }
+        self.undo.push(diff);
+        self.redo.clear();
+        if let Some(limit) = self.limit {
+            let excess = self.undo.len().saturating_sub(limit);
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Applies a diff to the narrative and records it.
+    pub fn apply(&mut self, narrative: &mut Narrative, diff: NarrativeDiff) -> Result<()> {
+        diff.apply(narrative)?;
+        self.record(diff);
+        Ok(())
+    }
+
+    /// Runs an edit on the narrative and records the resulting diff.
+    ///
+    /// Changes to events, their order, sections, the telling order and
+    /// actors are recorded. Returns true if the edit changed any of them.
+    pub fn edit<F>(&mut self, narrative: &mut Narrative, f: F) -> bool
+    where
+        F: FnOnce(&mut Narrative),
+    {
+        let before = narrative.clone();
+        f(narrative);
+        let diff = NarrativeDiff::between(&before, narrative);
+        let changed = !diff.is_empty();
+        if changed {
+            narrative.metadata.modified = Some(Timestamp::now());
+        }
+        self.record(diff);
+        changed
+    }
+
+    /// Reverts the most recent edit. Returns false if there is nothing to undo.
+    pub fn undo(&mut self, narrative: &mut Narrative) -> Result<bool> {
+        let Some(diff) = self.undo.pop() else {
+            return Ok(false);
+        };
+        if let Err(e) = diff.invert().apply(narrative) {
+            self.undo.push(diff);
+            return Err(e);
+        }
+        self.redo.push(diff);
+        Ok(true)
+    }
+
+    /// Re-applies the most recently undone edit. Returns false if there is nothing to redo.
+    pub fn redo(&mut self, narrative: &mut Narrative) -> Result<bool> {
+        let Some(diff) = self.redo.pop() else {
+            return Ok(false);
+        };
+        if let Err(e) = diff.apply(narrative) {
+            self.redo.push(diff);
+            return Err(e);
+        }
+        self.undo.push(diff);
+        Ok(true)
+    }
+
+    /// Returns true if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is an edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the recorded edits, oldest first.
+    pub fn history(&self) -> &[NarrativeDiff] {
+        &self.undo
+    }
+
+    /// Discards all history.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Actor, ActorKind};
+
+    fn narrative() -> Narrative {
+        let mut n = Narrative::new("Test");
+        n.add_event(
+            Event::builder()
+                .coordinates(10.0, 20.0)
+                .timestamp(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+                .text("First")
+                .tag("a")
+                .metadata("k", "v")
+                .build(),
+        );
+        n.add_event(Event::new(
+            Location::new(0.0, 0.0),
+            Timestamp::parse("2024-01-02T00:00:00Z").unwrap(),
+            "Second",
+        ));
+        n
+    }
+
+    #[test]
+    fn test_field_level_changes() {
+        let old = narrative();
+        let mut new = old.clone();
+        {
+            let event = &mut new.events_mut()[0];
+            event.location = Location::new(11.0, 20.0);
+            event.timestamp = Timestamp::parse("2024-01-01T06:00:00Z").unwrap();
+            event.remove_tag("a");
+            event.add_tag("b");
+            event.metadata.remove("k");
+            event.set_metadata("n", "1");
+        }
+
+        let diff = NarrativeDiff::between(&old, &new);
+        assert_eq!(diff.len(), 1);
+        let changes = &diff.modified[0].changes;
+        assert!(matches!(changes[0], FieldChange::Location { .. }));
+        assert!(matches!(changes[1], FieldChange::Timestamp { .. }));
+        assert!(changes.contains(&FieldChange::TagRemoved { tag: "a".into() }));
+        assert!(changes.contains(&FieldChange::TagAdded { tag: "b".into() }));
+        assert!(changes.contains(&FieldChange::Metadata {
+            key: "k".into(),
+            old: Some("v".into()),
+            new: None,
+        }));
+        assert_eq!(changes.len(), 6);
+    }
+
+    #[test]
+    fn test_apply_and_invert() {
+        let old = narrative();
+        let mut new = old.clone();
+        let removed = new.events[1].id.clone();
+        new.remove_event(&removed);
+        new.events_mut()[0].text = "Edited".into();
+        new.add_event(Event::new(
+            Location::new(5.0, 5.0),
+            Timestamp::now(),
+            "Third",
+        ));
+
+        let diff = NarrativeDiff::between(&old, &new);
+        assert_eq!((diff.added.len(), diff.removed.len()), (1, 1));
+
+        let mut patched = old.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(patched.events, new.events);
+
+        diff.invert().apply(&mut patched).unwrap();
+        assert_eq!(patched.len(), 2);
+        assert_eq!(patched.events[0], old.events[0]);
+        assert!(patched.get_event(&removed).is_some());
+    }
+
+    #[test]
+    fn test_apply_conflict_leaves_narrative_untouched() {
+        let old = narrative();
+        let mut new = old.clone();
+        new.events_mut()[0].text = "Edited".into();
+        new.events_mut()[1].text = "Also edited".into();
+        let diff = NarrativeDiff::between(&old, &new);
+
+        let mut other = old.clone();
+        other.events_mut()[1].text = "Concurrent edit".into();
+        let snapshot = other.clone();
+
+        let err = diff.apply(&mut other).unwrap_err();
+        assert!(matches!(err, Error::PatchConflict(_)));
+        assert_eq!(other, snapshot);
+    }
+
+    #[test]
+    fn test_diff_serialization() {
+        let old = narrative();
+        let mut new = old.clone();
+        new.events_mut()[0].set_metadata("k", "w");
+        let diff = NarrativeDiff::between(&old, &new);
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["modified"][0]["changes"][0]["field"], "metadata");
+        assert!(json.get("added").is_none());
+
+        let parsed: NarrativeDiff = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, diff);
+    }
+
+    #[test]
+    fn test_change_log_undo_redo() {
+        let mut n = narrative();
+        let original = n.events.clone();
+        let mut log = ChangeLog::new();
+
+        assert!(log.edit(&mut n, |n| n.events_mut()[0].text = "One".into()));
+        assert!(log.edit(&mut n, |n| n.events_mut()[0].text = "Two".into()));
+        assert!(!log.edit(&mut n, |_| {}));
+        assert_eq!(log.history().len(), 2);
+
+        assert!(log.undo(&mut n).unwrap());
+        assert_eq!(n.events[0].text, "One");
+        assert!(log.undo(&mut n).unwrap());
+        assert_eq!(n.events, original);
+        assert!(!log.undo(&mut n).unwrap());
+
+        assert!(log.redo(&mut n).unwrap());
+        assert_eq!(n.events[0].text, "One");
+
+        // A new edit discards the redo stack
+        log.edit(&mut n, |n| n.events_mut()[0].text = "Three".into());
+        assert!(!log.can_redo());
+    }
+
+    #[test]
+    fn test_undo_restores_structure() {
+        let mut n = narrative();
+        n.add_event(Event::new(
+            Location::new(5.0, 5.0),
+            Timestamp::parse("2024-01-03T00:00:00Z").unwrap(),
+            "Third",
+        ));
+        let first = n.events[0].id.clone();
+        let middle = n.events[1].id.clone();
+        n.add_section(Section::new("Part I").with_events([middle.clone(), n.events[2].id.clone()]));
+        let told: Vec<EventId> = n.events.iter().rev().map(|e| e.id.clone()).collect();
+        n.set_discourse_order(told).unwrap();
+        let original = n.clone();
+
+        let mut log = ChangeLog::new();
+        assert!(log.edit(&mut n, |n| {
+            n.remove_event(&middle);
+        }));
+        assert!(log.edit(&mut n, |n| {
+            n.actors
+                .add(Actor::new("Harbour master", ActorKind::Person));
+        }));
+        assert!(log.edit(&mut n, |n| n.sections[0].title = "Prologue".into()));
+        assert!(log.edit(&mut n, |n| n.move_in_discourse(&first, 0).unwrap()));
+
+        while log.undo(&mut n).unwrap() {}
+        // Applying a diff stamps the modification time
+        n.metadata.modified = original.metadata.modified.clone();
+        assert_eq!(n, original);
+
+        while log.redo(&mut n).unwrap() {}
+        assert!(n.get_event(&middle).is_none());
+        assert_eq!(n.sections[0].title, "Prologue");
+        assert_eq!(n.actors.len(), 1);
+    }
+
+    #[test]
+    fn test_change_log_limit() {
+        let mut n = narrative();
+        let mut log = ChangeLog::with_limit(2);
+        for text in ["a", "b", "c"] {
+            log.edit(&mut n, |n| n.events_mut()[0].text = text.into());
+        }
+        assert_eq!(log.history().len(), 2);
+    }
+}
@@ -1,14 +1,17 @@
 //! Event representation - something that happened at a place and time.
 
+use geo::Centroid;
+use geo_types::Geometry;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::core::{Location, SourceRef, Timestamp};
+use crate::core::{ActorId, ActorRef, Location, MetadataValue, SourceRef, Timestamp};
 use crate::error::{Error, Result};
 
 /// Unique identifier for an event.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(transparent)]
 pub struct EventId(pub Uuid);
 
@@ -54,11 +57,98 @@ impl From<Uuid> for EventId {
     }
 }
 
+/// Namespace for content-derived event IDs (UUIDv5).
+const EVENT_ID_NAMESPACE: Uuid = Uuid::from_u128(0x739ce6e9_e5e6_5f5f_86d5_f96ec23c7450);
+
+/// How events without an explicit ID get one.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, IdStrategy, Location, Timestamp};
+///
+/// let build = || {
+///     Event::builder()
+///         .location(Location::new(48.8566, 2.3522))
+///         .timestamp(Timestamp::parse("2024-07-26T19:30:00Z").unwrap())
+///         .text("Opening ceremony")
+///         .id_strategy(IdStrategy::Content)
+///         .build()
+/// };
+/// assert_eq!(build().id, build().id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IdStrategy {
+    /// A random UUIDv4.
+    #[default]
+    Random,
+    /// A UUIDv5 over the event's location, time, text and sources.
+    ///
+    /// Re-importing the same data yields the same IDs. Events that agree
+    /// on all of these fields get the same ID; the importers give repeats
+    /// within one file distinct IDs derived from the first (see
+    /// [`Event::content_id`] for what the hash leaves out).
+    Content,
+}
+
+impl IdStrategy {
+    /// Returns an ID for the event under this strategy.
+    pub fn id_for(&self, event: &Event) -> EventId {
+        match self {
+            IdStrategy::Random => EventId::new(),
+            IdStrategy::Content => event.content_id(),
+        }
+    }
+}
+
+/// Keeps generated IDs distinct within one import.
+///
+/// Rows with identical content hash to the same [`IdStrategy::Content`]
+/// ID. The first keeps it; the n-th repeat gets a UUIDv5 over that ID and
+/// n, so re-importing the same rows in the same order yields the same IDs.
+#[derive(Debug)]
+pub(crate) struct ImportIds {
+    strategy: IdStrategy,
+    seen: HashMap<EventId, u32>,
+}
+
+impl ImportIds {
+    pub(crate) fn new(strategy: IdStrategy) -> Self {
+        Self {
+            strategy,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Returns a generated ID, replaced if it was already handed out.
+    ///
+    /// Explicit IDs from the data should not be passed through this.
+    pub(crate) fn distinct(&mut self, id: EventId) -> EventId {
+        if self.strategy != IdStrategy::Content {
+            return id;
+        }
+        let seen = self.seen.entry(id.clone()).or_insert(0);
+        *seen += 1;
+        if *seen == 1 {
+            return id;
+        }
+        let repeat = format!("{}#{}", id, *seen - 1);
+        EventId(Uuid::new_v5(&EVENT_ID_NAMESPACE, repeat.as_bytes()))
+    }
+}
+
 /// An event in a spatial narrative.
 ///
 /// Events are the fundamental unit of spatial narratives. Each event
 /// represents something that happened at a specific place and time.
 ///
+/// Events that cover an area or follow a path (a march route, a flood
+/// extent) can carry an extended [`Geometry`]. In that case `location`
+/// holds a representative point, by default the geometry's centroid.
+///
+/// Events that last a while (a siege, a strike) can carry an
+/// `end_timestamp`, making `timestamp` the start of an interval.
+///
 /// # Examples
 ///
 /// ```
@@ -71,28 +161,57 @@ impl From<Uuid> for EventId {
 ///     .tag("protest")
 ///     .tag("politics")
 ///     .source(SourceRef::article("https://news.example.com/protest"))
-///     .metadata("participants", "5000")
+///     .metadata("participants", 5000)
 ///     .build();
+///
+/// // An event with a line geometry (coordinates are lon, lat)
+/// let route = geo_types::LineString::from(vec![(-74.0060, 40.7128), (-73.9857, 40.7484)]);
+/// let march = Event::builder()
+///     .geometry(route.into())
+///     .timestamp(Timestamp::now())
+///     .text("March from City Hall to Midtown")
+///     .build();
+/// assert!(march.geometry.is_some());
+///
+/// // An event lasting three days
+/// let strike = Event::builder()
+///     .location(Location::new(51.5074, -0.1278))
+///     .timestamp(Timestamp::parse("2024-03-01").unwrap())
+///     .duration(chrono::Duration::days(3))
+///     .text("General strike")
+///     .build();
+/// assert!(strike.is_interval());
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     /// Unique identifier.
     pub id: EventId,
     /// Geographic location.
+    ///
+    /// For events with an extended geometry this is a representative point.
     pub location: Location,
-    /// When the event occurred.
+    /// Extended geometry (line, polygon, multi-geometry), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry<f64>>,
+    /// When the event occurred (the start, for interval events).
     pub timestamp: Timestamp,
+    /// When the event ended, for events that last a period of time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_timestamp: Option<Timestamp>,
     /// Description of the event.
     pub text: String,
     /// Key-value metadata.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub metadata: HashMap<String, String>,
+    pub metadata: HashMap<String, MetadataValue>,
     /// References to source material.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sources: Vec<SourceRef>,
     /// Categorical tags.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Actors involved in the event, with their roles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actors: Vec<ActorRef>,
 }
 
 impl Event {
@@ -101,19 +220,51 @@ impl Event {
         Self {
             id: EventId::new(),
             location,
+            geometry: None,
             timestamp,
+            end_timestamp: None,
             text: text.into(),
             metadata: HashMap::new(),
             sources: Vec::new(),
             tags: Vec::new(),
+            actors: Vec::new(),
         }
     }
 
+    /// Creates a new event with an extended geometry.
+    ///
+    /// The event's `location` is set to the geometry's centroid.
+    pub fn with_geometry(
+        geometry: Geometry<f64>,
+        timestamp: Timestamp,
+        text: impl Into<String>,
+    ) -> Self {
+        let location = centroid_location(&geometry).unwrap_or_default();
+        let mut event = Self::new(location, timestamp, text);
+        event.geometry = Some(geometry);
+        event
+    }
+
     /// Creates a builder for constructing an Event.
     pub fn builder() -> EventBuilder {
         EventBuilder::new()
     }
 
+    /// Returns true if the event spans a period of time.
+    pub fn is_interval(&self) -> bool {
+        self.end_timestamp.is_some()
+    }
+
+    /// Returns the duration of the event.
+    ///
+    /// Instantaneous events have a zero duration.
+    pub fn duration(&self) -> chrono::Duration {
+        self.end_timestamp
+            .as_ref()
+            .map(|end| end.duration_since(&self.timestamp))
+            .unwrap_or_else(chrono::Duration::zero)
+    }
+
     /// Returns true if the event has the given tag.
     pub fn has_tag(&self, tag: &str) -> bool {
         self.tags.iter().any(|t| t == tag)
@@ -133,12 +284,17 @@ impl Event {
     }
 
     /// Gets a metadata value.
-    pub fn get_metadata(&self, key: &str) -> Option<&str> {
-        self.metadata.get(key).map(|s| s.as_str())
+    pub fn get_metadata(&self, key: &str) -> Option<&MetadataValue> {
+        self.metadata.get(key)
+    }
+
+    /// Gets a numeric metadata value as a float.
+    pub fn metadata_f64(&self, key: &str) -> Option<f64> {
+        self.metadata.get(key).and_then(MetadataValue::as_f64)
     }
 
     /// Sets a metadata value.
-    pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+    pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<MetadataValue>) {
         self.metadata.insert(key.into(), value.into());
     }
 
@@ -147,10 +303,171 @@ impl Event {
         self.sources.push(source);
     }
 
+    /// Returns the confidence in this event from its sources, using the
+    /// default [`ReliabilityModel`](crate::core::ReliabilityModel).
+    pub fn confidence(&self) -> f64 {
+        crate::core::ReliabilityModel::default().confidence(self)
+    }
+
+    /// Adds an actor reference, unless the same actor and role are already present.
+    pub fn add_actor(&mut self, actor: impl Into<ActorRef>) {
+        let actor = actor.into();
+        if !self.actors.contains(&actor) {
+            self.actors.push(actor);
+        }
+    }
+
+    /// Returns true if the actor is involved in this event.
+    pub fn involves(&self, actor: &ActorId) -> bool {
+        self.actors.iter().any(|a| &a.actor == actor)
+    }
+
+    /// Returns the roles the actor plays in this event.
+    pub fn actor_roles(&self, actor: &ActorId) -> Vec<&str> {
+        self.actors
+            .iter()
+            .filter(|a| &a.actor == actor)
+            .filter_map(|a| a.role.as_deref())
+            .collect()
+    }
+
+    /// Sets the extended geometry and moves `location` to its centroid.
+    ///
+    /// Other location fields (name, elevation, uncertainty) are kept.
+    pub fn set_geometry(&mut self, geometry: Geometry<f64>) {
+        if let Some(centroid) = centroid_location(&geometry) {
+            self.location.lat = centroid.lat;
+            self.location.lon = centroid.lon;
+        }
+        self.geometry = Some(geometry);
+    }
+
+    /// Returns a deterministic ID derived from the event's content.
+    ///
+    /// The ID is a UUIDv5 over a canonical form of the location (to seven
+    /// decimal places), start and end time, trimmed text and sources. Tags,
+    /// metadata, actors and the current ID do not contribute.
+    ///
+    /// Neither do the extended geometry (only its representative point in
+    /// `location` counts) or the timestamps' precision, approximation flag,
+    /// calendar and time zone: times are hashed as instants, so "1850" and
+    /// "1850-01-01" at the same location and text share an ID.
+    pub fn content_id(&self) -> EventId {
+        EventId(Uuid::new_v5(
+            &EVENT_ID_NAMESPACE,
+            self.canonical_content().as_bytes(),
+        ))
+    }
+
+    /// Canonical form of the fields that identify an event's content.
+    ///
+    /// Every field is written as `name:<byte length>:value` so that no
+    /// choice of text, title or URL can make two different events collide.
+    /// Source types use their stable lowercase names.
+    fn canonical_content(&self) -> String {
+        fn push_field(out: &mut String, name: &str, value: &str) {
+            out.push_str(&format!("{}:{}:{}\n", name, value.len(), value));
+        }
+        let time = |t: &Timestamp| {
+            t.datetime
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+        };
+
+        let mut out = String::new();
+        // Adding 0.0 turns -0.0 into 0.0
+        push_field(&mut out, "lat", &format!("{:.7}", self.location.lat + 0.0));
+        push_field(&mut out, "lon", &format!("{:.7}", self.location.lon + 0.0));
+        push_field(&mut out, "time", &time(&self.timestamp));
+        push_field(
+            &mut out,
+            "end",
+            &self.end_timestamp.as_ref().map(time).unwrap_or_default(),
+        );
+        push_field(&mut out, "text", self.text.trim());
+
+        // Source order does not matter
+        let mut sources: Vec<String> = self
+            .sources
+            .iter()
+            .map(|s| {
+                let field = |v: &Option<String>| v.as_deref().unwrap_or("").trim().to_string();
+                let mut source = String::new();
+                push_field(&mut source, "type", &s.source_type.to_string());
+                push_field(&mut source, "doi", &field(&s.doi));
+                push_field(&mut source, "url", &field(&s.url));
+                push_field(&mut source, "title", &field(&s.title));
+                push_field(&mut source, "author", &field(&s.author));
+                source
+            })
+            .collect();
+        sources.sort();
+        for source in sources {
+            push_field(&mut out, "source", &source);
+        }
+        out
+    }
+
     /// Returns the location as a geo-types Point.
     pub fn to_geo_point(&self) -> geo_types::Point<f64> {
         self.location.to_geo_point()
     }
+
+    /// Infers the time zone from the event's location and attaches it to
+    /// timestamps that have none.
+    ///
+    /// The UTC instants are unchanged; only local-time accessors and
+    /// formatting are affected. Nothing changes when the zone cannot be
+    /// determined (see [`Location::time_zone`]).
+    pub fn infer_time_zone(&mut self) {
+        let Some(tz) = self.location.time_zone() else {
+            return;
+        };
+        for ts in std::iter::once(&mut self.timestamp).chain(self.end_timestamp.as_mut()) {
+            if ts.time_zone.is_none() {
+                *ts = ts.clone().with_time_zone(tz);
+            }
+        }
+    }
+
+    /// Returns the event footprint as a geo-types Geometry.
+    ///
+    /// This is the extended geometry if set, otherwise the location point.
+    pub fn to_geo_geometry(&self) -> Geometry<f64> {
+        self.geometry
+            .clone()
+            .unwrap_or_else(|| Geometry::Point(self.location.to_geo_point()))
+    }
+}
+
+/// Sets the explicit ID, or one chosen by the strategy.
+fn assign_id(mut event: Event, id: Option<EventId>, strategy: IdStrategy) -> Event {
+    event.id = id.unwrap_or_else(|| strategy.id_for(&event));
+    event
+}
+
+/// Resolves an event's end time from an explicit end or a duration.
+fn resolve_end(
+    start: &Timestamp,
+    end: Option<Timestamp>,
+    duration: Option<chrono::Duration>,
+) -> Option<Timestamp> {
+    end.or_else(|| duration.map(|d| Timestamp::with_precision(start.datetime + d, start.precision)))
+}
+
+/// Returns an error if an interval ends before it starts.
+pub(crate) fn check_interval(start: &Timestamp, end: Option<&Timestamp>) -> Result<()> {
+    match end {
+        Some(end) if end < start => Err(Error::InvalidTimestamp(format!(
+            "end {} is before start {}",
+            end, start
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the centroid of a geometry as a Location.
+fn centroid_location(geometry: &Geometry<f64>) -> Option<Location> {
+    geometry.centroid().map(Location::from_geo_point)
 }
 
 /// Builder for constructing [`Event`] instances.
@@ -158,11 +475,16 @@ impl Event {
 pub struct EventBuilder {
     id: Option<EventId>,
     location: Option<Location>,
+    geometry: Option<Geometry<f64>>,
     timestamp: Option<Timestamp>,
+    end_timestamp: Option<Timestamp>,
+    duration: Option<chrono::Duration>,
     text: Option<String>,
-    metadata: HashMap<String, String>,
+    metadata: HashMap<String, MetadataValue>,
     sources: Vec<SourceRef>,
     tags: Vec<String>,
+    actors: Vec<ActorRef>,
+    id_strategy: IdStrategy,
 }
 
 impl EventBuilder {
@@ -177,6 +499,12 @@ impl EventBuilder {
         self
     }
 
+    /// Sets how the ID is chosen when none is set explicitly.
+    pub fn id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = strategy;
+        self
+    }
+
     /// Sets the location.
     pub fn location(mut self, location: Location) -> Self {
         self.location = Some(location);
@@ -189,6 +517,14 @@ impl EventBuilder {
         self
     }
 
+    /// Sets an extended geometry.
+    ///
+    /// If no location is set, the geometry's centroid is used.
+    pub fn geometry(mut self, geometry: Geometry<f64>) -> Self {
+        self.geometry = Some(geometry);
+        self
+    }
+
     /// Sets the timestamp.
     pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
         self.timestamp = Some(timestamp);
@@ -201,6 +537,21 @@ impl EventBuilder {
         Ok(self)
     }
 
+    /// Sets the end time, making this an interval event.
+    pub fn end_timestamp(mut self, end: Timestamp) -> Self {
+        self.end_timestamp = Some(end);
+        self
+    }
+
+    /// Sets the duration, making this an interval event.
+    ///
+    /// The end time is computed from the start when the event is built.
+    /// Ignored if an explicit end time is set.
+    pub fn duration(mut self, duration: chrono::Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
     /// Sets the event text.
     pub fn text(mut self, text: impl Into<String>) -> Self {
         self.text = Some(text.into());
@@ -231,8 +582,20 @@ impl EventBuilder {
         self
     }
 
+    /// Adds an actor involved in the event.
+    pub fn actor(mut self, actor: impl Into<ActorRef>) -> Self {
+        self.actors.push(actor.into());
+        self
+    }
+
+    /// Adds an actor with the role they play in the event.
+    pub fn actor_with_role(mut self, actor: ActorId, role: impl Into<String>) -> Self {
+        self.actors.push(ActorRef::with_role(actor, role));
+        self
+    }
+
     /// Sets a metadata value.
-    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<MetadataValue>) -> Self {
         self.metadata.insert(key.into(), value.into());
         self
     }
@@ -241,39 +604,67 @@ impl EventBuilder {
     ///
     /// Uses current time if timestamp is not set.
     /// Uses empty string if text is not set.
+    ///
+    /// The end time must not be before the start; debug builds panic if it
+    /// is. Use [`EventBuilder::try_build`] to get an error instead.
     pub fn build(self) -> Event {
-        Event {
-            id: self.id.unwrap_or_default(),
-            location: self.location.unwrap_or_default(),
-            timestamp: self.timestamp.unwrap_or_else(Timestamp::now),
+        let location = self
+            .location
+            .or_else(|| self.geometry.as_ref().and_then(centroid_location))
+            .unwrap_or_default();
+        let timestamp = self.timestamp.unwrap_or_else(Timestamp::now);
+        let end_timestamp = resolve_end(&timestamp, self.end_timestamp, self.duration);
+        debug_assert!(
+            check_interval(&timestamp, end_timestamp.as_ref()).is_ok(),
+            "event end is before its start"
+        );
+
+        let event = Event {
+            id: EventId(Uuid::nil()),
+            location,
+            geometry: self.geometry,
+            timestamp,
+            end_timestamp,
             text: self.text.unwrap_or_default(),
             metadata: self.metadata,
             sources: self.sources,
             tags: self.tags,
-        }
+            actors: self.actors,
+        };
+        assign_id(event, self.id, self.id_strategy)
     }
 
     /// Builds the Event, returning an error if required fields are missing.
     pub fn try_build(self) -> Result<Event> {
-        let location = self.location.ok_or(Error::MissingField("location"))?;
+        let location = self
+            .location
+            .or_else(|| self.geometry.as_ref().and_then(centroid_location))
+            .ok_or(Error::MissingField("location"))?;
         let timestamp = self.timestamp.ok_or(Error::MissingField("timestamp"))?;
         let text = self.text.ok_or(Error::MissingField("text"))?;
+        let end_timestamp = resolve_end(&timestamp, self.end_timestamp, self.duration);
+        check_interval(&timestamp, end_timestamp.as_ref())?;
 
-        Ok(Event {
-            id: self.id.unwrap_or_default(),
+        let event = Event {
+            id: EventId(Uuid::nil()),
             location,
+            geometry: self.geometry,
             timestamp,
+            end_timestamp,
             text,
             metadata: self.metadata,
             sources: self.sources,
             tags: self.tags,
-        })
+            actors: self.actors,
+        };
+        Ok(assign_id(event, self.id, self.id_strategy))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use geo_types::polygon;
 
     #[test]
     fn test_event_id_new() {
@@ -289,6 +680,48 @@ mod tests {
         assert_eq!(id, parsed);
     }
 
+    #[test]
+    fn test_content_id() {
+        let event = Event::builder()
+            .location(Location::new(51.5074, -0.1278))
+            .timestamp(Timestamp::parse("2024-03-01T09:00:00Z").unwrap())
+            .text("General strike ")
+            .source(SourceRef::article("https://a.example.com"))
+            .source(SourceRef::report("https://b.example.com"))
+            .id_strategy(IdStrategy::Content)
+            .build();
+
+        // Stable, and independent of source order, tags and surrounding whitespace
+        let mut same = event.clone();
+        same.id = EventId::new();
+        same.text = "General strike".into();
+        same.sources.reverse();
+        same.add_tag("labour");
+        assert_eq!(same.content_id(), event.id);
+
+        // Pinned: changing the canonical form changes every stored ID
+        assert_eq!(event.id.to_string(), "f2334398-cf66-5667-992f-d216955f4156");
+
+        let mut moved = event.clone();
+        moved.location.lat += 0.001;
+        assert_ne!(moved.content_id(), event.id);
+
+        // Field boundaries are unambiguous: a separator inside one field
+        // cannot reproduce a different split of the same characters
+        let mut a = event.clone();
+        a.sources = vec![SourceRef::builder().title("x|y").author("z").build()];
+        let mut b = event.clone();
+        b.sources = vec![SourceRef::builder().title("x").author("y|z").build()];
+        assert_ne!(a.content_id(), b.content_id());
+
+        let explicit = EventId::new();
+        let built = Event::builder()
+            .id(explicit.clone())
+            .id_strategy(IdStrategy::Content)
+            .build();
+        assert_eq!(built.id, explicit);
+    }
+
     #[test]
     fn test_event_new() {
         let event = Event::new(
@@ -308,14 +741,14 @@ mod tests {
             .text("Protest at City Hall")
             .tag("protest")
             .tag("politics")
-            .metadata("participants", "1000")
+            .metadata("participants", 1000)
             .source(SourceRef::article("https://example.com"))
             .build();
 
         assert_eq!(event.text, "Protest at City Hall");
         assert!(event.has_tag("protest"));
         assert!(event.has_tag("politics"));
-        assert_eq!(event.get_metadata("participants"), Some("1000"));
+        assert_eq!(event.metadata_f64("participants"), Some(1000.0));
         assert_eq!(event.sources.len(), 1);
     }
 
@@ -350,11 +783,95 @@ mod tests {
             .metadata("key1", "value1")
             .build();
 
-        assert_eq!(event.get_metadata("key1"), Some("value1"));
+        assert_eq!(
+            event.get_metadata("key1").and_then(MetadataValue::as_str),
+            Some("value1")
+        );
         assert_eq!(event.get_metadata("key2"), None);
 
-        event.set_metadata("key2", "value2");
-        assert_eq!(event.get_metadata("key2"), Some("value2"));
+        event.set_metadata("key2", 2.5);
+        assert_eq!(event.metadata_f64("key2"), Some(2.5));
+    }
+
+    #[test]
+    fn test_event_with_geometry() {
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 2.0, y: 0.0),
+            (x: 2.0, y: 2.0),
+            (x: 0.0, y: 2.0),
+        ];
+        let event = Event::builder()
+            .geometry(polygon.into())
+            .timestamp(Timestamp::now())
+            .text("Flood extent")
+            .build();
+
+        assert!(event.geometry.is_some());
+        assert!((event.location.lat - 1.0).abs() < 1e-9);
+        assert!((event.location.lon - 1.0).abs() < 1e-9);
+
+        let json = serde_json::to_string(&event).unwrap();
+        let parsed: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(event.geometry, parsed.geometry);
+    }
+
+    #[test]
+    fn test_event_infer_time_zone() {
+        let mut event = Event::builder()
+            .location(Location::new(35.6762, 139.6503))
+            .timestamp(Timestamp::parse("2024-03-15T00:30:00Z").unwrap())
+            .text("Tokyo morning")
+            .build();
+
+        event.infer_time_zone();
+
+        assert_eq!(event.timestamp.time_zone, Some(chrono_tz::Asia::Tokyo));
+        assert_eq!(event.timestamp.local_hour(), 9);
+        assert_eq!(
+            event.timestamp.datetime.to_rfc3339(),
+            "2024-03-15T00:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_event_interval() {
+        let start = Timestamp::parse("2024-03-01T00:00:00Z").unwrap();
+        let event = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(start.clone())
+            .duration(chrono::Duration::days(3))
+            .text("Siege")
+            .build();
+
+        assert!(event.is_interval());
+        assert_eq!(event.duration().num_days(), 3);
+        assert_eq!(
+            event.end_timestamp,
+            Some(Timestamp::parse("2024-03-04T00:00:00Z").unwrap())
+        );
+
+        let instant = Event::new(Location::new(0.0, 0.0), start.clone(), "Instant");
+        assert!(!instant.is_interval());
+        assert_eq!(instant.duration(), chrono::Duration::zero());
+
+        let reversed = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(start)
+            .end_timestamp(Timestamp::parse("2024-02-01T00:00:00Z").unwrap())
+            .text("Bad interval")
+            .try_build();
+        assert!(reversed.is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "event end is before its start")]
+    fn test_event_build_reversed_interval() {
+        Event::builder()
+            .timestamp(Timestamp::parse("2024-03-01T00:00:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-02-01T00:00:00Z").unwrap())
+            .build();
     }
 
     #[test]
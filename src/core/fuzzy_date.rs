@@ -0,0 +1,610 @@
+//! Parsing of historical and approximate date expressions.
+//!
+//! Handles ISO 8601 strings as well as archival notations such as
+//! "c. 1850", "the 1840s", "spring 1943", "early March 1916",
+//! "44 BCE" and "12 March 1916 (Julian)".
+//!
+//! An ordinal century is the hundred years that share its hundreds digit
+//! within its era, so the 19th century is the 1800s and the 5th century BCE
+//! is 499–400 BCE. With no year 0, the 1st centuries BCE and CE have 99
+//! years each.
+
+use crate::core::{Calendar, TemporalPrecision, TimeRange, Timestamp};
+use crate::error::{Error, Result};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Result of parsing a date expression.
+#[derive(Debug, Clone)]
+pub(crate) enum ParsedDate {
+    /// A single timestamp at some precision.
+    Instant(Timestamp),
+    /// A span implied by the notation, such as a season.
+    Range(TimeRange),
+    /// An explicit range between two dates, such as "1840-1845".
+    Between(TimeRange),
+}
+
+/// Parsed expression before calendar and approximation markers are applied.
+///
+/// Dates are read field by field, so a Julian date is still labelled
+/// with its Julian day and month at this stage.
+#[derive(Debug, Clone, Copy)]
+enum Span {
+    Instant(DateTime<Utc>, TemporalPrecision),
+    Range(DateTime<Utc>, DateTime<Utc>),
+    Between(DateTime<Utc>, DateTime<Utc>),
+}
+
+impl Span {
+    /// Returns the first and last instant covered by this span.
+    fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        match *self {
+            Span::Instant(dt, precision) => {
+                let ts = Timestamp::with_precision(dt, precision);
+                (ts.earliest().datetime, ts.latest().datetime)
+            },
+            Span::Range(start, end) | Span::Between(start, end) => (start, end),
+        }
+    }
+}
+
+const JULIAN_MARKERS: &[&str] = &["[u-ca=julian]", "(julian)", "(o.s.)", "o.s.", "julian"];
+const GREGORIAN_MARKERS: &[&str] = &[
+    "[u-ca=gregorian]",
+    "(gregorian)",
+    "(n.s.)",
+    "n.s.",
+    "gregorian",
+];
+
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+static APPROXIMATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:circa|ca\.|c\.|about|around|approximately|approx\.)\s*").unwrap()
+});
+static ISO_DATE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([+-]?\d{4,6})(?:-(\d{2})(?:-(\d{2}))?)?$").unwrap());
+static RANGE_BETWEEN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:between|from)\s+(.+?)\s+(?:and|to|until)\s+(.+)$").unwrap());
+static RANGE_SEPARATOR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+?)\s*(?:–|—|/|\bto\b|\buntil\b)\s*(.+)$").unwrap());
+static RANGE_YEARS: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d{3,4})\s*-\s*(\d{3,4})$").unwrap());
+static QUALIFIER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(early|mid|late)[\s-]+(.+)$").unwrap());
+static EDTF_UNSPECIFIED: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(-?\d{2,3})(x{1,2})$").unwrap());
+static DECADE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?:the\s+)?(\d{1,3}0)'?s$").unwrap());
+static CENTURY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:the\s+)?(\d{1,2})(?:st|nd|rd|th)\s+century(?:\s+(.+))?$").unwrap()
+});
+static SEASON: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(spring|summer|autumn|fall|winter)\s+(?:of\s+)?(.+)$").unwrap());
+static DAY_MONTH_YEAR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{1,2})(?:st|nd|rd|th)?\s+(?:of\s+)?([a-z]+)\.?\s+(.+)$").unwrap()
+});
+static MONTH_DAY_YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([a-z]+)\.?\s+(\d{1,2})(?:st|nd|rd|th)?\s+(.+)$").unwrap());
+static MONTH_YEAR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([a-z]+)\.?\s+(.+)$").unwrap());
+static YEAR_BCE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d{1,4})\s*(?:bce|bc|b\.c\.e\.|b\.c\.)$").unwrap());
+static YEAR_CE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(\d{1,4})\s*(?:ce|ad|c\.e\.|a\.d\.)|(?:ad|a\.d\.)\s*(\d{1,4}))$").unwrap()
+});
+static YEAR: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d{3,4}$").unwrap());
+
+/// Parses a date expression into a timestamp or an implied range.
+pub(crate) fn parse(input: &str) -> Result<ParsedDate> {
+    let invalid = || Error::InvalidTimestamp(input.to_string());
+
+    let mut text = input.trim().to_string();
+    let calendar = take_calendar(&mut text);
+    let time_zone = take_time_zone(&mut text);
+    let approximate = take_approximate(&mut text);
+    let span = parse_span(&text).ok_or_else(invalid)?;
+    let offset = DateTime::parse_from_rfc3339(&text)
+        .or_else(|_| text.parse::<DateTime<FixedOffset>>())
+        .ok()
+        .map(|dt| *dt.offset());
+
+    let finish = |dt: DateTime<Utc>, precision: TemporalPrecision| {
+        let dt = match calendar {
+            Calendar::Gregorian => dt,
+            Calendar::Julian => {
+                let date = dt.date_naive();
+                let gregorian = calendar.to_gregorian(date.year(), date.month(), date.day())?;
+                Utc.from_utc_datetime(&gregorian.and_time(dt.time()))
+            },
+        };
+        let mut ts = Timestamp::with_precision(dt, precision)
+            .with_calendar(calendar)
+            .with_approximate(approximate);
+        if let Some(tz) = time_zone {
+            ts = ts.with_time_zone(tz);
+        }
+        Some(ts)
+    };
+
+    let parsed = match span {
+        Span::Instant(dt, precision) => finish(dt, precision).map(|ts| match offset {
+            Some(offset) if time_zone.is_none() => ParsedDate::Instant(ts.with_offset(offset)),
+            _ => ParsedDate::Instant(ts),
+        }),
+        Span::Range(start, end) | Span::Between(start, end) => {
+            let start = finish(start, TemporalPrecision::Millisecond);
+            let end = finish(end, TemporalPrecision::Millisecond);
+            start.zip(end).map(|(start, end)| {
+                let range = TimeRange::new(start, end);
+                match span {
+                    Span::Between(..) => ParsedDate::Between(range),
+                    _ => ParsedDate::Range(range),
+                }
+            })
+        },
+    };
+    parsed.ok_or_else(invalid)
+}
+
+/// Strips a trailing calendar marker and returns the calendar it names.
+fn take_calendar(text: &mut String) -> Calendar {
+    let lower = text.to_ascii_lowercase();
+    for (markers, calendar) in [
+        (JULIAN_MARKERS, Calendar::Julian),
+        (GREGORIAN_MARKERS, Calendar::Gregorian),
+    ] {
+        if let Some(marker) = markers.iter().find(|m| lower.ends_with(*m)) {
+            text.truncate(text.len() - marker.len());
+            text.truncate(text.trim_end().len());
+            return calendar;
+        }
+    }
+    Calendar::Gregorian
+}
+
+/// Strips a trailing `[Area/City]` annotation and returns the zone it names.
+fn take_time_zone(text: &mut String) -> Option<Tz> {
+    if !text.ends_with(']') {
+        return None;
+    }
+    let open = text.rfind('[')?;
+    let tz = text[open + 1..text.len() - 1].parse().ok()?;
+    text.truncate(open);
+    text.truncate(text.trim_end().len());
+    Some(tz)
+}
+
+/// Strips approximation markers and returns whether any were present.
+fn take_approximate(text: &mut String) -> bool {
+    let mut approximate = false;
+    if text.ends_with('~') {
+        text.pop();
+        approximate = true;
+    }
+    if let Some(m) = APPROXIMATE.find(text) {
+        text.replace_range(..m.end(), "");
+        approximate = true;
+    }
+    approximate
+}
+
+/// Parses an expression that may denote a range.
+fn parse_span(text: &str) -> Option<Span> {
+    if let Some(span) = parse_iso(text) {
+        return Some(span);
+    }
+
+    let lower = text
+        .to_lowercase()
+        .replace(',', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    for regex in [&*RANGE_BETWEEN, &*RANGE_YEARS, &*RANGE_SEPARATOR] {
+        if let Some(caps) = regex.captures(&lower) {
+            let (start, _) = parse_side(&caps[1])?.bounds();
+            let (_, end) = parse_side(&caps[2])?.bounds();
+            return (start <= end).then_some(Span::Between(start, end));
+        }
+    }
+
+    parse_single(&lower)
+}
+
+/// Parses one side of a range.
+fn parse_side(text: &str) -> Option<Span> {
+    parse_iso(text).or_else(|| parse_single(text))
+}
+
+/// Parses ISO 8601 timestamps and dates, including signed years.
+fn parse_iso(text: &str) -> Option<Span> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Some(Span::Instant(
+            dt.with_timezone(&Utc),
+            TemporalPrecision::Second,
+        ));
+    }
+    if let Ok(dt) = text.parse::<DateTime<Utc>>() {
+        return Some(Span::Instant(dt, TemporalPrecision::Second));
+    }
+
+    let caps = ISO_DATE.captures(text)?;
+    let year: i32 = caps[1].parse().ok()?;
+    match (caps.get(2), caps.get(3)) {
+        (Some(month), Some(day)) => Some(Span::Instant(
+            date(
+                year,
+                month.as_str().parse().ok()?,
+                day.as_str().parse().ok()?,
+            )?,
+            TemporalPrecision::Day,
+        )),
+        (Some(month), None) => Some(Span::Instant(
+            date(year, month.as_str().parse().ok()?, 1)?,
+            TemporalPrecision::Month,
+        )),
+        _ => Some(Span::Instant(date(year, 1, 1)?, TemporalPrecision::Year)),
+    }
+}
+
+/// Parses a single, non-range expression in normalized lowercase.
+fn parse_single(text: &str) -> Option<Span> {
+    if let Some(caps) = QUALIFIER.captures(text) {
+        return qualify(&caps[1], parse_side(&caps[2])?);
+    }
+
+    if let Some(caps) = EDTF_UNSPECIFIED.captures(text) {
+        let prefix: i32 = caps[1].parse().ok()?;
+        return if caps[2].len() == 1 {
+            Some(Span::Instant(
+                date(prefix * 10, 1, 1)?,
+                TemporalPrecision::Decade,
+            ))
+        } else {
+            Some(Span::Instant(
+                date(prefix * 100, 1, 1)?,
+                TemporalPrecision::Century,
+            ))
+        };
+    }
+
+    if let Some(caps) = DECADE.captures(text) {
+        let year: i32 = caps[1].parse().ok()?;
+        let precision = if year % 100 == 0 && year >= 100 {
+            TemporalPrecision::Century
+        } else {
+            TemporalPrecision::Decade
+        };
+        return Some(Span::Instant(date(year, 1, 1)?, precision));
+    }
+
+    if let Some(caps) = CENTURY.captures(text) {
+        let century: i32 = caps[1].parse().ok()?;
+        if century == 0 {
+            return None;
+        }
+        // See the module docs: the 19th century is 1800–1899 and the
+        // 5th century BCE is 499–400 BCE
+        let hundreds = (century - 1) * 100;
+        return match caps.get(2).map(|era| era.as_str()) {
+            None | Some("ce" | "ad" | "c.e." | "a.d.") if century == 1 => {
+                Some(Span::Range(date(1, 1, 1)?, year_end(99)?))
+            },
+            None | Some("ce" | "ad" | "c.e." | "a.d.") => Some(Span::Instant(
+                date(hundreds, 1, 1)?,
+                TemporalPrecision::Century,
+            )),
+            // Astronomical numbering: 1 BCE is year 0
+            Some("bce" | "bc" | "b.c.e." | "b.c.") => Some(Span::Range(
+                date(1 - (hundreds + 99), 1, 1)?,
+                year_end(1 - hundreds.max(1))?,
+            )),
+            Some(_) => None,
+        };
+    }
+
+    if let Some(caps) = SEASON.captures(text) {
+        let year = parse_year(&caps[2])?;
+        let (start, end) = match &caps[1] {
+            "spring" => (date(year, 3, 1)?, month_end(year, 5)?),
+            "summer" => (date(year, 6, 1)?, month_end(year, 8)?),
+            "autumn" | "fall" => (date(year, 9, 1)?, month_end(year, 11)?),
+            _ => (date(year, 12, 1)?, month_end(year + 1, 2)?),
+        };
+        return Some(Span::Range(start, end));
+    }
+
+    if let Some(caps) = DAY_MONTH_YEAR.captures(text) {
+        if let (Some(month), Some(year)) = (month_number(&caps[2]), parse_year(&caps[3])) {
+            let day = caps[1].parse().ok()?;
+            return Some(Span::Instant(
+                date(year, month, day)?,
+                TemporalPrecision::Day,
+            ));
+        }
+    }
+
+    if let Some(caps) = MONTH_DAY_YEAR.captures(text) {
+        if let (Some(month), Some(year)) = (month_number(&caps[1]), parse_year(&caps[3])) {
+            let day = caps[2].parse().ok()?;
+            return Some(Span::Instant(
+                date(year, month, day)?,
+                TemporalPrecision::Day,
+            ));
+        }
+    }
+
+    if let Some(caps) = MONTH_YEAR.captures(text) {
+        if let (Some(month), Some(year)) = (month_number(&caps[1]), parse_year(&caps[2])) {
+            return Some(Span::Instant(
+                date(year, month, 1)?,
+                TemporalPrecision::Month,
+            ));
+        }
+    }
+
+    parse_year(text)
+        .and_then(|year| Some(Span::Instant(date(year, 1, 1)?, TemporalPrecision::Year)))
+}
+
+/// Narrows a span to its early, middle or late third.
+fn qualify(qualifier: &str, span: Span) -> Option<Span> {
+    let part = match qualifier {
+        "early" => 0,
+        "mid" => 1,
+        _ => 2,
+    };
+
+    let (start, end) = match span {
+        Span::Instant(dt, TemporalPrecision::Century) => {
+            let base = dt.year().div_euclid(100) * 100;
+            let (first, last) = [(0, 32), (33, 65), (66, 99)][part];
+            (date(base + first, 1, 1)?, year_end(base + last)?)
+        },
+        Span::Instant(dt, TemporalPrecision::Decade) => {
+            let base = dt.year().div_euclid(10) * 10;
+            let (first, last) = [(0, 3), (4, 6), (7, 9)][part];
+            (date(base + first, 1, 1)?, year_end(base + last)?)
+        },
+        Span::Instant(dt, TemporalPrecision::Year) => {
+            let (first, last) = [(1, 4), (5, 8), (9, 12)][part];
+            (date(dt.year(), first, 1)?, month_end(dt.year(), last)?)
+        },
+        Span::Instant(dt, TemporalPrecision::Month) => {
+            let (year, month) = (dt.year(), dt.month());
+            let last_day = month_end(year, month)?.day();
+            let (first, last) = [(1, 10), (11, 20), (21, last_day)][part];
+            (date(year, month, first)?, day_end(year, month, last)?)
+        },
+        other => {
+            let (start, end) = other.bounds();
+            let third = (end - start) / 3;
+            let start = start + third * part as i32;
+            (start, start + third)
+        },
+    };
+
+    Some(Span::Range(start, end))
+}
+
+/// Parses a year with an optional era marker.
+fn parse_year(text: &str) -> Option<i32> {
+    if let Some(caps) = YEAR_BCE.captures(text) {
+        let year: i32 = caps[1].parse().ok()?;
+        // Astronomical numbering: 1 BCE is year 0
+        return (year > 0).then_some(1 - year);
+    }
+    if let Some(caps) = YEAR_CE.captures(text) {
+        let year = caps.get(1).or_else(|| caps.get(2))?;
+        return year.as_str().parse().ok();
+    }
+    if YEAR.is_match(text) {
+        return text.parse().ok();
+    }
+    None
+}
+
+/// Looks up a month by full name or three-letter abbreviation.
+fn month_number(name: &str) -> Option<u32> {
+    if name.len() < 3 {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(name) && (name.len() == 3 || *month == name))
+        .or_else(|| (name == "sept").then_some(8))
+        .map(|index| index as u32 + 1)
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<DateTime<Utc>> {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|ndt| Utc.from_utc_datetime(&ndt))
+}
+
+fn day_end(year: i32, month: u32, day: u32) -> Option<DateTime<Utc>> {
+    Some(date(year, month, day)? + Duration::days(1) - Duration::milliseconds(1))
+}
+
+fn month_end(year: i32, month: u32) -> Option<DateTime<Utc>> {
+    let start = date(year, month, 1)?;
+    Some(
+        Timestamp::with_precision(start, TemporalPrecision::Month)
+            .latest()
+            .datetime,
+    )
+}
+
+fn year_end(year: i32) -> Option<DateTime<Utc>> {
+    month_end(year, 12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    fn range(text: &str) -> TimeRange {
+        match parse(text).unwrap() {
+            ParsedDate::Range(range) | ParsedDate::Between(range) => range,
+            ParsedDate::Instant(ts) => panic!("expected range, got {}", ts),
+        }
+    }
+
+    fn instant(text: &str) -> Timestamp {
+        match parse(text).unwrap() {
+            ParsedDate::Instant(ts) => ts,
+            ParsedDate::Range(range) | ParsedDate::Between(range) => {
+                panic!("expected instant, got {:?}", range)
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse_circa() {
+        let ts = instant("c. 1850");
+        assert!(ts.approximate);
+        assert_eq!(ts.precision, TemporalPrecision::Year);
+        assert_eq!(ts.datetime.year(), 1850);
+
+        assert!(instant("circa March 1916").approximate);
+        assert!(instant("1850~").approximate);
+    }
+
+    #[test]
+    fn test_parse_decades_and_centuries() {
+        let decade = instant("the 1840s");
+        assert_eq!(decade.precision, TemporalPrecision::Decade);
+        assert_eq!(decade.datetime.year(), 1840);
+
+        let century = instant("19th century");
+        assert_eq!(century.precision, TemporalPrecision::Century);
+        assert_eq!(century.datetime.year(), 1800);
+
+        assert_eq!(instant("184X").precision, TemporalPrecision::Decade);
+        assert_eq!(instant("the 1800s").precision, TemporalPrecision::Century);
+
+        // Both eras count centuries by their hundreds digit
+        let bce = range("5th century BCE");
+        assert_eq!(bce.start.datetime.year(), -498); // 499 BCE
+        assert_eq!(bce.end.datetime.year(), -399); // 400 BCE
+
+        let first_bce = range("1st century BC");
+        assert_eq!(first_bce.start.datetime.year(), -98); // 99 BCE
+        assert_eq!(first_bce.end.datetime.year(), 0); // 1 BCE
+
+        let first_ce = range("the 1st century");
+        assert_eq!(first_ce.start.datetime.year(), 1);
+        assert_eq!(first_ce.end.datetime.year(), 99);
+    }
+
+    #[test]
+    fn test_parse_season() {
+        let spring = range("spring 1943");
+        assert_eq!(spring.start.to_rfc3339(), "1943-03-01T00:00:00+00:00");
+        assert_eq!(spring.end.to_rfc3339(), "1943-05-31T23:59:59.999+00:00");
+
+        let winter = range("Winter, 1916");
+        assert_eq!(winter.end.datetime.year(), 1917);
+        assert_eq!(winter.end.datetime.month(), 2);
+    }
+
+    #[test]
+    fn test_parse_qualified() {
+        let early = range("early March 1916");
+        assert_eq!(early.start.datetime.day(), 1);
+        assert_eq!(early.end.datetime.day(), 10);
+
+        let late = range("late 1840s");
+        assert_eq!(late.start.datetime.year(), 1847);
+        assert_eq!(late.end.datetime.year(), 1849);
+
+        let mid = range("mid-1943");
+        assert_eq!(mid.start.datetime.month(), 5);
+        assert_eq!(mid.end.datetime.month(), 8);
+    }
+
+    #[test]
+    fn test_parse_days_and_months() {
+        let ts = instant("12 March 1916");
+        assert_eq!(ts.precision, TemporalPrecision::Day);
+        assert_eq!((ts.datetime.month(), ts.datetime.day()), (3, 12));
+
+        let ts = instant("March 12th, 1916");
+        assert_eq!((ts.datetime.month(), ts.datetime.day()), (3, 12));
+
+        let ts = instant("Sept 1939");
+        assert_eq!(ts.precision, TemporalPrecision::Month);
+        assert_eq!(ts.datetime.month(), 9);
+    }
+
+    #[test]
+    fn test_parse_bce() {
+        let ts = instant("44 BCE");
+        assert_eq!(ts.datetime.year(), -43);
+        assert_eq!(ts.precision, TemporalPrecision::Year);
+
+        assert_eq!(instant("AD 800").datetime.year(), 800);
+        assert_eq!(instant("-0043-03-15").datetime.year(), -43);
+    }
+
+    #[test]
+    fn test_parse_julian() {
+        let ts = instant("23 February 1917 (Julian)");
+        assert_eq!(ts.calendar, Calendar::Julian);
+        assert_eq!((ts.datetime.month(), ts.datetime.day()), (3, 8));
+
+        assert_eq!(instant("1917-02-23 O.S.").datetime.day(), 8);
+    }
+
+    #[test]
+    fn test_parse_offset_and_zone() {
+        let ts = instant("2024-03-15T14:30:00+05:30");
+        assert_eq!(ts.utc_offset, Some(5 * 3600 + 1800));
+        assert_eq!(ts.datetime.hour(), 9);
+
+        let ts = instant("2024-07-01T09:00:00+02:00[Europe/Paris]");
+        assert_eq!(ts.time_zone, Some(Tz::Europe__Paris));
+        assert_eq!(ts.utc_offset, Some(7200));
+
+        assert_eq!(instant("2024-03-15T14:30:00Z").utc_offset, None);
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        let r = range("1840-1845");
+        assert_eq!(r.start.datetime.year(), 1840);
+        assert_eq!(r.end.datetime.year(), 1845);
+
+        let r = range("between May 1940 and June 1941");
+        assert_eq!(r.start.datetime.month(), 5);
+        assert_eq!(r.end.datetime.month(), 6);
+
+        let r = range("100 BC – 44 BC");
+        assert_eq!(r.start.datetime.year(), -99);
+        assert_eq!(r.end.datetime.year(), -43);
+
+        assert!(parse("1845-1840").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        assert!(parse("not a timestamp").is_err());
+        assert!(parse("").is_err());
+        assert!(parse("12").is_err());
+        assert!(parse("Marchember 1916").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! Geographic location representation.
 
+use crate::core::{AdminLevel, GeoBounds, PlaceHierarchy};
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 
@@ -50,6 +51,9 @@ pub struct Location {
     /// Human-readable place name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Administrative units the location falls in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hierarchy: Option<PlaceHierarchy>,
 }
 
 impl Location {
@@ -74,6 +78,7 @@ impl Location {
             elevation: None,
             uncertainty_meters: None,
             name: None,
+            hierarchy: None,
         }
     }
 
@@ -91,6 +96,7 @@ impl Location {
             elevation: Some(elevation),
             uncertainty_meters: None,
             name: None,
+            hierarchy: None,
         }
     }
 
@@ -99,6 +105,17 @@ impl Location {
         LocationBuilder::new()
     }
 
+    /// Sets the administrative place hierarchy.
+    pub fn with_hierarchy(mut self, hierarchy: PlaceHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+
+    /// Returns the administrative unit at a level, if known.
+    pub fn admin(&self, level: AdminLevel) -> Option<&str> {
+        self.hierarchy.as_ref().and_then(|h| h.get(level))
+    }
+
     /// Checks if the coordinates are valid WGS84 values.
     ///
     /// Returns `true` if latitude is between -90 and 90,
@@ -109,10 +126,10 @@ impl Location {
 
     /// Validates the location, returning an error if invalid.
     pub fn validate(&self) -> Result<()> {
-        if self.lat < -90.0 || self.lat > 90.0 {
+        if !(-90.0..=90.0).contains(&self.lat) {
             return Err(Error::InvalidLatitude(self.lat));
         }
-        if self.lon < -180.0 || self.lon > 180.0 {
+        if !(-180.0..=180.0).contains(&self.lon) {
             return Err(Error::InvalidLongitude(self.lon));
         }
         Ok(())
@@ -132,6 +149,159 @@ impl Location {
     pub fn from_geo_point(point: geo_types::Point<f64>) -> Self {
         Self::new(point.y(), point.x())
     }
+
+    /// Returns the uncertainty radius in meters, or 0.0 for an exact location.
+    pub fn uncertainty_radius(&self) -> f64 {
+        self.uncertainty_meters
+            .filter(|u| u.is_finite() && *u > 0.0)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the great-circle distance to another location in meters.
+    ///
+    /// Uses [`haversine_distance`](crate::analysis::haversine_distance), so
+    /// it agrees with the distances used by the analysis functions.
+    pub fn distance_to(&self, other: &Location) -> f64 {
+        crate::analysis::haversine_distance(self.lat, self.lon, other.lat, other.lon)
+    }
+
+    /// Returns the probability that the true position lies within
+    /// `radius_m` meters of `center`.
+    ///
+    /// The true position is taken to be uniformly distributed over the
+    /// uncertainty circle. Exact locations give 0.0 or 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::Location;
+    ///
+    /// let vague = Location::builder()
+    ///     .coordinates(51.5074, -0.1278)
+    ///     .uncertainty_meters(10_000.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let center = Location::new(51.5074, -0.1278);
+    ///
+    /// assert_eq!(vague.probability_within_radius(&center, 20_000.0), 1.0);
+    /// assert!((vague.probability_within_radius(&center, 5_000.0) - 0.25).abs() < 1e-9);
+    /// ```
+    pub fn probability_within_radius(&self, center: &Location, radius_m: f64) -> f64 {
+        circle_overlap_fraction(
+            self.distance_to(center),
+            self.uncertainty_radius(),
+            radius_m,
+        )
+    }
+
+    /// Returns the probability that the true position lies inside the bounds.
+    ///
+    /// The true position is taken to be uniformly distributed over the
+    /// uncertainty circle, which is sampled on a fixed pattern. Exact
+    /// locations give 0.0 or 1.0.
+    pub fn probability_within_bounds(&self, bounds: &GeoBounds) -> f64 {
+        let samples = self.uncertainty_samples();
+        let inside = samples
+            .iter()
+            .filter(|(lat, lon)| bounds.contains_coords(*lat, *lon))
+            .count();
+        inside as f64 / samples.len() as f64
+    }
+
+    /// Returns (lat, lon) points spread evenly over the uncertainty circle.
+    ///
+    /// Each point stands for an equal share of the location's probability
+    /// mass. Exact locations return just their own coordinates.
+    pub(crate) fn uncertainty_samples(&self) -> Vec<(f64, f64)> {
+        const RINGS: usize = 8;
+        const PER_RING: usize = 16;
+
+        let radius = self.uncertainty_radius();
+        if radius == 0.0 {
+            return vec![(self.lat, self.lon)];
+        }
+
+        let (lat_per_m, lon_per_m) = degrees_per_meter(self.lat);
+        let mut samples = Vec::with_capacity(RINGS * PER_RING);
+        for ring in 0..RINGS {
+            // Equal-area rings, so every sample covers the same area
+            let r = radius * ((ring as f64 + 0.5) / RINGS as f64).sqrt();
+            let offset = if ring % 2 == 0 { 0.0 } else { 0.5 };
+            for step in 0..PER_RING {
+                let angle = (step as f64 + offset) * std::f64::consts::TAU / PER_RING as f64;
+                let lat = (self.lat + r * angle.sin() * lat_per_m).clamp(-90.0, 90.0);
+                let lon = self.lon + r * angle.cos() * lon_per_m;
+                samples.push((lat, wrap_longitude(lon)));
+            }
+        }
+        samples
+    }
+
+    /// Infers the IANA time zone at this location.
+    ///
+    /// Uses the bundled zone boundary polygons where they cover the
+    /// location, and otherwise the zone of the nearest place in a bundled
+    /// table of GeoNames cities and tz database reference points. Returns
+    /// `None` when places in different zones are about equally close, as
+    /// on some borders. With no place within 300 km, as at sea, it returns
+    /// the nautical `Etc/GMT±N` zone for the longitude; note the inverted
+    /// sign, so `Etc/GMT+3` is UTC-3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::Location;
+    ///
+    /// let paris = Location::new(48.8566, 2.3522);
+    /// assert_eq!(paris.time_zone().unwrap().name(), "Europe/Paris");
+    ///
+    /// let mid_atlantic = Location::new(30.0, -45.0);
+    /// assert_eq!(mid_atlantic.time_zone().unwrap().name(), "Etc/GMT+3");
+    /// ```
+    pub fn time_zone(&self) -> Option<chrono_tz::Tz> {
+        super::time_zone::time_zone_at(self)
+    }
+}
+
+/// Degrees of latitude and longitude per meter at the given latitude.
+pub(crate) fn degrees_per_meter(lat: f64) -> (f64, f64) {
+    const METERS_PER_DEGREE: f64 = 111_320.0;
+    let cos_lat = lat.to_radians().cos().max(1e-6);
+    (1.0 / METERS_PER_DEGREE, 1.0 / (METERS_PER_DEGREE * cos_lat))
+}
+
+/// Wraps a longitude into -180..=180.
+pub(super) fn wrap_longitude(lon: f64) -> f64 {
+    if (-180.0..=180.0).contains(&lon) {
+        lon
+    } else {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+/// Fraction of a disk of radius `r` lying within a disk of radius `big_r`
+/// whose center is `d` away.
+pub(crate) fn circle_overlap_fraction(d: f64, r: f64, big_r: f64) -> f64 {
+    if r <= 0.0 {
+        return if d <= big_r { 1.0 } else { 0.0 };
+    }
+    if big_r <= 0.0 || d >= r + big_r {
+        return 0.0;
+    }
+    if d + r <= big_r {
+        return 1.0;
+    }
+    if d + big_r <= r {
+        return (big_r / r).powi(2);
+    }
+
+    // Area of the lens where the two circles overlap
+    let a = (d * d + r * r - big_r * big_r) / (2.0 * d * r);
+    let b = (d * d + big_r * big_r - r * r) / (2.0 * d * big_r);
+    let k = (-d + r + big_r) * (d + r - big_r) * (d - r + big_r) * (d + r + big_r);
+    let lens = r * r * a.clamp(-1.0, 1.0).acos() + big_r * big_r * b.clamp(-1.0, 1.0).acos()
+        - 0.5 * k.max(0.0).sqrt();
+    (lens / (std::f64::consts::PI * r * r)).clamp(0.0, 1.0)
 }
 
 impl Default for Location {
@@ -160,6 +330,7 @@ pub struct LocationBuilder {
     elevation: Option<f64>,
     uncertainty_meters: Option<f64>,
     name: Option<String>,
+    hierarchy: Option<PlaceHierarchy>,
 }
 
 impl LocationBuilder {
@@ -205,6 +376,12 @@ impl LocationBuilder {
         self
     }
 
+    /// Sets the administrative place hierarchy.
+    pub fn hierarchy(mut self, hierarchy: PlaceHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+
     /// Builds the Location, returning an error if required fields are missing.
     pub fn build(self) -> Result<Location> {
         let lat = self.lat.ok_or(Error::MissingField("lat"))?;
@@ -216,6 +393,7 @@ impl LocationBuilder {
             elevation: self.elevation,
             uncertainty_meters: self.uncertainty_meters,
             name: self.name,
+            hierarchy: self.hierarchy,
         };
 
         location.validate()?;
@@ -255,6 +433,10 @@ mod tests {
         let invalid_lon = Location::new(0.0, 181.0);
         assert!(!invalid_lon.is_valid());
         assert!(invalid_lon.validate().is_err());
+
+        let nan = Location::new(f64::NAN, 0.0);
+        assert!(!nan.is_valid());
+        assert!(nan.validate().is_err());
     }
 
     #[test]
@@ -295,6 +477,59 @@ mod tests {
         assert_eq!(point.y(), 40.7128);
     }
 
+    #[test]
+    fn test_uncertainty_probabilities() {
+        let center = Location::new(40.0, -74.0);
+        let exact = Location::new(40.0, -74.0);
+        assert_eq!(exact.probability_within_radius(&center, 1.0), 1.0);
+        assert_eq!(exact.uncertainty_samples().len(), 1);
+
+        let mut vague = exact.clone();
+        vague.uncertainty_meters = Some(1_000.0);
+        assert_eq!(vague.uncertainty_radius(), 1_000.0);
+
+        // Half the circle lies east of the center
+        let east = GeoBounds::new(39.0, -74.0, 41.0, -73.0);
+        assert!((vague.probability_within_bounds(&east) - 0.5).abs() < 0.05);
+        assert_eq!(exact.probability_within_bounds(&east), 1.0);
+
+        // Partial overlap falls strictly between 0 and 1
+        let nearby = Location::new(40.0, -73.99);
+        let p = vague.probability_within_radius(&nearby, 500.0);
+        assert!(p > 0.0 && p < 0.5);
+        assert_eq!(vague.probability_within_radius(&nearby, 5_000.0), 1.0);
+    }
+
+    #[test]
+    fn test_location_distance_to() {
+        let london = Location::new(51.5074, -0.1278);
+        let paris = Location::new(48.8566, 2.3522);
+        let distance = london.distance_to(&paris);
+        assert!((distance - 343_500.0).abs() < 1_000.0);
+        assert_eq!(
+            distance,
+            crate::analysis::haversine_distance(london.lat, london.lon, paris.lat, paris.lon)
+        );
+    }
+
+    #[test]
+    fn test_probability_within_bounds_across_antimeridian() {
+        let pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        assert_eq!(
+            Location::new(0.0, 175.0).probability_within_bounds(&pacific),
+            1.0
+        );
+        assert_eq!(
+            Location::new(0.0, 0.0).probability_within_bounds(&pacific),
+            0.0
+        );
+
+        // A circle centred on the antimeridian lies wholly inside
+        let mut vague = Location::new(0.0, 180.0);
+        vague.uncertainty_meters = Some(10_000.0);
+        assert_eq!(vague.probability_within_bounds(&pacific), 1.0);
+    }
+
     #[test]
     fn test_location_serialization() {
         let loc = Location::new(40.7128, -74.0060);
@@ -0,0 +1,448 @@
+//! Typed metadata values.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{Metadata, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::core::{Location, Timestamp};
+
+/// Strings in any form [`Timestamp::format_with_precision`] writes are read
+/// back as timestamps: years (`1850`, `-0043`, `+12345`), decades (`184X`),
+/// centuries (`18XX`) and dates, each optionally approximate (`~`) or
+/// annotated (`[...]`). Unsigned years have exactly four digits, so longer
+/// digit strings such as postcodes stay text.
+static TIMESTAMP_TEXT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:(?:\d{4}|[+-]\d{4,})(?:-\d{2}|~|\[|$)|(?:\d{3}|[+-]\d{3,})X(?:~|\[|$)|(?:\d{2}|[+-]\d{2,})XX(?:~|\[|$))",
+    )
+    .unwrap()
+});
+
+/// A typed metadata value attached to an event or narrative.
+///
+/// Values serialize to plain JSON, so metadata stays readable in
+/// exported files. When reading JSON back, strings in a form timestamps
+/// are written in (including bare years such as `"1850"`) become
+/// timestamps and objects with only location fields become locations.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, MetadataValue, Timestamp};
+///
+/// let mut event = Event::new(Location::new(38.3, 142.4), Timestamp::now(), "Earthquake");
+/// event.set_metadata("magnitude", 9.1);
+/// event.set_metadata("tsunami", true);
+/// event.set_metadata("agency", "USGS");
+///
+/// assert_eq!(event.metadata_f64("magnitude"), Some(9.1));
+/// assert_eq!(
+///     event.get_metadata("tsunami").and_then(MetadataValue::as_bool),
+///     Some(true)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    /// A boolean flag.
+    Bool(bool),
+    /// A whole number.
+    Int(i64),
+    /// A floating point number.
+    Float(f64),
+    /// Free text.
+    String(String),
+    /// A point in time.
+    Timestamp(Timestamp),
+    /// A geographic location.
+    Location(Location),
+    /// A list of values.
+    List(Vec<MetadataValue>),
+    /// Any other JSON value, including nested objects and null.
+    Json(Value),
+}
+
+impl MetadataValue {
+    /// Returns the string value, if this is text.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MetadataValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value, if this is a whole number.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            MetadataValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float, if this is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            MetadataValue::Int(i) => Some(*i as f64),
+            MetadataValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean value, if this is a flag.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MetadataValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp, if this is a point in time.
+    pub fn as_timestamp(&self) -> Option<&Timestamp> {
+        match self {
+            MetadataValue::Timestamp(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Returns the location, if this is a place.
+    pub fn as_location(&self) -> Option<&Location> {
+        match self {
+            MetadataValue::Location(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Returns the items, if this is a list.
+    pub fn as_list(&self) -> Option<&[MetadataValue]> {
+        match self {
+            MetadataValue::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw JSON, if this is an untyped JSON value.
+    pub fn as_json(&self) -> Option<&Value> {
+        match self {
+            MetadataValue::Json(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Converts the value to plain JSON.
+    pub fn to_json(&self) -> Value {
+        match self {
+            MetadataValue::Bool(b) => Value::Bool(*b),
+            MetadataValue::Int(i) => Value::from(*i),
+            MetadataValue::Float(f) => serde_json::Number::from_f64(*f)
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            MetadataValue::String(s) => Value::String(s.clone()),
+            MetadataValue::Timestamp(t) => Value::String(t.format_with_precision()),
+            MetadataValue::Location(l) => serde_json::to_value(l).unwrap_or(Value::Null),
+            MetadataValue::List(items) => Value::Array(items.iter().map(Self::to_json).collect()),
+            MetadataValue::Json(v) => v.clone(),
+        }
+    }
+
+    /// Converts plain JSON to a typed value, inferring timestamps and locations.
+    pub fn from_json(value: Value) -> Self {
+        match value {
+            Value::Bool(b) => MetadataValue::Bool(b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => MetadataValue::Int(i),
+                None => n
+                    .as_f64()
+                    .map(MetadataValue::Float)
+                    .unwrap_or(MetadataValue::Json(Value::Number(n))),
+            },
+            Value::String(s) => Self::from_string(s),
+            Value::Array(items) => {
+                MetadataValue::List(items.into_iter().map(Self::from_json).collect())
+            },
+            Value::Object(map) => {
+                if is_location_object(&map) {
+                    if let Ok(location) = serde_json::from_value(Value::Object(map.clone())) {
+                        return MetadataValue::Location(location);
+                    }
+                }
+                MetadataValue::Json(Value::Object(map))
+            },
+            Value::Null => MetadataValue::Json(Value::Null),
+        }
+    }
+
+    /// Renders the value as a single line of text, as used in CSV cells.
+    ///
+    /// Text is written as-is unless it would read back as another type
+    /// (such as `"5"` or `"1850"`), in which case it is quoted as a JSON
+    /// string. Timestamps are written in their precision-aware form, with
+    /// a leading `+` on bare years that would otherwise read as numbers.
+    /// Everything else is written as JSON.
+    pub fn to_text(&self) -> String {
+        match self {
+            MetadataValue::String(s) => match Self::from_text(s) {
+                MetadataValue::String(parsed) if &parsed == s => s.clone(),
+                _ => Value::String(s.clone()).to_string(),
+            },
+            MetadataValue::Timestamp(t) => {
+                let text = t.format_with_precision();
+                match serde_json::from_str::<Value>(&text) {
+                    Ok(Value::Number(_)) => format!("+{}", text),
+                    _ => text,
+                }
+            },
+            other => other.to_json().to_string(),
+        }
+    }
+
+    /// Parses text written by [`to_text`](Self::to_text), inferring the type.
+    ///
+    /// Quoted JSON strings are read as text without inference.
+    pub fn from_text(text: &str) -> Self {
+        match serde_json::from_str::<Value>(text) {
+            Ok(Value::String(s)) => MetadataValue::String(s),
+            Ok(value) => Self::from_json(value),
+            Err(_) => Self::from_string(text.to_string()),
+        }
+    }
+
+    fn from_string(s: String) -> Self {
+        if TIMESTAMP_TEXT.is_match(&s) {
+            if let Ok(timestamp) = Timestamp::parse(&s) {
+                return MetadataValue::Timestamp(timestamp);
+            }
+        }
+        MetadataValue::String(s)
+    }
+}
+
+fn is_location_object(map: &serde_json::Map<String, Value>) -> bool {
+    const FIELDS: [&str; 6] = [
+        "lat",
+        "lon",
+        "elevation",
+        "uncertainty_meters",
+        "name",
+        "hierarchy",
+    ];
+    map.get("lat").is_some_and(Value::is_number)
+        && map.get("lon").is_some_and(Value::is_number)
+        && map.keys().all(|k| FIELDS.contains(&k.as_str()))
+}
+
+impl std::fmt::Display for MetadataValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+impl Serialize for MetadataValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MetadataValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Self::from_json)
+    }
+}
+
+impl JsonSchema for MetadataValue {
+    fn schema_name() -> String {
+        "MetadataValue".to_string()
+    }
+
+    /// Any JSON value; typed values are recognised from their shape.
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A metadata value: any JSON value. Strings that parse as timestamps and \
+                     objects with `lat` and `lon` are read as typed values."
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl From<bool> for MetadataValue {
+    fn from(value: bool) -> Self {
+        MetadataValue::Bool(value)
+    }
+}
+
+impl From<i32> for MetadataValue {
+    fn from(value: i32) -> Self {
+        MetadataValue::Int(value.into())
+    }
+}
+
+impl From<i64> for MetadataValue {
+    fn from(value: i64) -> Self {
+        MetadataValue::Int(value)
+    }
+}
+
+impl From<u32> for MetadataValue {
+    fn from(value: u32) -> Self {
+        MetadataValue::Int(value.into())
+    }
+}
+
+impl From<f64> for MetadataValue {
+    fn from(value: f64) -> Self {
+        MetadataValue::Float(value)
+    }
+}
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self {
+        MetadataValue::String(value.to_string())
+    }
+}
+
+impl From<String> for MetadataValue {
+    fn from(value: String) -> Self {
+        MetadataValue::String(value)
+    }
+}
+
+impl From<Timestamp> for MetadataValue {
+    fn from(value: Timestamp) -> Self {
+        MetadataValue::Timestamp(value)
+    }
+}
+
+impl From<Location> for MetadataValue {
+    fn from(value: Location) -> Self {
+        MetadataValue::Location(value)
+    }
+}
+
+impl From<Vec<MetadataValue>> for MetadataValue {
+    fn from(value: Vec<MetadataValue>) -> Self {
+        MetadataValue::List(value)
+    }
+}
+
+impl From<Value> for MetadataValue {
+    fn from(value: Value) -> Self {
+        MetadataValue::from_json(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::PlaceHierarchy;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let values = vec![
+            MetadataValue::Bool(true),
+            MetadataValue::Int(-42),
+            MetadataValue::Float(1.0),
+            MetadataValue::String("hello".into()),
+            MetadataValue::Timestamp(Timestamp::parse("2024-03-01T12:00:00Z").unwrap()),
+            MetadataValue::Timestamp(Timestamp::parse("2024-03").unwrap()),
+            MetadataValue::Location(Location::new(51.5, -0.12)),
+            MetadataValue::Location(
+                Location::new(35.68, 139.69).with_hierarchy(
+                    PlaceHierarchy::new()
+                        .with_country("Japan")
+                        .with_admin1("Tokyo")
+                        .with_locality("Shinjuku"),
+                ),
+            ),
+            MetadataValue::List(vec![1.into(), "two".into()]),
+            MetadataValue::Json(json!({"nested": {"depth": 2}})),
+            MetadataValue::Json(Value::Null),
+        ];
+
+        for value in values {
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed: MetadataValue = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, value, "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let values = vec![
+            MetadataValue::Bool(false),
+            MetadataValue::Int(7),
+            MetadataValue::Float(2.5),
+            MetadataValue::Float(3.0),
+            MetadataValue::String("Main St, Springfield".into()),
+            MetadataValue::Timestamp(Timestamp::parse("1916-03-12").unwrap()),
+            MetadataValue::Location(Location::new(10.0, 20.0)),
+            MetadataValue::List(vec![true.into(), 2.5.into()]),
+            // Text that would otherwise read as another type
+            MetadataValue::String("5".into()),
+            MetadataValue::String("true".into()),
+            MetadataValue::String("1850".into()),
+            MetadataValue::String("1916-03-12".into()),
+            MetadataValue::String("\"quoted\"".into()),
+            MetadataValue::String("".into()),
+        ];
+
+        for value in values {
+            let text = value.to_text();
+            assert_eq!(MetadataValue::from_text(&text), value, "{}", text);
+        }
+        assert_eq!(MetadataValue::from("Main St").to_text(), "Main St");
+    }
+
+    #[test]
+    fn test_timestamp_forms_roundtrip() {
+        for form in [
+            "1850",
+            "0044",
+            "-0043",
+            "+12345",
+            "184X",
+            "18XX",
+            "1850~",
+            "184X~",
+            "1850[u-ca=julian]",
+            "1850-03",
+            "1916-03-12~",
+        ] {
+            let value = MetadataValue::Timestamp(Timestamp::parse(form).unwrap());
+
+            let json = serde_json::to_string(&value).unwrap();
+            let parsed: MetadataValue = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, value, "{}", json);
+
+            let text = value.to_text();
+            assert_eq!(MetadataValue::from_text(&text), value, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_inference() {
+        assert_eq!(MetadataValue::from(json!(5)).as_i64(), Some(5));
+        assert_eq!(MetadataValue::from(json!(5)).as_f64(), Some(5.0));
+        assert!(MetadataValue::from(json!("1850")).as_timestamp().is_some());
+        assert!(MetadataValue::from(json!("10001")).as_str().is_some());
+        assert!(MetadataValue::from(json!("185")).as_str().is_some());
+        assert_eq!(MetadataValue::from_text("1850").as_i64(), Some(1850));
+        assert!(
+            MetadataValue::from(json!({"lat": 1.0, "lon": 2.0, "id": 3}))
+                .as_json()
+                .is_some()
+        );
+        assert!(MetadataValue::from(json!({"lat": 1.0, "lon": 2.0}))
+            .as_location()
+            .is_some());
+    }
+}
@@ -5,20 +5,50 @@
 //! - [`Timestamp`] - Temporal information with precision
 //! - [`Event`] - Something that happened at a place and time
 //! - [`Narrative`] - A collection of related events
+//! - [`Section`] - A chapter or sub-narrative within a narrative
 //! - [`SourceRef`] - Reference to source material
+//! - [`ReliabilityModel`] - Source credibility and event confidence
+//! - [`Actor`] - A person, organisation or group involved in events
 
+mod actor;
+mod allen;
 mod bounds;
+mod calendar;
+mod diff;
 mod event;
+mod fuzzy_date;
 mod location;
+mod metadata;
 mod narrative;
+mod place;
+mod reliability;
+mod section;
 mod source;
+mod time_zone;
+mod time_zone_boundaries;
+mod time_zone_data;
 mod timestamp;
 mod traits;
+mod validation;
 
-pub use bounds::{GeoBounds, TimeRange};
-pub use event::{Event, EventBuilder, EventId};
+pub use actor::{Actor, ActorId, ActorKind, ActorRef, ActorRegistry};
+pub use allen::AllenRelation;
+pub use bounds::{GeoBounds, TemporalMatch, TimeRange};
+pub use calendar::Calendar;
+pub use diff::{ChangeLog, EventChange, FieldChange, NarrativeDiff, ValueChange};
+pub(crate) use event::{check_interval, ImportIds};
+pub use event::{Event, EventBuilder, EventId, IdStrategy};
+pub(crate) use location::{circle_overlap_fraction, degrees_per_meter};
 pub use location::{Location, LocationBuilder};
+pub use metadata::MetadataValue;
 pub use narrative::{Narrative, NarrativeBuilder, NarrativeId, NarrativeMetadata};
+pub use place::{AdminLevel, PlaceHierarchy};
+pub use reliability::{ConfidenceAssessment, CorroborationLevel, ReliabilityModel};
+pub use section::{Section, SectionId};
+pub(crate) use source::normalize_doi;
 pub use source::{SourceRef, SourceType};
 pub use timestamp::{TemporalPrecision, Timestamp};
 pub use traits::{SpatialEntity, TemporalEntity};
+pub use validation::{
+    Severity, ValidationCode, ValidationConfig, ValidationFinding, ValidationReport,
+};
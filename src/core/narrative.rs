@@ -1,10 +1,14 @@
 //! Narrative - a collection of related events forming a coherent story.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::core::{Event, EventId, GeoBounds, TimeRange, Timestamp};
+use crate::core::validation::{self, ValidationConfig, ValidationReport};
+use crate::core::{
+    Actor, ActorId, ActorRegistry, Event, EventId, GeoBounds, MetadataValue, Section, SectionId,
+    SpatialEntity, TemporalEntity, TemporalMatch, TimeRange, Timestamp,
+};
 use crate::error::{Error, Result};
 
 /// Unique identifier for a narrative.
@@ -68,7 +72,7 @@ pub struct NarrativeMetadata {
     pub category: Option<String>,
     /// Additional key-value metadata.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub extra: HashMap<String, String>,
+    pub extra: HashMap<String, MetadataValue>,
 }
 
 impl NarrativeMetadata {
@@ -124,6 +128,18 @@ pub struct Narrative {
     /// Categorical tags for the narrative.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// People, organisations and groups referenced by events.
+    #[serde(default, skip_serializing_if = "ActorRegistry::is_empty")]
+    pub actors: ActorRegistry,
+    /// Chapters and sections, in reading order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<Section>,
+    /// Explicit telling order of events.
+    ///
+    /// Empty means events are told in the order of `events`. Events not
+    /// listed are told after the listed ones, in insertion order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discourse: Vec<EventId>,
 }
 
 impl Narrative {
@@ -135,6 +151,9 @@ impl Narrative {
             events: Vec::new(),
             metadata: NarrativeMetadata::with_created_now(),
             tags: Vec::new(),
+            actors: ActorRegistry::new(),
+            sections: Vec::new(),
+            discourse: Vec::new(),
         }
     }
 
@@ -169,9 +188,13 @@ impl Narrative {
         self.metadata.modified = Some(Timestamp::now());
     }
 
-    /// Removes an event by ID.
+    /// Removes an event by ID, along with its references from sections.
     pub fn remove_event(&mut self, id: &EventId) -> Option<Event> {
         if let Some(pos) = self.events.iter().position(|e| &e.id == id) {
+            for section in &mut self.sections {
+                section.remove_event(id);
+            }
+            self.discourse.retain(|d| d != id);
             self.metadata.modified = Some(Timestamp::now());
             Some(self.events.remove(pos))
         } else {
@@ -196,19 +219,93 @@ impl Narrative {
         events
     }
 
+    /// Returns events in telling (discourse) order.
+    ///
+    /// Follows [`discourse`](Self::discourse) when set, then any unlisted
+    /// events in insertion order. IDs of missing events are skipped.
+    pub fn events_in_discourse_order(&self) -> Vec<&Event> {
+        let by_id: HashMap<&EventId, &Event> = self.events.iter().map(|e| (&e.id, e)).collect();
+        self.discourse_ids()
+            .into_iter()
+            .filter_map(|id| by_id.get(id).copied())
+            .collect()
+    }
+
+    /// Sets the telling order of events.
+    ///
+    /// Fails without changing the narrative if an ID is unknown or listed
+    /// twice. Events not listed are told afterwards, in insertion order.
+    pub fn set_discourse_order(&mut self, ids: impl IntoIterator<Item = EventId>) -> Result<()> {
+        let ids: Vec<EventId> = ids.into_iter().collect();
+        let known: HashSet<&EventId> = self.events.iter().map(|e| &e.id).collect();
+        let mut seen = HashSet::with_capacity(ids.len());
+        for id in &ids {
+            if !known.contains(id) {
+                return Err(Error::EventNotFound(id.to_string()));
+            }
+            if !seen.insert(id) {
+                return Err(Error::InvalidFormat(format!(
+                    "event {} appears twice in the discourse order",
+                    id
+                )));
+            }
+        }
+        self.discourse = ids;
+        self.metadata.modified = Some(Timestamp::now());
+        Ok(())
+    }
+
+    /// Moves an event to a position in the telling order.
+    pub fn move_in_discourse(&mut self, id: &EventId, position: usize) -> Result<()> {
+        if self.get_event(id).is_none() {
+            return Err(Error::EventNotFound(id.to_string()));
+        }
+        let mut order: Vec<EventId> = self
+            .discourse_ids()
+            .into_iter()
+            .filter(|d| *d != id)
+            .cloned()
+            .collect();
+        order.insert(position.min(order.len()), id.clone());
+        self.discourse = order;
+        self.metadata.modified = Some(Timestamp::now());
+        Ok(())
+    }
+
+    /// IDs in telling order, including unlisted events.
+    pub(crate) fn discourse_ids(&self) -> Vec<&EventId> {
+        let listed: HashSet<&EventId> = self.discourse.iter().collect();
+        let mut ids: Vec<&EventId> = self.discourse.iter().collect();
+        ids.extend(
+            self.events
+                .iter()
+                .map(|e| &e.id)
+                .filter(|id| !listed.contains(id)),
+        );
+        ids
+    }
+
     /// Filters events by spatial bounds.
+    ///
+    /// Events with an extended geometry are included if the geometry
+    /// intersects the bounds.
     pub fn filter_spatial(&self, bounds: &GeoBounds) -> Vec<&Event> {
         self.events
             .iter()
-            .filter(|e| bounds.contains(&e.location))
+            .filter(|e| e.is_within_bounds(bounds))
             .collect()
     }
 
     /// Filters events by time range.
-    pub fn filter_temporal(&self, range: &TimeRange) -> Vec<&Event> {
+    ///
+    /// Events are expanded to the interval their timestamps' precision
+    /// implies, and interval events are included if their span overlaps
+    /// the range. With [`TemporalMatch::Possibly`] an event dated `1943`
+    /// matches March 1943; with [`TemporalMatch::Definitely`] it does not.
+    pub fn filter_temporal(&self, range: &TimeRange, mode: TemporalMatch) -> Vec<&Event> {
         self.events
             .iter()
-            .filter(|e| range.contains(&e.timestamp))
+            .filter(|e| e.matches_time_range(range, mode))
             .collect()
     }
 
@@ -218,30 +315,17 @@ impl Narrative {
     }
 
     /// Returns the geographic bounds of all events.
+    ///
+    /// Extended event geometries contribute their full extent.
     pub fn bounds(&self) -> Option<GeoBounds> {
-        let locations: Vec<_> = self.events.iter().map(|e| &e.location).collect();
-        GeoBounds::from_locations(locations)
+        events_bounds(&self.events)
     }
 
     /// Returns the time range spanning all events.
+    ///
+    /// Interval events contribute their end time.
     pub fn time_range(&self) -> Option<TimeRange> {
-        if self.events.is_empty() {
-            return None;
-        }
-
-        let mut min_ts = &self.events[0].timestamp;
-        let mut max_ts = &self.events[0].timestamp;
-
-        for event in &self.events {
-            if event.timestamp < *min_ts {
-                min_ts = &event.timestamp;
-            }
-            if event.timestamp > *max_ts {
-                max_ts = &event.timestamp;
-            }
-        }
-
-        Some(TimeRange::new(min_ts.clone(), max_ts.clone()))
+        events_time_range(&self.events)
     }
 
     /// Returns all unique tags used by events in this narrative.
@@ -269,25 +353,109 @@ impl Narrative {
     where
         F: Fn(&Event) -> bool,
     {
-        let events = self
+        let events: Vec<Event> = self
             .events
             .iter()
             .filter(|e| predicate(e))
             .cloned()
             .collect();
+        let kept_ids: HashSet<&EventId> = events.iter().map(|e| &e.id).collect();
+        let kept = |id: &EventId| kept_ids.contains(id);
+        let mut sections = self.sections.clone();
+        for section in &mut sections {
+            section.retain_events(&kept);
+        }
+        let discourse = self
+            .discourse
+            .iter()
+            .filter(|id| kept(id))
+            .cloned()
+            .collect();
         Narrative {
             id: NarrativeId::new(),
             title: format!("{} (filtered)", self.title),
             events,
             metadata: NarrativeMetadata::with_created_now(),
             tags: self.tags.clone(),
+            actors: self.actors.clone(),
+            sections,
+            discourse,
         }
     }
 
     /// Merges another narrative into this one.
+    ///
+    /// Events are appended as-is; use
+    /// [`merge_narratives`](crate::analysis::merge_narratives) to collapse duplicates.
     pub fn merge(&mut self, other: Narrative) {
+        if !self.discourse.is_empty() || !other.discourse.is_empty() {
+            // Tell this narrative's events first, then the other's
+            let mut discourse: Vec<EventId> = self.discourse_ids().into_iter().cloned().collect();
+            discourse.extend(other.discourse_ids().into_iter().cloned());
+            self.discourse = discourse;
+        }
         self.events.extend(other.events);
+        self.actors.merge(other.actors);
+        self.sections.extend(other.sections);
+        self.metadata.modified = Some(Timestamp::now());
+    }
+
+    /// Returns the events involving an actor, sorted by timestamp.
+    pub fn events_involving(&self, actor: &ActorId) -> Vec<&Event> {
+        let mut events: Vec<_> = self.events.iter().filter(|e| e.involves(actor)).collect();
+        events.sort_by_key(|e| &e.timestamp);
+        events
+    }
+
+    /// Returns the events in which an actor plays a role, sorted by timestamp.
+    pub fn events_with_role(&self, actor: &ActorId, role: &str) -> Vec<&Event> {
+        let mut events: Vec<_> = self
+            .events
+            .iter()
+            .filter(|e| e.actor_roles(actor).contains(&role))
+            .collect();
+        events.sort_by_key(|e| &e.timestamp);
+        events
+    }
+
+    /// Adds a top-level section and returns its ID.
+    pub fn add_section(&mut self, section: Section) -> SectionId {
+        let id = section.id.clone();
+        self.sections.push(section);
         self.metadata.modified = Some(Timestamp::now());
+        id
+    }
+
+    /// Finds a section at any depth by ID.
+    pub fn section(&self, id: &SectionId) -> Option<&Section> {
+        self.sections.iter().find_map(|s| s.find(id))
+    }
+
+    /// Finds a section at any depth by ID (mutable).
+    pub fn section_mut(&mut self, id: &SectionId) -> Option<&mut Section> {
+        self.sections.iter_mut().find_map(|s| s.find_mut(id))
+    }
+
+    /// Returns all sections at any depth that list the event directly.
+    pub fn sections_containing(&self, id: &EventId) -> Vec<&Section> {
+        self.sections
+            .iter()
+            .flat_map(Section::flatten)
+            .filter(|s| s.events.contains(id))
+            .collect()
+    }
+
+    /// Checks the narrative for data problems using the default configuration.
+    ///
+    /// Returns a report instead of failing, so callers can decide which
+    /// severities should block an import.
+    pub fn validate(&self) -> ValidationReport {
+        self.validate_with(&ValidationConfig::default())
+    }
+
+    /// Checks the narrative for data problems using a custom configuration.
+    pub fn validate_with(&self, config: &ValidationConfig) -> ValidationReport {
+        validation::validate(self, config)
     }
 }
 
@@ -305,6 +473,8 @@ pub struct NarrativeBuilder {
     events: Vec<Event>,
     metadata: NarrativeMetadata,
     tags: Vec<String>,
+    actors: ActorRegistry,
+    sections: Vec<Section>,
 }
 
 impl NarrativeBuilder {
@@ -359,7 +529,7 @@ impl NarrativeBuilder {
     }
 
     /// Adds a metadata key-value pair.
-    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<MetadataValue>) -> Self {
         self.metadata.extra.insert(key.into(), value.into());
         self
     }
@@ -376,6 +546,18 @@ impl NarrativeBuilder {
         self
     }
 
+    /// Adds an actor to the registry.
+    pub fn actor(mut self, actor: Actor) -> Self {
+        self.actors.add(actor);
+        self
+    }
+
+    /// Adds a top-level section.
+    pub fn section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
+    }
+
     /// Builds the Narrative.
     pub fn build(self) -> Narrative {
         Narrative {
@@ -384,14 +566,50 @@ impl NarrativeBuilder {
             events: self.events,
             metadata: self.metadata,
             tags: self.tags,
+            actors: self.actors,
+            sections: self.sections,
+            discourse: Vec::new(),
         }
     }
 }
 
+/// Returns the geographic bounds of some events.
+///
+/// Extended event geometries contribute their full extent.
+pub(crate) fn events_bounds<'a>(events: impl IntoIterator<Item = &'a Event>) -> Option<GeoBounds> {
+    events
+        .into_iter()
+        .map(|e| e.bounds())
+        .reduce(|acc, b| acc.union(&b))
+}
+
+/// Returns the time range spanning some events, including interval ends.
+pub(crate) fn events_time_range<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+) -> Option<TimeRange> {
+    let mut events = events.into_iter();
+    let first = events.next()?;
+    let mut min_ts = &first.timestamp;
+    let mut max_ts = first.end_timestamp.as_ref().unwrap_or(&first.timestamp);
+
+    for event in events {
+        if event.timestamp < *min_ts {
+            min_ts = &event.timestamp;
+        }
+        let end = event.end_timestamp.as_ref().unwrap_or(&event.timestamp);
+        if end > max_ts {
+            max_ts = end;
+        }
+    }
+
+    Some(TimeRange::new(min_ts.clone(), max_ts.clone()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::Location;
+    use crate::core::{ActorKind, ActorRef, Location};
+    use geo_types::polygon;
 
     fn make_event(lat: f64, lon: f64, time: &str, text: &str) -> Event {
         Event::builder()
@@ -408,6 +626,87 @@ mod tests {
         assert!(narrative.is_empty());
     }
 
+    #[test]
+    fn test_events_involving_actor() {
+        let mut narrative = Narrative::new("Summit");
+        let host = narrative
+            .actors
+            .resolve("Host Nation", ActorKind::Organization);
+        let envoy = narrative.actors.resolve("Envoy", ActorKind::Person);
+
+        let mut arrival = make_event(0.0, 0.0, "2024-01-02T00:00:00Z", "Arrival");
+        arrival.add_actor(ActorRef::with_role(envoy.clone(), "guest"));
+        arrival.add_actor(ActorRef::with_role(host.clone(), "host"));
+        let mut talks = make_event(0.0, 0.0, "2024-01-01T00:00:00Z", "Talks");
+        talks.add_actor(envoy.clone());
+        narrative.add_event(arrival);
+        narrative.add_event(talks);
+
+        let involving = narrative.events_involving(&envoy);
+        assert_eq!(involving.len(), 2);
+        assert_eq!(involving[0].text, "Talks");
+        assert_eq!(narrative.events_with_role(&envoy, "guest").len(), 1);
+        assert_eq!(narrative.events_with_role(&host, "guest").len(), 0);
+    }
+
+    #[test]
+    fn test_sections() {
+        let a = make_event(0.0, 0.0, "2024-01-01T00:00:00Z", "A");
+        let b = make_event(1.0, 1.0, "2024-01-02T00:00:00Z", "B");
+        let (id_a, id_b) = (a.id.clone(), b.id.clone());
+
+        let mut narrative = Narrative::builder()
+            .events([a, b])
+            .section(Section::new("One").with_events([id_a.clone(), id_b.clone()]))
+            .build();
+        let two = narrative.add_section(Section::new("Two").with_event(id_b.clone()));
+
+        assert_eq!(narrative.sections_containing(&id_b).len(), 2);
+        assert_eq!(narrative.section(&two).unwrap().title, "Two");
+
+        let filtered = narrative.filter(|e| e.text == "A");
+        assert_eq!(filtered.sections[0].events, vec![id_a.clone()]);
+        assert!(filtered.sections[1].events.is_empty());
+
+        narrative.remove_event(&id_b);
+        assert!(narrative.sections_containing(&id_b).is_empty());
+        assert_eq!(narrative.sections[0].events, vec![id_a]);
+    }
+
+    #[test]
+    fn test_discourse_order() {
+        let a = make_event(0.0, 0.0, "2024-01-01T00:00:00Z", "A");
+        let b = make_event(0.0, 0.0, "2024-01-02T00:00:00Z", "B");
+        let c = make_event(0.0, 0.0, "2024-01-03T00:00:00Z", "C");
+        let (id_a, id_b, id_c) = (a.id.clone(), b.id.clone(), c.id.clone());
+        let mut narrative = Narrative::builder().events([a, b, c]).build();
+
+        let texts = |n: &Narrative| -> Vec<String> {
+            n.events_in_discourse_order()
+                .iter()
+                .map(|e| e.text.clone())
+                .collect()
+        };
+        assert_eq!(texts(&narrative), ["A", "B", "C"]);
+
+        // Unlisted events follow the listed ones
+        narrative.set_discourse_order([id_c.clone()]).unwrap();
+        assert_eq!(texts(&narrative), ["C", "A", "B"]);
+
+        narrative.move_in_discourse(&id_a, 5).unwrap();
+        assert_eq!(texts(&narrative), ["C", "B", "A"]);
+
+        assert!(narrative
+            .set_discourse_order([id_b.clone(), id_b.clone()])
+            .is_err());
+        assert!(narrative.set_discourse_order([EventId::new()]).is_err());
+        assert_eq!(texts(&narrative), ["C", "B", "A"]);
+
+        narrative.remove_event(&id_b);
+        assert_eq!(texts(&narrative), ["C", "A"]);
+        assert_eq!(narrative.events_chronological()[0].id, id_a);
+    }
+
     #[test]
     fn test_narrative_builder() {
         let narrative = Narrative::builder()
@@ -456,12 +755,57 @@ mod tests {
         narrative.add_event(make_event(40.0, -74.0, "2024-04-15T10:00:00Z", "April"));
 
         let march = TimeRange::month(2024, 3);
-        let filtered = narrative.filter_temporal(&march);
+        let filtered = narrative.filter_temporal(&march, TemporalMatch::Possibly);
 
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].text, "March");
     }
 
+    #[test]
+    fn test_narrative_filter_temporal_precision() {
+        let mut narrative = Narrative::new("Test");
+        narrative.add_event(make_event(51.5, -0.1, "1943", "Raid"));
+        narrative.add_event(make_event(51.5, -0.1, "1943-03-10", "Exact"));
+        narrative.add_event(make_event(51.5, -0.1, "1942", "Earlier"));
+
+        let march = TimeRange::month(1943, 3);
+        let texts = |events: Vec<&Event>| events.iter().map(|e| e.text.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            texts(narrative.filter_temporal(&march, TemporalMatch::Possibly)),
+            ["Raid", "Exact"]
+        );
+        assert_eq!(
+            texts(narrative.filter_temporal(&march, TemporalMatch::Definitely)),
+            ["Exact"]
+        );
+        assert_eq!(
+            texts(narrative.filter_temporal(&TimeRange::year(1943), TemporalMatch::Definitely)),
+            ["Raid", "Exact"]
+        );
+    }
+
+    #[test]
+    fn test_narrative_filter_temporal_interval() {
+        let mut narrative = Narrative::new("Test");
+        narrative.add_event(
+            Event::builder()
+                .location(Location::new(40.0, -74.0))
+                .timestamp(Timestamp::parse("2024-02-20T00:00:00Z").unwrap())
+                .end_timestamp(Timestamp::parse("2024-03-02T00:00:00Z").unwrap())
+                .text("Siege")
+                .build(),
+        );
+        narrative.add_event(make_event(40.0, -74.0, "2024-02-10T10:00:00Z", "February"));
+
+        let filtered =
+            narrative.filter_temporal(&TimeRange::month(2024, 3), TemporalMatch::Possibly);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].text, "Siege");
+
+        let range = narrative.time_range().unwrap();
+        assert_eq!(range.end, Timestamp::parse("2024-03-02T00:00:00Z").unwrap());
+    }
+
     #[test]
     fn test_narrative_bounds() {
         let mut narrative = Narrative::new("Test");
@@ -475,6 +819,36 @@ mod tests {
         assert_eq!(bounds.max_lon, -74.0);
     }
 
+    #[test]
+    fn test_narrative_geometry_events() {
+        let perimeter = polygon![
+            (x: -120.0, y: 38.0),
+            (x: -119.0, y: 38.0),
+            (x: -119.0, y: 39.0),
+            (x: -120.0, y: 39.0),
+        ];
+
+        let mut narrative = Narrative::new("Test");
+        narrative.add_event(make_event(40.0, -74.0, "2024-03-15T10:00:00Z", "NYC"));
+        narrative.add_event(
+            Event::builder()
+                .geometry(perimeter.into())
+                .timestamp(Timestamp::parse("2024-03-16T10:00:00Z").unwrap())
+                .text("Wildfire perimeter")
+                .build(),
+        );
+
+        let bounds = narrative.bounds().unwrap();
+        assert_eq!(bounds.min_lon, -120.0);
+        assert_eq!(bounds.max_lat, 40.0);
+
+        // Overlaps the polygon's eastern edge but not its centroid
+        let edge = GeoBounds::new(38.5, -119.2, 38.6, -118.0);
+        let filtered = narrative.filter_spatial(&edge);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].text, "Wildfire perimeter");
+    }
+
     #[test]
     fn test_narrative_time_range() {
         let mut narrative = Narrative::new("Test");
@@ -0,0 +1,181 @@
+//! Administrative place hierarchy for locations.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A level in the administrative hierarchy, from broadest to narrowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminLevel {
+    /// Country.
+    Country,
+    /// First-level subdivision (state, province, region).
+    Admin1,
+    /// Second-level subdivision (county, district).
+    Admin2,
+    /// City, town or village.
+    Locality,
+}
+
+impl AdminLevel {
+    /// All levels, from broadest to narrowest.
+    pub const ALL: [AdminLevel; 4] = [
+        AdminLevel::Country,
+        AdminLevel::Admin1,
+        AdminLevel::Admin2,
+        AdminLevel::Locality,
+    ];
+
+    /// Returns the level's name as used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdminLevel::Country => "country",
+            AdminLevel::Admin1 => "admin1",
+            AdminLevel::Admin2 => "admin2",
+            AdminLevel::Locality => "locality",
+        }
+    }
+}
+
+impl std::fmt::Display for AdminLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The administrative units a location falls in.
+///
+/// Any level may be unknown.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{AdminLevel, Location, PlaceHierarchy};
+///
+/// let lyon = Location::new(45.764, 4.8357).with_hierarchy(
+///     PlaceHierarchy::new()
+///         .with_country("France")
+///         .with_admin1("Auvergne-Rhône-Alpes")
+///         .with_admin2("Rhône")
+///         .with_locality("Lyon"),
+/// );
+///
+/// assert_eq!(lyon.admin(AdminLevel::Admin1), Some("Auvergne-Rhône-Alpes"));
+/// ```
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[schemars(description = "The administrative units a location falls in.")]
+pub struct PlaceHierarchy {
+    /// Country.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// First-level subdivision (state, province, region).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin1: Option<String>,
+    /// Second-level subdivision (county, district).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin2: Option<String>,
+    /// City, town or village.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+}
+
+impl PlaceHierarchy {
+    /// Creates an empty hierarchy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the country.
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Sets the first-level subdivision.
+    pub fn with_admin1(mut self, admin1: impl Into<String>) -> Self {
+        self.admin1 = Some(admin1.into());
+        self
+    }
+
+    /// Sets the second-level subdivision.
+    pub fn with_admin2(mut self, admin2: impl Into<String>) -> Self {
+        self.admin2 = Some(admin2.into());
+        self
+    }
+
+    /// Sets the locality.
+    pub fn with_locality(mut self, locality: impl Into<String>) -> Self {
+        self.locality = Some(locality.into());
+        self
+    }
+
+    /// Returns the unit at a level, if known.
+    pub fn get(&self, level: AdminLevel) -> Option<&str> {
+        match level {
+            AdminLevel::Country => self.country.as_deref(),
+            AdminLevel::Admin1 => self.admin1.as_deref(),
+            AdminLevel::Admin2 => self.admin2.as_deref(),
+            AdminLevel::Locality => self.locality.as_deref(),
+        }
+    }
+
+    /// Sets the unit at a level.
+    pub fn set(&mut self, level: AdminLevel, value: Option<String>) {
+        match level {
+            AdminLevel::Country => self.country = value,
+            AdminLevel::Admin1 => self.admin1 = value,
+            AdminLevel::Admin2 => self.admin2 = value,
+            AdminLevel::Locality => self.locality = value,
+        }
+    }
+
+    /// Returns a copy keeping only the given level and those above it.
+    ///
+    /// Two locations in provinces of the same name but different countries
+    /// truncate to different hierarchies at [`AdminLevel::Admin1`].
+    pub fn truncate(&self, level: AdminLevel) -> PlaceHierarchy {
+        let mut out = PlaceHierarchy::new();
+        for l in AdminLevel::ALL.into_iter().filter(|l| *l <= level) {
+            out.set(l, self.get(l).map(str::to_string));
+        }
+        out
+    }
+
+    /// Returns true if no level is known.
+    pub fn is_empty(&self) -> bool {
+        AdminLevel::ALL.iter().all(|l| self.get(*l).is_none())
+    }
+}
+
+impl std::fmt::Display for PlaceHierarchy {
+    /// Formats the known units from narrowest to broadest, e.g. "Lyon, Rhône, France".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<&str> = AdminLevel::ALL
+            .iter()
+            .rev()
+            .filter_map(|l| self.get(*l))
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_and_display() {
+        let place = PlaceHierarchy::new()
+            .with_country("Canada")
+            .with_admin1("Ontario")
+            .with_locality("Toronto");
+
+        let province = place.truncate(AdminLevel::Admin1);
+        assert_eq!(province.get(AdminLevel::Admin1), Some("Ontario"));
+        assert_eq!(province.locality, None);
+        assert_eq!(place.to_string(), "Toronto, Ontario, Canada");
+        assert!(PlaceHierarchy::new().is_empty());
+    }
+}
@@ -0,0 +1,289 @@
+//! Source reliability and multi-source corroboration.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::core::{Event, SourceRef, SourceType};
+
+/// How well an event's claim is corroborated by its sources.
+///
+/// Levels are ordered, so `level >= CorroborationLevel::Corroborated`
+/// selects every claim backed by more than one independent source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorroborationLevel {
+    /// No sources at all.
+    Unsourced,
+    /// All sources share a single origin.
+    SingleSource,
+    /// Several independent sources, but below the verification threshold.
+    Corroborated,
+    /// Enough independent, credible sources to treat the claim as verified.
+    Verified,
+}
+
+impl CorroborationLevel {
+    /// Returns the level's name as used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorroborationLevel::Unsourced => "unsourced",
+            CorroborationLevel::SingleSource => "single_source",
+            CorroborationLevel::Corroborated => "corroborated",
+            CorroborationLevel::Verified => "verified",
+        }
+    }
+}
+
+impl std::fmt::Display for CorroborationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Confidence in an event, derived from its sources.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceAssessment {
+    /// Combined confidence (0.0 to 1.0).
+    pub confidence: f64,
+    /// Number of independent origins among the sources.
+    pub independent_sources: usize,
+    /// Corroboration level.
+    pub level: CorroborationLevel,
+}
+
+/// Credibility scores for sources and the rules for combining them.
+///
+/// Each source gets a credibility score: its own
+/// [`credibility`](SourceRef::credibility) if set, otherwise a score for
+/// its [origin](SourceRef::origin), otherwise a score for its type.
+/// Sources sharing an origin count once, with the best score among them.
+/// The independent scores are combined as `1 - Π(1 - score)`, so each
+/// extra independent source raises confidence without reaching 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{
+///     CorroborationLevel, Event, Location, ReliabilityModel, SourceRef, Timestamp,
+/// };
+///
+/// let mut event = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "Bridge closed");
+/// event.add_source(SourceRef::article("https://a.example.com/1"));
+/// event.add_source(SourceRef::article("https://a.example.com/2"));
+///
+/// let model = ReliabilityModel::new();
+/// assert_eq!(model.assess(&event).level, CorroborationLevel::SingleSource);
+///
+/// event.add_source(SourceRef::report("https://city.example.gov/notice"));
+/// let assessment = model.assess(&event);
+/// assert_eq!(assessment.independent_sources, 2);
+/// assert!(assessment.confidence > 0.85);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReliabilityModel {
+    /// Credibility overrides per source type.
+    #[serde(default)]
+    pub type_scores: HashMap<SourceType, f64>,
+    /// Credibility overrides per origin (lowercase author or URL host).
+    #[serde(default)]
+    pub origin_scores: HashMap<String, f64>,
+    /// Minimum independent sources for a claim to be verified.
+    pub min_verified_sources: usize,
+    /// Minimum combined confidence for a claim to be verified.
+    pub verified_confidence: f64,
+}
+
+impl Default for ReliabilityModel {
+    fn default() -> Self {
+        Self {
+            type_scores: HashMap::new(),
+            origin_scores: HashMap::new(),
+            min_verified_sources: 2,
+            verified_confidence: 0.9,
+        }
+    }
+}
+
+impl ReliabilityModel {
+    /// Creates a model using the default score for each source type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the credibility of a source type.
+    pub fn with_type_score(mut self, source_type: SourceType, score: f64) -> Self {
+        self.type_scores.insert(source_type, score.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Overrides the credibility of an origin, such as "reuters.com".
+    pub fn with_origin_score(mut self, origin: impl Into<String>, score: f64) -> Self {
+        self.origin_scores
+            .insert(origin.into().trim().to_lowercase(), score.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the thresholds for a claim to count as verified.
+    pub fn with_verification(mut self, min_sources: usize, min_confidence: f64) -> Self {
+        self.min_verified_sources = min_sources;
+        self.verified_confidence = min_confidence;
+        self
+    }
+
+    /// Returns the credibility score of a single source.
+    pub fn source_score(&self, source: &SourceRef) -> f64 {
+        if let Some(score) = source.credibility {
+            return score.clamp(0.0, 1.0);
+        }
+        source
+            .origin()
+            .and_then(|origin| self.origin_scores.get(&origin).copied())
+            .or_else(|| self.type_scores.get(&source.source_type).copied())
+            .unwrap_or_else(|| source.source_type.default_credibility())
+    }
+
+    /// Assesses the confidence and corroboration of an event.
+    pub fn assess(&self, event: &Event) -> ConfidenceAssessment {
+        // Best score per origin; sources without an origin count separately
+        let mut by_origin: HashMap<String, f64> = HashMap::new();
+        let mut anonymous = Vec::new();
+        for source in &event.sources {
+            let score = self.source_score(source);
+            match source.origin() {
+                Some(origin) => {
+                    let best = by_origin.entry(origin).or_insert(score);
+                    *best = best.max(score);
+                },
+                None => anonymous.push(score),
+            }
+        }
+
+        let scores: Vec<f64> = by_origin.into_values().chain(anonymous).collect();
+        let independent_sources = scores.len();
+        let confidence = 1.0 - scores.iter().map(|s| 1.0 - s).product::<f64>();
+
+        let level = match independent_sources {
+            0 => CorroborationLevel::Unsourced,
+            1 => CorroborationLevel::SingleSource,
+            n if n >= self.min_verified_sources && confidence >= self.verified_confidence => {
+                CorroborationLevel::Verified
+            },
+            _ => CorroborationLevel::Corroborated,
+        };
+
+        ConfidenceAssessment {
+            confidence: if independent_sources == 0 {
+                0.0
+            } else {
+                confidence
+            },
+            independent_sources,
+            level,
+        }
+    }
+
+    /// Returns the combined confidence in an event (0.0 to 1.0).
+    pub fn confidence(&self, event: &Event) -> f64 {
+        self.assess(event).confidence
+    }
+
+    /// Returns copies of the events with at least the given confidence.
+    pub fn filter_events(&self, events: &[Event], min_confidence: f64) -> Vec<Event> {
+        events
+            .iter()
+            .filter(|e| self.confidence(e) >= min_confidence)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns copies of the events with at least the given corroboration level.
+    pub fn filter_by_level(&self, events: &[Event], min_level: CorroborationLevel) -> Vec<Event> {
+        events
+            .iter()
+            .filter(|e| self.assess(e).level >= min_level)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn event_with(sources: Vec<SourceRef>) -> Event {
+        let mut event = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "Claim");
+        for source in sources {
+            event.add_source(source);
+        }
+        event
+    }
+
+    #[test]
+    fn test_unsourced_and_single_source() {
+        let model = ReliabilityModel::new();
+
+        let assessment = model.assess(&event_with(Vec::new()));
+        assert_eq!(assessment.level, CorroborationLevel::Unsourced);
+        assert_eq!(assessment.confidence, 0.0);
+
+        // Same author through two outlets is still one origin
+        let event = event_with(vec![
+            SourceRef::article("https://a.example.com").with_author("J. Smith"),
+            SourceRef::article("https://b.example.com").with_author("j. smith"),
+        ]);
+        let assessment = model.assess(&event);
+        assert_eq!(assessment.level, CorroborationLevel::SingleSource);
+        assert!((assessment.confidence - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_corroboration_levels() {
+        let model = ReliabilityModel::new();
+
+        let social = event_with(vec![
+            SourceRef::new(SourceType::Social).with_url("https://x.example.com/1"),
+            SourceRef::new(SourceType::Social).with_url("https://y.example.com/2"),
+        ]);
+        let assessment = model.assess(&social);
+        assert_eq!(assessment.independent_sources, 2);
+        assert_eq!(assessment.level, CorroborationLevel::Corroborated);
+        assert!((assessment.confidence - 0.51).abs() < 1e-9);
+
+        let strong = event_with(vec![
+            SourceRef::sensor("https://usgs.example.gov/feed"),
+            SourceRef::report("https://agency.example.org/report"),
+        ]);
+        assert_eq!(model.assess(&strong).level, CorroborationLevel::Verified);
+    }
+
+    #[test]
+    fn test_score_overrides() {
+        let model = ReliabilityModel::new()
+            .with_type_score(SourceType::Article, 0.2)
+            .with_origin_score("Trusted.example.com", 0.95);
+
+        let trusted = SourceRef::article("https://trusted.example.com/story");
+        let other = SourceRef::article("https://other.example.com/story");
+        assert_eq!(model.source_score(&trusted), 0.95);
+        assert_eq!(model.source_score(&other), 0.2);
+        assert_eq!(model.source_score(&other.with_credibility(0.7)), 0.7);
+    }
+
+    #[test]
+    fn test_filter_events() {
+        let model = ReliabilityModel::new();
+        let events = vec![
+            event_with(Vec::new()),
+            event_with(vec![SourceRef::sensor("https://s.example.com")]),
+        ];
+
+        assert_eq!(model.filter_events(&events, 0.5).len(), 1);
+        assert_eq!(
+            model
+                .filter_by_level(&events, CorroborationLevel::SingleSource)
+                .len(),
+            1
+        );
+    }
+}
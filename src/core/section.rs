@@ -0,0 +1,307 @@
+//! Sections - chapters and sub-narratives that structure a narrative.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::core::narrative::{events_bounds, events_time_range};
+use crate::core::{Event, EventId, GeoBounds, Narrative, TimeRange};
+use crate::error::{Error, Result};
+
+/// Unique identifier for a section.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct SectionId(pub Uuid);
+
+impl SectionId {
+    /// Creates a new random SectionId.
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Creates a SectionId from a UUID.
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Parses a SectionId from a string.
+    pub fn parse(s: &str) -> Result<Self> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| Error::ParseError(format!("invalid section ID: {}", s)))
+    }
+
+    /// Returns the inner UUID.
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for SectionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for SectionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A chapter, section or sub-narrative.
+///
+/// Sections reference events by ID in reading order, so the same event
+/// can appear in several sections. Sections nest to any depth.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, Section, Timestamp};
+///
+/// let landfall = Event::new(Location::new(25.0, -80.0), Timestamp::now(), "Landfall");
+/// let flooding = Event::new(Location::new(25.8, -80.2), Timestamp::now(), "Flooding");
+///
+/// let chapter = Section::new("Impact")
+///     .with_description("The storm reaches the coast")
+///     .with_event(landfall.id.clone())
+///     .with_section(Section::new("Aftermath").with_event(flooding.id.clone()));
+///
+/// let narrative = Narrative::builder()
+///     .event(landfall)
+///     .event(flooding)
+///     .section(chapter)
+///     .build();
+///
+/// let chapter = &narrative.sections[0];
+/// assert_eq!(chapter.events(&narrative).len(), 2);
+/// assert!(chapter.bounds(&narrative).is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "A chapter, section or sub-narrative.")]
+pub struct Section {
+    /// Unique identifier.
+    pub id: SectionId,
+    /// Title of the section.
+    pub title: String,
+    /// Description of the section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Events in reading order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<EventId>,
+    /// Nested sections.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<Section>,
+}
+
+impl Section {
+    /// Creates an empty section with a fresh ID.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: SectionId::new(),
+            title: title.into(),
+            description: None,
+            events: Vec::new(),
+            sections: Vec::new(),
+        }
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds an event reference.
+    pub fn with_event(mut self, id: EventId) -> Self {
+        self.add_event(id);
+        self
+    }
+
+    /// Adds several event references.
+    pub fn with_events(mut self, ids: impl IntoIterator<Item = EventId>) -> Self {
+        for id in ids {
+            self.add_event(id);
+        }
+        self
+    }
+
+    /// Adds a nested section.
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Adds an event reference, unless it is already listed.
+    pub fn add_event(&mut self, id: EventId) {
+        if !self.events.contains(&id) {
+            self.events.push(id);
+        }
+    }
+
+    /// Adds a nested section.
+    pub fn add_section(&mut self, section: Section) {
+        self.sections.push(section);
+    }
+
+    /// Removes an event reference from this section and all nested sections.
+    ///
+    /// Returns true if any reference was removed.
+    pub fn remove_event(&mut self, id: &EventId) -> bool {
+        let before = self.events.len();
+        self.events.retain(|e| e != id);
+        let mut removed = self.events.len() != before;
+        for section in &mut self.sections {
+            removed |= section.remove_event(id);
+        }
+        removed
+    }
+
+    /// Keeps only the event references matching the predicate, recursively.
+    pub fn retain_events<F>(&mut self, predicate: &F)
+    where
+        F: Fn(&EventId) -> bool,
+    {
+        self.events.retain(predicate);
+        for section in &mut self.sections {
+            section.retain_events(predicate);
+        }
+    }
+
+    /// Replaces event references through a map of old to new IDs, recursively.
+    ///
+    /// References that become repeats within one section are dropped.
+    pub(crate) fn remap_events(&mut self, map: &HashMap<EventId, EventId>) {
+        let mut seen = HashSet::new();
+        self.events = std::mem::take(&mut self.events)
+            .into_iter()
+            .map(|id| map.get(&id).cloned().unwrap_or(id))
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+        for section in &mut self.sections {
+            section.remap_events(map);
+        }
+    }
+
+    /// Returns this section and all nested sections, depth first.
+    pub fn flatten(&self) -> Vec<&Section> {
+        let mut out = vec![self];
+        for section in &self.sections {
+            out.extend(section.flatten());
+        }
+        out
+    }
+
+    /// Finds this section or a nested section by ID.
+    pub fn find(&self, id: &SectionId) -> Option<&Section> {
+        if &self.id == id {
+            return Some(self);
+        }
+        self.sections.iter().find_map(|s| s.find(id))
+    }
+
+    /// Finds this section or a nested section by ID (mutable).
+    pub fn find_mut(&mut self, id: &SectionId) -> Option<&mut Section> {
+        if &self.id == id {
+            return Some(self);
+        }
+        self.sections.iter_mut().find_map(|s| s.find_mut(id))
+    }
+
+    /// Returns true if this section or a nested section lists the event.
+    pub fn contains_event(&self, id: &EventId) -> bool {
+        self.events.contains(id) || self.sections.iter().any(|s| s.contains_event(id))
+    }
+
+    /// Returns the IDs of all events in this section and nested sections.
+    ///
+    /// IDs are in reading order, with each ID listed once.
+    pub fn event_ids(&self) -> Vec<&EventId> {
+        let mut seen = HashSet::new();
+        self.flatten()
+            .into_iter()
+            .flat_map(|section| &section.events)
+            .filter(|id| seen.insert(*id))
+            .collect()
+    }
+
+    /// Resolves the section's events, including nested sections, in reading order.
+    ///
+    /// References to events missing from the narrative are skipped.
+    pub fn events<'a>(&self, narrative: &'a Narrative) -> Vec<&'a Event> {
+        let by_id: HashMap<&EventId, &Event> =
+            narrative.events().iter().map(|e| (&e.id, e)).collect();
+        self.event_ids()
+            .into_iter()
+            .filter_map(|id| by_id.get(id).copied())
+            .collect()
+    }
+
+    /// Returns the geographic bounds of the section's events.
+    pub fn bounds(&self, narrative: &Narrative) -> Option<GeoBounds> {
+        events_bounds(self.events(narrative))
+    }
+
+    /// Returns the time range spanned by the section's events.
+    pub fn time_range(&self, narrative: &Narrative) -> Option<TimeRange> {
+        events_time_range(self.events(narrative))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+
+    fn make_event(lat: f64, lon: f64, time: &str) -> Event {
+        Event::new(
+            Location::new(lat, lon),
+            Timestamp::parse(time).unwrap(),
+            "test",
+        )
+    }
+
+    #[test]
+    fn test_nested_sections() {
+        let a = make_event(10.0, 10.0, "2024-01-01T00:00:00Z");
+        let b = make_event(20.0, 30.0, "2024-02-01T00:00:00Z");
+        let c = make_event(-5.0, 0.0, "2024-03-01T00:00:00Z");
+
+        let part = Section::new("Part I")
+            .with_event(b.id.clone())
+            .with_section(Section::new("Chapter 1").with_events([a.id.clone(), b.id.clone()]))
+            .with_section(Section::new("Chapter 2").with_event(c.id.clone()));
+        let chapter_two = part.sections[1].id.clone();
+
+        let narrative = Narrative::builder()
+            .events([a.clone(), b.clone(), c.clone()])
+            .section(part.clone())
+            .build();
+
+        // Shared events are listed once, in reading order
+        let ids: Vec<_> = part.event_ids().into_iter().cloned().collect();
+        assert_eq!(ids, vec![b.id.clone(), a.id.clone(), c.id.clone()]);
+        assert_eq!(part.flatten().len(), 3);
+        assert_eq!(part.find(&chapter_two).unwrap().title, "Chapter 2");
+
+        let bounds = part.bounds(&narrative).unwrap();
+        assert_eq!((bounds.min_lat, bounds.max_lat), (-5.0, 20.0));
+        let range = part.sections[0].time_range(&narrative).unwrap();
+        assert_eq!(range.start, a.timestamp);
+        assert_eq!(range.end, b.timestamp);
+    }
+
+    #[test]
+    fn test_remove_event() {
+        let id = EventId::new();
+        let mut section = Section::new("Root")
+            .with_event(id.clone())
+            .with_section(Section::new("Child").with_event(id.clone()));
+
+        assert!(section.remove_event(&id));
+        assert!(!section.contains_event(&id));
+        assert!(!section.remove_event(&id));
+    }
+}
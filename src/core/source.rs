@@ -28,6 +28,47 @@ pub enum SourceType {
     Other,
 }
 
+impl SourceType {
+    /// All source types.
+    pub const ALL: [SourceType; 9] = [
+        SourceType::Article,
+        SourceType::Report,
+        SourceType::Witness,
+        SourceType::Sensor,
+        SourceType::Social,
+        SourceType::Academic,
+        SourceType::Government,
+        SourceType::Archive,
+        SourceType::Other,
+    ];
+
+    /// Parses a source type from its lowercase name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|t| t.to_string().eq_ignore_ascii_case(name))
+    }
+
+    /// Default credibility score (0.0 to 1.0) for sources of this type.
+    ///
+    /// These are deliberately coarse priors; override them per narrative
+    /// with a [`ReliabilityModel`](crate::core::ReliabilityModel).
+    pub fn default_credibility(&self) -> f64 {
+        match self {
+            SourceType::Sensor => 0.9,
+            SourceType::Academic => 0.85,
+            SourceType::Government => 0.8,
+            SourceType::Report => 0.75,
+            SourceType::Archive => 0.7,
+            SourceType::Article => 0.6,
+            SourceType::Witness => 0.5,
+            SourceType::Other => 0.4,
+            SourceType::Social => 0.3,
+        }
+    }
+}
+
 impl std::fmt::Display for SourceType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -63,7 +104,7 @@ impl std::fmt::Display for SourceType {
 ///     .author("Department of Safety")
 ///     .build();
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceRef {
     /// Type of source.
     pub source_type: SourceType,
@@ -76,12 +117,27 @@ pub struct SourceRef {
     /// Author or creator.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
-    /// Publication or access date.
+    /// Publication date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<Timestamp>,
+    /// Publisher, journal or outlet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    /// Page or page range (e.g. "12-15").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<String>,
+    /// Digital Object Identifier, without a resolver prefix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    /// When an online source was accessed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessed: Option<Timestamp>,
     /// Additional notes about the source.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+    /// Credibility score (0.0 to 1.0), overriding the default for its type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credibility: Option<f64>,
 }
 
 impl SourceRef {
@@ -93,56 +149,36 @@ impl SourceRef {
             title: None,
             author: None,
             date: None,
+            publisher: None,
+            pages: None,
+            doi: None,
+            accessed: None,
             notes: None,
+            credibility: None,
         }
     }
 
     /// Creates an article source with a URL.
     pub fn article(url: impl Into<String>) -> Self {
-        Self {
-            source_type: SourceType::Article,
-            url: Some(url.into()),
-            title: None,
-            author: None,
-            date: None,
-            notes: None,
-        }
+        Self::new(SourceType::Article).with_url(url)
     }
 
     /// Creates a report source with a URL.
     pub fn report(url: impl Into<String>) -> Self {
-        Self {
-            source_type: SourceType::Report,
-            url: Some(url.into()),
-            title: None,
-            author: None,
-            date: None,
-            notes: None,
-        }
+        Self::new(SourceType::Report).with_url(url)
     }
 
     /// Creates a witness source with optional notes.
     pub fn witness(notes: Option<String>) -> Self {
         Self {
-            source_type: SourceType::Witness,
-            url: None,
-            title: None,
-            author: None,
-            date: None,
             notes,
+            ..Self::new(SourceType::Witness)
         }
     }
 
     /// Creates a sensor source with a URL.
     pub fn sensor(url: impl Into<String>) -> Self {
-        Self {
-            source_type: SourceType::Sensor,
-            url: Some(url.into()),
-            title: None,
-            author: None,
-            date: None,
-            notes: None,
-        }
+        Self::new(SourceType::Sensor).with_url(url)
     }
 
     /// Creates a builder for constructing a SourceRef.
@@ -173,6 +209,87 @@ impl SourceRef {
         self.date = Some(date);
         self
     }
+
+    /// Sets the publisher.
+    pub fn with_publisher(mut self, publisher: impl Into<String>) -> Self {
+        self.publisher = Some(publisher.into());
+        self
+    }
+
+    /// Sets the page or page range.
+    pub fn with_pages(mut self, pages: impl Into<String>) -> Self {
+        self.pages = Some(pages.into());
+        self
+    }
+
+    /// Sets the DOI, stripping any "doi:" or resolver URL prefix.
+    pub fn with_doi(mut self, doi: impl Into<String>) -> Self {
+        self.doi = Some(normalize_doi(&doi.into()));
+        self
+    }
+
+    /// Sets the access date.
+    pub fn with_accessed(mut self, accessed: Timestamp) -> Self {
+        self.accessed = Some(accessed);
+        self
+    }
+
+    /// Sets the credibility score, clamped to 0.0..=1.0.
+    pub fn with_credibility(mut self, credibility: f64) -> Self {
+        self.credibility = Some(credibility.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Returns the credibility score, falling back to the type default.
+    pub fn effective_credibility(&self) -> f64 {
+        self.credibility
+            .unwrap_or_else(|| self.source_type.default_credibility())
+    }
+
+    /// Returns a key identifying where the source originates.
+    ///
+    /// Sources with the same origin are not independent of each other.
+    /// The origin is the author if known, otherwise the URL host, otherwise
+    /// the title.
+    pub fn origin(&self) -> Option<String> {
+        let normalize = |s: &str| s.trim().to_lowercase();
+        if let Some(author) = self.author.as_deref().filter(|a| !a.trim().is_empty()) {
+            return Some(normalize(author));
+        }
+        if let Some(host) = self.url.as_deref().and_then(url_host) {
+            return Some(host);
+        }
+        self.title
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+            .map(normalize)
+    }
+}
+
+/// Strips "doi:" and resolver URL prefixes from a DOI.
+pub(crate) fn normalize_doi(doi: &str) -> String {
+    let doi = doi.trim();
+    let lower = doi.to_lowercase();
+    for prefix in [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ] {
+        if lower.starts_with(prefix) {
+            return doi[prefix.len()..].trim().to_string();
+        }
+    }
+    doi.to_string()
+}
+
+/// Extracts the lowercase host of a URL, without a leading "www.".
+fn url_host(url: &str) -> Option<String> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest.split(['/', '?', '#']).next()?;
+    let host = host.rsplit('@').next()?.split(':').next()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+    (!host.is_empty()).then_some(host)
 }
 
 impl Default for SourceRef {
@@ -189,7 +306,12 @@ pub struct SourceRefBuilder {
     title: Option<String>,
     author: Option<String>,
     date: Option<Timestamp>,
+    publisher: Option<String>,
+    pages: Option<String>,
+    doi: Option<String>,
+    accessed: Option<Timestamp>,
     notes: Option<String>,
+    credibility: Option<f64>,
 }
 
 impl SourceRefBuilder {
@@ -228,12 +350,42 @@ impl SourceRefBuilder {
         self
     }
 
+    /// Sets the publisher.
+    pub fn publisher(mut self, publisher: impl Into<String>) -> Self {
+        self.publisher = Some(publisher.into());
+        self
+    }
+
+    /// Sets the page or page range.
+    pub fn pages(mut self, pages: impl Into<String>) -> Self {
+        self.pages = Some(pages.into());
+        self
+    }
+
+    /// Sets the DOI, stripping any "doi:" or resolver URL prefix.
+    pub fn doi(mut self, doi: impl Into<String>) -> Self {
+        self.doi = Some(normalize_doi(&doi.into()));
+        self
+    }
+
+    /// Sets the access date.
+    pub fn accessed(mut self, accessed: Timestamp) -> Self {
+        self.accessed = Some(accessed);
+        self
+    }
+
     /// Sets the notes.
     pub fn notes(mut self, notes: impl Into<String>) -> Self {
         self.notes = Some(notes.into());
         self
     }
 
+    /// Sets the credibility score, clamped to 0.0..=1.0.
+    pub fn credibility(mut self, credibility: f64) -> Self {
+        self.credibility = Some(credibility.clamp(0.0, 1.0));
+        self
+    }
+
     /// Builds the SourceRef.
     pub fn build(self) -> SourceRef {
         SourceRef {
@@ -242,7 +394,12 @@ impl SourceRefBuilder {
             title: self.title,
             author: self.author,
             date: self.date,
+            publisher: self.publisher,
+            pages: self.pages,
+            doi: self.doi,
+            accessed: self.accessed,
             notes: self.notes,
+            credibility: self.credibility,
         }
     }
 }
@@ -284,6 +441,48 @@ mod tests {
         assert!(source.title.is_some());
     }
 
+    #[test]
+    fn test_source_origin() {
+        let a = SourceRef::article("https://www.Example.com/a?x=1");
+        let b = SourceRef::article("http://example.com:8080/b");
+        assert_eq!(a.origin(), Some("example.com".to_string()));
+        assert_eq!(a.origin(), b.origin());
+
+        let authored = a.clone().with_author("Jane Doe ");
+        assert_eq!(authored.origin(), Some("jane doe".to_string()));
+        assert_eq!(SourceRef::witness(None).origin(), None);
+    }
+
+    #[test]
+    fn test_source_credibility() {
+        let source = SourceRef::new(SourceType::Social);
+        assert_eq!(source.effective_credibility(), 0.3);
+        assert_eq!(source.with_credibility(1.5).effective_credibility(), 1.0);
+        assert_eq!(
+            SourceType::from_name("Government"),
+            Some(SourceType::Government)
+        );
+        assert_eq!(SourceType::from_name("blog"), None);
+    }
+
+    #[test]
+    fn test_source_bibliographic_fields() {
+        let source = SourceRef::new(SourceType::Academic)
+            .with_doi("https://doi.org/10.1000/XYZ123")
+            .with_publisher("Journal of Examples")
+            .with_pages("12-15");
+
+        assert_eq!(source.doi.as_deref(), Some("10.1000/XYZ123"));
+        assert_eq!(
+            SourceRef::builder()
+                .doi("doi: 10.1/a")
+                .build()
+                .doi
+                .as_deref(),
+            Some("10.1/a")
+        );
+    }
+
     #[test]
     fn test_source_serialization() {
         let source = SourceRef::article("https://example.com");
@@ -0,0 +1,264 @@
+//! Time zone lookup from coordinates.
+//!
+//! ## Data
+//!
+//! Lookup goes through three layers, first match wins:
+//!
+//! 1. **Boundary polygons**: simplified zone boundaries from
+//!    timezone-boundary-builder. A point inside a polygon takes its zone;
+//!    a point inside polygons of two zones (an edge blurred by
+//!    simplification) gets `None`.
+//! 2. **Nearest place**: the built-in gazetteer's GeoNames cities, each
+//!    assigned a zone of its own country from the IANA tz database, plus
+//!    the reference point of every zone in `zone.tab`. Since a place's
+//!    zone follows its country, a city never picks up a neighbouring
+//!    country's zone. A location takes the zone of the nearest place
+//!    within 300 km, unless a place in a different zone is nearly as
+//!    close (within 1.5 times the distance), so the point could be on
+//!    either side of a border; that case returns `None`.
+//! 3. **Nautical zones**: anywhere farther than 300 km from every place
+//!    (open ocean, in practice) takes the `Etc/GMT±N` zone of its
+//!    longitude, one hour per 15°. The sign follows POSIX, so
+//!    `Etc/GMT+3` is three hours *behind* UTC.
+//!
+//! The bundled boundary table only covers the El Paso / Ciudad Juárez
+//! border until it is regenerated with
+//! `scripts/generate_time_zone_boundaries.py`; elsewhere the nearest-place
+//! layer decides, and points very far from any place inland also fall
+//! through to the nautical zone.
+
+use super::time_zone_boundaries::BOUNDARIES;
+use super::time_zone_data::PLACES;
+use crate::core::Location;
+use chrono_tz::Tz;
+use geo::{BoundingRect, Intersects, LineString, Point, Polygon};
+use once_cell::sync::Lazy;
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::RTree;
+
+/// Farthest a place can be and still set the zone, in meters.
+const MAX_PLACE_DISTANCE_M: f64 = 300_000.0;
+
+/// How much farther than the nearest place a place in another zone must
+/// be for the answer to count as unambiguous.
+const AMBIGUITY_RATIO: f64 = 1.5;
+
+/// Nautical zones from UTC-12 to UTC+12, by hour.
+const NAUTICAL_ZONES: [Tz; 25] = [
+    Tz::Etc__GMTPlus12,
+    Tz::Etc__GMTPlus11,
+    Tz::Etc__GMTPlus10,
+    Tz::Etc__GMTPlus9,
+    Tz::Etc__GMTPlus8,
+    Tz::Etc__GMTPlus7,
+    Tz::Etc__GMTPlus6,
+    Tz::Etc__GMTPlus5,
+    Tz::Etc__GMTPlus4,
+    Tz::Etc__GMTPlus3,
+    Tz::Etc__GMTPlus2,
+    Tz::Etc__GMTPlus1,
+    Tz::Etc__GMT,
+    Tz::Etc__GMTMinus1,
+    Tz::Etc__GMTMinus2,
+    Tz::Etc__GMTMinus3,
+    Tz::Etc__GMTMinus4,
+    Tz::Etc__GMTMinus5,
+    Tz::Etc__GMTMinus6,
+    Tz::Etc__GMTMinus7,
+    Tz::Etc__GMTMinus8,
+    Tz::Etc__GMTMinus9,
+    Tz::Etc__GMTMinus10,
+    Tz::Etc__GMTMinus11,
+    Tz::Etc__GMTMinus12,
+];
+
+/// Boundary polygons keyed by bounding box, in `(lon, lat)`.
+static BOUNDARY_TREE: Lazy<RTree<GeomWithData<Rectangle<[f64; 2]>, (Polygon, Tz)>>> =
+    Lazy::new(|| {
+        let boundaries = BOUNDARIES
+            .iter()
+            .filter_map(|(zone, polygons)| Some((zone.parse::<Tz>().ok()?, *polygons)))
+            .flat_map(|(tz, polygons)| {
+                polygons.iter().filter_map(move |rings| {
+                    let mut rings = rings.iter().map(|ring| LineString::from(ring.to_vec()));
+                    let polygon = Polygon::new(rings.next()?, rings.collect());
+                    let rect = polygon.bounding_rect()?;
+                    Some(GeomWithData::new(
+                        Rectangle::from_corners(rect.min().x_y().into(), rect.max().x_y().into()),
+                        (polygon, tz),
+                    ))
+                })
+            })
+            .collect();
+        RTree::bulk_load(boundaries)
+    });
+
+/// Places keyed by unit vector, so Euclidean nearest neighbours are
+/// great-circle nearest neighbours and the antimeridian needs no care.
+static PLACE_TREE: Lazy<RTree<GeomWithData<[f64; 3], (Location, Tz)>>> = Lazy::new(|| {
+    let places = PLACES
+        .iter()
+        .filter_map(|(_, lat, lon, zone)| {
+            let tz = zone.parse().ok()?;
+            Some(GeomWithData::new(
+                unit_vector(*lat, *lon),
+                (Location::new(*lat, *lon), tz),
+            ))
+        })
+        .collect();
+    RTree::bulk_load(places)
+});
+
+/// Looks up the time zone for a location.
+pub(crate) fn time_zone_at(location: &Location) -> Option<Tz> {
+    let point = Point::new(location.lon, location.lat);
+    let mut zones = BOUNDARY_TREE
+        .locate_all_at_point(&[location.lon, location.lat])
+        .filter(|boundary| boundary.data.0.intersects(&point))
+        .map(|boundary| boundary.data.1);
+    if let Some(zone) = zones.next() {
+        return zones.all(|tz| tz == zone).then_some(zone);
+    }
+
+    let mut neighbours = PLACE_TREE.nearest_neighbor_iter(&unit_vector(location.lat, location.lon));
+    let (nearest, zone) = &neighbours.next()?.data;
+    let distance = location.distance_to(nearest);
+    if distance > MAX_PLACE_DISTANCE_M {
+        return Some(nautical_zone(location.lon));
+    }
+
+    let limit = distance * AMBIGUITY_RATIO;
+    let contested = neighbours
+        .map(|place| (location.distance_to(&place.data.0), place.data.1))
+        .take_while(|(d, _)| *d < limit)
+        .any(|(_, tz)| tz != *zone);
+    (!contested).then_some(*zone)
+}
+
+/// The nautical zone for a longitude: the nearest whole hour at 15° per
+/// hour, so the ±180° meridian falls in UTC±12.
+fn nautical_zone(lon: f64) -> Tz {
+    let hours = (lon / 15.0).round().clamp(-12.0, 12.0);
+    NAUTICAL_ZONES[(hours + 12.0) as usize]
+}
+
+fn unit_vector(lat: f64, lon: f64) -> [f64; 3] {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone_name(lat: f64, lon: f64) -> Option<&'static str> {
+        time_zone_at(&Location::new(lat, lon)).map(|tz| tz.name())
+    }
+
+    #[test]
+    fn test_time_zone_cities() {
+        let cases = [
+            (48.8566, 2.3522, "Europe/Paris"),
+            (51.5074, -0.1278, "Europe/London"),
+            (40.7128, -74.0060, "America/New_York"),
+            (41.8781, -87.6298, "America/Chicago"),
+            (34.0522, -118.2437, "America/Los_Angeles"),
+            (35.6762, 139.6503, "Asia/Tokyo"),
+            (-33.8688, 151.2093, "Australia/Sydney"),
+            (28.6139, 77.2090, "Asia/Kolkata"),
+            (-23.5505, -46.6333, "America/Sao_Paulo"),
+            (55.7558, 37.6173, "Europe/Moscow"),
+            (-1.2921, 36.8219, "Africa/Nairobi"),
+            (39.9042, 116.4074, "Asia/Shanghai"),
+        ];
+
+        for (lat, lon, expected) in cases {
+            assert_eq!(zone_name(lat, lon), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_time_zone_border_cities() {
+        let cases = [
+            (22.5726, 88.3639, "Asia/Kolkata"),
+            (33.6844, 73.0479, "Asia/Karachi"),
+            (34.0151, 71.5249, "Asia/Karachi"),
+            (31.6340, 74.8723, "Asia/Kolkata"),
+            (26.8467, 80.9462, "Asia/Kolkata"),
+            (59.9311, 30.3609, "Europe/Moscow"),
+            (48.1351, 11.5820, "Europe/Berlin"),
+            (38.4237, 27.1428, "Europe/Istanbul"),
+            (54.7104, 20.4522, "Europe/Kaliningrad"),
+            (48.5734, 7.7521, "Europe/Paris"),
+            (31.7619, -106.4850, "America/Denver"),
+            (32.5149, -117.0382, "America/Tijuana"),
+        ];
+
+        for (lat, lon, expected) in cases {
+            assert_eq!(zone_name(lat, lon), Some(expected), "({}, {})", lat, lon);
+        }
+    }
+
+    #[test]
+    fn test_time_zone_near_city() {
+        // Salt Lake, on the eastern edge of Kolkata
+        assert_eq!(zone_name(22.58, 88.41), Some("Asia/Kolkata"));
+        // Schwabing, north Munich
+        assert_eq!(zone_name(48.16, 11.58), Some("Europe/Berlin"));
+    }
+
+    #[test]
+    fn test_time_zone_contested_border() {
+        // Halfway between Amritsar and Lahore
+        assert_eq!(zone_name(31.60, 74.61), None);
+    }
+
+    #[test]
+    fn test_time_zone_names_are_valid() {
+        for (place, _, _, name) in PLACES {
+            assert!(
+                name.parse::<Tz>().is_ok(),
+                "unknown zone {} for {}",
+                name,
+                place
+            );
+        }
+    }
+
+    #[test]
+    fn test_time_zone_el_paso_juarez() {
+        // Downtown El Paso and central Ciudad Juárez, either side of the
+        // Rio Grande and about 5 km apart
+        assert_eq!(zone_name(31.7587, -106.4869), Some("America/Denver"));
+        assert_eq!(zone_name(31.7202, -106.4608), Some("America/Ciudad_Juarez"));
+        assert_eq!(zone_name(31.69, -106.42), Some("America/Ciudad_Juarez"));
+        // Sunland Park, New Mexico, across the state line from El Paso
+        assert_eq!(zone_name(31.80, -106.58), Some("America/Denver"));
+    }
+
+    #[test]
+    fn test_time_zone_boundary_names_are_valid() {
+        for (name, polygons) in BOUNDARIES {
+            assert!(name.parse::<Tz>().is_ok(), "unknown zone {}", name);
+            assert!(!polygons.is_empty(), "no polygons for {}", name);
+        }
+    }
+
+    #[test]
+    fn test_time_zone_ocean() {
+        // Mid-Atlantic, three hours behind UTC
+        assert_eq!(zone_name(30.0, -45.0), Some("Etc/GMT+3"));
+        // South Atlantic, on the prime meridian
+        assert_eq!(zone_name(-40.0, 0.0), Some("Etc/GMT"));
+        // Mid-Pacific near the antimeridian, twelve hours ahead
+        assert_eq!(zone_name(0.0, 179.0), Some("Etc/GMT-12"));
+        // Southern Indian Ocean
+        assert_eq!(zone_name(-45.0, 80.0), Some("Etc/GMT-5"));
+    }
+
+    #[test]
+    fn test_time_zone_across_antimeridian() {
+        // Taveuni, Fiji, just east of the antimeridian
+        assert_eq!(zone_name(-16.85, -179.95), Some("Pacific/Fiji"));
+    }
+}
@@ -0,0 +1,31 @@
+// Simplified time zone boundary polygons.
+// Seed rows for the El Paso / Ciudad Juárez metro area, where the border
+// follows the Rio Grande and both cities lie within a few kilometres of
+// each other's reference places; digitised by hand along the river and
+// clipped to the Juárez municipality. Run
+// scripts/generate_time_zone_boundaries.py to replace them with the
+// simplified timezone-boundary-builder polygons for every zone.
+// Licensed under the Open Data Commons Open Database License (ODbL)
+
+/// A closed ring of `(lon, lat)` vertices.
+pub(super) type Ring = &'static [(f64, f64)];
+
+/// Boundaries as `(zone, polygons)`; each polygon is a list of rings,
+/// exterior ring first.
+#[rustfmt::skip]
+pub(super) const BOUNDARIES: &[(&str, &[&[Ring]])] = &[
+    ("America/Denver", &[&[&[
+        (-106.6500, 31.9500), (-106.2500, 31.9500), (-106.2500, 31.5850),
+        (-106.2800, 31.6200), (-106.3371, 31.6710), (-106.3800, 31.7100),
+        (-106.4200, 31.7450), (-106.4510, 31.7650), (-106.4700, 31.7560),
+        (-106.4874, 31.7497), (-106.5100, 31.7600), (-106.5284, 31.7837),
+        (-106.6500, 31.7837), (-106.6500, 31.9500),
+    ]]]),
+    ("America/Ciudad_Juarez", &[&[&[
+        (-106.6500, 31.7837), (-106.5284, 31.7837), (-106.5100, 31.7600),
+        (-106.4874, 31.7497), (-106.4700, 31.7560), (-106.4510, 31.7650),
+        (-106.4200, 31.7450), (-106.3800, 31.7100), (-106.3371, 31.6710),
+        (-106.2800, 31.6200), (-106.2500, 31.5850), (-106.2500, 31.4500),
+        (-106.6500, 31.4500), (-106.6500, 31.7837),
+    ]]]),
+];
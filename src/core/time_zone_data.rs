@@ -0,0 +1,2926 @@
+// Auto-generated time zone table from GeoNames cities (via the built-in
+// gazetteer) and the IANA tz database's zone.tab.
+// DO NOT EDIT MANUALLY - regenerate with scripts/generate_time_zones.py
+
+/// Reference places as `(name, lat, lon, zone)`.
+#[rustfmt::skip]
+pub(super) const PLACES: &[(&str, f64, f64, &str)] = &[
+    ("Shanghai", 31.2222, 121.4581, "Asia/Shanghai"),
+    ("Beijing", 39.9075, 116.3972, "Asia/Shanghai"),
+    ("Shenzhen", 22.5455, 114.0683, "Asia/Shanghai"),
+    ("Guangzhou", 23.1167, 113.2500, "Asia/Shanghai"),
+    ("Kinshasa", -4.3276, 15.3136, "Africa/Kinshasa"),
+    ("Istanbul", 41.0138, 28.9497, "Europe/Istanbul"),
+    ("Lagos", 6.4541, 3.3947, "Africa/Lagos"),
+    ("Ho Chi Minh City", 10.8230, 106.6296, "Asia/Ho_Chi_Minh"),
+    ("Chengdu", 30.6667, 104.0667, "Asia/Shanghai"),
+    ("Lahore", 31.5580, 74.3507, "Asia/Karachi"),
+    ("Mumbai", 19.0728, 72.8826, "Asia/Kolkata"),
+    ("São Paulo", -23.5475, -46.6361, "America/Sao_Paulo"),
+    ("Mexico City", 19.4285, -99.1277, "America/Mexico_City"),
+    ("Karachi", 24.8608, 67.0104, "Asia/Karachi"),
+    ("Tianjin", 39.1422, 117.1767, "Asia/Shanghai"),
+    ("Delhi", 28.6519, 77.2315, "Asia/Kolkata"),
+    ("Wuhan", 30.5833, 114.2667, "Asia/Shanghai"),
+    ("Moscow", 55.7520, 37.6178, "Europe/Moscow"),
+    ("Dhaka", 23.7104, 90.4074, "Asia/Dhaka"),
+    ("Seoul", 37.5660, 126.9784, "Asia/Seoul"),
+    ("Tokyo", 35.6895, 139.6917, "Asia/Tokyo"),
+    ("Dongguan", 23.0180, 113.7487, "Asia/Shanghai"),
+    ("Cairo", 30.0626, 31.2497, "Africa/Cairo"),
+    ("Xi’an", 34.2583, 108.9286, "Asia/Shanghai"),
+    ("Johannesburg", -26.2023, 28.0436, "Africa/Johannesburg"),
+    ("Nanjing", 32.0617, 118.7778, "Asia/Shanghai"),
+    ("Hangzhou", 30.2936, 120.1614, "Asia/Shanghai"),
+    ("Foshan", 23.0268, 113.1315, "Asia/Shanghai"),
+    ("London", 51.5085, -0.1257, "Europe/London"),
+    ("New York City", 40.7143, -74.0060, "America/New_York"),
+    ("Jakarta", -6.2146, 106.8451, "Asia/Jakarta"),
+    ("Bengaluru", 12.9719, 77.5937, "Asia/Kolkata"),
+    ("Hanoi", 21.0245, 105.8412, "Asia/Ho_Chi_Minh"),
+    ("Taipei", 25.0531, 121.5264, "Asia/Taipei"),
+    ("Lima", -12.0432, -77.0282, "America/Lima"),
+    ("Bogotá", 4.6097, -74.0817, "America/Bogota"),
+    ("Chongqing", 29.5603, 106.5577, "Asia/Shanghai"),
+    ("Hong Kong", 22.2783, 114.1747, "Asia/Hong_Kong"),
+    ("Baghdad", 33.3406, 44.4009, "Asia/Baghdad"),
+    ("Qingdao", 36.0649, 120.3804, "Asia/Shanghai"),
+    ("Tehran", 35.6944, 51.4215, "Asia/Tehran"),
+    ("Shenyang", 41.7922, 123.4328, "Asia/Shanghai"),
+    ("Hyderabad", 17.3840, 78.4564, "Asia/Kolkata"),
+    ("Rio de Janeiro", -22.9064, -43.1822, "America/Sao_Paulo"),
+    ("Suzhou", 31.3041, 120.5954, "Asia/Shanghai"),
+    ("Ahmedabad", 23.0258, 72.5873, "Asia/Kolkata"),
+    ("Abidjan", 5.3544, -4.0017, "Africa/Abidjan"),
+    ("Singapore", 1.2897, 103.8501, "Asia/Singapore"),
+    ("Sydney", -33.8678, 151.2073, "Australia/Sydney"),
+    ("Dar es Salaam", -6.8235, 39.2695, "Africa/Dar_es_Salaam"),
+    ("Saint Petersburg", 59.9386, 30.3141, "Europe/Moscow"),
+    ("Melbourne", -37.8140, 144.9633, "Australia/Melbourne"),
+    ("Alexandria", 31.2018, 29.9158, "Africa/Cairo"),
+    ("Harbin", 45.7500, 126.6500, "Asia/Shanghai"),
+    ("Bangkok", 13.7540, 100.5014, "Asia/Bangkok"),
+    ("Hefei", 31.8639, 117.2808, "Asia/Shanghai"),
+    ("Dalian", 38.9122, 121.6022, "Asia/Shanghai"),
+    ("Kano", 12.0001, 8.5167, "Africa/Lagos"),
+    ("Santiago", -33.4569, -70.6483, "America/Santiago"),
+    ("Cape Town", -33.9258, 18.4232, "Africa/Johannesburg"),
+    ("Peshawar", 34.0080, 71.5785, "Asia/Karachi"),
+    ("Changchun", 43.8800, 125.3228, "Asia/Shanghai"),
+    ("Jeddah", 21.4901, 39.1862, "Asia/Riyadh"),
+    ("Chennai", 13.0878, 80.2785, "Asia/Kolkata"),
+    ("Kolkata", 22.5626, 88.3630, "Asia/Kolkata"),
+    ("Xiamen", 24.4798, 118.0819, "Asia/Shanghai"),
+    ("Surat", 21.1959, 72.8302, "Asia/Kolkata"),
+    ("Yangon", 16.8053, 96.1561, "Asia/Yangon"),
+    ("Bao'an", 22.5521, 113.8829, "Asia/Shanghai"),
+    ("Kabul", 34.5281, 69.1723, "Asia/Kabul"),
+    ("Nairobi", -1.2833, 36.8167, "Africa/Nairobi"),
+    ("Wuxi", 31.5689, 120.2886, "Asia/Shanghai"),
+    ("Giza", 30.0094, 31.2086, "Africa/Cairo"),
+    ("Jinan", 36.6683, 116.9972, "Asia/Shanghai"),
+    ("Taiyuan", 37.8694, 112.5603, "Asia/Shanghai"),
+    ("Zhengzhou", 34.7578, 113.6486, "Asia/Shanghai"),
+    ("Bamako", 12.6091, -7.9752, "Africa/Bamako"),
+    ("Riyadh", 24.6877, 46.7219, "Asia/Riyadh"),
+    ("New Taipei City", 25.0620, 121.4570, "Asia/Taipei"),
+    ("New Territories", 22.4244, 114.1110, "Asia/Hong_Kong"),
+    ("Shijiazhuang", 38.0414, 114.4786, "Asia/Shanghai"),
+    ("Chattogram", 22.3384, 91.8317, "Asia/Dhaka"),
+    ("Addis Ababa", 9.0250, 38.7469, "Africa/Addis_Ababa"),
+    ("Kunming", 25.0389, 102.7183, "Asia/Shanghai"),
+    ("Zhongshan", 22.5231, 113.3791, "Asia/Shanghai"),
+    ("Nanning", 22.8167, 108.3167, "Asia/Shanghai"),
+    ("Shantou", 23.3549, 116.6788, "Asia/Shanghai"),
+    ("Los Angeles", 34.0522, -118.2437, "America/Los_Angeles"),
+    ("Faisalabad", 31.4155, 73.0897, "Asia/Karachi"),
+    ("Dubai", 25.0772, 55.3093, "Asia/Dubai"),
+    ("Yokohama", 35.4333, 139.6500, "Asia/Tokyo"),
+    ("Fuzhou", 26.0614, 119.3061, "Asia/Shanghai"),
+    ("Ningbo", 29.8782, 121.5494, "Asia/Shanghai"),
+    ("Casablanca", 33.5883, -7.6114, "Africa/Casablanca"),
+    ("Ibadan", 7.3776, 3.9059, "Africa/Lagos"),
+    ("Puyang", 29.4568, 119.8887, "Asia/Shanghai"),
+    ("Ankara", 39.9199, 32.8543, "Europe/Istanbul"),
+    ("Shiyan", 32.6475, 110.7781, "Asia/Shanghai"),
+    ("Berlin", 52.5244, 13.4105, "Europe/Berlin"),
+    ("Tangshan", 39.6438, 118.1832, "Asia/Shanghai"),
+    ("Rawalpindi", 33.5973, 73.0479, "Asia/Karachi"),
+    ("Lüliang", 37.5192, 111.1444, "Asia/Shanghai"),
+    ("Busan", 35.1017, 129.0300, "Asia/Seoul"),
+    ("Durban", -29.8579, 31.0292, "Africa/Johannesburg"),
+    ("Changzhou", 31.7736, 119.9540, "Asia/Shanghai"),
+    ("Madrid", 40.4165, -3.7026, "Europe/Madrid"),
+    ("Pyongyang", 39.0339, 125.7543, "Asia/Pyongyang"),
+    ("Zibo", 36.7906, 118.0633, "Asia/Shanghai"),
+    ("Pune", 18.5196, 73.8554, "Asia/Kolkata"),
+    ("Bursa", 40.1956, 29.0601, "Europe/Istanbul"),
+    ("Changsha", 28.1987, 112.9709, "Asia/Shanghai"),
+    ("Quezon City", 14.6488, 121.0509, "Asia/Manila"),
+    ("Jaipur", 26.9196, 75.7878, "Asia/Kolkata"),
+    ("Incheon", 37.4565, 126.7052, "Asia/Seoul"),
+    ("Guiyang", 26.5833, 106.7167, "Asia/Shanghai"),
+    ("Ürümqi", 43.8010, 87.6005, "Asia/Urumqi"),
+    ("Lanzhou", 36.0570, 103.8399, "Asia/Shanghai"),
+    ("Caracas", 10.4880, -66.8792, "America/Caracas"),
+    ("İzmir", 38.4127, 27.1384, "Europe/Istanbul"),
+    ("Huizhou", 23.1115, 114.4152, "Asia/Shanghai"),
+    ("Buenos Aires", -34.6131, -58.3772, "America/Argentina/Buenos_Aires"),
+    ("Surabaya", -7.2492, 112.7508, "Asia/Jakarta"),
+    ("Haikou", 20.0342, 110.3465, "Asia/Shanghai"),
+    ("Taichung", 24.1469, 120.6839, "Asia/Taipei"),
+    ("Kanpur", 26.4652, 80.3498, "Asia/Kolkata"),
+    ("Kyiv", 50.4547, 30.5238, "Europe/Kyiv"),
+    ("Toronto", 43.7064, -79.3986, "America/Toronto"),
+    ("Quito", -0.2298, -78.5250, "America/Guayaquil"),
+    ("Brisbane", -27.4679, 153.0281, "Australia/Brisbane"),
+    ("Luanda", -8.8368, 13.2343, "Africa/Luanda"),
+    ("Osaka", 34.6938, 135.5011, "Asia/Tokyo"),
+    ("Linyi", 35.0631, 118.3428, "Asia/Shanghai"),
+    ("Baoding", 38.8729, 115.4625, "Asia/Shanghai"),
+    ("Kaohsiung", 22.6163, 120.3133, "Asia/Taipei"),
+    ("Brooklyn", 40.6501, -73.9496, "America/New_York"),
+    ("Guayaquil", -2.1962, -79.8862, "America/Guayaquil"),
+    ("Belo Horizonte", -19.9208, -43.9378, "America/Sao_Paulo"),
+    ("Salvador", -12.9756, -38.4910, "America/Bahia"),
+    ("Abuja", 9.0579, 7.4951, "Africa/Lagos"),
+    ("Gazipur", 23.9984, 90.4223, "Asia/Dhaka"),
+    ("Chicago", 41.8500, -87.6500, "America/Chicago"),
+    ("Wenzhou", 27.9994, 120.6668, "Asia/Shanghai"),
+    ("Dakar", 14.6937, -17.4441, "Africa/Dakar"),
+    ("Haiphong", 20.8648, 106.6834, "Asia/Ho_Chi_Minh"),
+    ("Yunfu", 22.9279, 112.0381, "Asia/Shanghai"),
+    ("Navi Mumbai", 19.0368, 73.0158, "Asia/Kolkata"),
+    ("Mogadishu", 2.0371, 45.3438, "Africa/Mogadishu"),
+    ("Bekasi", -6.2349, 106.9896, "Asia/Jakarta"),
+    ("Kumasi", 6.6885, -1.6244, "Africa/Accra"),
+    ("Gujranwala", 32.1557, 74.1870, "Asia/Karachi"),
+    ("Huai'an", 33.5886, 119.0192, "Asia/Shanghai"),
+    ("Lucknow", 26.8393, 80.9231, "Asia/Kolkata"),
+    ("Bandung", -6.9222, 107.6069, "Asia/Jakarta"),
+    ("Medan", 3.5833, 98.6667, "Asia/Jakarta"),
+    ("Ouagadougou", 12.3657, -1.5339, "Africa/Ouagadougou"),
+    ("Nagpur", 21.1463, 79.0849, "Asia/Kolkata"),
+    ("Fortaleza", -3.7172, -38.5431, "America/Fortaleza"),
+    ("Cali", 3.4305, -76.5199, "America/Bogota"),
+    ("Daegu", 35.8703, 128.5911, "Asia/Seoul"),
+    ("Algiers", 36.7323, 3.0875, "Africa/Algiers"),
+    ("Nanchang", 28.6840, 115.8531, "Asia/Shanghai"),
+    ("Hohhot", 40.8106, 111.6522, "Asia/Shanghai"),
+    ("Nagoya", 35.1815, 136.9064, "Asia/Tokyo"),
+    ("Rome", 41.8919, 12.5113, "Europe/Rome"),
+    ("Queens", 40.6815, -73.8365, "America/New_York"),
+    ("Houston", 29.7633, -95.3633, "America/Chicago"),
+    ("Perth", -31.9522, 115.8614, "Australia/Perth"),
+    ("Mashhad", 36.2981, 59.6057, "Asia/Tehran"),
+    ("Shaoxing", 30.0024, 120.5786, "Asia/Shanghai"),
+    ("Nantong", 32.0303, 120.8747, "Asia/Shanghai"),
+    ("Kowloon", 22.3167, 114.1833, "Asia/Hong_Kong"),
+    ("Yantai", 37.4765, 121.4408, "Asia/Shanghai"),
+    ("Lubumbashi", -11.6609, 27.4794, "Africa/Lubumbashi"),
+    ("Manaus", -3.1019, -60.0250, "America/Manaus"),
+    ("Lusaka", -15.4067, 28.2871, "Africa/Lusaka"),
+    ("Brasília", -15.7797, -47.9297, "America/Sao_Paulo"),
+    ("Zhuhai", 22.2769, 113.5678, "Asia/Shanghai"),
+    ("Santo Domingo", 18.4719, -69.8923, "America/Santo_Domingo"),
+    ("Lomé", 6.1287, 1.2215, "Africa/Lome"),
+    ("Multan", 30.1968, 71.4782, "Asia/Karachi"),
+    ("Havana", 23.1330, -82.3830, "America/Havana"),
+    ("Baotou", 40.6516, 109.8439, "Asia/Shanghai"),
+    ("Depok", -6.4000, 106.8186, "Asia/Jakarta"),
+    ("Paris", 48.8534, 2.3488, "Europe/Paris"),
+    ("Coimbatore", 11.0055, 76.9661, "Asia/Kolkata"),
+    ("Gaziantep", 37.0594, 37.3825, "Europe/Istanbul"),
+    ("Qingyang", 35.7098, 107.6445, "Asia/Shanghai"),
+    ("Port Harcourt", 4.7774, 7.0134, "Africa/Lagos"),
+    ("Pretoria", -25.7449, 28.1878, "Africa/Johannesburg"),
+    ("Córdoba", -31.4065, -64.1885, "America/Argentina/Cordoba"),
+    ("Mbuji-Mayi", -6.1360, 23.5898, "Africa/Lubumbashi"),
+    ("Aleppo", 36.2012, 37.1612, "Asia/Damascus"),
+    ("Kunshan", 31.3776, 120.9543, "Asia/Shanghai"),
+    ("Al Mawşil al Jadīdah", 36.3327, 43.1056, "Asia/Baghdad"),
+    ("Weifang", 36.7100, 119.1019, "Asia/Shanghai"),
+    ("Zunyi", 27.6867, 106.9072, "Asia/Shanghai"),
+    ("Al Başrah al Qadīmah", 30.5032, 47.8151, "Asia/Baghdad"),
+    ("La Paz", -16.5000, -68.1500, "America/La_Paz"),
+    ("Lianyungang", 34.5984, 119.2156, "Asia/Shanghai"),
+    ("Medellín", 6.2450, -75.5715, "America/Bogota"),
+    ("Indore", 22.7179, 75.8333, "Asia/Kolkata"),
+    ("Brazzaville", -4.2661, 15.2832, "Africa/Brazzaville"),
+    ("Tashkent", 41.2647, 69.2163, "Asia/Tashkent"),
+    ("Ganzhou", 25.8466, 114.9326, "Asia/Shanghai"),
+    ("Almaty", 43.2525, 76.9115, "Asia/Almaty"),
+    ("Khartoum", 15.5518, 32.5324, "Africa/Khartoum"),
+    ("Sapporo", 43.0667, 141.3500, "Asia/Tokyo"),
+    ("Accra", 5.5560, -0.1969, "Africa/Accra"),
+    ("Curitiba", -25.4278, -49.2731, "America/Sao_Paulo"),
+    ("Ordos", 39.6086, 109.7816, "Asia/Shanghai"),
+    ("Sanaa", 15.3545, 44.2065, "Asia/Aden"),
+    ("Conakry", 9.5380, -13.6773, "Africa/Conakry"),
+    ("Tijuana", 32.5027, -117.0037, "America/Tijuana"),
+    ("Hyderabad", 25.3969, 68.3772, "Asia/Karachi"),
+    ("Beirut", 33.8933, 35.5016, "Asia/Beirut"),
+    ("Tangerang", -6.1781, 106.6300, "Asia/Jakarta"),
+    ("Jieyang", 23.5418, 116.3658, "Asia/Shanghai"),
+    ("Jilin", 43.8465, 126.5608, "Asia/Shanghai"),
+    ("Bucharest", 44.4323, 26.1063, "Europe/Bucharest"),
+    ("Camayenne", 9.5350, -13.6878, "Africa/Conakry"),
+    ("Kakamega", 0.2842, 34.7523, "Africa/Nairobi"),
+    ("Nanchong", 30.7951, 106.0847, "Asia/Shanghai"),
+    ("Tainan", 22.9908, 120.2133, "Asia/Taipei"),
+    ("Datong", 40.0936, 113.2914, "Asia/Shanghai"),
+    ("Kaduna", 10.5264, 7.4388, "Africa/Lagos"),
+    ("Omdurman", 15.6445, 32.4777, "Africa/Khartoum"),
+    ("Davao", 7.0731, 125.6128, "Asia/Manila"),
+    ("Hamburg", 53.5507, 9.9930, "Europe/Berlin"),
+    ("Thāne", 19.1970, 72.9635, "Asia/Kolkata"),
+    ("Iztapalapa", 19.3553, -99.0622, "America/Mexico_City"),
+    ("Santa Cruz de la Sierra", -17.7863, -63.1812, "America/La_Paz"),
+    ("Vadodara", 22.2994, 73.2081, "Asia/Kolkata"),
+    ("Adana", 36.9862, 35.3253, "Europe/Istanbul"),
+    ("Nanyang", 32.9947, 112.5328, "Asia/Shanghai"),
+    ("Abu Dhabi", 24.4512, 54.3970, "Asia/Dubai"),
+    ("Sharjah", 25.3342, 55.4122, "Asia/Dubai"),
+    ("Bhopal", 23.2547, 77.4029, "Asia/Kolkata"),
+    ("Jiangmen", 22.5833, 113.0833, "Asia/Shanghai"),
+    ("Diyarbakır", 37.9136, 40.2172, "Europe/Istanbul"),
+    ("Benin City", 6.3381, 5.6258, "Africa/Lagos"),
+    ("Jiangyin", 31.9110, 120.2630, "Asia/Shanghai"),
+    ("Fuyang", 32.9000, 115.8167, "Asia/Shanghai"),
+    ("Montréal", 45.5088, -73.5878, "America/Toronto"),
+    ("Bayan Nur", 40.7414, 107.3860, "Asia/Shanghai"),
+    ("Maracaibo", 10.6423, -71.6109, "America/Caracas"),
+    ("Chaozhou", 23.6540, 116.6226, "Asia/Shanghai"),
+    ("Minsk", 53.9002, 27.5665, "Europe/Minsk"),
+    ("Budapest", 47.4984, 19.0404, "Europe/Budapest"),
+    ("Qingyuan", 23.7000, 113.0333, "Asia/Shanghai"),
+    ("Tai’an", 36.1853, 117.1200, "Asia/Shanghai"),
+    ("Rasapūdipalem", 17.7331, 83.3162, "Asia/Kolkata"),
+    ("Pimpri-Chinchwad", 18.6187, 73.8037, "Asia/Kolkata"),
+    ("Caloocan", 14.6495, 120.9679, "Asia/Manila"),
+    ("Warsaw", 52.2298, 21.0118, "Europe/Warsaw"),
+    ("Soweto", -26.2678, 27.8585, "Africa/Johannesburg"),
+    ("Puebla", 19.0478, -98.2072, "America/Mexico_City"),
+    ("Vienna", 48.2085, 16.3721, "Europe/Vienna"),
+    ("Barcelona", 41.3888, 2.1590, "Europe/Madrid"),
+    ("Patna", 25.5941, 85.1356, "Asia/Kolkata"),
+    ("Mosul", 36.3350, 43.1189, "Asia/Baghdad"),
+    ("Kallakurichi", 11.7338, 78.9592, "Asia/Kolkata"),
+    ("Kampala", 0.3163, 32.5822, "Africa/Kampala"),
+    ("Xining", 36.6255, 101.7574, "Asia/Shanghai"),
+    ("Changshu", 31.6461, 120.7422, "Asia/Shanghai"),
+    ("Palembang", -2.9167, 104.7458, "Asia/Jakarta"),
+    ("Huainan", 32.6264, 116.9969, "Asia/Shanghai"),
+    ("Rabat", 34.0132, -6.8326, "Africa/Casablanca"),
+    ("Semarang", -6.9931, 110.4208, "Asia/Jakarta"),
+    ("Recife", -8.0539, -34.8811, "America/Recife"),
+    ("Phoenix", 33.4484, -112.0740, "America/Phoenix"),
+    ("Suzhou", 33.6361, 116.9789, "Asia/Shanghai"),
+    ("Ecatepec de Morelos", 19.6049, -99.0606, "America/Mexico_City"),
+    ("Lu’an", 31.7356, 116.5169, "Asia/Shanghai"),
+    ("Valencia", 10.1615, -68.0004, "America/Caracas"),
+    ("Ludhiana", 30.9120, 75.8538, "Asia/Kolkata"),
+    ("Yancheng", 33.3575, 120.1573, "Asia/Shanghai"),
+    ("Novosibirsk", 55.0226, 82.9317, "Asia/Novosibirsk"),
+    ("Erbil", 36.1912, 44.0094, "Asia/Baghdad"),
+    ("Fukuoka", 33.6000, 130.4167, "Asia/Tokyo"),
+    ("Taizhou", 32.4907, 119.9081, "Asia/Shanghai"),
+    ("Daqing", 46.5833, 125.0000, "Asia/Shanghai"),
+    ("Manila", 14.6042, 120.9822, "Asia/Manila"),
+    ("Wuhu", 31.3526, 118.4295, "Asia/Shanghai"),
+    ("Santiago de Querétaro", 20.5881, -100.3881, "America/Mexico_City"),
+    ("Dazhou", 31.2106, 107.4631, "Asia/Shanghai"),
+    ("Yangzhou", 32.3972, 119.4358, "Asia/Shanghai"),
+    ("León de los Aldama", 21.1291, -101.6737, "America/Mexico_City"),
+    ("Makkah", 21.4266, 39.8256, "Asia/Riyadh"),
+    ("Philadelphia", 39.9524, -75.1636, "America/New_York"),
+    ("Phnom Penh", 11.5625, 104.9160, "Asia/Phnom_Penh"),
+    ("Guilin", 25.2802, 110.2964, "Asia/Shanghai"),
+    ("Damascus", 33.5102, 36.2913, "Asia/Damascus"),
+    ("Quetta", 30.1841, 67.0014, "Asia/Karachi"),
+    ("Zhaoqing", 23.0489, 112.4609, "Asia/Shanghai"),
+    ("Onitsha", 6.1498, 6.7857, "Africa/Lagos"),
+    ("Mianyang", 31.4678, 104.6817, "Asia/Shanghai"),
+    ("Isfahan", 32.6525, 51.6746, "Asia/Tehran"),
+    ("Wanzhou", 30.7645, 108.3959, "Asia/Shanghai"),
+    ("Harare", -17.8277, 31.0534, "Africa/Harare"),
+    ("Monrovia", 6.3005, -10.7969, "Africa/Monrovia"),
+    ("Putian", 25.4394, 119.0103, "Asia/Shanghai"),
+    ("Kawasaki", 35.5206, 139.7172, "Asia/Tokyo"),
+    ("Shangqiu", 34.4500, 115.6500, "Asia/Shanghai"),
+    ("Goiânia", -16.6786, -49.2539, "America/Sao_Paulo"),
+    ("Auckland", -36.8485, 174.7635, "Pacific/Auckland"),
+    ("San Antonio", 29.4241, -98.4936, "America/Chicago"),
+    ("Kobe", 34.6913, 135.1830, "Asia/Tokyo"),
+    ("Stockholm", 59.3294, 18.0687, "Europe/Stockholm"),
+    ("Ciudad Juárez", 31.7202, -106.4608, "America/Ciudad_Juarez"),
+    ("Cần Thơ", 10.0371, 105.7883, "Asia/Ho_Chi_Minh"),
+    ("Khulna", 22.8098, 89.5644, "Asia/Dhaka"),
+    ("Belém", -1.4558, -48.5044, "America/Belem"),
+    ("Yekaterinburg", 56.8573, 60.6153, "Asia/Yekaterinburg"),
+    ("Porto Alegre", -30.0328, -51.2302, "America/Sao_Paulo"),
+    ("Yinchuan", 38.4681, 106.2731, "Asia/Shanghai"),
+    ("Manhattan", 40.7834, -73.9663, "America/New_York"),
+    ("Nashik", 19.9973, 73.7910, "Asia/Kolkata"),
+    ("Taizhou", 28.6627, 121.4331, "Asia/Shanghai"),
+    ("Asunción", -25.2865, -57.6470, "America/Asuncion"),
+    ("Yiwu", 29.3151, 120.0768, "Asia/Shanghai"),
+    ("Zapopan", 20.7211, -103.3874, "America/Mexico_City"),
+    ("Daejeon", 36.3491, 127.3849, "Asia/Seoul"),
+    ("Adelaide", -34.9287, 138.5986, "Australia/Adelaide"),
+    ("Quanzhou", 24.9139, 118.5858, "Asia/Shanghai"),
+    ("Madurai", 9.9190, 78.1195, "Asia/Kolkata"),
+    ("Jinhua", 29.1068, 119.6442, "Asia/Shanghai"),
+    ("Kyoto", 35.0211, 135.7538, "Asia/Tokyo"),
+    ("Cixi", 30.1764, 121.2457, "Asia/Shanghai"),
+    ("Changde", 29.0321, 111.6984, "Asia/Shanghai"),
+    ("Kuala Lumpur", 3.1412, 101.6865, "Asia/Kuala_Lumpur"),
+    ("Kaifeng", 34.7986, 114.3074, "Asia/Shanghai"),
+    ("Anshan", 41.1236, 122.9900, "Asia/Shanghai"),
+    ("Karaj", 35.8327, 50.9915, "Asia/Tehran"),
+    ("Kathmandu", 27.7017, 85.3206, "Asia/Kathmandu"),
+    ("Baoji", 34.3678, 107.2370, "Asia/Shanghai"),
+    ("Suqian", 33.9492, 118.2958, "Asia/Shanghai"),
+    ("Liuzhou", 24.3240, 109.4070, "Asia/Shanghai"),
+    ("Tirunelveli", 8.7274, 77.6838, "Asia/Kolkata"),
+    ("Kayseri", 38.7322, 35.4853, "Europe/Istanbul"),
+    ("Kharkiv", 49.9818, 36.2548, "Europe/Kyiv"),
+    ("Konya", 37.8713, 32.4846, "Europe/Istanbul"),
+    ("Zhangjiagang", 31.8650, 120.5389, "Asia/Shanghai"),
+    ("Agra", 27.1833, 78.0167, "Asia/Kolkata"),
+    ("Tabriz", 38.0800, 46.2919, "Asia/Tehran"),
+    ("Makassar", -5.1486, 119.4319, "Asia/Makassar"),
+    ("Jinjiang", 24.8198, 118.5742, "Asia/Shanghai"),
+    ("Faridabad", 28.4112, 77.3132, "Asia/Kolkata"),
+    ("Bozhou", 33.8772, 115.7703, "Asia/Shanghai"),
+    ("Qujing", 25.4833, 103.7833, "Asia/Shanghai"),
+    ("South Tangerang", -6.2886, 106.7179, "Asia/Jakarta"),
+    ("San Diego", 32.7157, -117.1647, "America/Los_Angeles"),
+    ("Zhanjiang", 21.2339, 110.3875, "Asia/Shanghai"),
+    ("Fushun", 41.8867, 123.9436, "Asia/Shanghai"),
+    ("Gwangju", 35.1547, 126.9156, "Asia/Seoul"),
+    ("Rājkot", 22.2916, 70.7932, "Asia/Kolkata"),
+    ("Luoyang", 34.6735, 112.4368, "Asia/Shanghai"),
+    ("Guadalajara", 20.6774, -103.3475, "America/Mexico_City"),
+    ("The Bronx", 40.8499, -73.8664, "America/New_York"),
+    ("Guankou", 28.1586, 113.6271, "Asia/Shanghai"),
+    ("Huế", 16.4619, 107.5955, "Asia/Ho_Chi_Minh"),
+    ("Milan", 45.4643, 9.1895, "Europe/Rome"),
+    ("Najafgarh", 28.6092, 76.9798, "Asia/Kolkata"),
+    ("N'Djamena", 12.1067, 15.0444, "Africa/Ndjamena"),
+    ("Handan", 36.6100, 114.4876, "Asia/Shanghai"),
+    ("Bannu", 32.9853, 70.6040, "Asia/Karachi"),
+    ("Yichang", 30.7144, 111.2847, "Asia/Shanghai"),
+    ("Antananarivo", -18.9137, 47.5361, "Indian/Antananarivo"),
+    ("Heze", 35.2393, 115.4736, "Asia/Shanghai"),
+    ("Antalya", 36.9081, 30.6956, "Europe/Istanbul"),
+    ("Abobo", 5.4161, -4.0159, "Africa/Abidjan"),
+    ("Jamshedpur", 22.8028, 86.1855, "Asia/Kolkata"),
+    ("Douala", 4.0483, 9.7043, "Africa/Douala"),
+    ("Basrah", 30.5085, 47.7804, "Asia/Baghdad"),
+    ("Dallas", 32.7831, -96.8067, "America/Chicago"),
+    ("Saitama", 35.9081, 139.6566, "Asia/Tokyo"),
+    ("Gorakhpur", 29.4477, 75.6721, "Asia/Kolkata"),
+    ("Niamey", 13.5137, 2.1098, "Africa/Niamey"),
+    ("Liupanshui", 26.5944, 104.8333, "Asia/Shanghai"),
+    ("Taguig", 14.5243, 121.0792, "Asia/Manila"),
+    ("Maoming", 21.6663, 110.9136, "Asia/Shanghai"),
+    ("Calgary", 51.0501, -114.0853, "America/Edmonton"),
+    ("Tripoli", 32.8874, 13.1873, "Africa/Tripoli"),
+    ("Callao", -12.0516, -77.1345, "America/Lima"),
+    ("Madinah", 24.4686, 39.6142, "Asia/Riyadh"),
+    ("Yaoundé", 3.8667, 11.5167, "Africa/Douala"),
+    ("Qinzhou", 21.9825, 108.6506, "Asia/Shanghai"),
+    ("Luohe", 33.5639, 114.0427, "Asia/Shanghai"),
+    ("Xiangyang", 32.0422, 112.1448, "Asia/Shanghai"),
+    ("Yangjiang", 21.8556, 111.9627, "Asia/Shanghai"),
+    ("Yixing", 31.3606, 119.8202, "Asia/Shanghai"),
+    ("Pimpri", 18.6229, 73.8070, "Asia/Kolkata"),
+    ("Da Nang", 16.0678, 108.2208, "Asia/Ho_Chi_Minh"),
+    ("Amman", 31.9552, 35.9450, "Asia/Amman"),
+    ("Budta", 7.2042, 124.4397, "Asia/Manila"),
+    ("Belgrade", 44.8040, 20.4651, "Europe/Belgrade"),
+    ("Biên Hòa", 10.9447, 106.8243, "Asia/Ho_Chi_Minh"),
+    ("Montevideo", -34.9033, -56.1882, "America/Montevideo"),
+    ("Xuchang", 34.0319, 113.8630, "Asia/Shanghai"),
+    ("Kalyān", 19.2437, 73.1355, "Asia/Kolkata"),
+    ("Zigong", 29.3416, 104.7769, "Asia/Shanghai"),
+    ("Munich", 48.1374, 11.5755, "Europe/Berlin"),
+    ("Nizhniy Novgorod", 56.3287, 44.0020, "Europe/Moscow"),
+    ("Jepara", -6.5924, 110.6710, "Asia/Jakarta"),
+    ("Maputo", -25.9655, 32.5832, "Africa/Maputo"),
+    ("Xuzhou", 34.2044, 117.2839, "Asia/Shanghai"),
+    ("Dammam", 26.4344, 50.1033, "Asia/Riyadh"),
+    ("Ra’s Bayrūt", 33.9000, 35.4833, "Asia/Beirut"),
+    ("Neijiang", 29.5835, 105.0622, "Asia/Shanghai"),
+    ("Shiraz", 29.6103, 52.5311, "Asia/Tehran"),
+    ("Heshan", 28.5694, 112.3473, "Asia/Shanghai"),
+    ("Dombivali", 19.2167, 73.0833, "Asia/Kolkata"),
+    ("Kananga", -5.8962, 22.4166, "Africa/Lubumbashi"),
+    ("Kazan", 55.7887, 49.1221, "Europe/Moscow"),
+    ("Jining", 35.4050, 116.5814, "Asia/Shanghai"),
+    ("Barquisimeto", 10.0647, -69.3570, "America/Caracas"),
+    ("Shubrā al Khaymah", 30.1251, 31.2505, "Africa/Cairo"),
+    ("Port-au-Prince", 18.5435, -72.3388, "America/Port-au-Prince"),
+    ("Xinyang", 32.1228, 114.0656, "Asia/Shanghai"),
+    ("Liaocheng", 36.4506, 116.0025, "Asia/Shanghai"),
+    ("Jinzhong", 37.6840, 112.7547, "Asia/Shanghai"),
+    ("Meerut", 28.9800, 77.7064, "Asia/Kolkata"),
+    ("Virār", 19.4559, 72.8114, "Asia/Kolkata"),
+    ("Nowrangapur", 19.2311, 82.5483, "Asia/Kolkata"),
+    ("Karbala", 32.6160, 44.0249, "Asia/Baghdad"),
+    ("Changzhi", 36.1839, 113.1053, "Asia/Shanghai"),
+    ("Tianshui", 34.5795, 105.7424, "Asia/Shanghai"),
+    ("Mombasa", -4.0547, 39.6636, "Africa/Nairobi"),
+    ("Mandalay", 21.9747, 96.0836, "Asia/Yangon"),
+    ("Srinagar", 34.0857, 74.8055, "Asia/Kolkata"),
+    ("Barranquilla", 10.9685, -74.7813, "America/Bogota"),
+    ("Chelyabinsk", 55.1611, 61.4288, "Asia/Yekaterinburg"),
+    ("Mérida", 20.9670, -89.6232, "America/Merida"),
+    ("Hiroshima", 34.4000, 132.4500, "Asia/Tokyo"),
+    ("Santiago de los Caballeros", 19.4504, -70.6908, "America/Santo_Domingo"),
+    ("Shymkent", 42.3099, 69.6004, "Asia/Almaty"),
+    ("Weinan", 34.5035, 109.5089, "Asia/Shanghai"),
+    ("Ghāziābād", 28.6654, 77.4391, "Asia/Kolkata"),
+    ("Matola", -25.9622, 32.4589, "Africa/Maputo"),
+    ("Dhanbad", 23.7976, 86.4299, "Asia/Kolkata"),
+    ("Hong Kong Island", 22.2630, 114.1842, "Asia/Hong_Kong"),
+    ("Fes", 34.0331, -5.0003, "Africa/Casablanca"),
+    ("Suwon", 37.2911, 127.0089, "Asia/Seoul"),
+    ("Gustavo Adolfo Madero", 19.4939, -99.1107, "America/Mexico_City"),
+    ("Nouakchott", 18.0858, -15.9785, "Africa/Nouakchott"),
+    ("Kisangani", 0.5153, 25.1910, "Africa/Lubumbashi"),
+    ("Jiaxing", 30.7522, 120.7500, "Asia/Shanghai"),
+    ("Aurangabad", 19.8776, 75.3423, "Asia/Kolkata"),
+    ("Omsk", 54.9924, 73.3686, "Asia/Omsk"),
+    ("Guarulhos", -23.4628, -46.5333, "America/Sao_Paulo"),
+    ("Bandar Lampung", -5.4292, 105.2611, "Asia/Jakarta"),
+    ("Prague", 50.0880, 14.4208, "Europe/Prague"),
+    ("Varanasi", 25.3167, 83.0104, "Asia/Kolkata"),
+    ("Batam", 1.1494, 104.0249, "Asia/Jakarta"),
+    ("Jiujiang", 29.7048, 116.0021, "Asia/Shanghai"),
+    ("Samara", 53.2077, 50.1355, "Europe/Samara"),
+    ("Aba", 5.1066, 7.3667, "Africa/Lagos"),
+    ("Amritsar", 31.6223, 74.8753, "Asia/Kolkata"),
+    ("Birmingham", 52.4814, -1.8998, "Europe/London"),
+    ("Copenhagen", 55.6759, 12.5655, "Europe/Copenhagen"),
+    ("Sofia", 42.6975, 23.3241, "Europe/Sofia"),
+    ("Anyang", 36.0960, 114.3828, "Asia/Shanghai"),
+    ("Yerevan", 40.1776, 44.5126, "Asia/Yerevan"),
+    ("Luohu District", 22.5472, 114.1315, "Asia/Shanghai"),
+    ("Vijayawada", 16.5074, 80.6466, "Asia/Kolkata"),
+    ("Bijie", 27.3019, 105.2863, "Asia/Shanghai"),
+    ("Monterrey", 25.6843, -100.3172, "America/Monterrey"),
+    ("Kigali", -1.9500, 30.0588, "Africa/Kigali"),
+    ("Rostov-na-Donu", 47.2200, 39.7077, "Europe/Moscow"),
+    ("Zhuzhou", 27.8333, 113.1500, "Asia/Shanghai"),
+    ("Bogor", -6.5944, 106.7892, "Asia/Jakarta"),
+    ("Malingao", 7.1608, 124.4750, "Asia/Manila"),
+    ("Touba", 14.8623, -15.8753, "Africa/Dakar"),
+    ("Ufa", 54.7431, 55.9678, "Asia/Yekaterinburg"),
+    ("Ranchi", 23.3432, 85.3094, "Asia/Kolkata"),
+    ("Baku", 40.3777, 49.8920, "Asia/Baku"),
+    ("Shangrao", 28.4518, 117.9429, "Asia/Shanghai"),
+    ("Lilongwe", -13.9669, 33.7873, "Africa/Blantyre"),
+    ("Huaibei", 33.9744, 116.7917, "Asia/Shanghai"),
+    ("Maiduguri", 11.8469, 13.1571, "Africa/Lagos"),
+    ("Meishan", 30.0439, 103.8370, "Asia/Shanghai"),
+    ("Mwanza", -2.5167, 32.9000, "Africa/Dar_es_Salaam"),
+    ("Sendai", 38.2667, 140.8667, "Asia/Tokyo"),
+    ("Ulsan", 35.5372, 129.3167, "Asia/Seoul"),
+    ("Krasnoyarsk", 56.0374, 92.9314, "Asia/Krasnoyarsk"),
+    ("Fuzhou", 27.9600, 116.3333, "Asia/Shanghai"),
+    ("Guigang", 23.1160, 109.5947, "Asia/Shanghai"),
+    ("Pekanbaru", 0.5167, 101.4417, "Asia/Jakarta"),
+    ("Oslo", 59.9127, 10.7461, "Europe/Oslo"),
+    ("Jabalpur", 23.1670, 79.9501, "Asia/Kolkata"),
+    ("Ilorin", 8.4966, 4.5421, "Africa/Lagos"),
+    ("Aden", 12.7796, 45.0385, "Asia/Aden"),
+    ("Ciudad Nezahualcoyotl", 19.4006, -99.0148, "America/Mexico_City"),
+    ("Hengyang", 26.8895, 112.6189, "Asia/Shanghai"),
+    ("Prayagraj", 25.4448, 81.8432, "Asia/Kolkata"),
+    ("Visakhapatnam", 17.6801, 83.2016, "Asia/Kolkata"),
+    ("Goyang-si", 37.6564, 126.8350, "Asia/Seoul"),
+    ("Yulin", 22.6305, 110.1469, "Asia/Shanghai"),
+    ("Jodhpur", 26.2684, 73.0059, "Asia/Kolkata"),
+    ("Gwalior", 26.2298, 78.1734, "Asia/Kolkata"),
+    ("Jingzhou", 30.3503, 112.1903, "Asia/Shanghai"),
+    ("Gqeberha", -33.9611, 25.6149, "Africa/Johannesburg"),
+    ("Tbilisi", 41.6914, 44.8341, "Asia/Tbilisi"),
+    ("Voronezh", 51.6683, 39.1920, "Europe/Moscow"),
+    ("Xinxiang", 35.1903, 113.8015, "Asia/Shanghai"),
+    ("Yichun", 27.8333, 114.4000, "Asia/Shanghai"),
+    ("Sokoto", 13.0627, 5.2432, "Africa/Lagos"),
+    ("Jos", 9.9285, 8.8921, "Africa/Lagos"),
+    ("Tangier", 35.7673, -5.7998, "Africa/Casablanca"),
+    ("Teni", 10.0112, 77.4777, "Asia/Kolkata"),
+    ("Xianyang", 34.3378, 108.7026, "Asia/Shanghai"),
+    ("Mexicali", 32.6278, -115.4545, "America/Tijuana"),
+    ("Pointe-Noire", -4.7761, 11.8635, "Africa/Brazzaville"),
+    ("Maceió", -9.6658, -35.7353, "America/Maceio"),
+    ("Campinas", -22.9056, -47.0608, "America/Sao_Paulo"),
+    ("Sanya", 18.2543, 109.5095, "Asia/Shanghai"),
+    ("Rangpur", 25.7466, 89.2517, "Asia/Dhaka"),
+    ("Kirkuk", 35.4681, 44.3922, "Asia/Baghdad"),
+    ("Comilla", 23.4619, 91.1850, "Asia/Dhaka"),
+    ("Shaoguan", 24.8000, 113.5833, "Asia/Shanghai"),
+    ("Howrah", 22.5769, 88.3186, "Asia/Kolkata"),
+    ("Raipur", 21.2333, 81.6333, "Asia/Kolkata"),
+    ("Changwon", 35.2281, 128.6811, "Asia/Seoul"),
+    ("Longyan", 25.0749, 117.0178, "Asia/Shanghai"),
+    ("Dublin", 53.3331, -6.2489, "Europe/Dublin"),
+    ("Tiruchirappalli", 10.8155, 78.6965, "Asia/Kolkata"),
+    ("Yongzhou", 26.4239, 111.6131, "Asia/Shanghai"),
+    ("Brussels", 50.8505, 4.3488, "Europe/Brussels"),
+    ("Zamboanga", 6.9103, 122.0739, "Asia/Manila"),
+    ("Ottawa", 45.4112, -75.6981, "America/Toronto"),
+    ("Huzhou", 30.8703, 120.0933, "Asia/Shanghai"),
+    ("Odesa", 46.4857, 30.7438, "Europe/Kyiv"),
+    ("Volgograd", 48.7138, 44.4976, "Europe/Volgograd"),
+    ("Khartoum North", 15.6493, 32.5346, "Africa/Khartoum"),
+    ("Edmonton", 53.5501, -113.4687, "America/Edmonton"),
+    ("Wuwei", 37.9267, 102.6320, "Asia/Shanghai"),
+    ("Jacksonville", 30.3322, -81.6556, "America/New_York"),
+    ("Arequipa", -16.3990, -71.5375, "America/Lima"),
+    ("Fort Worth", 32.7254, -97.3208, "America/Chicago"),
+    ("Hanzhong", 33.0751, 107.0221, "Asia/Shanghai"),
+    ("Hezhou", 24.4036, 111.5667, "Asia/Shanghai"),
+    ("Kota", 25.1825, 75.8391, "Asia/Kolkata"),
+    ("Zhu Cheng City", 35.9950, 119.4026, "Asia/Shanghai"),
+    ("Shivaji Nagar", 18.5302, 73.8526, "Asia/Kolkata"),
+    ("Dongying", 37.4627, 118.4917, "Asia/Shanghai"),
+    ("Luzhou", 28.8903, 105.4257, "Asia/Shanghai"),
+    ("San Jose", 37.3394, -121.8950, "America/Los_Angeles"),
+    ("Sholapur", 17.6715, 75.9104, "Asia/Kolkata"),
+    ("Marrakesh", 31.6342, -7.9999, "Africa/Casablanca"),
+    ("Guatemala City", 14.6407, -90.5133, "America/Guatemala"),
+    ("Meizhou", 24.2886, 116.1177, "Asia/Shanghai"),
+    ("Yueyang", 29.3745, 113.0948, "Asia/Shanghai"),
+    ("Laiwu", 36.1928, 117.6569, "Asia/Shanghai"),
+    ("Benxi", 41.2886, 123.7650, "Asia/Shanghai"),
+    ("Perm", 58.0105, 56.2502, "Asia/Yekaterinburg"),
+    ("Zaria", 11.1113, 7.7227, "Africa/Lagos"),
+    ("Chiba", 35.6000, 140.1167, "Asia/Tokyo"),
+    ("Pingdingshan", 33.7309, 113.3155, "Asia/Shanghai"),
+    ("Ciudad Guayana", 8.3512, -62.6410, "America/Caracas"),
+    ("Sargodha", 32.0859, 72.6742, "Asia/Karachi"),
+    ("Austin", 30.2672, -97.7431, "America/Chicago"),
+    ("Managua", 12.1328, -86.2504, "America/Managua"),
+    ("Bengbu", 32.9408, 117.3608, "Asia/Shanghai"),
+    ("Salé", 34.0531, -6.7985, "Africa/Casablanca"),
+    ("Jerusalem", 31.7690, 35.2163, "Asia/Jerusalem"),
+    ("Chandigarh", 30.7363, 76.7884, "Asia/Kolkata"),
+    ("Dnipro", 48.4666, 35.0407, "Europe/Kyiv"),
+    ("Cebu City", 10.3167, 123.8907, "Asia/Manila"),
+    ("Sanhe", 39.9805, 117.0689, "Asia/Shanghai"),
+    ("Köln", 50.9333, 6.9500, "Europe/Berlin"),
+    ("Tiruppur", 11.1154, 77.3546, "Asia/Kolkata"),
+    ("Guwahati", 26.1844, 91.7458, "Asia/Kolkata"),
+    ("Xiangtan", 27.8500, 112.9000, "Asia/Shanghai"),
+    ("Linfen", 36.0889, 111.5189, "Asia/Shanghai"),
+    ("Victoria", 22.2875, 114.1442, "Asia/Hong_Kong"),
+    ("Zhenjiang", 32.2109, 119.4551, "Asia/Shanghai"),
+    ("Enugu", 6.4413, 7.4988, "Africa/Lagos"),
+    ("Rosario", -32.9468, -60.6393, "America/Argentina/Cordoba"),
+    ("Sulţānah", 24.4926, 39.5857, "Asia/Riyadh"),
+    ("Huludao", 40.7524, 120.8355, "Asia/Shanghai"),
+    ("Hubballi", 15.3478, 75.1338, "Asia/Kolkata"),
+    ("Kitakyushu", 33.8518, 130.8503, "Asia/Tokyo"),
+    ("Taiz", 13.5795, 44.0209, "Asia/Aden"),
+    ("Setagaya", 35.6419, 139.6472, "Asia/Tokyo"),
+    ("Kingston", 17.9970, -76.7936, "America/Jamaica"),
+    ("Baoshan", 25.1163, 99.1637, "Asia/Shanghai"),
+    ("Rui’an", 27.7761, 120.6586, "Asia/Shanghai"),
+    ("Chihuahua", 28.6353, -106.0889, "America/Chihuahua"),
+    ("Nay Pyi Taw", 19.7450, 96.1297, "Asia/Yangon"),
+    ("Mysuru", 12.2979, 76.6393, "Asia/Kolkata"),
+    ("Trujillo", -8.1160, -79.0300, "America/Lima"),
+    ("Salem", 11.6538, 78.1554, "Asia/Kolkata"),
+    ("São Luís", -2.5297, -44.3028, "America/Fortaleza"),
+    ("Seongnam-si", 37.4386, 127.1378, "Asia/Seoul"),
+    ("Cartagena", 10.3982, -75.4933, "America/Bogota"),
+    ("Antipolo", 14.6258, 121.1225, "Asia/Manila"),
+    ("Columbus", 39.9612, -82.9988, "America/New_York"),
+    ("Sialkot", 32.4927, 74.5313, "Asia/Karachi"),
+    ("Charlotte", 35.2271, -80.8431, "America/New_York"),
+    ("Laibin", 23.7474, 109.2222, "Asia/Shanghai"),
+    ("Warri", 5.5174, 5.7501, "Africa/Lagos"),
+    ("Naples", 40.8522, 14.2681, "Europe/Rome"),
+    ("Padang", -0.9492, 100.3543, "Asia/Jakarta"),
+    ("Xiaogan", 30.9269, 113.9222, "Asia/Shanghai"),
+    ("Campo Grande", -20.4428, -54.6464, "America/Campo_Grande"),
+    ("Ziyang", 30.1211, 104.6481, "Asia/Shanghai"),
+    ("Bobo-Dioulasso", 11.1806, -4.2949, "Africa/Ouagadougou"),
+    ("Bahawalpur", 29.3978, 71.6752, "Asia/Karachi"),
+    ("Quzhou", 28.9594, 118.8686, "Asia/Shanghai"),
+    ("Blantyre", -15.7850, 35.0085, "Africa/Blantyre"),
+    ("Donetsk", 48.0230, 37.8022, "Europe/Kyiv"),
+    ("Abū Ghurayb", 33.3056, 44.1848, "Asia/Baghdad"),
+    ("Qom", 34.6401, 50.8764, "Asia/Tehran"),
+    ("Bishkek", 42.8700, 74.5900, "Asia/Bishkek"),
+    ("Zaozhuang", 34.8647, 117.5542, "Asia/Shanghai"),
+    ("Krasnodar", 45.0453, 38.9818, "Europe/Moscow"),
+    ("Natal", -5.7950, -35.2094, "America/Fortaleza"),
+    ("Pingxiang", 27.6167, 113.8535, "Asia/Shanghai"),
+    ("Cancún", 21.1743, -86.8466, "America/Cancun"),
+    ("Indianapolis", 39.7684, -86.1580, "America/Indiana/Indianapolis"),
+    ("Gurugram", 28.4601, 77.0263, "Asia/Kolkata"),
+    ("Bhubaneswar", 20.2724, 85.8338, "Asia/Kolkata"),
+    ("Zhoushan", 29.9887, 122.2049, "Asia/Shanghai"),
+    ("Qiqihar", 47.3392, 123.9615, "Asia/Shanghai"),
+    ("Sulaymaniyah", 35.5650, 45.4329, "Asia/Baghdad"),
+    ("Marseille", 43.2970, 5.3811, "Europe/Paris"),
+    ("Puning", 23.3107, 116.1687, "Asia/Shanghai"),
+    ("Pikine", 14.7646, -17.3907, "Africa/Dakar"),
+    ("Bhiwandi", 19.3002, 73.0588, "Asia/Kolkata"),
+    ("Soshanguve", -25.4729, 28.0992, "Africa/Johannesburg"),
+    ("Teresina", -5.0892, -42.8019, "America/Fortaleza"),
+    ("Ankang", 32.6800, 109.0172, "Asia/Shanghai"),
+    ("Jalandhar", 31.3256, 75.5792, "Asia/Kolkata"),
+    ("Rotterdam", 51.9225, 4.4792, "Europe/Amsterdam"),
+    ("Langfang", 39.5208, 116.7147, "Asia/Shanghai"),
+    ("Jiaozuo", 35.2392, 113.2391, "Asia/Shanghai"),
+    ("Rohini", 28.7432, 77.0678, "Asia/Kolkata"),
+    ("Wanxian", 30.8160, 108.3741, "Asia/Shanghai"),
+    ("Guang’an", 30.4741, 106.6370, "Asia/Shanghai"),
+    ("Johor Bahru", 1.4655, 103.7578, "Asia/Kuala_Lumpur"),
+    ("Cheongju-si", 36.6372, 127.4897, "Asia/Seoul"),
+    ("Pasig City", 14.5869, 121.0614, "Asia/Manila"),
+    ("Kanayannur", 9.9667, 76.2667, "Asia/Kolkata"),
+    ("Tegucigalpa", 14.0818, -87.2068, "America/Tegucigalpa"),
+    ("Bucheon-si", 37.4989, 126.7831, "Asia/Seoul"),
+    ("Thanh Hóa", 19.8000, 105.7667, "Asia/Ho_Chi_Minh"),
+    ("Turin", 45.0705, 7.6868, "Europe/Rome"),
+    ("Malang", -7.9797, 112.6304, "Asia/Jakarta"),
+    ("Al Ain City", 24.1917, 55.7606, "Asia/Dubai"),
+    ("Libreville", 0.3924, 9.4536, "Africa/Libreville"),
+    ("Saratov", 51.5405, 45.9901, "Europe/Saratov"),
+    ("Ulan Bator", 47.9077, 106.8832, "Asia/Ulaanbaatar"),
+    ("Weihai", 37.5091, 122.1136, "Asia/Shanghai"),
+    ("Takeo", 10.9908, 104.7850, "Asia/Phnom_Penh"),
+    ("Cochabamba", -17.3819, -66.1599, "America/La_Paz"),
+    ("Ahvaz", 31.3190, 48.6842, "Asia/Tehran"),
+    ("Zhabei", 31.2586, 121.4597, "Asia/Shanghai"),
+    ("Xinyu", 27.8043, 114.9334, "Asia/Shanghai"),
+    ("Pietermaritzburg", -29.6168, 30.3928, "Africa/Johannesburg"),
+    ("Yibin", 28.7593, 104.6399, "Asia/Shanghai"),
+    ("Naucalpan de Juárez", 19.4785, -99.2396, "America/Mexico_City"),
+    ("Kampung Baru Subang", 3.1500, 101.5333, "Asia/Kuala_Lumpur"),
+    ("Bouaké", 7.6939, -5.0303, "Africa/Abidjan"),
+    ("Samarinda", -0.4917, 117.1458, "Asia/Makassar"),
+    ("Taicang", 31.4478, 121.0939, "Asia/Shanghai"),
+    ("San Francisco", 37.7749, -122.4194, "America/Los_Angeles"),
+    ("Sakai", 34.5822, 135.4665, "Asia/Tokyo"),
+    ("Valencia", 39.4739, -0.3797, "Europe/Madrid"),
+    ("Nova Iguaçu", -22.7592, -43.4511, "America/Sao_Paulo"),
+    ("Chenzhou", 25.8000, 113.0333, "Asia/Shanghai"),
+    ("Duque de Caxias", -22.7856, -43.3117, "America/Sao_Paulo"),
+    ("João Pessoa", -7.1150, -34.8631, "America/Fortaleza"),
+    ("Bukavu", -2.4908, 28.8428, "Africa/Lubumbashi"),
+    ("Barcelona", 10.1384, -64.6877, "America/Caracas"),
+    ("Bangui", 4.3612, 18.5550, "Africa/Bangui"),
+    ("Hermosillo", 29.0887, -110.9668, "America/Hermosillo"),
+    ("Bhayandar", 19.3016, 72.8511, "Asia/Kolkata"),
+    ("Culiacán", 24.8021, -107.3942, "America/Mazatlan"),
+    ("Petaling Jaya", 3.1073, 101.6067, "Asia/Kuala_Lumpur"),
+    ("Malatya", 38.3502, 38.3167, "Europe/Istanbul"),
+    ("Anqing", 30.5136, 117.0472, "Asia/Shanghai"),
+    ("Kraków", 50.0614, 19.9366, "Europe/Warsaw"),
+    ("Oran", 35.6991, -0.6359, "Africa/Algiers"),
+    ("Freetown", 8.4871, -13.2356, "Africa/Freetown"),
+    ("San Pedro Sula", 15.5059, -88.0259, "America/Tegucigalpa"),
+    ("Narela", 28.8527, 77.0929, "Asia/Kolkata"),
+    ("Xingtai", 37.0622, 114.4927, "Asia/Shanghai"),
+    ("Niigata", 37.9226, 139.0412, "Asia/Tokyo"),
+    ("Muscat", 23.5841, 58.4078, "Asia/Muscat"),
+    ("Zarqa", 32.0727, 36.0880, "Asia/Amman"),
+    ("Çankaya", 39.9179, 32.8627, "Europe/Istanbul"),
+    ("Hamamatsu", 34.7000, 137.7333, "Asia/Tokyo"),
+    ("Kolwezi", -10.7148, 25.4667, "Africa/Lubumbashi"),
+    ("Vinh", 18.6734, 105.6923, "Asia/Ho_Chi_Minh"),
+    ("Eskişehir", 39.7767, 30.5206, "Europe/Istanbul"),
+    ("Thiruvananthapuram", 8.4855, 76.9492, "Asia/Kolkata"),
+    ("Zhaotong", 27.3167, 103.7167, "Asia/Shanghai"),
+    ("Panzhihua", 26.5851, 101.7128, "Asia/Shanghai"),
+    ("Chuzhou", 32.3219, 118.2978, "Asia/Shanghai"),
+    ("Seattle", 47.6062, -122.3321, "America/Los_Angeles"),
+    ("Port Said", 31.2653, 32.3019, "Africa/Cairo"),
+    ("Cúcuta", 7.9074, -72.5049, "America/Bogota"),
+    ("Homs", 34.7240, 36.7256, "Asia/Damascus"),
+    ("Xuancheng", 30.9525, 118.7553, "Asia/Shanghai"),
+    ("Ibb", 13.9667, 44.1833, "Asia/Aden"),
+    ("Nampula", -15.1165, 39.2666, "Africa/Maputo"),
+    ("Shangyu", 30.0156, 120.8711, "Asia/Shanghai"),
+    ("Bujumbura", -3.3819, 29.3614, "Africa/Bujumbura"),
+    ("Tyumen", 57.1522, 65.5272, "Asia/Yekaterinburg"),
+    ("Erzurum", 39.9086, 41.2769, "Europe/Istanbul"),
+    ("Anshun", 26.2500, 105.9333, "Asia/Shanghai"),
+    ("Dodoma", -6.1722, 35.7395, "Africa/Dar_es_Salaam"),
+    ("Rajshahi", 24.3740, 88.6011, "Asia/Dhaka"),
+    ("Dera Ismail Khan", 31.8313, 70.9017, "Asia/Karachi"),
+    ("Wuzhou", 23.4805, 111.2885, "Asia/Shanghai"),
+    ("Ipoh", 4.5841, 101.0829, "Asia/Kuala_Lumpur"),
+    ("Qinhuangdao", 39.9410, 119.5894, "Asia/Shanghai"),
+    ("Benghazi", 32.1149, 20.0686, "Africa/Tripoli"),
+    ("Alīgarh", 27.8815, 78.0746, "Asia/Kolkata"),
+    ("Shaoyang", 27.2382, 111.4621, "Asia/Shanghai"),
+    ("Winnipeg", 49.8844, -97.1470, "America/Winnipeg"),
+    ("Bağcılar", 41.0390, 28.8567, "Europe/Istanbul"),
+    ("Ōta", 35.5613, 139.7160, "Asia/Tokyo"),
+    ("Andijon", 40.7834, 72.3507, "Asia/Tashkent"),
+    ("Bareilly", 28.3668, 79.4317, "Asia/Kolkata"),
+    ("Buraydah", 26.3260, 43.9750, "Asia/Riyadh"),
+    ("São Bernardo do Campo", -23.6939, -46.5650, "America/Sao_Paulo"),
+    ("Hegang", 47.3473, 130.2903, "Asia/Shanghai"),
+    ("Morelia", 19.7008, -101.1844, "America/Mexico_City"),
+    ("Riga", 56.9460, 24.1059, "Europe/Riga"),
+    ("Tasikmalaya", -7.3274, 108.2207, "Asia/Jakarta"),
+    ("Amsterdam", 52.3740, 4.8897, "Europe/Amsterdam"),
+    ("Cagayan de Oro", 8.4822, 124.6472, "Asia/Manila"),
+    ("Ma’anshan", 31.6858, 118.5101, "Asia/Shanghai"),
+    ("Shah Alam", 3.0851, 101.5328, "Asia/Kuala_Lumpur"),
+    ("Shizuishan", 38.9808, 106.3892, "Asia/Shanghai"),
+    ("Kumamoto", 32.8059, 130.6918, "Asia/Tokyo"),
+    ("Oyo", 7.8537, 3.9324, "Africa/Lagos"),
+    ("Torreón", 25.5439, -103.4190, "America/Monterrey"),
+    ("Deyang", 31.1302, 104.3820, "Asia/Shanghai"),
+    ("Abeokuta", 7.1557, 3.3451, "Africa/Lagos"),
+    ("Al Ḩudaydah", 14.7978, 42.9545, "Asia/Aden"),
+    ("Yangquan", 37.8575, 113.5633, "Asia/Shanghai"),
+    ("Akure", 7.2526, 5.1931, "Africa/Lagos"),
+    ("São José dos Campos", -23.1794, -45.8869, "America/Sao_Paulo"),
+    ("Denver", 39.7392, -104.9847, "America/Denver"),
+    ("Osasco", -23.5325, -46.7917, "America/Sao_Paulo"),
+    ("Ashgabat", 37.9500, 58.3833, "Asia/Ashgabat"),
+    ("Álvaro Obregón", 19.3587, -99.2033, "America/Mexico_City"),
+    ("Aihara", 35.6000, 139.3167, "Asia/Tokyo"),
+    ("Evaton", -26.5333, 27.8500, "Africa/Johannesburg"),
+    ("Denpasar", -8.6500, 115.2167, "Asia/Makassar"),
+    ("Valenzuela", 14.7000, 120.9667, "Asia/Manila"),
+    ("Muzaffarābād", 34.3700, 73.4708, "Asia/Karachi"),
+    ("Okayama", 34.6500, 133.9333, "Asia/Tokyo"),
+    ("San Luis Potosí", 22.1523, -100.9714, "America/Mexico_City"),
+    ("Aguascalientes", 21.8826, -102.2843, "America/Mexico_City"),
+    ("General Santos", 6.1128, 125.1717, "Asia/Manila"),
+    ("Zhumadian", 32.9794, 114.0294, "Asia/Shanghai"),
+    ("Morādābād", 28.8389, 78.7768, "Asia/Kolkata"),
+    ("Sagamihara", 35.5671, 139.2417, "Asia/Tokyo"),
+    ("Mississauga", 43.5789, -79.6583, "America/Toronto"),
+    ("Lviv", 49.8383, 24.0232, "Europe/Kyiv"),
+    ("Namangan", 40.9983, 71.6726, "Asia/Tashkent"),
+    ("Ribeirão Preto", -21.1775, -47.8103, "America/Sao_Paulo"),
+    ("Zaporizhzhya", 47.8517, 35.1171, "Europe/Kyiv"),
+    ("Zanzibar", -6.1639, 39.1979, "Africa/Dar_es_Salaam"),
+    ("Saltillo", 25.4260, -100.9796, "America/Monterrey"),
+    ("Latakia", 35.5312, 35.7909, "Asia/Damascus"),
+    ("Subang Jaya", 3.0438, 101.5806, "Asia/Kuala_Lumpur"),
+    ("Warangal", 18.0000, 79.5833, "Asia/Kolkata"),
+    ("Paranaque City", 14.4816, 121.0175, "Asia/Manila"),
+    ("Tolyatti", 53.5303, 49.3461, "Europe/Samara"),
+    ("Jaboatão", -8.1803, -35.0014, "America/Recife"),
+    ("Santo Domingo Oeste", 18.5000, -70.0000, "America/Santo_Domingo"),
+    ("Santo Domingo Este", 18.4851, -69.8476, "America/Santo_Domingo"),
+    ("Battagram", 34.6772, 73.0233, "Asia/Karachi"),
+    ("Suez", 29.9737, 32.5263, "Africa/Cairo"),
+    ("Changzhi", 35.2089, 111.7386, "Asia/Shanghai"),
+    ("Agadir", 30.4202, -9.5982, "Africa/Casablanca"),
+    ("Edogawe", 35.6923, 139.8731, "Asia/Tokyo"),
+    ("Sarajevo", 43.8486, 18.3564, "Europe/Sarajevo"),
+    ("Balikpapan", -1.2675, 116.8289, "Asia/Makassar"),
+    ("Adachi", 35.7632, 139.8076, "Asia/Tokyo"),
+    ("Bauchi", 10.3103, 9.8439, "Africa/Lagos"),
+    ("Shizuoka", 34.9833, 138.3833, "Asia/Tokyo"),
+    ("Tunis", 36.8190, 10.1658, "Africa/Tunis"),
+    ("Zhangjiakou", 40.7834, 114.8714, "Asia/Shanghai"),
+    ("Serang", -6.1153, 106.1542, "Asia/Jakarta"),
+    ("Washington", 38.8951, -77.0364, "America/New_York"),
+    ("Nashville", 36.1659, -86.7844, "America/Chicago"),
+    ("Fuxin", 42.0156, 121.6589, "Asia/Shanghai"),
+    ("Ta’if", 21.2703, 40.4158, "Asia/Riyadh"),
+    ("Changsha", 22.3812, 112.6849, "Asia/Shanghai"),
+    ("Huangshi", 30.2471, 115.0481, "Asia/Shanghai"),
+    ("Liaoyang", 41.2719, 123.1731, "Asia/Shanghai"),
+    ("Hlaingthaya", 16.8500, 96.0667, "Asia/Yangon"),
+    ("Beira", -19.8436, 34.8389, "Africa/Maputo"),
+    ("Sorocaba", -23.5017, -47.4581, "America/Sao_Paulo"),
+    ("Zaragoza", 41.6561, -0.8773, "Europe/Madrid"),
+    ("Sevilla", 37.3828, -5.9732, "Europe/Madrid"),
+    ("Baise", 23.8901, 106.6268, "Asia/Shanghai"),
+    ("Situbondo", -7.7062, 114.0098, "Asia/Jakarta"),
+    ("Binzhou", 37.3667, 118.0167, "Asia/Shanghai"),
+    ("Oklahoma City", 35.4676, -97.5164, "America/Chicago"),
+    ("Yuncheng", 35.0231, 110.9928, "Asia/Shanghai"),
+    ("Dezhou", 37.4466, 116.3671, "Asia/Shanghai"),
+    ("Dushanbe", 38.5358, 68.7790, "Asia/Dushanbe"),
+    ("Cotonou", 6.3654, 2.4183, "Africa/Porto-Novo"),
+    ("El Paso", 31.7587, -106.4869, "America/Denver"),
+    ("Gorakhpur", 26.7663, 83.3689, "Asia/Kolkata"),
+    ("Guadalupe", 25.6768, -100.2565, "America/Monterrey"),
+    ("Wrocław", 51.1029, 17.0301, "Europe/Warsaw"),
+    ("Guntur", 16.2997, 80.4573, "Asia/Kolkata"),
+    ("Katsina", 12.9908, 7.6018, "Africa/Lagos"),
+    ("Sanmenxia", 34.7808, 111.1929, "Asia/Shanghai"),
+    ("E’zhou", 30.3961, 114.8865, "Asia/Shanghai"),
+    ("Madīnat an Naşr", 30.0667, 31.3000, "Africa/Cairo"),
+    ("Tabuk", 28.3998, 36.5715, "Asia/Riyadh"),
+    ("Kitwe", -12.8024, 28.2132, "Africa/Lusaka"),
+    ("Bulawayo", -20.1500, 28.5833, "Africa/Harare"),
+    ("Mudanjiang", 44.5480, 129.6259, "Asia/Shanghai"),
+    ("Aracaju", -10.9111, -37.0717, "America/Maceio"),
+    ("Athens", 37.9838, 23.7278, "Europe/Athens"),
+    ("Zagreb", 45.8144, 15.9780, "Europe/Zagreb"),
+    ("Leshan", 29.5623, 103.7639, "Asia/Shanghai"),
+    ("Santo André", -23.6639, -46.5383, "America/Sao_Paulo"),
+    ("Vancouver", 49.2497, -123.1193, "America/Vancouver"),
+    ("Rizhao", 35.4141, 119.5291, "Asia/Shanghai"),
+    ("Helsinki", 60.1695, 24.9354, "Europe/Helsinki"),
+    ("Cheonan", 36.8065, 127.1522, "Asia/Seoul"),
+    ("Pontianak", -0.0319, 109.3250, "Asia/Pontianak"),
+    ("Acapulco de Juárez", 16.8494, -99.9089, "America/Mexico_City"),
+    ("Banjarmasin", -3.3199, 114.5907, "Asia/Makassar"),
+    ("Puducherry", 11.9338, 79.8298, "Asia/Kolkata"),
+    ("Suining", 30.5080, 105.5733, "Asia/Shanghai"),
+    ("Brampton", 43.6834, -79.7663, "America/Toronto"),
+    ("Puyang", 35.7564, 115.0436, "Asia/Shanghai"),
+    ("Soacha", 4.5794, -74.2168, "America/Bogota"),
+    ("Boston", 42.3584, -71.0598, "America/New_York"),
+    ("Tlalnepantla", 19.5401, -99.1954, "America/Mexico_City"),
+    ("Portland", 45.5234, -122.6762, "America/Los_Angeles"),
+    ("Tlaquepaque", 20.6412, -103.2934, "America/Mexico_City"),
+    ("Frankfurt am Main", 50.1155, 8.6842, "Europe/Berlin"),
+    ("Macau", 22.2006, 113.5461, "Asia/Macau"),
+    ("Palermo", 38.1166, 13.3636, "Europe/Rome"),
+    ("Izhevsk", 56.8522, 53.1986, "Europe/Samara"),
+    ("Colombo", 6.9355, 79.8487, "Asia/Colombo"),
+    ("Maturín", 9.7457, -63.1832, "America/Caracas"),
+    ("Amravati", 20.9333, 77.7500, "Asia/Kolkata"),
+    ("Detroit", 42.3314, -83.0457, "America/Detroit"),
+    ("Łódź", 51.7706, 19.4739, "Europe/Warsaw"),
+    ("Osogbo", 7.7710, 4.5570, "Africa/Lagos"),
+    ("Honchō", 35.7013, 139.9865, "Asia/Tokyo"),
+    ("Bikaner", 28.0176, 73.3149, "Asia/Kolkata"),
+    ("Jaboatão dos Guararapes", -8.1128, -35.0147, "America/Recife"),
+    ("Las Vegas", 36.1750, -115.1372, "America/Los_Angeles"),
+    ("New South Memphis", 35.0868, -90.0568, "America/Chicago"),
+    ("Hwaseong-si", 37.2068, 126.8169, "Asia/Seoul"),
+    ("Gold Coast", -28.0003, 153.4309, "Australia/Brisbane"),
+    ("Al Aḩmadī", 29.0769, 48.0839, "Asia/Kuwait"),
+    ("Cuenca", -2.8953, -78.9963, "America/Guayaquil"),
+    ("Chisinau", 47.0090, 28.8594, "Europe/Chisinau"),
+    ("Likasi", -10.9830, 26.7384, "Africa/Lubumbashi"),
+    ("Hebi", 35.7323, 114.2862, "Asia/Shanghai"),
+    ("Tshikapa", -6.4162, 20.7999, "Africa/Lubumbashi"),
+    ("Kochi", 9.9399, 76.2602, "Asia/Kolkata"),
+    ("Memphis", 35.1495, -90.0490, "America/Chicago"),
+    ("Jingmen", 31.0336, 112.2047, "Asia/Shanghai"),
+    ("Barnaul", 53.3620, 83.7279, "Asia/Barnaul"),
+    ("Dandong", 40.1292, 124.3947, "Asia/Shanghai"),
+    ("Stuttgart", 48.7823, 9.1770, "Europe/Berlin"),
+    ("Jeonju", 35.8219, 127.1489, "Asia/Seoul"),
+    ("Bhilai", 21.2092, 81.4285, "Asia/Kolkata"),
+    ("Ndola", -12.9587, 28.6366, "Africa/Lusaka"),
+    ("Contagem", -19.9317, -44.0536, "America/Sao_Paulo"),
+    ("Ulyanovsk", 54.3282, 48.3866, "Europe/Ulyanovsk"),
+    ("Djibouti", 11.5890, 43.1450, "Africa/Djibouti"),
+    ("Glasgow", 55.8651, -4.2576, "Europe/London"),
+    ("Panshan", 41.1881, 122.0494, "Asia/Shanghai"),
+    ("Louisville", 38.2542, -85.7594, "America/Kentucky/Louisville"),
+    ("Irkutsk", 52.2957, 104.2908, "Asia/Irkutsk"),
+    ("Ansan-si", 37.3236, 126.8219, "Asia/Seoul"),
+    ("Al Mansurah", 31.0364, 31.3807, "Africa/Cairo"),
+    ("Kermanshah", 34.3142, 47.0650, "Asia/Tehran"),
+    ("Düsseldorf", 51.2217, 6.7762, "Europe/Berlin"),
+    ("Feira de Santana", -12.2667, -38.9667, "America/Bahia"),
+    ("Jiaozhou", 36.2839, 120.0033, "Asia/Shanghai"),
+    ("Suizhou", 31.7111, 113.3631, "Asia/Shanghai"),
+    ("Villa Nueva", 14.5251, -90.5854, "America/Guatemala"),
+    ("Khabarovsk", 48.4620, 135.0971, "Asia/Vladivostok"),
+    ("Cuiabá", -15.5961, -56.0967, "America/Cuiaba"),
+    ("Arusha", -3.3667, 36.6833, "Africa/Dar_es_Salaam"),
+    ("Las Piñas", 14.4506, 120.9828, "Asia/Manila"),
+    ("Chizhou", 30.6613, 117.4778, "Asia/Shanghai"),
+    ("Coyoacán", 19.3467, -99.1617, "America/Mexico_City"),
+    ("Ya'an", 29.9852, 102.9990, "Asia/Shanghai"),
+    ("Cuttack", 20.4650, 85.8793, "Asia/Kolkata"),
+    ("Borivli", 19.2350, 72.8598, "Asia/Kolkata"),
+    ("Yaroslavl", 57.6299, 39.8737, "Europe/Moscow"),
+    ("Göteborg", 57.7072, 11.9668, "Europe/Stockholm"),
+    ("Kawaguchi", 35.8052, 139.7107, "Asia/Tokyo"),
+    ("Bukit Rahman Putra", 3.2173, 101.5608, "Asia/Kuala_Lumpur"),
+    ("Jambi City", -1.6000, 103.6167, "Asia/Jakarta"),
+    ("Ha'il", 27.5219, 41.6907, "Asia/Riyadh"),
+    ("Bhavnagar", 21.7629, 72.1533, "Asia/Kolkata"),
+    ("Benoni", -26.1885, 28.3208, "Africa/Johannesburg"),
+    ("Vladivostok", 43.1056, 131.8735, "Asia/Vladivostok"),
+    ("Jinzhou", 41.1078, 121.1417, "Asia/Shanghai"),
+    ("Tuxtla", 16.7536, -93.1158, "America/Mexico_City"),
+    ("Kryvyy Rih", 47.9057, 33.3940, "Europe/Kyiv"),
+    ("Sanming", 26.2486, 117.6186, "Asia/Shanghai"),
+    ("Islamabad", 33.7215, 73.0433, "Asia/Karachi"),
+    ("Sāngli", 16.8544, 74.5642, "Asia/Kolkata"),
+    ("Jamnagar", 22.4729, 70.0667, "Asia/Kolkata"),
+    ("Lubango", -14.9172, 13.4925, "Africa/Luanda"),
+    ("Pokhara", 28.2669, 83.9685, "Asia/Kathmandu"),
+    ("Shuangyashan", 46.6769, 131.1327, "Asia/Shanghai"),
+    ("Borama", 9.9361, 43.1828, "Africa/Mogadishu"),
+    ("Pallabi", 23.8250, 90.3700, "Asia/Dhaka"),
+    ("Luancheng", 37.8845, 114.6463, "Asia/Shanghai"),
+    ("Makhachkala", 42.9778, 47.5003, "Europe/Moscow"),
+    ("Anyang-si", 37.3925, 126.9269, "Asia/Seoul"),
+    ("Huambo", -12.7761, 15.7392, "Africa/Luanda"),
+    ("Samarkand", 39.6546, 66.9644, "Asia/Samarkand"),
+    ("Mengzi", 23.3678, 103.3821, "Asia/Shanghai"),
+    ("Kagoshima", 31.5667, 130.5500, "Asia/Tokyo"),
+    ("Mukalla", 14.5425, 49.1242, "Asia/Aden"),
+    ("Rasht", 37.2761, 49.5886, "Asia/Tehran"),
+    ("Mar del Plata", -38.0004, -57.5562, "America/Argentina/Buenos_Aires"),
+    ("Essen", 51.4566, 7.0123, "Europe/Berlin"),
+    ("Al Maḩallah al Kubrá", 30.9706, 31.1669, "Africa/Cairo"),
+    ("Málaga", 36.7202, -4.4203, "Europe/Madrid"),
+    ("Shekhupura", 31.7129, 73.9856, "Asia/Karachi"),
+    ("Yingkou", 40.6647, 122.2318, "Asia/Shanghai"),
+    ("Cimahi", -6.8722, 107.5425, "Asia/Jakarta"),
+    ("Zhangzhou", 24.5133, 117.6556, "Asia/Shanghai"),
+    ("Reynosa", 26.0800, -98.2846, "America/Matamoros"),
+    ("Thuận An", 10.9239, 106.7143, "Asia/Ho_Chi_Minh"),
+    ("Dortmund", 51.5149, 7.4660, "Europe/Berlin"),
+    ("Suginami", 36.2013, 140.2841, "Asia/Tokyo"),
+    ("Londrina", -23.3103, -51.1628, "America/Sao_Paulo"),
+    ("Baltimore", 39.2904, -76.6122, "America/New_York"),
+    ("Itabashi", 35.7489, 139.7150, "Asia/Tokyo"),
+    ("New Kingston", 18.0075, -76.7832, "America/Jamaica"),
+    ("Pelentong", 1.5243, 103.8240, "Asia/Kuala_Lumpur"),
+    ("Bucaramanga", 7.1250, -73.1189, "America/Bogota"),
+    ("Genoa", 44.4048, 8.9444, "Europe/Rome"),
+    ("Hachiōji", 35.6558, 139.3239, "Asia/Tokyo"),
+    ("Malacca", 2.1960, 102.2405, "Asia/Kuala_Lumpur"),
+    ("Nha Trang", 12.2451, 109.1943, "Asia/Ho_Chi_Minh"),
+    ("Khabarovsk Vtoroy", 48.4379, 135.1299, "Asia/Vladivostok"),
+    ("Kerman", 30.2832, 57.0788, "Asia/Tehran"),
+    ("Orūmīyeh", 37.5527, 45.0761, "Asia/Tehran"),
+    ("Bahçelievler", 41.0023, 28.8598, "Europe/Istanbul"),
+    ("Tanta", 30.7885, 31.0019, "Africa/Cairo"),
+    ("Jammu", 32.7353, 74.8617, "Asia/Kolkata"),
+    ("Iskandar Puteri", 1.3932, 103.6232, "Asia/Kuala_Lumpur"),
+    ("Calamba", 14.2117, 121.1653, "Asia/Manila"),
+    ("Tlalpan", 19.2951, -99.1621, "America/Mexico_City"),
+    ("Herāt", 34.3482, 62.1997, "Asia/Kabul"),
+    ("Gujrat", 32.5742, 74.0754, "Asia/Karachi"),
+    ("Tomsk", 56.5005, 84.9822, "Asia/Tomsk"),
+    ("Juiz de Fora", -21.7642, -43.3503, "America/Sao_Paulo"),
+    ("Umraniye", 41.0164, 29.1248, "Europe/Istanbul"),
+    ("Shihezi", 44.3023, 86.0369, "Asia/Urumqi"),
+    ("South Boston", 42.3334, -71.0495, "America/New_York"),
+    ("Nakuru", -0.3072, 36.0722, "Africa/Nairobi"),
+    ("Hamilton", 43.2501, -79.8496, "America/Toronto"),
+    ("Irbid", 32.5556, 35.8500, "Asia/Amman"),
+    ("Manchester", 53.4809, -2.2374, "Europe/London"),
+    ("Kota Bharu", 6.1236, 102.2433, "Asia/Kuala_Lumpur"),
+    ("Surrey", 49.1063, -122.8251, "America/Vancouver"),
+    ("Meknes", 33.8935, -5.5473, "Africa/Casablanca"),
+    ("Puente Alto", -33.6117, -70.5758, "America/Santiago"),
+    ("Nyala", 12.0489, 24.8807, "Africa/Khartoum"),
+    ("Orenburg", 51.7671, 55.0988, "Asia/Yekaterinburg"),
+    ("Albuquerque", 35.0845, -106.6511, "America/Denver"),
+    ("Bokāro", 23.6693, 86.1516, "Asia/Kolkata"),
+    ("Asmara", 15.3381, 38.9318, "Africa/Asmara"),
+    ("Sukkur", 27.7032, 68.8589, "Asia/Karachi"),
+    ("Uberlândia", -18.9186, -48.2772, "America/Sao_Paulo"),
+    ("Milwaukee", 43.0389, -87.9065, "America/Chicago"),
+    ("Chợ Lớn", 10.7500, 106.6500, "Asia/Ho_Chi_Minh"),
+    ("Wenchang", 19.5516, 110.8028, "Asia/Shanghai"),
+    ("Ile-Ife", 7.4824, 4.5603, "Africa/Lagos"),
+    ("Gombe", 10.2897, 11.1673, "Africa/Lagos"),
+    ("Hamhŭng", 39.9183, 127.5364, "Asia/Pyongyang"),
+    ("Kemerovo", 55.3542, 86.1043, "Asia/Novokuznetsk"),
+    ("Nasiriyah", 31.0580, 46.2573, "Asia/Baghdad"),
+    ("Bloemfontein", -29.1211, 26.2140, "Africa/Johannesburg"),
+    ("Sheffield", 53.3830, -1.4659, "Europe/London"),
+    ("Dresden", 51.0509, 13.7383, "Europe/Berlin"),
+    ("Santiago de Cuba", 20.0229, -75.8217, "America/Havana"),
+    ("Siping", 43.1614, 124.3778, "Asia/Shanghai"),
+    ("Cuautitlán Izcalli", 19.6439, -99.2160, "America/Mexico_City"),
+    ("Benguela", -12.5767, 13.4027, "Africa/Luanda"),
+    ("Chuxiong", 25.0364, 101.5456, "Asia/Shanghai"),
+    ("Huaihua", 27.5634, 110.0040, "Asia/Shanghai"),
+    ("Chiclayo", -6.7701, -79.8550, "America/Lima"),
+    ("Bình Thạnh", 10.8110, 106.7060, "Asia/Ho_Chi_Minh"),
+    ("Zahedan", 29.4963, 60.8629, "Asia/Tehran"),
+    ("Banqiao", 25.0143, 121.4672, "Asia/Taipei"),
+    ("Nanded", 19.1602, 77.3150, "Asia/Kolkata"),
+    ("Kozhikode", 11.2480, 75.7804, "Asia/Kolkata"),
+    ("Ulanqab", 40.9930, 113.1330, "Asia/Shanghai"),
+    ("Cabinda", -5.5620, 12.1948, "Africa/Luanda"),
+    ("Ajegunle", 6.4520, 3.3312, "Africa/Lagos"),
+    ("Pristina", 42.6727, 21.1669, "Europe/Belgrade"),
+    ("Jiamusi", 46.7971, 130.3112, "Asia/Shanghai"),
+    ("Korla", 41.7606, 86.1523, "Asia/Urumqi"),
+    ("Kolhāpur", 16.6956, 74.2317, "Asia/Kolkata"),
+    ("Porto Velho", -8.7619, -63.9039, "America/Porto_Velho"),
+    ("San Miguel de Tucumán", -26.8160, -65.2105, "America/Argentina/Tucuman"),
+    ("Kuantan", 3.8077, 103.3260, "Asia/Kuala_Lumpur"),
+    ("Sevastopol", 44.6080, 33.5213, "Europe/Simferopol"),
+    ("Nellore", 14.4499, 79.9870, "Asia/Kolkata"),
+    ("Mirpur Model Thana", 23.8093, 90.3609, "Asia/Dhaka"),
+    ("Bremen", 53.0758, 8.8072, "Europe/Berlin"),
+    ("Wanning", 18.7993, 110.3841, "Asia/Shanghai"),
+    ("Owerri", 5.4836, 7.0332, "Africa/Lagos"),
+    ("Kota Kuala Muda", 5.5882, 100.3709, "Asia/Kuala_Lumpur"),
+    ("Sungai Petani", 5.6470, 100.4877, "Asia/Kuala_Lumpur"),
+    ("Xinzhou", 38.4092, 112.7333, "Asia/Shanghai"),
+    ("Kotō", 32.7786, 130.7454, "Asia/Tokyo"),
+    ("Kalaburagi", 17.3358, 76.8376, "Asia/Kolkata"),
+    ("Tucson", 32.2217, -110.9265, "America/Phoenix"),
+    ("Selayang Baru Utara", 3.2549, 101.6668, "Asia/Kuala_Lumpur"),
+    ("Vilnius", 54.6892, 25.2798, "Europe/Vilnius"),
+    ("Ajmer", 26.4521, 74.6387, "Asia/Kolkata"),
+    ("Pingdu", 36.7844, 119.9464, "Asia/Shanghai"),
+    ("Fresno", 36.7477, -119.7724, "America/Los_Angeles"),
+    ("Mbeya", -8.9000, 33.4500, "Africa/Dar_es_Salaam"),
+    ("Calabar", 4.9589, 8.3270, "Africa/Lagos"),
+    ("Oujda", 34.6814, -1.9086, "Africa/Casablanca"),
+    ("Novokuznetsk", 53.7575, 87.1360, "Asia/Novokuznetsk"),
+    ("Ryazan’", 54.6270, 39.7041, "Europe/Moscow"),
+    ("Ji’an", 27.1172, 114.9793, "Asia/Shanghai"),
+    ("Sahiwal", 30.6659, 73.1019, "Asia/Karachi"),
+    ("Mersin", 36.8120, 34.6389, "Europe/Istanbul"),
+    ("Leeds", 53.7965, -1.5478, "Europe/London"),
+    ("Poznań", 52.4069, 16.9299, "Europe/Warsaw"),
+    ("Guli", 28.8816, 120.0331, "Asia/Shanghai"),
+    ("Aqsu", 41.1842, 80.2792, "Asia/Urumqi"),
+    ("Ebute Ikorodu", 6.6009, 3.4882, "Africa/Lagos"),
+    ("Tanggu", 39.0211, 117.6469, "Asia/Shanghai"),
+    ("Pasir Gudang", 1.4620, 103.9053, "Asia/Kuala_Lumpur"),
+    ("Astrakhan", 46.3497, 48.0408, "Europe/Astrakhan"),
+    ("Okara", 30.8103, 73.4515, "Asia/Karachi"),
+    ("Nansana", 0.3639, 32.5286, "Africa/Kampala"),
+    ("Kimhae", 35.2342, 128.8811, "Asia/Seoul"),
+    ("Ar Raqqah", 35.9528, 39.0079, "Asia/Damascus"),
+    ("Québec", 46.8123, -71.2145, "America/Toronto"),
+    ("Cuauhtémoc", 19.4451, -99.1461, "America/Mexico_City"),
+    ("Shangluo", 33.8667, 109.9306, "Asia/Shanghai"),
+    ("Himeji", 34.8167, 134.7000, "Asia/Tokyo"),
+    ("Ibagué", 4.4357, -75.2029, "America/Bogota"),
+    ("Antwerpen", 51.2205, 4.4003, "Europe/Brussels"),
+    ("Assiut", 27.1810, 31.1837, "Africa/Cairo"),
+    ("Hamadān", 34.7992, 48.5146, "Asia/Tehran"),
+    ("Qionghai", 19.2425, 110.4642, "Asia/Shanghai"),
+    ("Cangzhou", 38.3112, 116.8533, "Asia/Shanghai"),
+    ("Mohāmmadpur", 24.8984, 88.5284, "Asia/Dhaka"),
+    ("Surakarta", -7.5561, 110.8317, "Asia/Jakarta"),
+    ("San Salvador", 13.6893, -89.1872, "America/El_Salvador"),
+    ("Beihai", 21.4835, 109.1155, "Asia/Shanghai"),
+    ("Van", 38.4946, 43.3832, "Europe/Istanbul"),
+    ("Sacramento", 38.5816, -121.4944, "America/Los_Angeles"),
+    ("Thủ Đức", 10.8486, 106.7721, "Asia/Ho_Chi_Minh"),
+    ("Üsküdar", 41.0227, 29.0137, "Europe/Istanbul"),
+    ("Penza", 53.1957, 45.0108, "Europe/Moscow"),
+    ("Mazār-e Sharīf", 36.7090, 67.1109, "Asia/Kabul"),
+    ("Kandahār", 31.6133, 65.7101, "Asia/Kabul"),
+    ("Hengshui", 37.7391, 115.6835, "Asia/Shanghai"),
+    ("Dehradun", 30.3244, 78.0339, "Asia/Kolkata"),
+    ("Erode", 11.3428, 77.7274, "Asia/Kolkata"),
+    ("Lyon", 45.7491, 4.8479, "Europe/Paris"),
+    ("Salta", -24.8065, -65.4200, "America/Argentina/Salta"),
+    ("Serra", -20.1286, -40.3078, "America/Sao_Paulo"),
+    ("Esenler", 41.0435, 28.8762, "Europe/Istanbul"),
+    ("Qui Nhon", 13.7765, 109.2237, "Asia/Ho_Chi_Minh"),
+    ("Al Fayyum", 29.3100, 30.8418, "Africa/Cairo"),
+    ("Durgapur", 23.5158, 87.3080, "Asia/Kolkata"),
+    ("Utsunomiya", 36.5667, 139.8833, "Asia/Tokyo"),
+    ("Victoria de Durango", 24.0203, -104.6576, "America/Monterrey"),
+    ("Lisbon", 38.7251, -9.1498, "Europe/Lisbon"),
+    ("Rahim Yar Khan", 28.4199, 70.3034, "Asia/Karachi"),
+    ("Ulhasnagar", 19.2167, 73.1500, "Asia/Kolkata"),
+    ("Guangyuan", 32.4420, 105.8230, "Asia/Shanghai"),
+    ("Loni", 28.7514, 77.2902, "Asia/Kolkata"),
+    ("Siliguri", 26.7100, 88.4285, "Asia/Kolkata"),
+    ("Nürnberg", 49.4542, 11.0775, "Europe/Berlin"),
+    ("Ujjain", 23.1824, 75.7764, "Asia/Kolkata"),
+    ("Hannover", 52.3705, 9.7332, "Europe/Berlin"),
+    ("Edinburgh", 55.9521, -3.1965, "Europe/London"),
+    ("Āzādshahr", 34.7905, 48.5701, "Asia/Tehran"),
+    ("Macapá", 0.0389, -51.0664, "America/Belem"),
+    ("Xianning", 29.8435, 114.3220, "Asia/Shanghai"),
+    ("Toulouse", 43.6043, 1.4437, "Europe/Paris"),
+    ("Thembisa", -25.9964, 28.2268, "Africa/Johannesburg"),
+    ("Matsuyama", 33.8392, 132.7657, "Asia/Tokyo"),
+    ("Campos dos Goytacazes", -21.7523, -41.3304, "America/Sao_Paulo"),
+    ("Bilimora", 20.7696, 72.9613, "Asia/Kolkata"),
+    ("Kasur", 31.1187, 74.4502, "Asia/Karachi"),
+    ("Atlanta", 33.7490, -84.3880, "America/New_York"),
+    ("Aparecida de Goiânia", -16.8233, -49.2439, "America/Sao_Paulo"),
+    ("Heroica Matamoros", 25.8797, -97.5042, "America/Matamoros"),
+    ("Makati City", 14.5503, 121.0327, "Asia/Manila"),
+    ("Tonghua", 41.7197, 125.9264, "Asia/Shanghai"),
+    ("Mianzhu, Deyang, Sichuan", 31.3379, 104.2206, "Asia/Shanghai"),
+    ("Naberezhnyye Chelny", 55.7372, 52.4196, "Europe/Moscow"),
+    ("Lipetsk", 52.5876, 39.5515, "Europe/Moscow"),
+    ("Kikwit", -5.0410, 18.8162, "Africa/Kinshasa"),
+    ("Florianópolis", -27.5967, -48.5492, "America/Sao_Paulo"),
+    ("Banan", 29.3786, 106.5400, "Asia/Shanghai"),
+    ("Newcastle", -32.9295, 151.7801, "Australia/Sydney"),
+    ("Tuen Mun", 22.3917, 113.9716, "Asia/Hong_Kong"),
+    ("Zhangye", 38.9342, 100.4517, "Asia/Shanghai"),
+    ("Kirov", 58.5981, 49.6578, "Europe/Kirov"),
+    ("Kashgar", 39.4672, 75.9868, "Asia/Urumqi"),
+    ("Mukim Pulai", 1.5333, 103.6667, "Asia/Kuala_Lumpur"),
+    ("Najrān", 17.4933, 44.1277, "Asia/Riyadh"),
+    ("Karol Bāgh", 28.6514, 77.1907, "Asia/Kolkata"),
+    ("Zhoukou", 33.6333, 114.6333, "Asia/Shanghai"),
+    ("Leipzig", 51.3396, 12.3713, "Europe/Berlin"),
+    ("Pingliang", 35.5392, 106.6861, "Asia/Shanghai"),
+    ("Kalininskiy", 59.9967, 30.3899, "Europe/Moscow"),
+    ("Duisburg", 51.4325, 6.7652, "Europe/Berlin"),
+    ("Āsansol", 23.6833, 86.9833, "Asia/Kolkata"),
+    ("Arāk", 34.0949, 49.6981, "Asia/Tehran"),
+    ("Maipú", -33.5142, -70.7651, "America/Santiago"),
+    ("Homyel'", 52.4345, 30.9754, "Europe/Minsk"),
+    ("Aktobe", 50.2797, 57.2072, "Asia/Aqtobe"),
+    ("Kota Kinabalu", 5.9749, 116.0724, "Asia/Kuching"),
+    ("Talatona", -8.9178, 13.1856, "Africa/Luanda"),
+    ("Kampung Larkin Lama", 1.5024, 103.7440, "Asia/Kuala_Lumpur"),
+    ("Kota Damansara", 3.1545, 101.5802, "Asia/Kuala_Lumpur"),
+    ("Mangaluru", 12.9172, 74.8560, "Asia/Kolkata"),
+    ("Zhucheng", 35.9947, 119.3975, "Asia/Shanghai"),
+    ("Santa Marta", 11.2386, -74.1943, "America/Bogota"),
+    ("Matsudo", 35.7799, 139.9014, "Asia/Tokyo"),
+    ("Hāthazāri", 22.5052, 91.8134, "Asia/Dhaka"),
+    ("Lapu-Lapu City", 10.3103, 123.9494, "Asia/Manila"),
+    ("Karagandy", 49.8019, 73.1021, "Asia/Almaty"),
+    ("Loudi", 27.7344, 111.9944, "Asia/Shanghai"),
+    ("Liverpool", 53.4106, -2.9779, "Europe/London"),
+    ("Ichikawa", 35.7341, 139.9065, "Asia/Tokyo"),
+    ("Bāndarban", 22.1953, 92.2195, "Asia/Dhaka"),
+    ("Sha Tin", 22.3833, 114.1833, "Asia/Hong_Kong"),
+    ("Dera Ghazi Khan", 30.0459, 70.6403, "Asia/Karachi"),
+    ("Higashiosaka", 34.6667, 135.5833, "Asia/Tokyo"),
+    ("Cheboksary", 56.1322, 47.2460, "Europe/Moscow"),
+    ("Pohang", 36.0292, 129.3648, "Asia/Seoul"),
+    ("Shanwei", 22.7820, 115.3475, "Asia/Shanghai"),
+    ("Montería", 8.7508, -75.8782, "America/Bogota"),
+    ("Ruiru", -1.1466, 36.9609, "Africa/Nairobi"),
+    ("Valledupar", 10.4654, -73.2531, "America/Bogota"),
+    ("Belagavi", 15.8521, 74.5045, "Asia/Kolkata"),
+    ("Ajman", 25.4018, 55.4788, "Asia/Dubai"),
+    ("Jianshui", 24.2774, 101.2240, "Asia/Shanghai"),
+    ("Port Sudan", 19.6175, 37.2164, "Africa/Khartoum"),
+    ("Toluca", 19.2879, -99.6532, "America/Mexico_City"),
+    ("Ciudad López Mateos", 19.5579, -99.2567, "America/Mexico_City"),
+    ("Al Khuşūş", 30.1529, 31.3150, "Africa/Cairo"),
+    ("Jeju City", 33.5097, 126.5219, "Asia/Seoul"),
+    ("Gdańsk", 54.3523, 18.6491, "Europe/Warsaw"),
+    ("Miami", 25.7743, -80.1937, "America/New_York"),
+    ("Omaha", 41.2563, -95.9404, "America/Chicago"),
+    ("Nishinomiya", 34.7156, 135.3320, "Asia/Tokyo"),
+    ("Masina", -4.3836, 15.3914, "Africa/Kinshasa"),
+    ("Sahāranpur", 29.9679, 77.5452, "Asia/Kolkata"),
+    ("Vellore", 12.9184, 79.1325, "Asia/Kolkata"),
+    ("Piura", -5.1819, -80.6572, "America/Lima"),
+    ("Kurashiki", 34.5833, 133.7667, "Asia/Tokyo"),
+    ("Angeles City", 15.1500, 120.5833, "Asia/Manila"),
+    ("Bhātpāra", 22.8664, 88.4011, "Asia/Kolkata"),
+    ("Jijiga", 9.3500, 42.8000, "Africa/Addis_Ababa"),
+    ("Tula", 54.1961, 37.6182, "Europe/Moscow"),
+    ("Najaf", 32.0259, 44.3462, "Asia/Baghdad"),
+    ("Raleigh", 35.7721, -78.6386, "America/New_York"),
+    ("Imus", 14.4297, 120.9367, "Asia/Manila"),
+    ("Xichang", 27.8964, 102.2634, "Asia/Shanghai"),
+    ("Malegaon", 20.5497, 74.5346, "Asia/Kolkata"),
+    ("São José do Rio Preto", -20.8197, -49.3794, "America/Sao_Paulo"),
+    ("Okene", 7.5512, 6.2359, "Africa/Lagos"),
+    ("Uijeongbu-si", 37.7415, 127.0474, "Asia/Seoul"),
+    ("Bristol", 51.4552, -2.5966, "Europe/London"),
+    ("East London", -33.0153, 27.9116, "Africa/Johannesburg"),
+    ("Chéngguān Qū", 29.6384, 91.0444, "Asia/Shanghai"),
+    ("Yazd", 31.8972, 54.3675, "Asia/Tehran"),
+    ("Hargeysa", 9.5600, 44.0650, "Africa/Mogadishu"),
+    ("Ōita", 33.2333, 131.6000, "Asia/Tokyo"),
+    ("Mauá", -23.6678, -46.4614, "America/Sao_Paulo"),
+    ("Jincheng", 35.5022, 112.8328, "Asia/Shanghai"),
+    ("Taoyuan", 24.9896, 121.3187, "Asia/Taipei"),
+    ("Eldoret", 0.5204, 35.2699, "Africa/Nairobi"),
+    ("Kansas City", 39.0997, -94.5786, "America/Chicago"),
+    ("Yan’an", 36.5989, 109.4917, "Asia/Shanghai"),
+    ("Kaliningrad", 54.7064, 20.5110, "Europe/Kaliningrad"),
+    ("Skopje", 41.9965, 21.4314, "Europe/Skopje"),
+    ("Vereeniging", -26.6731, 27.9261, "Africa/Johannesburg"),
+    ("The Hague", 52.0767, 4.2986, "Europe/Amsterdam"),
+    ("Long Beach", 33.7670, -118.1892, "America/Los_Angeles"),
+    ("Gaya", 24.7969, 85.0038, "Asia/Kolkata"),
+    ("Iloilo", 10.6969, 122.5644, "Asia/Manila"),
+    ("Shouguang", 36.8800, 118.7375, "Asia/Shanghai"),
+    ("Jingdezhen", 29.2947, 117.2079, "Asia/Shanghai"),
+    ("Murcia", 37.9870, -1.1300, "Europe/Madrid"),
+    ("Mesa", 33.4223, -111.8226, "America/Phoenix"),
+    ("Halifax", 44.6427, -63.5769, "America/Halifax"),
+    ("Morogoro", -6.8210, 37.6612, "Africa/Dar_es_Salaam"),
+    ("Marikina City", 14.6481, 121.1133, "Asia/Manila"),
+    ("Kenitra", 34.2610, -6.5802, "Africa/Casablanca"),
+    ("Seeb", 23.6703, 58.1891, "Asia/Muscat"),
+    ("Jiaojiang", 28.6984, 121.4733, "Asia/Shanghai"),
+    ("Mykolayiv", 46.9763, 31.9930, "Europe/Kyiv"),
+    ("Fukuyama", 34.4833, 133.3667, "Asia/Tokyo"),
+    ("Staten Island", 40.5623, -74.1399, "America/New_York"),
+    ("Nanping", 26.6450, 118.1736, "Asia/Shanghai"),
+    ("Pereira", 4.8143, -75.6949, "America/Bogota"),
+    ("Ciudad Apodaca", 25.7819, -100.1884, "America/Monterrey"),
+    ("São João de Meriti", -22.8039, -43.3722, "America/Sao_Paulo"),
+    ("Ambattur", 13.0982, 80.1615, "Asia/Kolkata"),
+    ("Belford Roxo", -22.7642, -43.3994, "America/Sao_Paulo"),
+    ("Kanazawa", 36.6000, 136.6167, "Asia/Tokyo"),
+    ("Gonder", 12.6000, 37.4667, "Africa/Addis_Ababa"),
+    ("Mandaluyong City", 14.5832, 121.0409, "Asia/Manila"),
+    ("Mixco", 14.6308, -90.6071, "America/Guatemala"),
+    ("Longshan", 42.8854, 125.1367, "Asia/Shanghai"),
+    ("Ikare", 7.5259, 5.7534, "Africa/Lagos"),
+    ("Vũng Tàu", 10.3460, 107.0843, "Asia/Ho_Chi_Minh"),
+    ("Maracay", 10.2497, -67.5948, "America/Caracas"),
+    ("Tamale", 9.4008, -0.8393, "Africa/Accra"),
+    ("Heyuan", 23.7333, 114.6833, "Asia/Shanghai"),
+    ("Dĩ An", 10.9068, 106.7694, "Asia/Ho_Chi_Minh"),
+    ("Kira", 0.4000, 32.6333, "Africa/Kampala"),
+    ("Esna", 25.2934, 32.5540, "Africa/Cairo"),
+    ("Joinville", -26.3044, -48.8456, "America/Sao_Paulo"),
+    ("Huangshan", 29.7114, 118.3125, "Asia/Shanghai"),
+    ("Ḩamāh", 35.1318, 36.7578, "Asia/Damascus"),
+    ("Jalgaon", 21.0029, 75.5660, "Asia/Kolkata"),
+    ("Kurnool", 15.8289, 78.0360, "Asia/Kolkata"),
+    ("Yola", 9.2084, 12.4815, "Africa/Lagos"),
+    ("Rạch Giá", 10.0124, 105.0809, "Asia/Ho_Chi_Minh"),
+    ("Amagasaki", 34.7167, 135.4167, "Asia/Tokyo"),
+    ("Santo Domingo de los Colorados", -0.2537, -79.1763, "America/Guayaquil"),
+    ("Ţarţūs", 34.8890, 35.8866, "Asia/Damascus"),
+    ("Karabağlar", 38.3820, 27.1320, "Europe/Istanbul"),
+    ("Mek'ele", 13.4967, 39.4753, "Africa/Addis_Ababa"),
+    ("Nazrēt", 8.5500, 39.2667, "Africa/Addis_Ababa"),
+    ("Colorado Springs", 38.8339, -104.8214, "America/Denver"),
+    ("Niterói", -22.8833, -43.1036, "America/Sao_Paulo"),
+    ("Huancayo", -12.0687, -75.2103, "America/Lima"),
+    ("Al Hillah", 32.4637, 44.4196, "Asia/Baghdad"),
+    ("Mbandaka", 0.0486, 18.2603, "Africa/Kinshasa"),
+    ("Malanje", -9.5402, 16.3410, "Africa/Luanda"),
+    ("Namp’o", 38.7375, 125.4078, "Asia/Pyongyang"),
+    ("Ciudad General Escobedo", 25.7970, -100.3179, "America/Monterrey"),
+    ("Bacolod City", 10.6667, 122.9500, "Asia/Manila"),
+    ("Virginia Beach", 36.8529, -75.9780, "America/New_York"),
+    ("Wafangdian", 39.6183, 122.0081, "Asia/Shanghai"),
+    ("Mansilingan", 10.6311, 122.9789, "Asia/Manila"),
+    ("Kahama", -3.8333, 32.6000, "Africa/Dar_es_Salaam"),
+    ("Hsinchu", 24.8036, 120.9686, "Asia/Taipei"),
+    ("Katsushika", 35.7333, 139.8500, "Asia/Tokyo"),
+    ("Rāmgundam", 18.8008, 79.4521, "Asia/Kolkata"),
+    ("Batman", 37.8874, 41.1322, "Europe/Istanbul"),
+    ("Manado", 1.4822, 124.8489, "Asia/Makassar"),
+    ("Lishui", 28.4604, 119.9103, "Asia/Shanghai"),
+    ("Udaipur", 24.5858, 73.7135, "Asia/Kolkata"),
+    ("Warder", 6.9744, 45.3408, "Africa/Addis_Ababa"),
+    ("Cilegon", -6.0144, 106.0542, "Asia/Jakarta"),
+    ("Wenshan City", 23.3631, 104.2505, "Asia/Shanghai"),
+    ("Eslamshahr", 35.5522, 51.2350, "Asia/Tehran"),
+    ("Juba", 4.8517, 31.5825, "Africa/Juba"),
+    ("Muratpaşa", 36.8916, 30.7650, "Europe/Istanbul"),
+    ("Bắc Giang", 21.2731, 106.1946, "Asia/Ho_Chi_Minh"),
+    ("Şanlıurfa", 37.1671, 38.7939, "Europe/Istanbul"),
+    ("Chengde", 40.9519, 117.9588, "Asia/Shanghai"),
+    ("Kursk", 51.7269, 36.1846, "Europe/Moscow"),
+    ("Maheshtala", 22.5086, 88.2532, "Asia/Kolkata"),
+    ("Nam Định", 20.4339, 106.1773, "Asia/Ho_Chi_Minh"),
+    ("Constantine", 36.3650, 6.6147, "Africa/Algiers"),
+    ("Patiāla", 30.3362, 76.3922, "Asia/Kolkata"),
+    ("Boksburg", -26.2120, 28.2596, "Africa/Johannesburg"),
+    ("Basuo", 19.1027, 108.6656, "Asia/Shanghai"),
+    ("Ensenada", 31.8715, -116.6007, "America/Tijuana"),
+    ("Elazığ", 38.6743, 39.2232, "Europe/Istanbul"),
+    ("Jundiaí", -23.1864, -46.8842, "America/Sao_Paulo"),
+    ("Kupang", -10.1708, 123.6069, "Asia/Makassar"),
+    ("Xochimilco", 19.2547, -99.1036, "America/Mexico_City"),
+    ("Carrefour", 18.5411, -72.3992, "America/Port-au-Prince"),
+    ("Shyamnagar", 22.8333, 88.3667, "Asia/Kolkata"),
+    ("Dasmariñas", 14.3294, 120.9367, "Asia/Manila"),
+    ("Zhangjiajie", 29.1294, 110.4783, "Asia/Shanghai"),
+    ("Korhogo", 9.4580, -5.6296, "Africa/Abidjan"),
+    ("Fujisawa", 35.3493, 139.4767, "Asia/Tokyo"),
+    ("Bissau", 11.8636, -15.5977, "Africa/Bissau"),
+    ("Sandakan", 5.8402, 118.1179, "Asia/Kuching"),
+    ("Mawlamyine", 16.4905, 97.6282, "Asia/Yangon"),
+    ("Laval", 45.5699, -73.6920, "America/Toronto"),
+    ("Palma", 39.5694, 2.6502, "Europe/Madrid"),
+    ("Sunch’ŏn", 39.4317, 125.9328, "Asia/Pyongyang"),
+    ("Sultangazi", 41.1065, 28.8685, "Europe/Istanbul"),
+    ("Uyo", 5.0513, 7.9335, "Africa/Lagos"),
+    ("Bei’an", 48.2667, 126.6000, "Asia/Shanghai"),
+    ("Davangere", 14.4669, 75.9269, "Asia/Kolkata"),
+    ("Ado-Ekiti", 7.6233, 5.2209, "Africa/Lagos"),
+    ("Manizales", 5.0668, -75.5068, "America/Bogota"),
+    ("Masan", 35.1272, 126.8315, "Asia/Seoul"),
+    ("Buôn Ma Thuột", 12.6675, 108.0378, "Asia/Ho_Chi_Minh"),
+    ("Ananindeua", -1.3656, -48.3722, "America/Belem"),
+    ("Stavropol’", 45.0344, 41.9642, "Europe/Moscow"),
+    ("Santos", -23.9608, -46.3336, "America/Sao_Paulo"),
+    ("Kashiwa", 35.8622, 139.9773, "Asia/Tokyo"),
+    ("Ogbomoso", 8.1337, 4.2401, "Africa/Lagos"),
+    ("Tel Aviv", 32.0809, 34.7806, "Asia/Jerusalem"),
+    ("Goma", -1.6741, 29.2284, "Africa/Lubumbashi"),
+    ("Buenaventura", 3.5833, -77.0000, "America/Bogota"),
+    ("Welkom", -27.9774, 26.7351, "Africa/Johannesburg"),
+    ("Sham Shui Po", 22.3302, 114.1595, "Asia/Hong_Kong"),
+    ("Machida", 35.5403, 139.4508, "Asia/Tokyo"),
+    ("Venustiano Carranza", 19.4436, -99.1050, "America/Mexico_City"),
+    ("Zagazig", 30.5877, 31.5020, "Africa/Cairo"),
+    ("Mataram", -8.5833, 116.1167, "Asia/Makassar"),
+    ("Ismailia", 30.6043, 32.2722, "Africa/Cairo"),
+    ("Ningde", 26.6617, 119.5228, "Asia/Shanghai"),
+    ("Akola", 20.7096, 76.9981, "Asia/Kolkata"),
+    ("Cusco", -13.5319, -71.9670, "America/Lima"),
+    ("Jiuquan", 39.7432, 98.5174, "Asia/Shanghai"),
+    ("Veracruz", 19.1809, -96.1429, "America/Mexico_City"),
+    ("East Jerusalem", 31.7834, 35.2339, "Asia/Hebron"),
+    ("Bryansk", 53.2710, 34.3214, "Europe/Moscow"),
+    ("Maltepe", 40.9357, 29.1551, "Europe/Istanbul"),
+    ("Kuala Terengganu", 5.3302, 103.1408, "Asia/Kuala_Lumpur"),
+    ("Toyota", 35.0833, 137.1500, "Asia/Tokyo"),
+    ("Matadi", -5.8386, 13.4631, "Africa/Kinshasa"),
+    ("Al Kharj", 24.1554, 47.3346, "Asia/Riyadh"),
+    ("Wong Tai Sin", 22.3500, 114.1833, "Asia/Hong_Kong"),
+    ("Minna", 9.6152, 6.5478, "Africa/Lagos"),
+    ("Mandaluyong", 14.5784, 121.0352, "Asia/Manila"),
+    ("Xalapa de Enríquez", 19.5312, -96.9159, "America/Mexico_City"),
+    ("Cazenga", -8.8249, 13.3132, "Africa/Luanda"),
+    ("Rajpur Sonarpur", 22.4382, 88.4321, "Asia/Kolkata"),
+    ("Bratislava", 48.1482, 17.1067, "Europe/Bratislava"),
+    ("Taman Petaling", 3.1992, 101.6498, "Asia/Kuala_Lumpur"),
+    ("Shinagawa", 33.6363, 133.0057, "Asia/Tokyo"),
+    ("Al Ḩasakah", 36.5024, 40.7477, "Asia/Damascus"),
+    ("Luxor", 25.6989, 32.6421, "Africa/Cairo"),
+    ("London", 42.9834, -81.2330, "America/Toronto"),
+    ("Awasa", 7.0621, 38.4763, "Africa/Addis_Ababa"),
+    ("Chimoio", -19.1164, 33.4833, "Africa/Maputo"),
+    ("Tando Allahyar", 25.4605, 68.7174, "Asia/Karachi"),
+    ("Daloa", 6.8773, -6.4502, "Africa/Abidjan"),
+    ("Dingxi", 35.5709, 104.6230, "Asia/Shanghai"),
+    ("Bamenda", 5.9597, 10.1460, "Africa/Douala"),
+    ("Tver", 56.8584, 35.9006, "Europe/Moscow"),
+    ("Thái Nguyên", 21.5942, 105.8482, "Asia/Ho_Chi_Minh"),
+    ("Boa Vista", 2.8197, -60.6733, "America/Boa_Vista"),
+    ("Rio Branco", -9.9747, -67.8100, "America/Rio_Branco"),
+    ("Oakland", 37.8044, -122.2708, "America/Los_Angeles"),
+    ("Korba", 22.3458, 82.6963, "Asia/Kolkata"),
+    ("Takamatsu", 34.3333, 134.0500, "Asia/Tokyo"),
+    ("Kowloon City", 22.3305, 114.1922, "Asia/Hong_Kong"),
+    ("Tirana", 41.3274, 19.8187, "Europe/Tirane"),
+    ("San Juan", 18.4663, -66.1057, "America/Puerto_Rico"),
+    ("Alor Setar", 6.1210, 100.3601, "Asia/Kuala_Lumpur"),
+    ("Tongchuan", 34.8988, 108.9506, "Asia/Shanghai"),
+    ("Pasay", 14.5375, 121.0014, "Asia/Manila"),
+    ("Nuevo Laredo", 27.4763, -99.5164, "America/Matamoros"),
+    ("Toyama", 36.7000, 137.2167, "Asia/Tokyo"),
+    ("Tétouan", 35.5784, -5.3684, "Africa/Casablanca"),
+    ("Zürich", 47.3667, 8.5500, "Europe/Zurich"),
+    ("Việt Trì", 21.3227, 105.4020, "Asia/Ho_Chi_Minh"),
+    ("Azcapotzalco", 19.4870, -99.1859, "America/Mexico_City"),
+    ("Tampa", 27.9475, -82.4584, "America/New_York"),
+    ("Montes Claros", -16.7350, -43.8617, "America/Sao_Paulo"),
+    ("Bunamwaya", 0.2534, 32.5572, "Africa/Kampala"),
+    ("Magnitogorsk", 53.3981, 59.0066, "Asia/Yekaterinburg"),
+    ("Tulsa", 36.1540, -95.9928, "America/Chicago"),
+    ("Jhānsi", 25.4589, 78.5799, "Asia/Kolkata"),
+    ("Tseung Kwan O", 22.3279, 114.2499, "Asia/Hong_Kong"),
+    ("Ciudad Bolívar", 8.1237, -63.5469, "America/Caracas"),
+    ("Kampung Kangkar Teberau", 1.5320, 103.7549, "Asia/Kuala_Lumpur"),
+    ("Koumassi", 5.2972, -3.9675, "Africa/Abidjan"),
+    ("San Nicolás de los Garza", 25.7417, -100.3022, "America/Monterrey"),
+    ("Christchurch", -43.5333, 172.6333, "Pacific/Auckland"),
+    ("Guyuan", 36.0067, 106.2808, "Asia/Shanghai"),
+    ("Wandsbek", 53.5833, 10.0831, "Europe/Berlin"),
+    ("Minneapolis", 44.9800, -93.2638, "America/Chicago"),
+    ("Thoothukudi", 8.7674, 78.1342, "Asia/Kolkata"),
+    ("Ardabīl", 38.2498, 48.2933, "Asia/Tehran"),
+    ("Ballari", 15.1420, 76.9240, "Asia/Kolkata"),
+    ("Chaoyang", 41.5703, 120.4586, "Asia/Shanghai"),
+    ("Gaza", 31.5016, 34.4667, "Asia/Gaza"),
+    ("Yokosuka", 35.2836, 139.6672, "Asia/Tokyo"),
+    ("Kom Ombo", 24.4767, 32.9463, "Africa/Cairo"),
+    ("Nagasaki", 32.7500, 129.8833, "Asia/Tokyo"),
+    ("Gujangbagh", 37.1093, 79.9343, "Asia/Urumqi"),
+    ("Tonalá", 20.6226, -103.2417, "America/Mexico_City"),
+    ("Panamá", 8.9936, -79.5197, "America/Panama"),
+    ("Piracicaba", -22.7253, -47.6492, "America/Sao_Paulo"),
+    ("Uvira", -3.3953, 29.1378, "Africa/Lubumbashi"),
+    ("Hirakata", 34.8135, 135.6491, "Asia/Tokyo"),
+    ("Ivanovo", 56.9999, 40.9726, "Europe/Moscow"),
+    ("Cumaná", 10.4639, -64.1786, "America/Caracas"),
+    ("Newcastle", -27.7580, 29.9318, "Africa/Johannesburg"),
+    ("Gumi", 36.1136, 128.3360, "Asia/Seoul"),
+    ("Jayapura", -2.5337, 140.7181, "Asia/Jayapura"),
+    ("Jixi", 45.2932, 130.9622, "Asia/Shanghai"),
+    ("Kuching", 1.5500, 110.3333, "Asia/Kuching"),
+    ("Gifu", 35.4229, 136.7604, "Asia/Tokyo"),
+    ("Caruaru", -8.2833, -35.9761, "America/Recife"),
+    ("Tongling", 30.9500, 117.7833, "Asia/Shanghai"),
+    ("Taoyuan City", 24.9937, 121.2970, "Asia/Taipei"),
+    ("Tarlac City", 15.4802, 120.5979, "Asia/Manila"),
+    ("Toyonaka", 34.7824, 135.4693, "Asia/Tokyo"),
+    ("Kassala", 15.4510, 36.4000, "Africa/Khartoum"),
+    ("Miyazaki", 31.9167, 131.4167, "Asia/Tokyo"),
+    ("Lekki", 6.4122, 4.0947, "Africa/Lagos"),
+    ("Antofagasta", -23.6509, -70.3975, "America/Santiago"),
+    ("Wah Cantt", 33.7709, 72.7512, "Asia/Karachi"),
+    ("Bhāgalpur", 25.2445, 86.9718, "Asia/Kolkata"),
+    ("Agartala", 23.8361, 91.2794, "Asia/Kolkata"),
+    ("Dayrah", 25.2714, 55.3021, "Asia/Dubai"),
+    ("West Jerusalem", 31.7820, 35.2196, "Asia/Jerusalem"),
+    ("Bida", 9.0804, 6.0099, "Africa/Lagos"),
+    ("Saltivka", 50.0221, 36.3490, "Europe/Kyiv"),
+    ("Bunia", 1.5594, 30.2522, "Africa/Lubumbashi"),
+    ("Quận Mười", 10.7682, 106.6663, "Asia/Ho_Chi_Minh"),
+    ("Kisumu", -0.1022, 34.7617, "Africa/Nairobi"),
+    ("Luhansk", 48.5681, 39.3055, "Europe/Kyiv"),
+    ("Barinas", 8.6206, -70.2310, "America/Caracas"),
+    ("Wichita", 37.6922, -97.3375, "America/Chicago"),
+    ("Al Hoceïma", 35.2516, -3.9372, "Africa/Casablanca"),
+    ("Szczecin", 53.4289, 14.5530, "Europe/Warsaw"),
+    ("Vila Velha", -20.3297, -40.2925, "America/Sao_Paulo"),
+    ("Bologna", 44.4938, 11.3387, "Europe/Rome"),
+    ("Sejong", 36.5924, 127.2922, "Asia/Seoul"),
+    ("Samsun", 41.2798, 36.3361, "Europe/Istanbul"),
+    ("Tallinn", 59.4370, 24.7535, "Europe/Tallinn"),
+    ("Tanga", -5.0689, 39.0988, "Africa/Dar_es_Salaam"),
+    ("El Obeid", 13.1842, 30.2167, "Africa/Khartoum"),
+    ("Diadema", -23.6861, -46.6228, "America/Sao_Paulo"),
+    ("Saurimo", -9.6608, 20.3915, "Africa/Luanda"),
+    ("Bello", 6.3373, -75.5580, "America/Bogota"),
+    ("Pasto", 1.2146, -77.2785, "America/Bogota"),
+    ("Gaomi", 36.3833, 119.7528, "Asia/Shanghai"),
+    ("Santa Fe", -31.6488, -60.7087, "America/Argentina/Cordoba"),
+    ("San-Pédro", 4.7485, -6.6363, "Africa/Abidjan"),
+    ("Makurdi", 7.7337, 8.5214, "Africa/Lagos"),
+    ("Takoradi", 4.8982, -1.7603, "Africa/Accra"),
+    ("Samut Prakan", 13.5976, 100.5972, "Asia/Bangkok"),
+    ("Arlington", 32.7357, -97.1081, "America/Chicago"),
+    ("Khamis Mushait", 18.3000, 42.7333, "Asia/Riyadh"),
+    ("Ambato", -1.2491, -78.6167, "America/Guayaquil"),
+    ("Carapicuíba", -23.5227, -46.8350, "America/Sao_Paulo"),
+    ("Petrolina", -9.3986, -40.5008, "America/Recife"),
+    ("Ojo de Agua", 19.6803, -99.0100, "America/Mexico_City"),
+    ("Windhoek", -22.5594, 17.0832, "Africa/Windhoek"),
+    ("Abomey-Calavi", 6.4485, 2.3557, "Africa/Porto-Novo"),
+    ("Bochum", 51.4817, 7.2165, "Europe/Berlin"),
+    ("Suita", 34.7614, 135.5157, "Asia/Tokyo"),
+    ("Benito Juárez", 19.3727, -99.1564, "America/Mexico_City"),
+    ("Sector 3", 44.4234, 26.1687, "Europe/Bucharest"),
+    ("Kahramanmaraş", 37.5847, 36.9264, "Europe/Istanbul"),
+    ("Chongzuo", 22.3816, 107.3683, "Asia/Shanghai"),
+    ("Grajaú", -23.7698, -46.6711, "America/Sao_Paulo"),
+    ("Okazaki", 34.9500, 137.1667, "Asia/Tokyo"),
+    ("Xico", 19.2703, -98.9509, "America/Mexico_City"),
+    ("Iztacalco", 19.3953, -99.0978, "America/Mexico_City"),
+    ("Kākināda", 16.9604, 82.2381, "Asia/Kolkata"),
+    ("Betim", -19.9678, -44.1983, "America/Sao_Paulo"),
+    ("Las Palmas de Gran Canaria", 28.1018, -15.4157, "Atlantic/Canary"),
+    ("Cotabato", 7.2236, 124.2464, "Asia/Manila"),
+    ("Bawshar", 23.5777, 58.3998, "Asia/Muscat"),
+    ("Latur", 18.3972, 76.5678, "Asia/Kolkata"),
+    ("Delmas", 18.5448, -72.3004, "America/Port-au-Prince"),
+    ("Tanzhou", 22.2550, 113.4669, "Asia/Shanghai"),
+    ("Wellington", -41.2866, 174.7756, "Pacific/Auckland"),
+    ("Mazatlán", 23.2216, -106.4189, "America/Mazatlan"),
+    ("Caxias do Sul", -29.1681, -51.1794, "America/Sao_Paulo"),
+    ("Nizhny Tagil", 57.9194, 59.9650, "Asia/Yekaterinburg"),
+    ("Irapuato", 20.6767, -101.3563, "America/Mexico_City"),
+    ("Ichinomiya", 35.3000, 136.8000, "Asia/Tokyo"),
+    ("Aswān", 24.0908, 32.8994, "Africa/Cairo"),
+    ("Brno", 49.1952, 16.6080, "Europe/Prague"),
+    ("Bauru", -22.3147, -49.0606, "America/Sao_Paulo"),
+    ("Iaşi", 47.1667, 27.6000, "Europe/Bucharest"),
+    ("Krugersdorp", -26.0858, 27.7752, "Africa/Johannesburg"),
+    ("Pānihāti", 22.6909, 88.3740, "Asia/Kolkata"),
+    ("Shibganj", 25.0015, 89.3227, "Asia/Dhaka"),
+    ("Iquitos", -3.7481, -73.2529, "America/Lima"),
+    ("Toyohashi", 34.7667, 137.3833, "Asia/Tokyo"),
+    ("Hechuan", 29.9923, 106.2646, "Asia/Shanghai"),
+    ("Utrecht", 52.0908, 5.1222, "Europe/Amsterdam"),
+    ("Rajamahendravaram", 17.0052, 81.7778, "Asia/Kolkata"),
+    ("Dhule", 20.9013, 74.7774, "Asia/Kolkata"),
+    ("Minato", 34.2152, 135.1501, "Asia/Tokyo"),
+    ("Puchong", 3.0000, 101.6167, "Asia/Kuala_Lumpur"),
+    ("Ondo", 7.0932, 4.8353, "Africa/Lagos"),
+    ("Rohtak", 28.8945, 76.5892, "Asia/Kolkata"),
+    ("Rustenburg", -25.6676, 27.2421, "Africa/Johannesburg"),
+    ("Bakersfield", 35.3733, -119.0187, "America/Los_Angeles"),
+    ("Bengkulu", -3.8004, 102.2655, "Asia/Jakarta"),
+    ("Yogyakarta", -7.8014, 110.3647, "Asia/Jakarta"),
+    ("Xuanhua", 40.6121, 115.0646, "Asia/Shanghai"),
+    ("Emalahleni", -25.8713, 29.2332, "Africa/Johannesburg"),
+    ("Bafoussam", 5.4778, 10.4176, "Africa/Douala"),
+    ("Palu", -0.9083, 119.8708, "Asia/Makassar"),
+    ("Thủ Dầu Một", 10.9804, 106.6519, "Asia/Ho_Chi_Minh"),
+    ("Takasaki", 36.3333, 139.0167, "Asia/Tokyo"),
+    ("Seremban", 2.7297, 101.9381, "Asia/Kuala_Lumpur"),
+    ("Miguel Hidalgo", 19.4341, -99.2002, "America/Mexico_City"),
+    ("Nagano", 36.6500, 138.1833, "Asia/Tokyo"),
+    ("Tawau", 4.2448, 117.8911, "Asia/Kuching"),
+    ("Cardiff", 51.4800, -3.1800, "Europe/London"),
+    ("Đống Đa", 21.0201, 105.8310, "Asia/Ho_Chi_Minh"),
+    ("Chitungwiza", -18.0127, 31.0755, "Africa/Harare"),
+    ("Abadan", 30.3392, 48.3043, "Asia/Tehran"),
+    ("Ingombota", -8.8181, 13.2289, "Africa/Luanda"),
+    ("Fenghuang", 27.9356, 109.5996, "Asia/Shanghai"),
+    ("Umuahia", 5.5249, 7.4946, "Africa/Lagos"),
+    ("Puerto La Cruz", 10.2138, -64.6328, "America/Caracas"),
+    ("Vinnytsya", 49.2322, 28.4687, "Europe/Kyiv"),
+    ("Uşak", 38.6735, 29.4058, "Europe/Istanbul"),
+    ("Bharatpur", 27.6803, 84.4365, "Asia/Kathmandu"),
+    ("Itaquaquecetuba", -23.4861, -46.3483, "America/Sao_Paulo"),
+    ("Natore", 24.4111, 88.9867, "Asia/Dhaka"),
+    ("6th of October City", 29.8167, 31.0500, "Africa/Cairo"),
+    ("Leicester", 52.6386, -1.1317, "Europe/London"),
+    ("Desna", 50.5221, 30.6823, "Europe/Kyiv"),
+    ("Sector 6", 44.4358, 26.0165, "Europe/Bucharest"),
+    ("Canberra", -35.2835, 149.1281, "Australia/Sydney"),
+    ("Nara-shi", 34.6850, 135.8048, "Asia/Tokyo"),
+    ("Florence", 43.7792, 11.2463, "Europe/Rome"),
+    ("Ahilyanagar", 19.0946, 74.7384, "Asia/Kolkata"),
+    ("Kollam", 8.8811, 76.5847, "Asia/Kolkata"),
+    ("Huanggang", 30.4514, 114.8704, "Asia/Shanghai"),
+    ("Olinda", -8.0089, -34.8553, "America/Recife"),
+    ("Bradford", 53.7939, -1.7521, "Europe/London"),
+    ("Bilāspur", 22.0800, 82.1554, "Asia/Kolkata"),
+    ("Malabon", 14.6733, 120.9397, "Asia/Manila"),
+    ("Cleveland", 41.4995, -81.6954, "America/New_York"),
+    ("Iseyin", 7.9702, 3.5963, "Africa/Lagos"),
+    ("Etobicoke", 43.6442, -79.5699, "America/Toronto"),
+    ("Yenagoa", 4.9268, 6.2676, "Africa/Lagos"),
+    ("Gboko", 7.3228, 9.0011, "Africa/Lagos"),
+    ("Pyeongtaek", 36.9947, 127.0889, "Asia/Seoul"),
+    ("Petare", 10.4768, -66.8079, "America/Caracas"),
+    ("Bến Cát", 11.1500, 106.6000, "Asia/Ho_Chi_Minh"),
+    ("Anqiu", 36.4342, 119.1925, "Asia/Shanghai"),
+    ("Alanya", 36.5438, 31.9998, "Europe/Istanbul"),
+    ("Larkana", 27.5590, 68.2120, "Asia/Karachi"),
+    ("Al Qadarif", 14.0349, 35.3834, "Africa/Khartoum"),
+    ("Cibinong", -6.4817, 106.8542, "Asia/Jakarta"),
+    ("Nawabshah", 26.2394, 68.4037, "Asia/Karachi"),
+    ("New Orleans", 29.9547, -90.0751, "America/Chicago"),
+    ("Keelung", 25.1309, 121.7409, "Asia/Taipei"),
+    ("Malmö", 55.6059, 13.0007, "Europe/Stockholm"),
+    ("Jizhou", 37.5505, 115.5687, "Asia/Shanghai"),
+    ("Manukau City", -36.9928, 174.8799, "Pacific/Auckland"),
+    ("Blumenau", -26.9194, -49.0661, "America/Sao_Paulo"),
+    ("Maradi", 13.5000, 7.1017, "Africa/Niamey"),
+    ("Ataşehir", 40.9833, 29.1167, "Europe/Istanbul"),
+    ("Hrodna", 53.6758, 23.8289, "Europe/Minsk"),
+    ("Wuppertal", 51.2563, 7.1482, "Europe/Berlin"),
+    ("Sukabumi", -6.9181, 106.9267, "Asia/Jakarta"),
+    ("Ulan-Ude", 51.8265, 107.5998, "Asia/Irkutsk"),
+    ("Huocheng", 44.0530, 80.8717, "Asia/Urumqi"),
+    ("Ijebu Ode", 6.8194, 3.9173, "Africa/Lagos"),
+    ("Maseru", -29.3167, 27.4833, "Africa/Maseru"),
+    ("Bhilwara", 25.3471, 74.6408, "Asia/Kolkata"),
+    ("Aurora", 39.7294, -104.8319, "America/Denver"),
+    ("Sumqayıt", 40.5897, 49.6686, "Asia/Baku"),
+    ("Franca", -20.5386, -47.4008, "America/Sao_Paulo"),
+    ("Vitebsk", 55.1904, 30.2049, "Europe/Minsk"),
+    ("Taraz", 42.8980, 71.3733, "Asia/Almaty"),
+    ("Yangsan", 35.3420, 129.0336, "Asia/Seoul"),
+    ("Lobito", -12.3644, 13.5360, "Africa/Luanda"),
+    ("Dniprovskyi", 50.4537, 30.6015, "Europe/Kyiv"),
+    ("San Jose del Monte", 14.8139, 121.0453, "Asia/Manila"),
+    ("Gwangmyeong", 37.4772, 126.8664, "Asia/Seoul"),
+    ("Zanjan", 36.6764, 48.4963, "Asia/Tehran"),
+    ("Neiva", 2.9300, -75.2797, "America/Bogota"),
+    ("Iwaki", 37.0500, 140.8833, "Asia/Tokyo"),
+    ("Vladimir", 56.1385, 40.3998, "Europe/Moscow"),
+    ("Tete", -16.1564, 33.5867, "Africa/Maputo"),
+    ("Bacoor", 14.4590, 120.9385, "Asia/Manila"),
+    ("Wakayama", 34.2333, 135.1667, "Asia/Tokyo"),
+    ("Brahmapur", 19.3115, 84.7929, "Asia/Kolkata"),
+    ("Fengshan", 22.6266, 120.3613, "Asia/Taipei"),
+    ("Caucaia", -3.7361, -38.6531, "America/Fortaleza"),
+    ("Misratah", 32.3753, 15.0925, "Africa/Tripoli"),
+    ("Cuíto", -12.3833, 16.9333, "Africa/Luanda"),
+    ("Benito Juarez", 19.3984, -99.1577, "America/Mexico_City"),
+    ("Kyzylorda", 44.8528, 65.5092, "Asia/Qyzylorda"),
+    ("Kawagoe", 35.9086, 139.4853, "Asia/Tokyo"),
+    ("Takatsuki", 34.8483, 135.6168, "Asia/Tokyo"),
+    ("Muzaffarpur", 26.1226, 85.3906, "Asia/Kolkata"),
+    ("Tapachula", 14.9054, -92.2589, "America/Mexico_City"),
+    ("Lhoka", 29.2430, 91.7724, "Asia/Shanghai"),
+    ("Villahermosa", 17.9862, -92.9393, "America/Mexico_City"),
+    ("Cariacica", -20.2639, -40.4200, "America/Sao_Paulo"),
+    ("Setapak", 3.2070, 101.7270, "Asia/Kuala_Lumpur"),
+    ("Mahilyow", 53.9088, 30.3404, "Europe/Minsk"),
+    ("Bandar Abbas", 27.1865, 56.2808, "Asia/Tehran"),
+    ("Yunusobod", 41.3714, 69.2794, "Asia/Tashkent"),
+    ("Ras Al Khaimah", 25.7895, 55.9432, "Asia/Dubai"),
+    ("Cabimas", 10.3991, -71.4521, "America/Caracas"),
+    ("Honolulu", 21.3069, -157.8583, "Pacific/Honolulu"),
+    ("Anaheim", 33.8353, -117.9145, "America/Los_Angeles"),
+    ("Tarsus", 36.9177, 34.8928, "Europe/Istanbul"),
+    ("Pengze", 29.8988, 116.5457, "Asia/Shanghai"),
+    ("Bahir Dar", 11.5936, 37.3908, "Africa/Addis_Ababa"),
+    ("Punāsa", 22.2351, 76.3933, "Asia/Kolkata"),
+    ("Al Maḩmūdīyah", 33.0622, 44.3656, "Asia/Baghdad"),
+    ("Diepsloot", -25.9331, 28.0121, "Africa/Johannesburg"),
+    ("Xilinhot", 43.9389, 116.0702, "Asia/Shanghai"),
+    ("Praia Grande", -24.0058, -46.4028, "America/Sao_Paulo"),
+    ("Quelimane", -17.8786, 36.8883, "Africa/Maputo"),
+    ("Arkhangel’sk", 64.5461, 40.5518, "Europe/Moscow"),
+    ("Muzaffarnagar", 29.4709, 77.7033, "Asia/Kolkata"),
+    ("Hulunbuir", 49.2114, 119.7558, "Asia/Shanghai"),
+    ("Shinjuku", 35.6912, 139.7085, "Asia/Tokyo"),
+    ("Sikasso", 11.3176, -5.6665, "Africa/Bamako"),
+    ("Sanandaj", 35.3150, 46.9988, "Asia/Tehran"),
+    ("Chita", 52.0431, 113.4917, "Asia/Chita"),
+    ("San Pedro", 14.3595, 121.0473, "Asia/Manila"),
+    ("Campina Grande", -7.2306, -35.8811, "America/Fortaleza"),
+    ("Alicante", 38.3452, -0.4815, "Europe/Madrid"),
+    ("Bimbo", 4.2567, 18.4158, "Africa/Bangui"),
+    ("Kalemyo", 23.1901, 94.0640, "Asia/Yangon"),
+    ("Belfast", 54.5968, -5.9254, "Europe/London"),
+    ("Long Bien", 21.0333, 105.9000, "Asia/Ho_Chi_Minh"),
+    ("Camagüey", 21.3808, -77.9169, "America/Havana"),
+    ("Daye", 30.0833, 114.9500, "Asia/Shanghai"),
+    ("Bilbao", 43.2627, -2.9253, "Europe/Madrid"),
+    ("Ambon", -3.6958, 128.1833, "Asia/Jayapura"),
+    ("Chifeng", 42.2683, 118.9636, "Asia/Shanghai"),
+    ("Central Coast", -33.4298, 151.3714, "Australia/Sydney"),
+    ("Sunshine Coast", -26.6568, 153.0796, "Australia/Brisbane"),
+    ("Corrientes", -27.4678, -58.8344, "America/Argentina/Cordoba"),
+    ("Hŭngnam", 39.8420, 127.6321, "Asia/Pyongyang"),
+    ("Avadi", 13.1147, 80.1098, "Asia/Kolkata"),
+    ("Astana", 51.1801, 71.4460, "Asia/Almaty"),
+    ("Yunlong", 34.2528, 117.2517, "Asia/Shanghai"),
+    ("Koshigaya", 35.8903, 139.7892, "Asia/Tokyo"),
+    ("Coventry", 52.4066, -1.5122, "Europe/London"),
+    ("Belgorod", 50.6034, 36.5809, "Europe/Moscow"),
+    ("Kendari", -3.9778, 122.5151, "Asia/Makassar"),
+    ("Toamasina", -18.1492, 49.4023, "Indian/Antananarivo"),
+    ("Logan City", -27.6392, 153.1094, "Australia/Brisbane"),
+    ("Ōtsu", 35.0000, 135.8667, "Asia/Tokyo"),
+    ("Kosti", 13.1629, 32.6635, "Africa/Khartoum"),
+    ("Qitaihe", 45.7680, 130.9953, "Asia/Shanghai"),
+    ("Doha", 25.2855, 51.5310, "Asia/Qatar"),
+    ("Kadapa", 14.4800, 78.8235, "Asia/Kolkata"),
+    ("Nakano", 35.7045, 139.6695, "Asia/Tokyo"),
+    ("Turmero", 10.2286, -67.4742, "America/Caracas"),
+    ("Brest", 52.1089, 23.7175, "Europe/Minsk"),
+    ("Tokorozawa", 35.7992, 139.4690, "Asia/Tokyo"),
+    ("Cabanatuan City", 15.4859, 120.9665, "Asia/Manila"),
+    ("Pizhou", 34.3114, 117.9503, "Asia/Shanghai"),
+    ("Darnytsya", 50.4160, 30.6953, "Europe/Kyiv"),
+    ("Dire Dawa", 9.5931, 41.8661, "Africa/Addis_Ababa"),
+    ("Annaba", 36.9000, 7.7667, "Africa/Algiers"),
+    ("Nice", 43.7031, 7.2661, "Europe/Paris"),
+    ("Iligan", 8.2289, 124.2434, "Asia/Manila"),
+    ("Soledad", 10.9184, -74.7646, "America/Bogota"),
+    ("Temara", 33.9287, -6.9066, "Africa/Casablanca"),
+    ("Shiqi", 22.5168, 113.3852, "Asia/Shanghai"),
+    ("Paulista", -7.9408, -34.8731, "America/Recife"),
+    ("Obalende", 6.4469, 3.4153, "Africa/Lagos"),
+    ("Cirebon", -6.7063, 108.5570, "Asia/Jakarta"),
+    ("Kukatpally", 17.4849, 78.4138, "Asia/Kolkata"),
+    ("Laixi", 36.8592, 120.5269, "Asia/Shanghai"),
+    ("Jhang Sadr", 31.2698, 72.3169, "Asia/Karachi"),
+    ("Dihok", 36.8661, 42.9879, "Asia/Baghdad"),
+    ("Kaluga", 54.5306, 36.2700, "Europe/Moscow"),
+    ("Bắc Từ Liêm", 21.0653, 105.7466, "Asia/Ho_Chi_Minh"),
+    ("Svyatoshyn", 50.4634, 30.3461, "Europe/Kyiv"),
+    ("Celaya", 20.5235, -100.8157, "America/Mexico_City"),
+    ("Serekunda", 13.4383, -16.6781, "Africa/Banjul"),
+    ("Kāfrul", 23.7882, 90.3736, "Asia/Dhaka"),
+    ("Ḩadā’iq al Qubbah", 30.0884, 31.2835, "Africa/Cairo"),
+    ("Makiivka", 48.0478, 37.9258, "Europe/Kyiv"),
+    ("West Raleigh", 35.7868, -78.6639, "America/New_York"),
+    ("Cuernavaca", 18.9261, -99.2308, "America/Mexico_City"),
+    ("Markham", 43.8668, -79.2663, "America/Toronto"),
+    ("Kaesŏng", 37.9708, 126.5544, "Asia/Pyongyang"),
+    ("Uberaba", -19.7483, -47.9319, "America/Sao_Paulo"),
+    ("Tungi", 23.8915, 90.4023, "Asia/Dhaka"),
+    ("Krasnogvargeisky", 59.9731, 30.4761, "Europe/Moscow"),
+    ("Randburg", -26.0941, 28.0012, "Africa/Johannesburg"),
+    ("Safi", 32.2994, -9.2372, "Africa/Casablanca"),
+    ("Simferopol", 44.9572, 34.1108, "Europe/Simferopol"),
+    ("Lublin", 51.2506, 22.5701, "Europe/Warsaw"),
+    ("Ganja", 40.6816, 46.3613, "Asia/Baku"),
+    ("San José", 9.9339, -84.0849, "America/Costa_Rica"),
+    ("Orlando", 28.5383, -81.3792, "America/New_York"),
+    ("Viña del Mar", -33.0246, -71.5518, "America/Santiago"),
+    ("Tieling", 42.2931, 123.8414, "Asia/Shanghai"),
+    ("Qazvin", 36.2688, 50.0041, "Asia/Tehran"),
+    ("Asahikawa", 43.7706, 142.3649, "Asia/Tokyo"),
+    ("Kāmārhāti", 22.6711, 88.3747, "Asia/Kolkata"),
+    ("Tepic", 21.5073, -104.8933, "America/Mazatlan"),
+    ("Wŏnju", 37.3514, 127.9453, "Asia/Seoul"),
+    ("Wad Medani", 14.4012, 33.5199, "Africa/Khartoum"),
+    ("Quận Mười Một", 10.7638, 106.6436, "Asia/Ho_Chi_Minh"),
+    ("Nukus", 42.4586, 59.6058, "Asia/Samarkand"),
+    ("Maebashi", 36.4000, 139.0833, "Asia/Tokyo"),
+    ("Kita", 35.7526, 139.7335, "Asia/Tokyo"),
+    ("Ciudad Victoria", 23.7406, -99.1436, "America/Monterrey"),
+    ("Soledad de Graciano Sánchez", 22.1891, -100.9379, "America/Mexico_City"),
+    ("Kochi", 33.5500, 133.5333, "Asia/Tokyo"),
+    ("Bielefeld", 52.0333, 8.5333, "Europe/Berlin"),
+    ("Dumai", 1.6671, 101.4432, "Asia/Jakarta"),
+    ("Blida", 36.4700, 2.8277, "Africa/Algiers"),
+    ("Kwai Chung", 22.3683, 114.1388, "Asia/Hong_Kong"),
+    ("Mandaue City", 10.3236, 123.9222, "Asia/Manila"),
+    ("Khorramshahr", 30.4408, 48.1843, "Asia/Tehran"),
+    ("Bonn", 50.7344, 7.0955, "Europe/Berlin"),
+    ("Mathura", 27.5035, 77.6722, "Asia/Kolkata"),
+    ("Hechi", 24.6928, 108.0838, "Asia/Shanghai"),
+    ("Bydgoszcz", 53.1235, 18.0076, "Europe/Warsaw"),
+    ("Smolensk", 54.7783, 32.0509, "Europe/Moscow"),
+    ("Oral", 51.2460, 51.4256, "Asia/Oral"),
+    ("São Vicente", -23.9631, -46.3919, "America/Sao_Paulo"),
+    ("Khorramabad", 33.4878, 48.3558, "Asia/Tehran"),
+    ("Ribeirão das Neves", -19.7669, -44.0867, "America/Sao_Paulo"),
+    ("Soyapango", 13.7102, -89.1399, "America/El_Salvador"),
+    ("Tongshan", 34.1805, 117.1571, "Asia/Shanghai"),
+    ("Guédiawaye", 14.7745, -17.4021, "Africa/Dakar"),
+    ("São José dos Pinhais", -25.5302, -49.2084, "America/Sao_Paulo"),
+    ("Plovdiv", 42.1539, 24.7500, "Europe/Sofia"),
+    ("Ciudad Obregón", 27.4864, -109.9408, "America/Hermosillo"),
+    ("Wŏnsan", 39.1528, 127.4436, "Asia/Pyongyang"),
+    ("Brent", 51.5531, -0.3023, "Europe/London"),
+    ("Pavlodar", 52.2760, 76.9688, "Asia/Almaty"),
+    ("Chānda", 19.9508, 79.2952, "Asia/Kolkata"),
+    ("Canoas", -29.9178, -51.1836, "America/Sao_Paulo"),
+    ("Kōriyama", 37.4000, 140.3833, "Asia/Tokyo"),
+    ("Sochi", 43.5970, 39.7248, "Europe/Moscow"),
+    ("Vijayapura", 16.8244, 75.7154, "Asia/Kolkata"),
+    ("Chipata", -13.6333, 32.6500, "Africa/Lusaka"),
+    ("Chongjin", 41.7956, 129.7758, "Asia/Pyongyang"),
+    ("Yanji", 42.8882, 129.5024, "Asia/Shanghai"),
+    ("Roodepoort", -26.1625, 27.8725, "Africa/Johannesburg"),
+    ("Pucallpa", -8.3791, -74.5539, "America/Lima"),
+    ("Mogi das Cruzes", -23.5228, -46.1883, "America/Sao_Paulo"),
+    ("Córdoba", 37.8916, -4.7728, "Europe/Madrid"),
+    ("Birkenhead", 53.3934, -3.0148, "Europe/London"),
+    ("Nantes", 47.2172, -1.5534, "Europe/Paris"),
+    ("Ilesa", 7.6279, 4.7416, "Africa/Lagos"),
+    ("Bhātāra", 23.8000, 90.4500, "Asia/Dhaka"),
+    ("Espoo", 60.2052, 24.6522, "Europe/Helsinki"),
+    ("Kikuyu", -1.2463, 36.6629, "Africa/Nairobi"),
+    ("Kluang", 2.0305, 103.3169, "Asia/Kuala_Lumpur"),
+    ("Lincang", 23.8797, 100.0945, "Asia/Shanghai"),
+    ("Nottingham", 52.9536, -1.1505, "Europe/London"),
+    ("Al ‘Amārah", 31.8356, 47.1448, "Asia/Baghdad"),
+    ("Volzhsky", 48.7858, 44.7797, "Europe/Volgograd"),
+    ("Vaughan", 43.8361, -79.4983, "America/Toronto"),
+    ("Xingyi", 25.0962, 104.9064, "Asia/Shanghai"),
+    ("Guarujá", -23.9931, -46.2564, "America/Sao_Paulo"),
+    ("Shivamogga", 13.9316, 75.5679, "Asia/Kolkata"),
+    ("Alwar", 27.5625, 76.6250, "Asia/Kolkata"),
+    ("Uíge", -7.6087, 15.0613, "Africa/Luanda"),
+    ("Ixtapaluca", 19.3156, -98.8828, "America/Mexico_City"),
+    ("Osh", 40.5283, 72.7985, "Asia/Bishkek"),
+    ("Portoviejo", -1.0576, -80.4514, "America/Guayaquil"),
+    ("Villavicencio", 4.1324, -73.6256, "America/Bogota"),
+    ("Man’gyŏngdae-ri", 38.9918, 125.6587, "Asia/Pyongyang"),
+    ("San Miguelito", 9.0503, -79.4707, "America/Panama"),
+    ("Pelotas", -31.7700, -52.3410, "America/Sao_Paulo"),
+    ("Shāhjānpur", 27.8817, 79.9092, "Asia/Kolkata"),
+    ("Lexington", 37.9887, -84.4777, "America/New_York"),
+    ("Tantou", 22.7512, 113.8350, "Asia/Shanghai"),
+    ("Anápolis", -16.3267, -48.9528, "America/Sao_Paulo"),
+    ("Kaech’ŏn", 39.7006, 125.8933, "Asia/Pyongyang"),
+    ("Jūnāgadh", 21.5197, 70.4598, "Asia/Kolkata"),
+    ("Islington", 51.5362, -0.1030, "Europe/London"),
+    ("Holguín", 20.8872, -76.2631, "America/Havana"),
+    ("Ust-Kamenogorsk", 49.9714, 82.6059, "Asia/Almaty"),
+    ("Maianga", -8.8290, 13.2310, "Africa/Luanda"),
+    ("Tsuen Wan", 22.3714, 114.1133, "Asia/Hong_Kong"),
+    ("Zinder", 13.8072, 8.9881, "Africa/Niamey"),
+    ("Saransk", 54.1848, 45.1717, "Europe/Moscow"),
+    ("Al Diwaniyah", 31.9929, 44.9255, "Asia/Baghdad"),
+    ("Varna", 43.2191, 27.9102, "Europe/Sofia"),
+    ("Hafizabad", 32.0710, 73.6880, "Asia/Karachi"),
+    ("Marne La Vallée", 48.8358, 2.6424, "Europe/Paris"),
+    ("Damanhur", 31.0341, 30.4682, "Africa/Cairo"),
+    ("Chiniot", 31.7209, 72.9784, "Asia/Karachi"),
+    ("Popayán", 2.4382, -76.6132, "America/Bogota"),
+    ("Reading", 51.4562, -0.9711, "Europe/London"),
+    ("Geita", -2.8725, 32.2325, "Africa/Dar_es_Salaam"),
+    ("Taubaté", -23.0264, -45.5553, "America/Sao_Paulo"),
+    ("Constanţa", 44.1807, 28.6343, "Europe/Bucharest"),
+    ("New Delhi", 28.6214, 77.2148, "Asia/Kolkata"),
+    ("Thessaloníki", 40.6407, 22.9349, "Europe/Athens"),
+    ("Thiès", 14.7894, -16.9260, "Africa/Dakar"),
+    ("Naha", 26.2130, 127.6785, "Asia/Tokyo"),
+    ("Pekalongan", -6.8886, 109.6753, "Asia/Jakarta"),
+    ("Obolon", 50.5163, 30.5064, "Europe/Kyiv"),
+    ("Riverside", 33.9534, -117.3962, "America/Los_Angeles"),
+    ("Baicheng", 45.6175, 122.8330, "Asia/Shanghai"),
+    ("Chimbote", -9.0751, -78.5937, "America/Lima"),
+    ("Bari", 41.1207, 16.8698, "Europe/Rome"),
+    ("Barueri", -23.5106, -46.8761, "America/Sao_Paulo"),
+    ("Corpus Christi", 27.8006, -97.3964, "America/Chicago"),
+    ("Thrissur", 10.5167, 76.2167, "Asia/Kolkata"),
+    ("Cherepovets", 59.1333, 37.9000, "Europe/Moscow"),
+    ("Eloy Alfaro", -2.1692, -79.8399, "America/Guayaquil"),
+    ("Hamburg-Nord", 53.5894, 9.9840, "Europe/Berlin"),
+    ("Al-Kut", 32.5128, 45.8182, "Asia/Baghdad"),
+    ("Muar", 2.0442, 102.5689, "Asia/Kuala_Lumpur"),
+    ("Şişli", 41.0605, 28.9872, "Europe/Istanbul"),
+    ("Várzea Grande", -15.6467, -56.1325, "America/Cuiaba"),
+    ("Lexington-Fayette", 38.0498, -84.4586, "America/New_York"),
+    ("Maroua", 10.5909, 14.3159, "Africa/Douala"),
+    ("Kingston upon Hull", 53.7446, -0.3352, "Europe/London"),
+    ("Preston", 53.7628, -2.7045, "Europe/London"),
+    ("Lianshan", 40.7643, 120.8533, "Asia/Shanghai"),
+    ("Denizli", 37.7742, 29.0875, "Europe/Istanbul"),
+    ("Ikeja", 6.5965, 3.3420, "Africa/Lagos"),
+    ("New Cairo", 30.0300, 31.4700, "Africa/Cairo"),
+    ("Al Qāhirah al Jadīdah", 30.0421, 31.4445, "Africa/Cairo"),
+    ("Vitória", -20.3194, -40.3378, "America/Sao_Paulo"),
+    ("Palmira", 3.5394, -76.3036, "America/Bogota"),
+    ("Vologda", 59.2239, 39.8840, "Europe/Moscow"),
+    ("Iligan City", 8.2500, 124.4000, "Asia/Manila"),
+    ("Maringá", -23.4253, -51.9386, "America/Sao_Paulo"),
+    ("Catania", 37.4922, 15.0704, "Europe/Rome"),
+    ("Jardim Angela", -23.7164, -46.7687, "America/Sao_Paulo"),
+    ("Nizāmābād", 18.6715, 78.0988, "Asia/Kolkata"),
+    ("Cincinnati", 39.1271, -84.5144, "America/New_York"),
+    ("Percut", 3.6253, 98.8640, "Asia/Jakarta"),
+    ("Coatzacoalcos", 18.1490, -94.4447, "America/Mexico_City"),
+    ("Santa Ana", 33.7456, -117.8678, "America/Los_Angeles"),
+    ("Sariwŏn-si", 38.5072, 125.7558, "Asia/Pyongyang"),
+    ("Botshabelo", -29.2674, 26.7260, "Africa/Johannesburg"),
+    ("Butuan", 8.9492, 125.5436, "Asia/Manila"),
+    ("Shahrīār", 35.6588, 51.0577, "Asia/Tehran"),
+    ("Shahr-e Qods", 35.7214, 51.1090, "Asia/Tehran"),
+    ("Gia Lâm", 21.0535, 106.0071, "Asia/Ho_Chi_Minh"),
+    ("Kurgan", 55.4490, 65.3434, "Asia/Yekaterinburg"),
+    ("Tampico", 22.2852, -97.8778, "America/Monterrey"),
+    ("Akowonjo", 6.6101, 3.3097, "Africa/Lagos"),
+    ("Cabuyao", 14.2726, 121.1262, "Asia/Manila"),
+    ("Tabora", -5.0162, 32.8266, "Africa/Dar_es_Salaam"),
+    ("Kasugai", 35.2476, 136.9723, "Asia/Tokyo"),
+    ("An Nhơn", 13.8858, 109.1082, "Asia/Ho_Chi_Minh"),
+    ("Alimosho", 6.6066, 3.3024, "Africa/Lagos"),
+    ("Ciudad Benito Juárez", 25.6472, -100.0958, "America/Monterrey"),
+    ("Mannheim", 49.4891, 8.4669, "Europe/Berlin"),
+    ("Akita", 39.7167, 140.1167, "Asia/Tokyo"),
+    ("Suzano", -23.5425, -46.3108, "America/Sao_Paulo"),
+    ("Tumkūr", 13.3414, 77.1022, "Asia/Kolkata"),
+    ("Chinju", 35.1928, 128.0847, "Asia/Seoul"),
+    ("Parbhani", 19.2686, 76.7708, "Asia/Kolkata"),
+    ("Hisar", 29.1539, 75.7229, "Asia/Kolkata"),
+    ("Iksan", 35.9439, 126.9544, "Asia/Seoul"),
+    ("Fīrozābād", 27.1509, 78.3978, "Asia/Kolkata"),
+    ("Palmas", -10.1675, -48.3277, "America/Araguaina"),
+    ("Vladikavkaz", 43.0410, 44.6699, "Europe/Moscow"),
+    ("Port-de-Paix", 19.9398, -72.8304, "America/Port-au-Prince"),
+    ("Damietta", 31.4165, 31.8133, "Africa/Cairo"),
+    ("Posadas", -27.3918, -55.9238, "America/Argentina/Cordoba"),
+    ("Brakpan", -26.2366, 28.3694, "Africa/Johannesburg"),
+    ("Stockton", 37.9577, -121.2908, "America/Los_Angeles"),
+    ("Yokkaichi", 34.9667, 136.6167, "Asia/Tokyo"),
+    ("Kulti", 23.7317, 86.8437, "Asia/Kolkata"),
+    ("Tláhuac", 19.2869, -99.0051, "America/Mexico_City"),
+    ("Sapele", 5.8941, 5.6767, "Africa/Lagos"),
+    ("Pittsburgh", 40.4406, -79.9959, "America/New_York"),
+    ("Armenia", 4.5366, -75.6726, "America/Bogota"),
+    ("Santa Catarina", 25.6732, -100.4581, "America/Monterrey"),
+    ("Sumbawanga", -7.9667, 31.6167, "Africa/Dar_es_Salaam"),
+    ("Orël", 52.9688, 36.0791, "Europe/Moscow"),
+    ("Akashi", 34.6552, 135.0069, "Asia/Tokyo"),
+    ("Hai Bà Trưng", 21.0134, 105.8477, "Asia/Ho_Chi_Minh"),
+    ("Kurume", 33.3167, 130.5167, "Asia/Tokyo"),
+    ("Graz", 47.0673, 15.4420, "Europe/Vienna"),
+    ("Saint Paul", 44.9444, -93.0933, "America/Chicago"),
+    ("Nghi Sơn", 19.3275, 105.8214, "Asia/Ho_Chi_Minh"),
+    ("Karnāl", 29.6920, 76.9845, "Asia/Kolkata"),
+    ("Changyi", 36.8536, 119.3908, "Asia/Shanghai"),
+    ("Ciudad del Este", -25.5036, -54.6507, "America/Asuncion"),
+    ("Rosetta", 31.3995, 30.4172, "Africa/Cairo"),
+    ("Barddhamān", 23.2557, 87.8569, "Asia/Kolkata"),
+    ("Toshima", 35.7613, 139.7449, "Asia/Tokyo"),
+    ("Solwezi", -12.1688, 26.3894, "Africa/Lusaka"),
+    ("Hamburg-Mitte", 53.5500, 10.0167, "Europe/Berlin"),
+    ("Valladolid", 41.6554, -4.7235, "Europe/Madrid"),
+    ("Miri", 4.3993, 113.9916, "Asia/Kuching"),
+    ("Xinyi", 34.3842, 118.3462, "Asia/Shanghai"),
+    ("Mardan", 34.1979, 72.0496, "Asia/Karachi"),
+    ("Surgut", 61.2576, 73.4177, "Asia/Yekaterinburg"),
+    ("Swansea", 51.6208, -3.9432, "Europe/London"),
+    ("San Pablo", 14.0683, 121.3256, "Asia/Manila"),
+    ("Newcastle upon Tyne", 54.9733, -1.6140, "Europe/London"),
+    ("Gundupālaiyam", 11.9410, 79.8029, "Asia/Kolkata"),
+    ("Gatineau", 45.4772, -75.7016, "America/Toronto"),
+    ("Yangshuo", 24.7808, 110.4897, "Asia/Shanghai"),
+    ("Biñan", 14.3427, 121.0807, "Asia/Manila"),
+    ("Malir Cantonment", 24.9434, 67.2059, "Asia/Karachi"),
+    ("Winejok", 9.0122, 27.5708, "Africa/Juba"),
+    ("Batikent", 39.9683, 32.7308, "Europe/Istanbul"),
+    ("Mérida", 8.5790, -71.1692, "America/Caracas"),
+    ("Linqu", 36.5156, 118.5397, "Asia/Shanghai"),
+    ("Uruapan", 19.4168, -102.0584, "America/Mexico_City"),
+    ("Pātan", 27.6766, 85.3142, "Asia/Kathmandu"),
+    ("Fergana", 40.3842, 71.7843, "Asia/Tashkent"),
+    ("Bahía Blanca", -38.7176, -62.2655, "America/Argentina/Buenos_Aires"),
+    ("Santol", 15.1622, 120.5675, "Asia/Manila"),
+    ("Kaolack", 14.1520, -16.0726, "Africa/Dakar"),
+    ("Jember", -8.1721, 113.6995, "Asia/Jakarta"),
+    ("Aomori", 40.8167, 140.7333, "Asia/Tokyo"),
+    ("Bārāsat", 22.7215, 88.4820, "Asia/Kolkata"),
+    ("Mulugu", 18.1910, 79.9430, "Asia/Kolkata"),
+    ("Bihār Sharīf", 25.2008, 85.5239, "Asia/Kolkata"),
+    ("Grozny", 43.3120, 45.6889, "Europe/Moscow"),
+    ("Boma", -5.8510, 13.0536, "Africa/Kinshasa"),
+    ("Bāli", 22.6486, 88.3411, "Asia/Kolkata"),
+    ("Simao", 22.7886, 100.9748, "Asia/Shanghai"),
+    ("Rāmpur", 28.8101, 79.0270, "Asia/Kolkata"),
+    ("Darbhanga", 26.1522, 85.8971, "Asia/Kolkata"),
+    ("Panipat", 29.3875, 76.9682, "Asia/Kolkata"),
+    ("Mwene", -9.8170, 22.8702, "Africa/Lubumbashi"),
+    ("Białystok", 53.1333, 23.1643, "Europe/Warsaw"),
+    ("Rufisque", 14.7154, -17.2733, "Africa/Dakar"),
+    ("Murmansk", 68.9678, 33.0992, "Europe/Moscow"),
+    ("Tirupati", 13.6355, 79.4199, "Asia/Kolkata"),
+    ("Southend-on-Sea", 51.5378, 0.7143, "Europe/London"),
+    ("Lincoln", 40.8000, -96.6670, "America/Chicago"),
+    ("Phu Quoc", 10.2241, 103.9716, "Asia/Ho_Chi_Minh"),
+    ("Baiyin", 36.5470, 104.1702, "Asia/Shanghai"),
+    ("Fukushima", 37.7500, 140.4667, "Asia/Tokyo"),
+    ("Bergen", 60.3930, 5.3242, "Europe/Oslo"),
+    ("Greater Noida", 28.4962, 77.5360, "Asia/Kolkata"),
+    ("Noida", 28.5800, 77.3300, "Asia/Kolkata"),
+    ("Tambov", 52.7363, 41.4410, "Europe/Moscow"),
+    ("Vigo", 42.2328, -8.7226, "Europe/Madrid"),
+    ("Palangkaraya", -2.2083, 113.9167, "Asia/Pontianak"),
+    ("Aizawl", 23.7289, 92.7179, "Asia/Kolkata"),
+    ("Thanh Xuân", 20.9947, 105.7998, "Asia/Ho_Chi_Minh"),
+    ("Al Hufūf", 25.3647, 49.5876, "Asia/Riyadh"),
+    ("Cholula", 19.0641, -98.3035, "America/Mexico_City"),
+    ("Gandhinagar", 23.2167, 72.6833, "Asia/Kolkata"),
+    ("Semey", 50.4206, 80.2502, "Asia/Almaty"),
+    ("Cầu Giấy", 21.0323, 105.8007, "Asia/Ho_Chi_Minh"),
+    ("Dindigul", 10.3690, 77.9804, "Asia/Kolkata"),
+    ("Ponta Grossa", -25.0950, -50.1619, "America/Sao_Paulo"),
+    ("Gaozhou", 21.9197, 110.8568, "Asia/Shanghai"),
+    ("Limeira", -22.5647, -47.4017, "America/Sao_Paulo"),
+    ("Thanjavur", 10.7852, 79.1391, "Asia/Kolkata"),
+    ("Kariega", -33.7556, 25.4007, "Africa/Johannesburg"),
+    ("Al Mubarraz", 25.4077, 49.5903, "Asia/Riyadh"),
+    ("Resistencia", -27.4636, -58.9866, "America/Argentina/Cordoba"),
+    ("Morioka", 39.7000, 141.1500, "Asia/Tokyo"),
+    ("Atyrau", 47.1048, 51.8843, "Asia/Atyrau"),
+    ("Sector 2", 44.4528, 26.1332, "Europe/Bucharest"),
+    ("Xiuying", 20.0007, 110.2936, "Asia/Shanghai"),
+    ("San Cristóbal", 7.7659, -72.2358, "America/Caracas"),
+    ("Karīmnagar", 18.4392, 79.1286, "Asia/Kolkata"),
+    ("Victoria", 48.4359, -123.3516, "America/Vancouver"),
+    ("Anchorage", 61.2181, -149.9003, "America/Anchorage"),
+    ("Dewas", 22.9658, 76.0553, "Asia/Kolkata"),
+    ("Batna City", 35.5560, 6.1741, "Africa/Algiers"),
+    ("Kaunas", 54.9016, 23.9091, "Europe/Vilnius"),
+    ("Sonīpat", 28.9948, 77.0194, "Asia/Kolkata"),
+    ("Machala", -3.2589, -79.9588, "America/Guayaquil"),
+    ("Zeytinburnu", 40.9944, 28.9042, "Europe/Istanbul"),
+    ("Meads", 38.4126, -82.7091, "America/New_York"),
+    ("Kabwe", -14.4469, 28.4464, "Africa/Lusaka"),
+    ("Cileungsir", -6.3947, 106.9592, "Asia/Jakarta"),
+    ("Sinŭiju", 40.1006, 124.3981, "Asia/Pyongyang"),
+    ("Meguro", 35.6322, 139.7017, "Asia/Tokyo"),
+    ("Sector 4", 44.3757, 26.1209, "Europe/Bucharest"),
+    ("Sumida", 35.7329, 139.8209, "Asia/Tokyo"),
+    ("Ibaraki", 34.8164, 135.5683, "Asia/Tokyo"),
+    ("Bắc Ninh", 21.1861, 106.0763, "Asia/Ho_Chi_Minh"),
+    ("Ichalkaranji", 16.6912, 74.4605, "Asia/Kolkata"),
+    ("Marienthal", 53.5667, 10.0833, "Europe/Berlin"),
+    ("Phú Mỹ", 10.6333, 107.0667, "Asia/Ho_Chi_Minh"),
+    ("Ceilândia", -15.8091, -48.1310, "America/Sao_Paulo"),
+    ("Katowice", 50.2597, 19.0217, "Europe/Warsaw"),
+    ("Adapazarı", 40.7806, 30.4033, "Europe/Istanbul"),
+    ("Sultanbeyli", 40.9607, 29.2707, "Europe/Istanbul"),
+    ("Cluj-Napoca", 46.7667, 23.6000, "Europe/Bucharest"),
+    ("Gunpo", 37.3675, 126.9469, "Asia/Seoul"),
+    ("Songea", -10.6833, 35.6500, "Africa/Dar_es_Salaam"),
+    ("Butembo", 0.1416, 29.2912, "Africa/Lubumbashi"),
+    ("Tacna", -18.0146, -70.2536, "America/Lima"),
+    ("Sumaré", -22.8219, -47.2669, "America/Sao_Paulo"),
+    ("Long Xuyên", 10.3864, 105.4352, "Asia/Ho_Chi_Minh"),
+    ("Chuncheon", 37.8747, 127.7342, "Asia/Seoul"),
+    ("Al Qurnah", 31.0155, 47.4336, "Asia/Baghdad"),
+    ("Savar", 23.8486, 90.2500, "Asia/Dhaka"),
+    ("Bathinda", 30.2075, 74.9389, "Asia/Kolkata"),
+    ("Henderson", 36.0397, -114.9819, "America/Los_Angeles"),
+    ("Jālna", 19.8410, 75.8864, "Asia/Kolkata"),
+    ("Sekondi", 4.9342, -1.7145, "Africa/Accra"),
+    ("Kyengera", 0.2952, 32.5016, "Africa/Kampala"),
+    ("Greensboro", 36.0726, -79.7920, "America/New_York"),
+    ("Haifa", 32.8130, 34.9993, "Asia/Jerusalem"),
+    ("Århus", 56.1567, 10.2108, "Europe/Copenhagen"),
+    ("Viamão", -30.0811, -51.0233, "America/Sao_Paulo"),
+    ("Chernihiv", 51.5054, 31.2866, "Europe/Kyiv"),
+    ("Dengzhou", 32.6827, 112.0887, "Asia/Shanghai"),
+    ("Artux", 39.7084, 76.1797, "Asia/Urumqi"),
+    ("Alto Barinas", 8.5931, -70.2261, "America/Caracas"),
+    ("Mbour", 14.4220, -16.9638, "Africa/Dakar"),
+    ("Karlsruhe", 49.0094, 8.4044, "Europe/Berlin"),
+    ("Port Moresby", -9.4772, 147.1509, "Pacific/Port_Moresby"),
+    ("Santa Maria", -29.6842, -53.8069, "America/Sao_Paulo"),
+    ("Kherson", 46.6369, 32.6146, "Europe/Kyiv"),
+    ("Minya", 28.0919, 30.7581, "Africa/Cairo"),
+    ("Plano", 33.0198, -96.6989, "America/Chicago"),
+    ("Ichihara", 35.5167, 140.0833, "Asia/Tokyo"),
+    ("Poltava", 49.5892, 34.5537, "Europe/Kyiv"),
+    ("Kirāri Sulemānnagar", 28.6974, 77.0648, "Asia/Kolkata"),
+    ("Cainta", 14.5786, 121.1222, "Asia/Manila"),
+    ("Pétionville", 18.5125, -72.2853, "America/Port-au-Prince"),
+    ("Satna", 24.5773, 80.8272, "Asia/Kolkata"),
+    ("Geelong", -38.1471, 144.3607, "Australia/Melbourne"),
+    ("Tegal", -6.8694, 109.1402, "Asia/Jakarta"),
+    ("Xinyuan", 43.4265, 83.2496, "Asia/Urumqi"),
+    ("Ba Vì", 21.0833, 105.3833, "Asia/Ho_Chi_Minh"),
+    ("Valparaíso", -33.0360, -71.6296, "America/Santiago"),
+    ("Ica", -14.0754, -75.7342, "America/Lima"),
+    ("Tin Shui Wai", 22.4568, 114.0023, "Asia/Hong_Kong"),
+    ("Purnia", 25.7789, 87.4742, "Asia/Kolkata"),
+    ("Newark", 40.7357, -74.1724, "America/New_York"),
+    ("Itagüí", 6.1846, -75.5991, "America/Bogota"),
+    ("Nicolás Romero", 19.6418, -99.3068, "America/Mexico_City"),
+    ("Gebze", 40.8028, 29.4307, "Europe/Istanbul"),
+    ("Lichinga", -13.3128, 35.2406, "Africa/Maputo"),
+    ("Narsingdi", 23.9230, 90.7177, "Asia/Dhaka"),
+    ("Sfax", 34.7406, 10.7603, "Africa/Tunis"),
+    ("Zumpango", 19.7967, -99.0995, "America/Mexico_City"),
+    ("Solomyansk", 50.4284, 30.4521, "Europe/Kyiv"),
+    ("Merkezefendi", 37.8054, 29.0424, "Europe/Istanbul"),
+    ("Madison", 43.0731, -89.4012, "America/Chicago"),
+    ("Bukhara", 39.7703, 64.4307, "Asia/Samarkand"),
+    ("Wollongong", -34.4240, 150.8935, "Australia/Sydney"),
+    ("Mingora", 34.7795, 72.3627, "Asia/Karachi"),
+    ("Volta Redonda", -22.5231, -44.1042, "America/Sao_Paulo"),
+    ("Ostrava", 49.8347, 18.2820, "Europe/Prague"),
+    ("St. Louis", 38.6273, -90.1979, "America/Chicago"),
+    ("Efon-Alaaye", 7.6565, 4.9223, "Africa/Lagos"),
+    ("Binjai", 3.6001, 98.4854, "Asia/Jakarta"),
+    ("Petrozavodsk", 61.7849, 34.3469, "Europe/Moscow"),
+    ("Enshi", 30.3000, 109.4833, "Asia/Shanghai"),
+    ("Taganrog", 47.2363, 38.9053, "Europe/Moscow"),
+    ("Santa Teresa del Tuy", 10.2329, -66.6647, "America/Caracas"),
+    ("South Dublin", 53.2903, -6.3415, "Europe/Dublin"),
+    ("Wiesbaden", 50.0860, 8.2444, "Europe/Berlin"),
+    ("Quảng Ngãi", 15.1205, 108.7923, "Asia/Ho_Chi_Minh"),
+    ("Qarshi", 38.8606, 65.7891, "Asia/Samarkand"),
+    ("Binhe", 32.6883, 112.8275, "Asia/Shanghai"),
+    ("Coacalco", 19.6292, -99.1069, "America/Mexico_City"),
+    ("Sincelejo", 9.3045, -75.3905, "America/Bogota"),
+    ("Zoucheng", 35.4006, 116.9656, "Asia/Shanghai"),
+    ("Khomeynī Shahr", 32.6856, 51.5361, "Asia/Tehran"),
+    ("Kostroma", 57.7664, 40.9283, "Europe/Moscow"),
+    ("Imphal", 24.8081, 93.9442, "Asia/Kolkata"),
+    ("Brighton", 50.8284, -0.1395, "Europe/London"),
+    ("Gagnoa", 6.1319, -5.9506, "Africa/Abidjan"),
+    ("Ulu Bedok", 1.3333, 103.9333, "Asia/Singapore"),
+    ("Bedok New Town", 1.3264, 103.9417, "Asia/Singapore"),
+    ("Owo", 7.1962, 5.5868, "Africa/Lagos"),
+    ("Suncheon", 34.9505, 127.4878, "Asia/Seoul"),
+    ("Fangchenggang", 21.7694, 108.3566, "Asia/Shanghai"),
+    ("Komsomolsk-on-Amur", 50.5503, 137.0100, "Asia/Vladivostok"),
+    ("Abbottabad", 34.1463, 73.2117, "Asia/Karachi"),
+    ("Kaili", 26.5858, 107.9797, "Asia/Shanghai"),
+    ("Hakodate", 41.7758, 140.7367, "Asia/Tokyo"),
+    ("Yamoussoukro", 6.8205, -5.2767, "Africa/Abidjan"),
+    ("Bab Ezzouar", 36.7261, 3.1829, "Africa/Algiers"),
+    ("Strasbourg", 48.5839, 7.7455, "Europe/Paris"),
+    ("Pematangsiantar", 2.9595, 99.0687, "Asia/Jakarta"),
+    ("Khmelnytskyi", 49.4183, 26.9794, "Europe/Kyiv"),
+    ("Saugor", 23.8388, 78.7387, "Asia/Kolkata"),
+    ("Neihu", 25.0815, 121.5881, "Asia/Taipei"),
+    ("Tsu", 34.7333, 136.5167, "Asia/Tokyo"),
+    ("Xingning", 24.1483, 115.7227, "Asia/Shanghai"),
+    ("Linxia Chengguanzhen", 35.6003, 103.2064, "Asia/Shanghai"),
+    ("Kushinagar", 26.7413, 83.8869, "Asia/Kolkata"),
+    ("Loja", -3.9931, -79.2042, "America/Guayaquil"),
+    ("Tai Po", 22.4501, 114.1688, "Asia/Hong_Kong"),
+    ("Isfara", 40.1265, 70.6253, "Asia/Dushanbe"),
+    ("Luena", -11.7833, 19.9167, "Africa/Luanda"),
+    ("Pasarkemis", -6.1703, 106.5303, "Asia/Jakarta"),
+    ("Taboão da Serra", -23.6261, -46.7917, "America/Sao_Paulo"),
+    ("Istaravshan", 39.9142, 69.0033, "Asia/Dushanbe"),
+    ("Turpan", 42.9477, 89.1789, "Asia/Urumqi"),
+    ("Rourkela", 22.2250, 84.8641, "Asia/Kolkata"),
+    ("Yao", 34.6167, 135.6000, "Asia/Tokyo"),
+    ("Banī Suwayf", 29.0744, 31.0979, "Africa/Cairo"),
+    ("Nagar Naluākot", 24.1561, 90.7728, "Asia/Dhaka"),
+    ("Guantánamo", 20.1444, -75.2092, "America/Havana"),
+    ("Baguio", 16.4164, 120.5931, "Asia/Manila"),
+    ("Petrópolis", -22.5050, -43.1786, "America/Sao_Paulo"),
+    ("Cherkasy", 49.4445, 32.0574, "Europe/Kyiv"),
+    ("Ar Rayyān", 25.2919, 51.4244, "Asia/Qatar"),
+    ("Polokwane", -23.9045, 29.4688, "Africa/Johannesburg"),
+    ("Ljubljana", 46.0511, 14.5051, "Europe/Ljubljana"),
+    ("Jalālābād", 34.4265, 70.4515, "Asia/Kabul"),
+    ("Deir ez-Zor", 35.3359, 40.1408, "Asia/Damascus"),
+    ("Gijón", 43.5357, -5.6615, "Europe/Madrid"),
+    ("Hafar Al-Batin", 28.4328, 45.9708, "Asia/Riyadh"),
+    ("Kakogawachō-honmachi", 34.7694, 134.8290, "Asia/Tokyo"),
+    ("Sector 5", 44.3881, 26.0714, "Europe/Bucharest"),
+    ("Quận Sáu", 10.7468, 106.6490, "Asia/Ho_Chi_Minh"),
+    ("Capao Redondo", -23.6688, -46.7805, "America/Sao_Paulo"),
+    ("Mỹ Tho", 10.3600, 106.3600, "Asia/Ho_Chi_Minh"),
+    ("Mito", 36.3500, 140.4500, "Asia/Tokyo"),
+    ("Derby", 52.9228, -1.4766, "Europe/London"),
+    ("Dessie", 11.1333, 39.6333, "Africa/Addis_Ababa"),
+    ("São José", -27.6153, -48.6275, "America/Sao_Paulo"),
+    ("Münster", 51.9624, 7.6257, "Europe/Berlin"),
+    ("Hạ Long", 20.9505, 107.0734, "Asia/Ho_Chi_Minh"),
+    ("Gelsenkirchen", 51.5051, 7.0965, "Europe/Berlin"),
+    ("Longling County", 24.5866, 98.6893, "Asia/Shanghai"),
+    ("Malolos", 14.8443, 120.8104, "Asia/Manila"),
+    ("Southampton", 50.9040, -1.4043, "Europe/London"),
+    ("Kapar", 3.1333, 101.3833, "Asia/Kuala_Lumpur"),
+    ("Çorum", 40.5489, 34.9533, "Europe/Istanbul"),
+    ("Ghulja", 43.9151, 81.3215, "Asia/Urumqi"),
+    ("Eimsbüttel", 53.5742, 9.9568, "Europe/Berlin"),
+    ("Merlo", -34.6654, -58.7274, "America/Argentina/Buenos_Aires"),
+    ("Yeosu", 34.7606, 127.6621, "Asia/Seoul"),
+    ("Durg", 21.1915, 81.2762, "Asia/Kolkata"),
+    ("Fuling", 29.7100, 107.3939, "Asia/Shanghai"),
+    ("Mokpo", 34.8128, 126.3918, "Asia/Seoul"),
+    ("Birgañj", 27.0174, 84.8805, "Asia/Kathmandu"),
+    ("Yoshkar-Ola", 56.6388, 47.8908, "Europe/Moscow"),
+    ("Russeifa", 32.0178, 36.0464, "Asia/Amman"),
+    ("Shibīn al Kawm", 30.5526, 31.0090, "Africa/Cairo"),
+    ("Parauapebas", -6.0675, -49.9022, "America/Belem"),
+    ("Mirpur Khas", 25.5276, 69.0126, "Asia/Karachi"),
+    ("Floridablanca", 7.0622, -73.0864, "America/Bogota"),
+    ("Tokushima", 34.0667, 134.5667, "Asia/Tokyo"),
+    ("Sterlitamak", 53.6379, 55.9533, "Asia/Yekaterinburg"),
+    ("Anantapur", 14.6778, 77.6081, "Asia/Kolkata"),
+    ("Adıyaman", 37.7644, 38.2763, "Europe/Istanbul"),
+    ("Parnamirim", -5.9156, -35.2628, "America/Fortaleza"),
+    ("Gyeongsan-si", 35.8233, 128.7378, "Asia/Seoul"),
+    ("Sohag", 26.5570, 31.6948, "Africa/Cairo"),
+    ("Nagaoka", 37.4500, 138.8500, "Asia/Tokyo"),
+    ("Sapopemba", -23.5956, -46.5247, "America/Sao_Paulo"),
+    ("Eixample", 41.3890, 2.1618, "Europe/Madrid"),
+    ("Bagerhat", 22.6566, 89.7912, "Asia/Dhaka"),
+    ("Saskatoon", 52.1324, -106.6689, "America/Regina"),
+    ("Chengzhong", 30.9445, 113.5528, "Asia/Shanghai"),
+    ("Djelfa", 34.6728, 3.2630, "Africa/Algiers"),
+    ("Chula Vista", 32.6401, -117.0842, "America/Los_Angeles"),
+    ("Shimonoseki", 33.9555, 130.9371, "Asia/Tokyo"),
+    ("Toledo", 41.6639, -83.5552, "America/New_York"),
+    ("Chernivtsi", 48.2904, 25.9324, "Europe/Kyiv"),
+    ("Kibaha", -6.7667, 38.9167, "Africa/Dar_es_Salaam"),
+    ("Hulan Ergi", 47.2042, 123.6333, "Asia/Shanghai"),
+    ("Tampines Estate", 1.3581, 103.9403, "Asia/Singapore"),
+    ("Bordeaux", 44.8412, -0.5805, "Europe/Paris"),
+    ("Aachen", 50.7766, 6.0834, "Europe/Berlin"),
+    ("Gent", 51.0500, 3.7167, "Europe/Brussels"),
+    ("Gravataí", -29.9422, -50.9928, "America/Sao_Paulo"),
+    ("Mantampay", 8.1667, 124.2167, "Asia/Manila"),
+    ("Ratlām", 23.3303, 75.0403, "Asia/Kolkata"),
+    ("Nogales", 31.3086, -110.9422, "America/Hermosillo"),
+    ("El Daein", 11.4619, 26.1258, "Africa/Khartoum"),
+    ("Donghai", 22.9459, 115.6420, "Asia/Shanghai"),
+    ("Dezfūl", 32.4167, 48.2167, "Asia/Tehran"),
+    ("Gunsan", 35.9786, 126.7114, "Asia/Seoul"),
+    ("Mossoró", -5.1875, -37.3442, "America/Fortaleza"),
+    ("Rānipet", 12.9247, 79.3333, "Asia/Kolkata"),
+    ("Brāhmanbāria", 23.9746, 91.1123, "Asia/Dhaka"),
+    ("Porto-Novo", 6.4965, 2.6036, "Africa/Porto-Novo"),
+    ("Jersey City", 40.7282, -74.0776, "America/New_York"),
+    ("Manta", -0.9494, -80.7314, "America/Guayaquil"),
+    ("Reno", 39.5296, -119.8138, "America/Los_Angeles"),
+    ("Bégoua", 4.4544, 18.5347, "Africa/Bangui"),
+    ("Riobamba", -1.6651, -78.6589, "America/Guayaquil"),
+    ("Sivas", 39.7483, 37.0161, "Europe/Istanbul"),
+    ("Wolverhampton", 52.5855, -2.1230, "Europe/London"),
+    ("Fanling", 22.4949, 114.1395, "Asia/Hong_Kong"),
+    ("Chiayi City", 23.4792, 120.4489, "Asia/Taipei"),
+    ("Dongtai", 32.8523, 120.3095, "Asia/Shanghai"),
+    ("Fuchū", 35.6745, 139.4822, "Asia/Tokyo"),
+    ("Jurong Town", 1.3342, 103.7228, "Asia/Singapore"),
+    ("Marāgheh", 37.3921, 46.2391, "Asia/Tehran"),
+    ("Marāgheh", 35.8292, 59.6332, "Asia/Tehran"),
+    ("Quilmes", -34.7206, -58.2545, "America/Argentina/Buenos_Aires"),
+    ("Fukui-shi", 36.0644, 136.2226, "Asia/Tokyo"),
+    ("Lal Bahadur Nagar", 17.3477, 78.5576, "Asia/Kolkata"),
+    ("Mönchengladbach", 51.1854, 6.4417, "Europe/Berlin"),
+    ("Bagong Silang", 14.7783, 121.0449, "Asia/Manila"),
+    ("Zhytomyr", 50.2623, 28.6791, "Europe/Kyiv"),
+    ("Karamay", 45.5847, 84.8872, "Asia/Urumqi"),
+    ("Sacomã", -23.6308, -46.5982, "America/Sao_Paulo"),
+    ("Arrah", 25.5563, 84.6633, "Asia/Kolkata"),
+    ("Tongliao", 43.6125, 122.2653, "Asia/Shanghai"),
+    ("Rangel", -8.8264, 13.2629, "Africa/Luanda"),
+    ("Bariadi", -2.8000, 33.9833, "Africa/Dar_es_Salaam"),
+    ("Antsirabe", -19.8659, 47.0333, "Indian/Antananarivo"),
+    ("Chandler", 33.3062, -111.8413, "America/Phoenix"),
+    ("Yei", 4.0944, 30.6764, "Africa/Juba"),
+    ("Chilanzar", 41.2819, 69.1811, "Asia/Tashkent"),
+    ("Tampere", 61.4991, 23.7871, "Europe/Helsinki"),
+    ("Mahajanga", -15.7167, 46.3167, "Indian/Antananarivo"),
+    ("Minato City", 35.6581, 139.7515, "Asia/Tokyo"),
+    ("Fort Wayne", 41.1306, -85.1289, "America/Indiana/Indianapolis"),
+    ("Plymouth", 50.3715, -4.1430, "Europe/London"),
+    ("Nianbo", 36.4800, 102.4164, "Asia/Shanghai"),
+    ("Baranagar", 22.6413, 88.3773, "Asia/Kolkata"),
+    ("Skardu", 35.2979, 75.6337, "Asia/Karachi"),
+    ("Marawi City", 8.0034, 124.2840, "Asia/Manila"),
+    ("Tampines New Town", 1.3492, 103.9497, "Asia/Singapore"),
+    ("Qo‘qon", 40.5286, 70.9425, "Asia/Tashkent"),
+    ("Sumy", 50.9174, 34.7991, "Europe/Kyiv"),
+    ("Jardim Sao Luis", -23.6807, -46.7394, "America/Sao_Paulo"),
+    ("Tacloban", 11.2433, 125.0047, "Asia/Manila"),
+    ("Augsburg", 48.3715, 10.8985, "Europe/Berlin"),
+    ("Rondonópolis", -16.4708, -54.6356, "America/Cuiaba"),
+    ("Changle", 36.7058, 118.8275, "Asia/Shanghai"),
+    ("Gajuwaka", 17.7000, 83.2167, "Asia/Kolkata"),
+    ("Jining", 41.0275, 113.1058, "Asia/Shanghai"),
+    ("North Shore", -36.8000, 174.7500, "Pacific/Auckland"),
+    ("Rishon LeTsiyyon", 31.9710, 34.7894, "Asia/Jerusalem"),
+    ("Hiratsuka", 35.3278, 139.3373, "Asia/Tokyo"),
+    ("Stoke-on-Trent", 53.0042, -2.1854, "Europe/London"),
+    ("Foz do Iguaçu", -25.5478, -54.5881, "America/Sao_Paulo"),
+    ("Buffalo", 42.8865, -78.8784, "America/New_York"),
+    ("Verona", 45.4385, 10.9938, "Europe/Rome"),
+    ("Ðà Lạt", 11.9465, 108.4419, "Asia/Ho_Chi_Minh"),
+    ("San Salvador de Jujuy", -24.1928, -65.2934, "America/Argentina/Jujuy"),
+    ("Durham", 35.9940, -78.8986, "America/New_York"),
+    ("Jurong West", 1.3503, 103.7228, "Asia/Singapore"),
+    ("Etāwah", 26.7762, 79.0213, "Asia/Kolkata"),
+    ("Gasteiz / Vitoria", 42.8500, -2.6727, "Europe/Madrid"),
+    ("Rugao", 32.3704, 120.5765, "Asia/Shanghai"),
+    ("Gómez Palacio", 25.5687, -103.4996, "America/Monterrey"),
+    ("Cascavel", -24.9558, -53.4553, "America/Sao_Paulo"),
+    ("St. Petersburg", 27.7709, -82.6793, "America/New_York"),
+    ("L'Hospitalet de Llobregat", 41.3597, 2.1003, "Europe/Madrid"),
+    ("Gdynia", 54.5189, 18.5319, "Europe/Warsaw"),
+    ("Cilacap", -7.7264, 109.0094, "Asia/Jakarta"),
+    ("Irvine", 33.6695, -117.8231, "America/Los_Angeles"),
+    ("Kitchener", 43.4254, -80.5112, "America/Toronto"),
+    ("Suicheng", 33.8963, 117.9331, "Asia/Shanghai"),
+    ("Nada", 19.5213, 109.5790, "Asia/Shanghai"),
+    ("Latina", 40.3890, -3.7457, "Europe/Madrid"),
+    ("Los Mochis", 25.7910, -108.9982, "America/Mazatlan"),
+    ("Bratsk", 56.1325, 101.6142, "Asia/Irkutsk"),
+    ("Pachuca de Soto", 20.1170, -98.7333, "America/Mexico_City"),
+    ("Chingola", -12.5290, 27.8838, "Africa/Lusaka"),
+    ("Milton Keynes", 52.0417, -0.7558, "Europe/London"),
+    ("Indaiatuba", -23.0884, -47.2119, "America/Sao_Paulo"),
+    ("Laredo", 27.5064, -99.5075, "America/Chicago"),
+    ("Germiston", -26.2348, 28.1766, "Africa/Johannesburg"),
+    ("Ñuñoa", -33.4474, -70.5828, "America/Santiago"),
+    ("Parakou", 9.3372, 2.6303, "Africa/Porto-Novo"),
+    ("Isiro", 2.7739, 27.6160, "Africa/Lubumbashi"),
+    ("Sari", 36.5633, 53.0601, "Asia/Tehran"),
+    ("Woodlands", 1.4380, 103.7888, "Asia/Singapore"),
+    ("Banda Aceh", 5.5417, 95.3333, "Asia/Jakarta"),
+    ("Oaxaca", 17.0602, -96.7254, "America/Mexico_City"),
+    ("Mossamedes", -15.1961, 12.1522, "Africa/Luanda"),
+    ("Al Madīnah", 30.9632, 47.2700, "Asia/Baghdad"),
+    ("Ikot Ekpene", 5.1819, 7.7148, "Africa/Lagos"),
+    ("Yanzhou", 35.5528, 116.8286, "Asia/Shanghai"),
+    ("Hanam", 37.5400, 127.2056, "Asia/Seoul"),
+    ("Mueang Nonthaburi", 13.8607, 100.5148, "Asia/Bangkok"),
+    ("Sorong", -0.8796, 131.2610, "Asia/Jayapura"),
+    ("Saint-Louis", 16.0179, -16.4896, "Africa/Dakar"),
+    ("Batu Caves", 3.2380, 101.6820, "Asia/Kuala_Lumpur"),
+    ("Novo Hamburgo", -29.6783, -51.1306, "America/Sao_Paulo"),
+    ("Cox’s Bāzār", 21.4397, 92.0096, "Asia/Dhaka"),
+    ("Carabanchel", 40.3909, -3.7242, "Europe/Madrid"),
+    ("Cotia", -23.6039, -46.9192, "America/Sao_Paulo"),
+    ("Petaẖ Tiqva", 32.0871, 34.8875, "Asia/Jerusalem"),
+    ("Marg‘ilon", 40.4724, 71.7246, "Asia/Tashkent"),
+    ("Ambarnath", 19.2000, 73.1667, "Asia/Kolkata"),
+    ("Godomè", 6.3895, 2.3458, "Africa/Porto-Novo"),
+    ("Naihāti", 22.8940, 88.4152, "Asia/Kolkata"),
+    ("Braşov", 45.6486, 25.6061, "Europe/Bucharest"),
+    ("Xuân Lộc", 10.9333, 107.2333, "Asia/Ho_Chi_Minh"),
+    ("Vitória da Conquista", -14.8661, -40.8394, "America/Bahia"),
+    ("Laohekou", 32.3858, 111.6678, "Asia/Shanghai"),
+    ("Richards Bay", -28.7830, 32.0377, "Africa/Johannesburg"),
+    ("Qina", 26.1642, 32.7267, "Africa/Cairo"),
+    ("Bharatpur", 27.2173, 77.4901, "Asia/Kolkata"),
+    ("Vantaa", 60.2941, 25.0410, "Europe/Helsinki"),
+    ("Porto", 41.1485, -8.6110, "Europe/Lisbon"),
+    ("Hobart", -42.8794, 147.3294, "Australia/Hobart"),
+    ("El Fasher", 13.6279, 25.3494, "Africa/Khartoum"),
+    ("Thiès Nones", 14.7833, -16.9667, "Africa/Dakar"),
+    ("Suihua", 46.6481, 126.9666, "Asia/Shanghai"),
+    ("Los Teques", 10.3495, -67.0427, "America/Caracas"),
+    ("Santiago del Estero", -27.8005, -64.2629, "America/Argentina/Cordoba"),
+    ("Sétif", 36.1911, 5.4137, "Africa/Algiers"),
+    ("Begusarai", 25.4185, 86.1339, "Asia/Kolkata"),
+    ("Borūjerd", 33.8973, 48.7516, "Asia/Tehran"),
+    ("Qarchak", 35.4287, 51.5776, "Asia/Tehran"),
+    ("Afyonkarahisar", 38.7567, 30.5433, "Europe/Istanbul"),
+    ("İskenderun", 36.5872, 36.1735, "Europe/Istanbul"),
+    ("Santiago de Surco", -12.1359, -77.0074, "America/Lima"),
+    ("Thika", -1.0333, 37.0693, "Africa/Nairobi"),
+    ("San Fernando", 15.0343, 120.6844, "Asia/Manila"),
+    ("Menongue", -14.6585, 17.6910, "Africa/Luanda"),
+    ("Holosiyiv", 50.3443, 30.5518, "Europe/Kyiv"),
+    ("Ḩayy Khildā", 31.9920, 35.8400, "Asia/Amman"),
+    ("Jimma", 7.6734, 36.8344, "Africa/Addis_Ababa"),
+    ("Governador Valadares", -18.8511, -41.9494, "America/Sao_Paulo"),
+    ("Timişoara", 45.7537, 21.2257, "Europe/Bucharest"),
+    ("Embu das Artes", -23.6489, -46.8522, "America/Sao_Paulo"),
+    ("Santa Clara", 22.4071, -79.9658, "America/Havana"),
+    ("Iwo", 7.6353, 4.1816, "Africa/Lagos"),
+    ("A Coruña", 43.3713, -8.3960, "Europe/Madrid"),
+    ("Libertad", 8.9442, 125.5019, "Asia/Manila"),
+    ("Altona", 53.5500, 9.9333, "Europe/Berlin"),
+    ("La Paz", 24.1423, -110.3132, "America/Mazatlan"),
+    ("Kelar", 34.6281, 45.3185, "Asia/Baghdad"),
+    ("Colonia del Valle", 19.3861, -99.1620, "America/Mexico_City"),
+    ("Niš", 43.3247, 21.9033, "Europe/Belgrade"),
+    ("Singa", 13.1483, 33.9312, "Africa/Khartoum"),
+    ("Tarakan", 3.3133, 117.5915, "Asia/Makassar"),
+    ("San Bernardo", -33.5922, -70.6996, "America/Santiago"),
+    ("Sōka", 35.8364, 139.7996, "Asia/Tokyo"),
+    ("Mzuzu", -11.4656, 34.0207, "Africa/Blantyre"),
+    ("Navotas", 14.6667, 120.9500, "Asia/Manila"),
+    ("Tiruvottiyūr", 13.1582, 80.3018, "Asia/Kolkata"),
+    ("Cidade Ademar", -23.6738, -46.6568, "America/Sao_Paulo"),
+    ("Burnaby", 49.2664, -122.9526, "America/Vancouver"),
+    ("Lubbock", 33.5779, -101.8552, "America/Chicago"),
+    ("Thị Trấn Đông Triều", 21.0824, 106.5138, "Asia/Ho_Chi_Minh"),
+    ("Thuqbah", 26.2602, 50.2049, "Asia/Riyadh"),
+    ("Yamagata", 38.2333, 140.3667, "Asia/Tokyo"),
+    ("Tehuacán", 18.4642, -97.3974, "America/Mexico_City"),
+    ("Guarenas", 10.4703, -66.6193, "America/Caracas"),
+    ("Montpellier", 43.6109, 3.8763, "Europe/Paris"),
+    ("Jimeta", 9.2795, 12.4582, "Africa/Lagos"),
+    ("Częstochowa", 50.7965, 19.1241, "Europe/Warsaw"),
+    ("Gāndhīdhām", 23.0833, 70.1333, "Asia/Kolkata"),
+    ("Beibei", 29.8274, 106.4364, "Asia/Shanghai"),
+    ("Insein", 16.8887, 96.1026, "Asia/Yangon"),
+    ("City of Westminster", 51.4975, -0.1357, "Europe/London"),
+    ("Gilbert", 33.3528, -111.7890, "America/Phoenix"),
+    ("Chemnitz", 50.8357, 12.9292, "Europe/Berlin"),
+    ("Aksaray", 38.3725, 34.0254, "Europe/Istanbul"),
+    ("Paraná", -31.7327, -60.5290, "America/Argentina/Cordoba"),
+    ("San Miguel", 13.4826, -88.1821, "America/El_Salvador"),
+    ("Probolinggo", -7.7543, 113.2159, "Asia/Jakarta"),
+    ("Shijie", 23.0955, 113.7919, "Asia/Shanghai"),
+    ("Chitato", -7.3000, 20.7333, "Africa/Luanda"),
+    ("Orsk", 51.2321, 58.4880, "Asia/Yekaterinburg"),
+    ("Vanderbijlpark", -26.7117, 27.8379, "Africa/Johannesburg"),
+    ("Coro", 11.4077, -69.6782, "America/Caracas"),
+    ("Americana", -22.7392, -47.3314, "America/Sao_Paulo"),
+    ("Kiel", 54.3213, 10.1349, "Europe/Berlin"),
+    ("Colombo", -25.2917, -49.2242, "America/Sao_Paulo"),
+    ("Hami", 42.8339, 93.5060, "Asia/Urumqi"),
+    ("Gaborone", -24.6545, 25.9086, "Africa/Gaborone"),
+    ("Al ‘Āshir min Ramaḑān", 30.2964, 31.7463, "Africa/Cairo"),
+    ("Singkawang", 0.9093, 108.9846, "Asia/Pontianak"),
+    ("Mau", 25.9417, 83.5611, "Asia/Kolkata"),
+    ("Puerto Montt", -41.4693, -72.9424, "America/Santiago"),
+    ("Northampton", 52.2500, -0.8833, "Europe/London"),
+    ("Juliaca", -15.5000, -70.1333, "America/Lima"),
+    ("Fuji", 35.1667, 138.6833, "Asia/Tokyo"),
+    ("Gyeongju", 35.8428, 129.2117, "Asia/Seoul"),
+    ("Rivne", 50.6204, 26.2370, "Europe/Kyiv"),
+    ("Sinfra", 6.6210, -5.9114, "Africa/Abidjan"),
+    ("Syktyvkar", 61.6639, 50.8163, "Europe/Moscow"),
+    ("Gorgān", 36.8427, 54.4439, "Asia/Tehran"),
+    ("Nizhnevartovsk", 60.9344, 76.5531, "Asia/Yekaterinburg"),
+    ("Groningen", 53.2192, 6.5667, "Europe/Amsterdam"),
+    ("Biratnagar", 26.4550, 87.2701, "Asia/Kathmandu"),
+    ("Braunschweig", 52.2659, 10.5267, "Europe/Berlin"),
+    ("Valera", 9.3178, -70.6036, "America/Caracas"),
+    ("Sengkang New Town", 1.3917, 103.8944, "Asia/Singapore"),
+    ("São José de Ribamar", -2.5557, -44.0599, "America/Fortaleza"),
+    ("Sīkar", 27.6121, 75.1400, "Asia/Kolkata"),
+    ("Bago", 17.3352, 96.4814, "Asia/Yangon"),
+    ("Baruta", 10.4342, -66.8756, "America/Caracas"),
+    ("Puente de Vallecas", 40.3935, -3.6620, "Europe/Madrid"),
+    ("Magé", -22.6528, -43.0406, "America/Sao_Paulo"),
+    ("Trabzon", 41.0050, 39.7269, "Europe/Istanbul"),
+    ("Tri-Cities", 46.2454, -119.1962, "America/Los_Angeles"),
+    ("Sambizanga", -8.7888, 13.2751, "Africa/Luanda"),
+    ("Jessore", 23.1697, 89.2137, "Asia/Dhaka"),
+    ("Manisa", 38.6120, 27.4265, "Europe/Istanbul"),
+    ("Arapiraca", -9.7525, -36.6611, "America/Maceio"),
+    ("Musaffah", 24.3589, 54.4827, "Asia/Dubai"),
+    ("Brasilandia", -23.4486, -46.6884, "America/Sao_Paulo"),
+    ("Sasebo", 33.1683, 129.7250, "Asia/Tokyo"),
+    ("Angarsk", 52.5597, 103.9141, "Asia/Irkutsk"),
+    ("Mar’ino", 55.6500, 37.7167, "Europe/Moscow"),
+    ("Ramagundam", 18.7550, 79.4740, "Asia/Kolkata"),
+    ("Hāpur", 28.7298, 77.7807, "Asia/Kolkata"),
+    ("Chigasaki", 35.3364, 139.4043, "Asia/Tokyo"),
+    ("Chōfu", 35.6592, 139.5484, "Asia/Tokyo"),
+    ("Huayin", 34.5653, 110.0664, "Asia/Shanghai"),
+    ("Berbera", 10.4396, 45.0143, "Africa/Mogadishu"),
+    ("Jiyuan", 35.0891, 112.5881, "Asia/Shanghai"),
+    ("Yamato", 35.4728, 139.4510, "Asia/Tokyo"),
+    ("Man", 7.4125, -7.5538, "Africa/Abidjan"),
+    ("Bahawalnagar", 29.9983, 73.2527, "Asia/Karachi"),
+    ("Novorossiysk", 44.7319, 37.7618, "Europe/Moscow"),
+    ("Tsukuba", 36.0833, 140.1167, "Asia/Tokyo"),
+    ("Arica", -18.4755, -70.3006, "America/Santiago"),
+    ("Peñalolén", -33.4684, -70.5341, "America/Santiago"),
+    ("Hải Dương", 20.9410, 106.3330, "Asia/Ho_Chi_Minh"),
+    ("Winston-Salem", 36.0999, -80.2442, "America/New_York"),
+    ("Farrukhābād", 27.3913, 79.5793, "Asia/Kolkata"),
+    ("Matsumoto", 36.2333, 137.9667, "Asia/Tokyo"),
+    ("Sancaktepe", 41.0024, 29.2319, "Europe/Istanbul"),
+    ("Alappuzha", 9.4900, 76.3264, "Asia/Kolkata"),
+    ("Itapevi", -23.5489, -46.9342, "America/Sao_Paulo"),
+    ("Katihar", 25.5385, 87.5704, "Asia/Kolkata"),
+    ("Marília", -22.2139, -49.9458, "America/Sao_Paulo"),
+    ("Buenaventura", 3.8801, -77.0312, "America/Bogota"),
+    ("Lankaran", 38.7543, 48.8506, "Asia/Baku"),
+    ("Ciudad Ojeda", 10.2016, -71.3148, "America/Caracas"),
+    ("Glendale", 33.5386, -112.1860, "America/Phoenix"),
+    ("Bunkyo", 35.5331, 139.4217, "Asia/Tokyo"),
+    ("Itaboraí", -22.7444, -42.8594, "America/Sao_Paulo"),
+    ("Klang", 3.0367, 101.4433, "Asia/Kuala_Lumpur"),
+    ("Almendares", 23.1101, -82.4232, "America/Havana"),
+    ("Golestān", 35.5183, 51.1819, "Asia/Tehran"),
+    ("Vyhurivshchyna-Troieshchyna", 50.5114, 30.6025, "Europe/Kyiv"),
+    ("Khimki", 55.9001, 37.4285, "Europe/Moscow"),
+    ("Horlivka", 48.3358, 38.0532, "Europe/Kyiv"),
+    ("Nacala", -14.5626, 40.6854, "Africa/Maputo"),
+    ("Xiantao", 30.3708, 113.4429, "Asia/Shanghai"),
+    ("Nalchik", 43.4981, 43.6189, "Europe/Moscow"),
+    ("Hachinohe", 40.5000, 141.5000, "Asia/Tokyo"),
+    ("Osan", 37.1522, 127.0706, "Asia/Seoul"),
+    ("Fuencarral", 40.5000, -3.6833, "Europe/Madrid"),
+    ("Lille", 50.6339, 3.0551, "Europe/Paris"),
+    ("Neyagawa", 34.7662, 135.6276, "Asia/Tokyo"),
+    ("Ormoc", 11.0064, 124.6075, "Asia/Manila"),
+    ("Magdalena Contreras", 19.3321, -99.2112, "America/Mexico_City"),
+    ("Nāgarpur", 24.0578, 89.8770, "Asia/Dhaka"),
+    ("Kasulu", -4.5767, 30.1025, "Africa/Dar_es_Salaam"),
+    ("Ngaoundéré", 7.3277, 13.5847, "Africa/Douala"),
+    ("Ivano-Frankivsk", 48.9231, 24.7125, "Europe/Kyiv"),
+    ("Cabo Frio", -22.8872, -42.0262, "America/Sao_Paulo"),
+    ("Balıkesir", 39.6492, 27.8861, "Europe/Istanbul"),
+    ("Temuco", -38.7363, -72.5974, "America/Santiago"),
+    ("Norfolk", 36.8468, -76.2852, "America/New_York"),
+    ("Krefeld", 51.3364, 6.5538, "Europe/Berlin"),
+    ("Halle (Saale)", 51.4816, 11.9795, "Europe/Berlin"),
+    ("Juazeiro", -9.4117, -40.4986, "America/Bahia"),
+    ("Sri Ganganagar", 29.9201, 73.8750, "Asia/Kolkata"),
+    ("Amarapura", 21.9071, 96.0489, "Asia/Yangon"),
+    ("Āmol", 36.4696, 52.3507, "Asia/Tehran"),
+    ("Athlone", -33.9672, 18.5021, "Africa/Johannesburg"),
+    ("Batangas", 13.7567, 121.0584, "Asia/Manila"),
+    ("Al Jubayl", 27.0174, 49.6225, "Asia/Riyadh"),
+    ("Tŏkch’ŏn", 39.7526, 126.2580, "Asia/Pyongyang"),
+    ("Oldham", 53.5405, -2.1183, "Europe/London"),
+    ("Pathein", 16.7792, 94.7321, "Asia/Yangon"),
+    ("Hialeah", 25.8576, -80.2781, "America/New_York"),
+    ("Sylhet", 24.8990, 91.8720, "Asia/Dhaka"),
+    ("Paarl", -33.7338, 18.9752, "Africa/Johannesburg"),
+    ("Garland", 32.9126, -96.6389, "America/Chicago"),
+    ("Podgorica", 42.4412, 19.2631, "Europe/Podgorica"),
+    ("Scottsdale", 33.5092, -111.8990, "America/Phoenix"),
+    ("Irving", 32.8140, -96.9489, "America/Chicago"),
+    ("Centurion", -25.8589, 28.1858, "Africa/Johannesburg"),
+    ("Qingzhou", 36.6967, 118.4797, "Asia/Shanghai"),
+    ("Pākdasht", 35.4785, 51.6834, "Asia/Tehran"),
+    ("Kajang", 2.9942, 101.7887, "Asia/Kuala_Lumpur"),
+    ("Padangsidempuan", 1.3795, 99.2715, "Asia/Jakarta"),
+    ("Campo Limpo", -23.6363, -46.7657, "America/Sao_Paulo"),
+    ("Yuci", 37.6803, 112.7319, "Asia/Shanghai"),
+    ("Magdeburg", 52.1313, 11.6319, "Europe/Berlin"),
+    ("Sant Martí", 41.4181, 2.1993, "Europe/Madrid"),
+    ("Eindhoven", 51.4408, 5.4778, "Europe/Amsterdam"),
+    ("Boise", 43.6135, -116.2035, "America/Boise"),
+    ("Rewa", 24.5326, 81.2923, "Asia/Kolkata"),
+    ("Yakutsk", 62.0311, 129.7229, "Asia/Yakutsk"),
+    ("Bole", 44.8933, 82.0699, "Asia/Urumqi"),
+    ("Muzaffargarh", 30.0726, 71.1938, "Asia/Karachi"),
+    ("Chesapeake", 36.8190, -76.2749, "America/New_York"),
+    ("Uluberiya", 22.4756, 88.0990, "Asia/Kolkata"),
+    ("Dali", 25.5847, 100.2123, "Asia/Shanghai"),
+    ("Thành Phố Bà Rịa", 10.4963, 107.1685, "Asia/Ho_Chi_Minh"),
+    ("Kediri", -7.8167, 112.0167, "Asia/Jakarta"),
+    ("Georgetown", 6.8045, -58.1553, "America/Guyana"),
+    ("Kismayo", -0.3582, 42.5454, "Africa/Mogadishu"),
+    ("Türkmenabat", 39.0733, 63.5786, "Asia/Ashgabat"),
+    ("North Las Vegas", 36.1989, -115.1175, "America/Los_Angeles"),
+    ("Elche", 38.2622, -0.7011, "Europe/Madrid"),
+    ("Sivakasi", 9.4500, 77.7980, "Asia/Kolkata"),
+    ("Kindu", -2.9437, 25.9224, "Africa/Lubumbashi"),
+    ("Maracanaú", -3.8767, -38.6256, "America/Fortaleza"),
+    ("Andorra", 42.5000, 1.5167, "Europe/Andorra"),
+    ("Dubai", 25.3000, 55.3000, "Asia/Dubai"),
+    ("Kabul", 34.5167, 69.2000, "Asia/Kabul"),
+    ("Antigua", 17.0500, -61.8000, "America/Antigua"),
+    ("Anguilla", 18.2000, -63.0667, "America/Anguilla"),
+    ("Tirane", 41.3333, 19.8333, "Europe/Tirane"),
+    ("Yerevan", 40.1833, 44.5000, "Asia/Yerevan"),
+    ("Luanda", -8.8000, 13.2333, "Africa/Luanda"),
+    ("McMurdo", -77.8333, 166.6000, "Antarctica/McMurdo"),
+    ("Casey", -66.2833, 110.5167, "Antarctica/Casey"),
+    ("Davis", -68.5833, 77.9667, "Antarctica/Davis"),
+    ("DumontDUrville", -66.6667, 140.0167, "Antarctica/DumontDUrville"),
+    ("Mawson", -67.6000, 62.8833, "Antarctica/Mawson"),
+    ("Palmer", -64.8000, -64.1000, "Antarctica/Palmer"),
+    ("Rothera", -67.5667, -68.1333, "Antarctica/Rothera"),
+    ("Syowa", -69.0061, 39.5900, "Antarctica/Syowa"),
+    ("Troll", -72.0114, 2.5350, "Antarctica/Troll"),
+    ("Vostok", -78.4000, 106.9000, "Antarctica/Vostok"),
+    ("Buenos Aires", -34.6000, -58.4500, "America/Argentina/Buenos_Aires"),
+    ("Cordoba", -31.4000, -64.1833, "America/Argentina/Cordoba"),
+    ("Salta", -24.7833, -65.4167, "America/Argentina/Salta"),
+    ("Jujuy", -24.1833, -65.3000, "America/Argentina/Jujuy"),
+    ("Tucuman", -26.8167, -65.2167, "America/Argentina/Tucuman"),
+    ("Catamarca", -28.4667, -65.7833, "America/Argentina/Catamarca"),
+    ("La Rioja", -29.4333, -66.8500, "America/Argentina/La_Rioja"),
+    ("San Juan", -31.5333, -68.5167, "America/Argentina/San_Juan"),
+    ("Mendoza", -32.8833, -68.8167, "America/Argentina/Mendoza"),
+    ("San Luis", -33.3167, -66.3500, "America/Argentina/San_Luis"),
+    ("Rio Gallegos", -51.6333, -69.2167, "America/Argentina/Rio_Gallegos"),
+    ("Ushuaia", -54.8000, -68.3000, "America/Argentina/Ushuaia"),
+    ("Pago Pago", -14.2667, -170.7000, "Pacific/Pago_Pago"),
+    ("Vienna", 48.2167, 16.3333, "Europe/Vienna"),
+    ("Lord Howe", -31.5500, 159.0833, "Australia/Lord_Howe"),
+    ("Macquarie", -54.5000, 158.9500, "Antarctica/Macquarie"),
+    ("Hobart", -42.8833, 147.3167, "Australia/Hobart"),
+    ("Melbourne", -37.8167, 144.9667, "Australia/Melbourne"),
+    ("Sydney", -33.8667, 151.2167, "Australia/Sydney"),
+    ("Broken Hill", -31.9500, 141.4500, "Australia/Broken_Hill"),
+    ("Brisbane", -27.4667, 153.0333, "Australia/Brisbane"),
+    ("Lindeman", -20.2667, 149.0000, "Australia/Lindeman"),
+    ("Adelaide", -34.9167, 138.5833, "Australia/Adelaide"),
+    ("Darwin", -12.4667, 130.8333, "Australia/Darwin"),
+    ("Perth", -31.9500, 115.8500, "Australia/Perth"),
+    ("Eucla", -31.7167, 128.8667, "Australia/Eucla"),
+    ("Aruba", 12.5000, -69.9667, "America/Aruba"),
+    ("Mariehamn", 60.1000, 19.9500, "Europe/Mariehamn"),
+    ("Baku", 40.3833, 49.8500, "Asia/Baku"),
+    ("Sarajevo", 43.8667, 18.4167, "Europe/Sarajevo"),
+    ("Barbados", 13.1000, -59.6167, "America/Barbados"),
+    ("Dhaka", 23.7167, 90.4167, "Asia/Dhaka"),
+    ("Brussels", 50.8333, 4.3333, "Europe/Brussels"),
+    ("Ouagadougou", 12.3667, -1.5167, "Africa/Ouagadougou"),
+    ("Sofia", 42.6833, 23.3167, "Europe/Sofia"),
+    ("Bahrain", 26.3833, 50.5833, "Asia/Bahrain"),
+    ("Bujumbura", -3.3833, 29.3667, "Africa/Bujumbura"),
+    ("Porto-Novo", 6.4833, 2.6167, "Africa/Porto-Novo"),
+    ("St Barthelemy", 17.8833, -62.8500, "America/St_Barthelemy"),
+    ("Bermuda", 32.2833, -64.7667, "Atlantic/Bermuda"),
+    ("Brunei", 4.9333, 114.9167, "Asia/Brunei"),
+    ("La Paz", -16.5000, -68.1500, "America/La_Paz"),
+    ("Kralendijk", 12.1508, -68.2767, "America/Kralendijk"),
+    ("Noronha", -3.8500, -32.4167, "America/Noronha"),
+    ("Belem", -1.4500, -48.4833, "America/Belem"),
+    ("Fortaleza", -3.7167, -38.5000, "America/Fortaleza"),
+    ("Recife", -8.0500, -34.9000, "America/Recife"),
+    ("Araguaina", -7.2000, -48.2000, "America/Araguaina"),
+    ("Maceio", -9.6667, -35.7167, "America/Maceio"),
+    ("Bahia", -12.9833, -38.5167, "America/Bahia"),
+    ("Sao Paulo", -23.5333, -46.6167, "America/Sao_Paulo"),
+    ("Campo Grande", -20.4500, -54.6167, "America/Campo_Grande"),
+    ("Cuiaba", -15.5833, -56.0833, "America/Cuiaba"),
+    ("Santarem", -2.4333, -54.8667, "America/Santarem"),
+    ("Porto Velho", -8.7667, -63.9000, "America/Porto_Velho"),
+    ("Boa Vista", 2.8167, -60.6667, "America/Boa_Vista"),
+    ("Manaus", -3.1333, -60.0167, "America/Manaus"),
+    ("Eirunepe", -6.6667, -69.8667, "America/Eirunepe"),
+    ("Rio Branco", -9.9667, -67.8000, "America/Rio_Branco"),
+    ("Nassau", 25.0833, -77.3500, "America/Nassau"),
+    ("Thimphu", 27.4667, 89.6500, "Asia/Thimphu"),
+    ("Gaborone", -24.6500, 25.9167, "Africa/Gaborone"),
+    ("Minsk", 53.9000, 27.5667, "Europe/Minsk"),
+    ("Belize", 17.5000, -88.2000, "America/Belize"),
+    ("St Johns", 47.5667, -52.7167, "America/St_Johns"),
+    ("Halifax", 44.6500, -63.6000, "America/Halifax"),
+    ("Glace Bay", 46.2000, -59.9500, "America/Glace_Bay"),
+    ("Moncton", 46.1000, -64.7833, "America/Moncton"),
+    ("Goose Bay", 53.3333, -60.4167, "America/Goose_Bay"),
+    ("Blanc-Sablon", 51.4167, -57.1167, "America/Blanc-Sablon"),
+    ("Toronto", 43.6500, -79.3833, "America/Toronto"),
+    ("Iqaluit", 63.7333, -68.4667, "America/Iqaluit"),
+    ("Atikokan", 48.7586, -91.6217, "America/Atikokan"),
+    ("Winnipeg", 49.8833, -97.1500, "America/Winnipeg"),
+    ("Resolute", 74.6956, -94.8292, "America/Resolute"),
+    ("Rankin Inlet", 62.8167, -92.0831, "America/Rankin_Inlet"),
+    ("Regina", 50.4000, -104.6500, "America/Regina"),
+    ("Swift Current", 50.2833, -107.8333, "America/Swift_Current"),
+    ("Edmonton", 53.5500, -113.4667, "America/Edmonton"),
+    ("Cambridge Bay", 69.1139, -105.0528, "America/Cambridge_Bay"),
+    ("Inuvik", 68.3497, -133.7167, "America/Inuvik"),
+    ("Creston", 49.1000, -116.5167, "America/Creston"),
+    ("Dawson Creek", 55.7667, -120.2333, "America/Dawson_Creek"),
+    ("Fort Nelson", 58.8000, -122.7000, "America/Fort_Nelson"),
+    ("Whitehorse", 60.7167, -135.0500, "America/Whitehorse"),
+    ("Dawson", 64.0667, -139.4167, "America/Dawson"),
+    ("Vancouver", 49.2667, -123.1167, "America/Vancouver"),
+    ("Cocos", -12.1667, 96.9167, "Indian/Cocos"),
+    ("Kinshasa", -4.3000, 15.3000, "Africa/Kinshasa"),
+    ("Lubumbashi", -11.6667, 27.4667, "Africa/Lubumbashi"),
+    ("Bangui", 4.3667, 18.5833, "Africa/Bangui"),
+    ("Brazzaville", -4.2667, 15.2833, "Africa/Brazzaville"),
+    ("Zurich", 47.3833, 8.5333, "Europe/Zurich"),
+    ("Abidjan", 5.3167, -4.0333, "Africa/Abidjan"),
+    ("Rarotonga", -21.2333, -159.7667, "Pacific/Rarotonga"),
+    ("Santiago", -33.4500, -70.6667, "America/Santiago"),
+    ("Coyhaique", -45.5667, -72.0667, "America/Coyhaique"),
+    ("Punta Arenas", -53.1500, -70.9167, "America/Punta_Arenas"),
+    ("Easter", -27.1500, -109.4333, "Pacific/Easter"),
+    ("Douala", 4.0500, 9.7000, "Africa/Douala"),
+    ("Shanghai", 31.2333, 121.4667, "Asia/Shanghai"),
+    ("Urumqi", 43.8000, 87.5833, "Asia/Urumqi"),
+    ("Bogota", 4.6000, -74.0833, "America/Bogota"),
+    ("Costa Rica", 9.9333, -84.0833, "America/Costa_Rica"),
+    ("Havana", 23.1333, -82.3667, "America/Havana"),
+    ("Cape Verde", 14.9167, -23.5167, "Atlantic/Cape_Verde"),
+    ("Curacao", 12.1833, -69.0000, "America/Curacao"),
+    ("Christmas", -10.4167, 105.7167, "Indian/Christmas"),
+    ("Nicosia", 35.1667, 33.3667, "Asia/Nicosia"),
+    ("Famagusta", 35.1167, 33.9500, "Asia/Famagusta"),
+    ("Prague", 50.0833, 14.4333, "Europe/Prague"),
+    ("Berlin", 52.5000, 13.3667, "Europe/Berlin"),
+    ("Busingen", 47.7000, 8.6833, "Europe/Busingen"),
+    ("Djibouti", 11.6000, 43.1500, "Africa/Djibouti"),
+    ("Copenhagen", 55.6667, 12.5833, "Europe/Copenhagen"),
+    ("Dominica", 15.3000, -61.4000, "America/Dominica"),
+    ("Santo Domingo", 18.4667, -69.9000, "America/Santo_Domingo"),
+    ("Algiers", 36.7833, 3.0500, "Africa/Algiers"),
+    ("Guayaquil", -2.1667, -79.8333, "America/Guayaquil"),
+    ("Galapagos", -0.9000, -89.6000, "Pacific/Galapagos"),
+    ("Tallinn", 59.4167, 24.7500, "Europe/Tallinn"),
+    ("Cairo", 30.0500, 31.2500, "Africa/Cairo"),
+    ("El Aaiun", 27.1500, -13.2000, "Africa/El_Aaiun"),
+    ("Asmara", 15.3333, 38.8833, "Africa/Asmara"),
+    ("Madrid", 40.4000, -3.6833, "Europe/Madrid"),
+    ("Ceuta", 35.8833, -5.3167, "Africa/Ceuta"),
+    ("Canary", 28.1000, -15.4000, "Atlantic/Canary"),
+    ("Addis Ababa", 9.0333, 38.7000, "Africa/Addis_Ababa"),
+    ("Helsinki", 60.1667, 24.9667, "Europe/Helsinki"),
+    ("Fiji", -18.1333, 178.4167, "Pacific/Fiji"),
+    ("Stanley", -51.7000, -57.8500, "Atlantic/Stanley"),
+    ("Chuuk", 7.4167, 151.7833, "Pacific/Chuuk"),
+    ("Pohnpei", 6.9667, 158.2167, "Pacific/Pohnpei"),
+    ("Kosrae", 5.3167, 162.9833, "Pacific/Kosrae"),
+    ("Faroe", 62.0167, -6.7667, "Atlantic/Faroe"),
+    ("Paris", 48.8667, 2.3333, "Europe/Paris"),
+    ("Libreville", 0.3833, 9.4500, "Africa/Libreville"),
+    ("London", 51.5083, -0.1253, "Europe/London"),
+    ("Grenada", 12.0500, -61.7500, "America/Grenada"),
+    ("Tbilisi", 41.7167, 44.8167, "Asia/Tbilisi"),
+    ("Cayenne", 4.9333, -52.3333, "America/Cayenne"),
+    ("Guernsey", 49.4547, -2.5361, "Europe/Guernsey"),
+    ("Accra", 5.5500, -0.2167, "Africa/Accra"),
+    ("Gibraltar", 36.1333, -5.3500, "Europe/Gibraltar"),
+    ("Nuuk", 64.1833, -51.7333, "America/Nuuk"),
+    ("Danmarkshavn", 76.7667, -18.6667, "America/Danmarkshavn"),
+    ("Scoresbysund", 70.4833, -21.9667, "America/Scoresbysund"),
+    ("Thule", 76.5667, -68.7833, "America/Thule"),
+    ("Banjul", 13.4667, -16.6500, "Africa/Banjul"),
+    ("Conakry", 9.5167, -13.7167, "Africa/Conakry"),
+    ("Guadeloupe", 16.2333, -61.5333, "America/Guadeloupe"),
+    ("Malabo", 3.7500, 8.7833, "Africa/Malabo"),
+    ("Athens", 37.9667, 23.7167, "Europe/Athens"),
+    ("South Georgia", -54.2667, -36.5333, "Atlantic/South_Georgia"),
+    ("Guatemala", 14.6333, -90.5167, "America/Guatemala"),
+    ("Guam", 13.4667, 144.7500, "Pacific/Guam"),
+    ("Bissau", 11.8500, -15.5833, "Africa/Bissau"),
+    ("Guyana", 6.8000, -58.1667, "America/Guyana"),
+    ("Hong Kong", 22.2833, 114.1500, "Asia/Hong_Kong"),
+    ("Tegucigalpa", 14.1000, -87.2167, "America/Tegucigalpa"),
+    ("Zagreb", 45.8000, 15.9667, "Europe/Zagreb"),
+    ("Port-au-Prince", 18.5333, -72.3333, "America/Port-au-Prince"),
+    ("Budapest", 47.5000, 19.0833, "Europe/Budapest"),
+    ("Jakarta", -6.1667, 106.8000, "Asia/Jakarta"),
+    ("Pontianak", -0.0333, 109.3333, "Asia/Pontianak"),
+    ("Makassar", -5.1167, 119.4000, "Asia/Makassar"),
+    ("Jayapura", -2.5333, 140.7000, "Asia/Jayapura"),
+    ("Dublin", 53.3333, -6.2500, "Europe/Dublin"),
+    ("Jerusalem", 31.7806, 35.2239, "Asia/Jerusalem"),
+    ("Isle of Man", 54.1500, -4.4667, "Europe/Isle_of_Man"),
+    ("Kolkata", 22.5333, 88.3667, "Asia/Kolkata"),
+    ("Chagos", -7.3333, 72.4167, "Indian/Chagos"),
+    ("Baghdad", 33.3500, 44.4167, "Asia/Baghdad"),
+    ("Tehran", 35.6667, 51.4333, "Asia/Tehran"),
+    ("Reykjavik", 64.1500, -21.8500, "Atlantic/Reykjavik"),
+    ("Rome", 41.9000, 12.4833, "Europe/Rome"),
+    ("Jersey", 49.1836, -2.1067, "Europe/Jersey"),
+    ("Jamaica", 17.9681, -76.7933, "America/Jamaica"),
+    ("Amman", 31.9500, 35.9333, "Asia/Amman"),
+    ("Tokyo", 35.6544, 139.7447, "Asia/Tokyo"),
+    ("Nairobi", -1.2833, 36.8167, "Africa/Nairobi"),
+    ("Bishkek", 42.9000, 74.6000, "Asia/Bishkek"),
+    ("Phnom Penh", 11.5500, 104.9167, "Asia/Phnom_Penh"),
+    ("Tarawa", 1.4167, 173.0000, "Pacific/Tarawa"),
+    ("Kanton", -2.7833, -171.7167, "Pacific/Kanton"),
+    ("Kiritimati", 1.8667, -157.3333, "Pacific/Kiritimati"),
+    ("Comoro", -11.6833, 43.2667, "Indian/Comoro"),
+    ("St Kitts", 17.3000, -62.7167, "America/St_Kitts"),
+    ("Pyongyang", 39.0167, 125.7500, "Asia/Pyongyang"),
+    ("Seoul", 37.5500, 126.9667, "Asia/Seoul"),
+    ("Kuwait", 29.3333, 47.9833, "Asia/Kuwait"),
+    ("Cayman", 19.3000, -81.3833, "America/Cayman"),
+    ("Almaty", 43.2500, 76.9500, "Asia/Almaty"),
+    ("Qyzylorda", 44.8000, 65.4667, "Asia/Qyzylorda"),
+    ("Qostanay", 53.2000, 63.6167, "Asia/Qostanay"),
+    ("Aqtobe", 50.2833, 57.1667, "Asia/Aqtobe"),
+    ("Aqtau", 44.5167, 50.2667, "Asia/Aqtau"),
+    ("Atyrau", 47.1167, 51.9333, "Asia/Atyrau"),
+    ("Oral", 51.2167, 51.3500, "Asia/Oral"),
+    ("Vientiane", 17.9667, 102.6000, "Asia/Vientiane"),
+    ("Beirut", 33.8833, 35.5000, "Asia/Beirut"),
+    ("St Lucia", 14.0167, -61.0000, "America/St_Lucia"),
+    ("Vaduz", 47.1500, 9.5167, "Europe/Vaduz"),
+    ("Colombo", 6.9333, 79.8500, "Asia/Colombo"),
+    ("Monrovia", 6.3000, -10.7833, "Africa/Monrovia"),
+    ("Maseru", -29.4667, 27.5000, "Africa/Maseru"),
+    ("Vilnius", 54.6833, 25.3167, "Europe/Vilnius"),
+    ("Luxembourg", 49.6000, 6.1500, "Europe/Luxembourg"),
+    ("Riga", 56.9500, 24.1000, "Europe/Riga"),
+    ("Tripoli", 32.9000, 13.1833, "Africa/Tripoli"),
+    ("Casablanca", 33.6500, -7.5833, "Africa/Casablanca"),
+    ("Monaco", 43.7000, 7.3833, "Europe/Monaco"),
+    ("Chisinau", 47.0000, 28.8333, "Europe/Chisinau"),
+    ("Podgorica", 42.4333, 19.2667, "Europe/Podgorica"),
+    ("Marigot", 18.0667, -63.0833, "America/Marigot"),
+    ("Antananarivo", -18.9167, 47.5167, "Indian/Antananarivo"),
+    ("Majuro", 7.1500, 171.2000, "Pacific/Majuro"),
+    ("Kwajalein", 9.0833, 167.3333, "Pacific/Kwajalein"),
+    ("Skopje", 41.9833, 21.4333, "Europe/Skopje"),
+    ("Bamako", 12.6500, -8.0000, "Africa/Bamako"),
+    ("Yangon", 16.7833, 96.1667, "Asia/Yangon"),
+    ("Ulaanbaatar", 47.9167, 106.8833, "Asia/Ulaanbaatar"),
+    ("Hovd", 48.0167, 91.6500, "Asia/Hovd"),
+    ("Macau", 22.1972, 113.5417, "Asia/Macau"),
+    ("Saipan", 15.2000, 145.7500, "Pacific/Saipan"),
+    ("Martinique", 14.6000, -61.0833, "America/Martinique"),
+    ("Nouakchott", 18.1000, -15.9500, "Africa/Nouakchott"),
+    ("Montserrat", 16.7167, -62.2167, "America/Montserrat"),
+    ("Malta", 35.9000, 14.5167, "Europe/Malta"),
+    ("Mauritius", -20.1667, 57.5000, "Indian/Mauritius"),
+    ("Maldives", 4.1667, 73.5000, "Indian/Maldives"),
+    ("Blantyre", -15.7833, 35.0000, "Africa/Blantyre"),
+    ("Mexico City", 19.4000, -99.1500, "America/Mexico_City"),
+    ("Cancun", 21.0833, -86.7667, "America/Cancun"),
+    ("Merida", 20.9667, -89.6167, "America/Merida"),
+    ("Monterrey", 25.6667, -100.3167, "America/Monterrey"),
+    ("Matamoros", 25.8333, -97.5000, "America/Matamoros"),
+    ("Chihuahua", 28.6333, -106.0833, "America/Chihuahua"),
+    ("Ciudad Juarez", 31.7333, -106.4833, "America/Ciudad_Juarez"),
+    ("Ojinaga", 29.5667, -104.4167, "America/Ojinaga"),
+    ("Mazatlan", 23.2167, -106.4167, "America/Mazatlan"),
+    ("Bahia Banderas", 20.8000, -105.2500, "America/Bahia_Banderas"),
+    ("Hermosillo", 29.0667, -110.9667, "America/Hermosillo"),
+    ("Tijuana", 32.5333, -117.0167, "America/Tijuana"),
+    ("Kuala Lumpur", 3.1667, 101.7000, "Asia/Kuala_Lumpur"),
+    ("Kuching", 1.5500, 110.3333, "Asia/Kuching"),
+    ("Maputo", -25.9667, 32.5833, "Africa/Maputo"),
+    ("Windhoek", -22.5667, 17.1000, "Africa/Windhoek"),
+    ("Noumea", -22.2667, 166.4500, "Pacific/Noumea"),
+    ("Niamey", 13.5167, 2.1167, "Africa/Niamey"),
+    ("Norfolk", -29.0500, 167.9667, "Pacific/Norfolk"),
+    ("Lagos", 6.4500, 3.4000, "Africa/Lagos"),
+    ("Managua", 12.1500, -86.2833, "America/Managua"),
+    ("Amsterdam", 52.3667, 4.9000, "Europe/Amsterdam"),
+    ("Oslo", 59.9167, 10.7500, "Europe/Oslo"),
+    ("Kathmandu", 27.7167, 85.3167, "Asia/Kathmandu"),
+    ("Nauru", -0.5167, 166.9167, "Pacific/Nauru"),
+    ("Niue", -19.0167, -169.9167, "Pacific/Niue"),
+    ("Auckland", -36.8667, 174.7667, "Pacific/Auckland"),
+    ("Chatham", -43.9500, -176.5500, "Pacific/Chatham"),
+    ("Muscat", 23.6000, 58.5833, "Asia/Muscat"),
+    ("Panama", 8.9667, -79.5333, "America/Panama"),
+    ("Lima", -12.0500, -77.0500, "America/Lima"),
+    ("Tahiti", -17.5333, -149.5667, "Pacific/Tahiti"),
+    ("Marquesas", -9.0000, -139.5000, "Pacific/Marquesas"),
+    ("Gambier", -23.1333, -134.9500, "Pacific/Gambier"),
+    ("Port Moresby", -9.5000, 147.1667, "Pacific/Port_Moresby"),
+    ("Bougainville", -6.2167, 155.5667, "Pacific/Bougainville"),
+    ("Manila", 14.5867, 120.9678, "Asia/Manila"),
+    ("Karachi", 24.8667, 67.0500, "Asia/Karachi"),
+    ("Warsaw", 52.2500, 21.0000, "Europe/Warsaw"),
+    ("Miquelon", 47.0500, -56.3333, "America/Miquelon"),
+    ("Pitcairn", -25.0667, -130.0833, "Pacific/Pitcairn"),
+    ("Puerto Rico", 18.4683, -66.1061, "America/Puerto_Rico"),
+    ("Gaza", 31.5000, 34.4667, "Asia/Gaza"),
+    ("Hebron", 31.5333, 35.0950, "Asia/Hebron"),
+    ("Lisbon", 38.7167, -9.1333, "Europe/Lisbon"),
+    ("Madeira", 32.6333, -16.9000, "Atlantic/Madeira"),
+    ("Azores", 37.7333, -25.6667, "Atlantic/Azores"),
+    ("Palau", 7.3333, 134.4833, "Pacific/Palau"),
+    ("Asuncion", -25.2667, -57.6667, "America/Asuncion"),
+    ("Qatar", 25.2833, 51.5333, "Asia/Qatar"),
+    ("Reunion", -20.8667, 55.4667, "Indian/Reunion"),
+    ("Bucharest", 44.4333, 26.1000, "Europe/Bucharest"),
+    ("Belgrade", 44.8333, 20.5000, "Europe/Belgrade"),
+    ("Kaliningrad", 54.7167, 20.5000, "Europe/Kaliningrad"),
+    ("Moscow", 55.7558, 37.6178, "Europe/Moscow"),
+    ("Kirov", 58.6000, 49.6500, "Europe/Kirov"),
+    ("Volgograd", 48.7333, 44.4167, "Europe/Volgograd"),
+    ("Astrakhan", 46.3500, 48.0500, "Europe/Astrakhan"),
+    ("Saratov", 51.5667, 46.0333, "Europe/Saratov"),
+    ("Ulyanovsk", 54.3333, 48.4000, "Europe/Ulyanovsk"),
+    ("Samara", 53.2000, 50.1500, "Europe/Samara"),
+    ("Yekaterinburg", 56.8500, 60.6000, "Asia/Yekaterinburg"),
+    ("Omsk", 55.0000, 73.4000, "Asia/Omsk"),
+    ("Novosibirsk", 55.0333, 82.9167, "Asia/Novosibirsk"),
+    ("Barnaul", 53.3667, 83.7500, "Asia/Barnaul"),
+    ("Tomsk", 56.5000, 84.9667, "Asia/Tomsk"),
+    ("Novokuznetsk", 53.7500, 87.1167, "Asia/Novokuznetsk"),
+    ("Krasnoyarsk", 56.0167, 92.8333, "Asia/Krasnoyarsk"),
+    ("Irkutsk", 52.2667, 104.3333, "Asia/Irkutsk"),
+    ("Chita", 52.0500, 113.4667, "Asia/Chita"),
+    ("Yakutsk", 62.0000, 129.6667, "Asia/Yakutsk"),
+    ("Khandyga", 62.6564, 135.5539, "Asia/Khandyga"),
+    ("Vladivostok", 43.1667, 131.9333, "Asia/Vladivostok"),
+    ("Ust-Nera", 64.5603, 143.2267, "Asia/Ust-Nera"),
+    ("Magadan", 59.5667, 150.8000, "Asia/Magadan"),
+    ("Sakhalin", 46.9667, 142.7000, "Asia/Sakhalin"),
+    ("Srednekolymsk", 67.4667, 153.7167, "Asia/Srednekolymsk"),
+    ("Kamchatka", 53.0167, 158.6500, "Asia/Kamchatka"),
+    ("Anadyr", 64.7500, 177.4833, "Asia/Anadyr"),
+    ("Simferopol", 44.9500, 34.1000, "Europe/Simferopol"),
+    ("Kyiv", 50.4333, 30.5167, "Europe/Kyiv"),
+    ("Kigali", -1.9500, 30.0667, "Africa/Kigali"),
+    ("Riyadh", 24.6333, 46.7167, "Asia/Riyadh"),
+    ("Guadalcanal", -9.5333, 160.2000, "Pacific/Guadalcanal"),
+    ("Mahe", -4.6667, 55.4667, "Indian/Mahe"),
+    ("Khartoum", 15.6000, 32.5333, "Africa/Khartoum"),
+    ("Stockholm", 59.3333, 18.0500, "Europe/Stockholm"),
+    ("Singapore", 1.2833, 103.8500, "Asia/Singapore"),
+    ("St Helena", -15.9167, -5.7000, "Atlantic/St_Helena"),
+    ("Ljubljana", 46.0500, 14.5167, "Europe/Ljubljana"),
+    ("Longyearbyen", 78.0000, 16.0000, "Arctic/Longyearbyen"),
+    ("Bratislava", 48.1500, 17.1167, "Europe/Bratislava"),
+    ("Freetown", 8.5000, -13.2500, "Africa/Freetown"),
+    ("San Marino", 43.9167, 12.4667, "Europe/San_Marino"),
+    ("Dakar", 14.6667, -17.4333, "Africa/Dakar"),
+    ("Mogadishu", 2.0667, 45.3667, "Africa/Mogadishu"),
+    ("Paramaribo", 5.8333, -55.1667, "America/Paramaribo"),
+    ("Juba", 4.8500, 31.6167, "Africa/Juba"),
+    ("Sao Tome", 0.3333, 6.7333, "Africa/Sao_Tome"),
+    ("El Salvador", 13.7000, -89.2000, "America/El_Salvador"),
+    ("Lower Princes", 18.0514, -63.0472, "America/Lower_Princes"),
+    ("Damascus", 33.5000, 36.3000, "Asia/Damascus"),
+    ("Mbabane", -26.3000, 31.1000, "Africa/Mbabane"),
+    ("Grand Turk", 21.4667, -71.1333, "America/Grand_Turk"),
+    ("Ndjamena", 12.1167, 15.0500, "Africa/Ndjamena"),
+    ("Kerguelen", -49.3528, 70.2175, "Indian/Kerguelen"),
+    ("Lome", 6.1333, 1.2167, "Africa/Lome"),
+    ("Bangkok", 13.7500, 100.5167, "Asia/Bangkok"),
+    ("Dushanbe", 38.5833, 68.8000, "Asia/Dushanbe"),
+    ("Fakaofo", -9.3667, -171.2333, "Pacific/Fakaofo"),
+    ("Dili", -8.5500, 125.5833, "Asia/Dili"),
+    ("Ashgabat", 37.9500, 58.3833, "Asia/Ashgabat"),
+    ("Tunis", 36.8000, 10.1833, "Africa/Tunis"),
+    ("Tongatapu", -21.1333, -175.2000, "Pacific/Tongatapu"),
+    ("Istanbul", 41.0167, 28.9667, "Europe/Istanbul"),
+    ("Port of Spain", 10.6500, -61.5167, "America/Port_of_Spain"),
+    ("Funafuti", -8.5167, 179.2167, "Pacific/Funafuti"),
+    ("Taipei", 25.0500, 121.5000, "Asia/Taipei"),
+    ("Dar es Salaam", -6.8000, 39.2833, "Africa/Dar_es_Salaam"),
+    ("Kampala", 0.3167, 32.4167, "Africa/Kampala"),
+    ("Midway", 28.2167, -177.3667, "Pacific/Midway"),
+    ("Wake", 19.2833, 166.6167, "Pacific/Wake"),
+    ("New York", 40.7142, -74.0064, "America/New_York"),
+    ("Detroit", 42.3314, -83.0458, "America/Detroit"),
+    ("Louisville", 38.2542, -85.7594, "America/Kentucky/Louisville"),
+    ("Monticello", 36.8297, -84.8492, "America/Kentucky/Monticello"),
+    ("Indianapolis", 39.7683, -86.1581, "America/Indiana/Indianapolis"),
+    ("Vincennes", 38.6772, -87.5286, "America/Indiana/Vincennes"),
+    ("Winamac", 41.0514, -86.6031, "America/Indiana/Winamac"),
+    ("Marengo", 38.3756, -86.3447, "America/Indiana/Marengo"),
+    ("Petersburg", 38.4919, -87.2786, "America/Indiana/Petersburg"),
+    ("Vevay", 38.7478, -85.0672, "America/Indiana/Vevay"),
+    ("Chicago", 41.8500, -87.6500, "America/Chicago"),
+    ("Tell City", 37.9531, -86.7614, "America/Indiana/Tell_City"),
+    ("Knox", 41.2958, -86.6250, "America/Indiana/Knox"),
+    ("Menominee", 45.1078, -87.6142, "America/Menominee"),
+    ("Center", 47.1164, -101.2992, "America/North_Dakota/Center"),
+    ("New Salem", 46.8450, -101.4108, "America/North_Dakota/New_Salem"),
+    ("Beulah", 47.2642, -101.7778, "America/North_Dakota/Beulah"),
+    ("Denver", 39.7392, -104.9842, "America/Denver"),
+    ("Boise", 43.6136, -116.2025, "America/Boise"),
+    ("Phoenix", 33.4483, -112.0733, "America/Phoenix"),
+    ("Los Angeles", 34.0522, -118.2428, "America/Los_Angeles"),
+    ("Anchorage", 61.2181, -149.9003, "America/Anchorage"),
+    ("Juneau", 58.3019, -134.4197, "America/Juneau"),
+    ("Sitka", 57.1764, -135.3019, "America/Sitka"),
+    ("Metlakatla", 55.1269, -131.5764, "America/Metlakatla"),
+    ("Yakutat", 59.5469, -139.7272, "America/Yakutat"),
+    ("Nome", 64.5011, -165.4064, "America/Nome"),
+    ("Adak", 51.8800, -176.6581, "America/Adak"),
+    ("Honolulu", 21.3069, -157.8583, "Pacific/Honolulu"),
+    ("Montevideo", -34.9092, -56.2125, "America/Montevideo"),
+    ("Samarkand", 39.6667, 66.8000, "Asia/Samarkand"),
+    ("Tashkent", 41.3333, 69.3000, "Asia/Tashkent"),
+    ("Vatican", 41.9022, 12.4531, "Europe/Vatican"),
+    ("St Vincent", 13.1500, -61.2333, "America/St_Vincent"),
+    ("Caracas", 10.5000, -66.9333, "America/Caracas"),
+    ("Tortola", 18.4500, -64.6167, "America/Tortola"),
+    ("St Thomas", 18.3500, -64.9333, "America/St_Thomas"),
+    ("Ho Chi Minh", 10.7500, 106.6667, "Asia/Ho_Chi_Minh"),
+    ("Efate", -17.6667, 168.4167, "Pacific/Efate"),
+    ("Wallis", -13.3000, -176.1667, "Pacific/Wallis"),
+    ("Apia", -13.8333, -171.7333, "Pacific/Apia"),
+    ("Aden", 12.7500, 45.2000, "Asia/Aden"),
+    ("Mayotte", -12.7833, 45.2333, "Indian/Mayotte"),
+    ("Johannesburg", -26.2500, 28.0000, "Africa/Johannesburg"),
+    ("Lusaka", -15.4167, 28.2833, "Africa/Lusaka"),
+    ("Harare", -17.8333, 31.0500, "Africa/Harare"),
+];
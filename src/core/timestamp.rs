@@ -1,7 +1,10 @@
 //! Timestamp representation with precision awareness.
 
+use crate::core::fuzzy_date::{self, ParsedDate};
+use crate::core::{AllenRelation, Calendar, TimeRange};
 use crate::error::{Error, Result};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Offset, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 /// Precision level for timestamps.
@@ -13,6 +16,10 @@ use serde::{Deserialize, Serialize};
 )]
 #[serde(rename_all = "lowercase")]
 pub enum TemporalPrecision {
+    /// Century only (e.g., "the 19th century")
+    Century,
+    /// Decade only (e.g., "the 1840s")
+    Decade,
     /// Year only (e.g., "2024")
     Year,
     /// Year and month (e.g., "2024-03")
@@ -51,6 +58,11 @@ pub enum TemporalPrecision {
 ///     Timestamp::parse("2024-03-01T00:00:00Z").unwrap().datetime,
 ///     TemporalPrecision::Month
 /// );
+///
+/// // Historical notation
+/// let circa = Timestamp::parse("c. 1850").unwrap();
+/// assert!(circa.approximate);
+/// assert_eq!(circa.precision, TemporalPrecision::Year);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Timestamp {
@@ -59,15 +71,26 @@ pub struct Timestamp {
     /// The precision of this timestamp.
     #[serde(default)]
     pub precision: TemporalPrecision,
+    /// Whether the source gives this time only approximately (e.g., "c. 1850").
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub approximate: bool,
+    /// Calendar the date was recorded in.
+    #[serde(default, skip_serializing_if = "Calendar::is_gregorian")]
+    pub calendar: Calendar,
+    /// Original UTC offset in seconds east of UTC.
+    ///
+    /// `None` means the time was given in UTC or without an offset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utc_offset: Option<i32>,
+    /// IANA time zone of the place where the time was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Tz>,
 }
 
 impl Timestamp {
     /// Creates a new timestamp with the given datetime and default (Second) precision.
     pub fn new(datetime: DateTime<Utc>) -> Self {
-        Self {
-            datetime,
-            precision: TemporalPrecision::Second,
-        }
+        Self::with_precision(datetime, TemporalPrecision::Second)
     }
 
     /// Creates a new timestamp with explicit precision.
@@ -75,78 +98,145 @@ impl Timestamp {
         Self {
             datetime,
             precision,
+            approximate: false,
+            calendar: Calendar::Gregorian,
+            utc_offset: None,
+            time_zone: None,
         }
     }
 
+    /// Creates a timestamp from a datetime with a fixed offset, keeping
+    /// the offset.
+    pub fn from_local(datetime: DateTime<FixedOffset>) -> Self {
+        Self::new(datetime.with_timezone(&Utc)).with_offset(*datetime.offset())
+    }
+
+    /// Marks the timestamp as approximate or exact.
+    pub fn with_approximate(mut self, approximate: bool) -> Self {
+        self.approximate = approximate;
+        self
+    }
+
+    /// Sets the calendar the date was recorded in.
+    ///
+    /// The stored instant is unchanged; only how it is read and
+    /// displayed changes.
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Sets the original UTC offset.
+    ///
+    /// The stored instant is unchanged. A zero offset is treated as UTC.
+    pub fn with_offset(mut self, offset: FixedOffset) -> Self {
+        let seconds = offset.local_minus_utc();
+        self.utc_offset = (seconds != 0 || self.time_zone.is_some()).then_some(seconds);
+        self
+    }
+
+    /// Sets the IANA time zone, updating the offset to match it at
+    /// this instant.
+    ///
+    /// The stored instant is unchanged.
+    pub fn with_time_zone(mut self, time_zone: Tz) -> Self {
+        self.time_zone = Some(time_zone);
+        let offset = self.datetime.with_timezone(&time_zone).offset().fix();
+        self.utc_offset = Some(offset.local_minus_utc());
+        self
+    }
+
+    /// Returns the original UTC offset, or UTC if none is known.
+    pub fn offset(&self) -> FixedOffset {
+        self.utc_offset
+            .and_then(FixedOffset::east_opt)
+            .unwrap_or_else(|| Utc.fix())
+    }
+
+    /// Returns the datetime in the local time of the original offset
+    /// or time zone.
+    pub fn local_datetime(&self) -> DateTime<FixedOffset> {
+        match self.time_zone {
+            Some(tz) => self.datetime.with_timezone(&tz).fixed_offset(),
+            None => self.datetime.with_timezone(&self.offset()),
+        }
+    }
+
+    /// Returns the local calendar date.
+    pub fn local_date(&self) -> NaiveDate {
+        self.local_datetime().date_naive()
+    }
+
+    /// Returns the local hour of day (0-23).
+    pub fn local_hour(&self) -> u32 {
+        self.local_datetime().hour()
+    }
+
     /// Creates a timestamp for the current moment.
     pub fn now() -> Self {
         Self::new(Utc::now())
     }
 
-    /// Parses a timestamp from an ISO 8601 string.
+    /// Parses a timestamp from an ISO 8601 string or a historical date expression.
     ///
     /// Supported formats:
     /// - `2024-03-15T14:30:00Z` (full precision)
     /// - `2024-03-15T14:30:00+00:00` (with timezone offset)
     /// - `2024-03-15` (date only, day precision)
     /// - `2024-03` (year-month, month precision)
-    /// - `2024` (year only, year precision)
+    /// - `2024`, `-0043`, `44 BCE`, `AD 800` (year precision)
+    /// - `12 March 1916`, `March 1916` (day or month precision)
+    /// - `the 1840s`, `184X` (decade precision)
+    /// - `the 19th century` (the 1800s), `18XX` (century precision)
+    /// - `c. 1850`, `circa 1850`, `1850~` (approximate)
+    /// - `12 March 1916 (Julian)`, `... O.S.` (Julian calendar)
+    /// - `2024-03-15T14:30:00+01:00[Europe/Paris]` (IANA time zone)
+    ///
+    /// Non-zero offsets and time zone annotations are kept in
+    /// [`utc_offset`](Self::utc_offset) and [`time_zone`](Self::time_zone).
+    ///
+    /// Notations that imply a span, such as `spring 1943`,
+    /// `early March 1916` or `5th century BCE`, yield a timestamp at the
+    /// start of the span with the finest precision whose unit covers it.
+    /// Use [`TimeRange::parse`] to keep the exact span.
+    ///
+    /// Ranges between two dates, such as `1840-1845` or
+    /// `between May 1940 and June 1941`, are not a single timestamp and
+    /// are rejected; parse them with [`TimeRange::parse`].
     pub fn parse(s: &str) -> Result<Self> {
-        // Try full ISO 8601 with timezone
-        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-            return Ok(Self::new(dt.with_timezone(&Utc)));
-        }
-
-        // Try full ISO 8601 with Z suffix
-        if let Ok(dt) = s.parse::<DateTime<Utc>>() {
-            return Ok(Self::new(dt));
+        match fuzzy_date::parse(s)? {
+            ParsedDate::Instant(ts) => Ok(ts),
+            ParsedDate::Range(range) => Ok(Self::covering(&range)),
+            ParsedDate::Between(_) => Err(Error::InvalidTimestamp(format!(
+                "{} is a range of dates; use TimeRange::parse",
+                s
+            ))),
         }
+    }
 
-        // Try date only (YYYY-MM-DD)
-        if s.len() == 10 {
-            if let Ok(naive) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-                let dt = naive
-                    .and_hms_opt(0, 0, 0)
-                    .map(|ndt| Utc.from_utc_datetime(&ndt))
-                    .ok_or_else(|| Error::InvalidTimestamp(s.to_string()))?;
-                return Ok(Self::with_precision(dt, TemporalPrecision::Day));
-            }
-        }
+    /// Returns a timestamp at the start of a range whose precision unit
+    /// covers the whole range.
+    fn covering(range: &TimeRange) -> Self {
+        use TemporalPrecision::*;
+
+        let covering = [Millisecond, Second, Minute, Hour, Day, Month, Year, Decade]
+            .into_iter()
+            .map(|precision| {
+                Self::with_precision(range.start.datetime, precision)
+                    .with_calendar(range.start.calendar)
+                    .with_approximate(range.start.approximate)
+            })
+            .find(|ts| ts.latest().datetime >= range.end.datetime)
+            .unwrap_or_else(|| {
+                Self::with_precision(range.start.datetime, Century)
+                    .with_calendar(range.start.calendar)
+                    .with_approximate(range.start.approximate)
+            });
 
-        // Try year-month (YYYY-MM)
-        if s.len() == 7 && s.chars().nth(4) == Some('-') {
-            let year: i32 = s[0..4]
-                .parse()
-                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
-            let month: u32 = s[5..7]
-                .parse()
-                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
-
-            if let Some(naive) = chrono::NaiveDate::from_ymd_opt(year, month, 1) {
-                let dt = naive
-                    .and_hms_opt(0, 0, 0)
-                    .map(|ndt| Utc.from_utc_datetime(&ndt))
-                    .ok_or_else(|| Error::InvalidTimestamp(s.to_string()))?;
-                return Ok(Self::with_precision(dt, TemporalPrecision::Month));
-            }
-        }
-
-        // Try year only (YYYY)
-        if s.len() == 4 {
-            let year: i32 = s
-                .parse()
-                .map_err(|_| Error::InvalidTimestamp(s.to_string()))?;
-
-            if let Some(naive) = chrono::NaiveDate::from_ymd_opt(year, 1, 1) {
-                let dt = naive
-                    .and_hms_opt(0, 0, 0)
-                    .map(|ndt| Utc.from_utc_datetime(&ndt))
-                    .ok_or_else(|| Error::InvalidTimestamp(s.to_string()))?;
-                return Ok(Self::with_precision(dt, TemporalPrecision::Year));
-            }
+        Self {
+            datetime: covering.earliest().datetime,
+            ..covering
         }
-
-        Err(Error::InvalidTimestamp(s.to_string()))
     }
 
     /// Creates a timestamp from Unix epoch seconds.
@@ -181,17 +271,76 @@ impl Timestamp {
     }
 
     /// Formats the timestamp according to precision.
+    ///
+    /// Decades and centuries use the EDTF form (`184X`, `18XX`),
+    /// approximate timestamps end in `~` and Julian dates carry a
+    /// `[u-ca=julian]` annotation. The output can be read back with
+    /// [`Timestamp::parse`].
     pub fn format_with_precision(&self) -> String {
-        match self.precision {
-            TemporalPrecision::Year => self.datetime.format("%Y").to_string(),
-            TemporalPrecision::Month => self.datetime.format("%Y-%m").to_string(),
-            TemporalPrecision::Day => self.datetime.format("%Y-%m-%d").to_string(),
-            TemporalPrecision::Hour => self.datetime.format("%Y-%m-%dT%H:00:00Z").to_string(),
-            TemporalPrecision::Minute => self.datetime.format("%Y-%m-%dT%H:%M:00Z").to_string(),
+        let (year, month, day) = self.calendar_date();
+        let year_str = format_year(year);
+        let date = format!("{}-{:02}-{:02}", year_str, month, day);
+
+        // Times of day are written in local time with the original offset
+        let local = self.local_datetime();
+        let (local_year, local_month, local_day) = self.calendar.from_gregorian(local.date_naive());
+        let local_date = format!(
+            "{}-{:02}-{:02}",
+            format_year(local_year),
+            local_month,
+            local_day
+        );
+        let offset = |utc: &str| {
+            if self.utc_offset.is_some() {
+                local.format("%:z").to_string()
+            } else {
+                utc.to_string()
+            }
+        };
+
+        let mut out = match self.precision {
+            TemporalPrecision::Century => {
+                format!("{}XX", &year_str[..year_str.len() - 2])
+            },
+            TemporalPrecision::Decade => format!("{}X", &year_str[..year_str.len() - 1]),
+            TemporalPrecision::Year => year_str,
+            TemporalPrecision::Month => format!("{}-{:02}", year_str, month),
+            TemporalPrecision::Day => date,
+            TemporalPrecision::Hour => {
+                format!("{}T{}{}", local_date, local.format("%H:00:00"), offset("Z"))
+            },
+            TemporalPrecision::Minute => {
+                format!("{}T{}{}", local_date, local.format("%H:%M:00"), offset("Z"))
+            },
             TemporalPrecision::Second | TemporalPrecision::Millisecond => {
-                self.datetime.to_rfc3339()
+                match (self.calendar, self.utc_offset) {
+                    (Calendar::Gregorian, None) => self.datetime.to_rfc3339(),
+                    (Calendar::Gregorian, Some(_)) => local.to_rfc3339(),
+                    _ => format!(
+                        "{}T{}{}",
+                        local_date,
+                        local.format("%H:%M:%S%.f"),
+                        offset("+00:00")
+                    ),
+                }
             },
+        };
+
+        if self.approximate {
+            out.push('~');
         }
+        if let Some(tz) = self.time_zone {
+            out.push_str(&format!("[{}]", tz.name()));
+        }
+        if !self.calendar.is_gregorian() {
+            out.push_str(&format!("[u-ca={}]", self.calendar));
+        }
+        out
+    }
+
+    /// Returns the (year, month, day) of this timestamp in its calendar.
+    pub fn calendar_date(&self) -> (i32, u32, u32) {
+        self.calendar.from_gregorian(self.datetime.date_naive())
     }
 
     /// Checks if this timestamp is before another.
@@ -208,6 +357,127 @@ impl Timestamp {
     pub fn duration_since(&self, earlier: &Timestamp) -> chrono::Duration {
         self.datetime.signed_duration_since(earlier.datetime)
     }
+
+    /// Returns the earliest instant this timestamp could refer to.
+    ///
+    /// A Year-precision "1943" could be any moment of 1943, so its
+    /// earliest instant is 1943-01-01T00:00:00.000Z.
+    pub fn earliest(&self) -> Timestamp {
+        Self::with_precision(self.unit_bounds().0, TemporalPrecision::Millisecond)
+    }
+
+    /// Returns the latest instant this timestamp could refer to.
+    ///
+    /// A Year-precision "1943" has 1943-12-31T23:59:59.999Z as its
+    /// latest instant.
+    pub fn latest(&self) -> Timestamp {
+        let (start, next) = self.unit_bounds();
+        let latest = (next - Duration::milliseconds(1)).max(start);
+        Self::with_precision(latest, TemporalPrecision::Millisecond)
+    }
+
+    /// Returns the interval implied by this timestamp's precision.
+    pub fn implied_range(&self) -> TimeRange {
+        TimeRange::new(self.earliest(), self.latest())
+    }
+
+    /// Checks if this timestamp is before another under every reading
+    /// of both precisions.
+    pub fn definitely_before(&self, other: &Timestamp) -> bool {
+        self.latest().datetime < other.earliest().datetime
+    }
+
+    /// Checks if this timestamp could be before another given the
+    /// precision of both.
+    pub fn possibly_before(&self, other: &Timestamp) -> bool {
+        self.earliest().datetime < other.latest().datetime
+    }
+
+    /// Checks if this timestamp is after another under every reading
+    /// of both precisions.
+    pub fn definitely_after(&self, other: &Timestamp) -> bool {
+        other.definitely_before(self)
+    }
+
+    /// Checks if this timestamp could be after another given the
+    /// precision of both.
+    pub fn possibly_after(&self, other: &Timestamp) -> bool {
+        other.possibly_before(self)
+    }
+
+    /// Returns the Allen relation between the implied intervals of
+    /// this timestamp and another.
+    pub fn relation(&self, other: &Timestamp) -> AllenRelation {
+        self.implied_range().relation(&other.implied_range())
+    }
+
+    /// Returns the start of the precision unit containing this timestamp
+    /// and the start of the following unit.
+    fn unit_bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let dt = self.datetime;
+        let calendar = self.calendar;
+        let date = |year: i32, month: u32| {
+            calendar
+                .to_gregorian(year, month, 1)
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|ndt| Utc.from_utc_datetime(&ndt))
+        };
+        let truncated = |secs: i64| DateTime::from_timestamp(secs, 0).unwrap_or(dt);
+        let (year, month, _) = self.calendar_date();
+
+        let bounds = match self.precision {
+            TemporalPrecision::Century => {
+                let start = year.div_euclid(100) * 100;
+                date(start, 1).zip(date(start + 100, 1))
+            },
+            TemporalPrecision::Decade => {
+                let start = year.div_euclid(10) * 10;
+                date(start, 1).zip(date(start + 10, 1))
+            },
+            TemporalPrecision::Year => date(year, 1).zip(date(year + 1, 1)),
+            TemporalPrecision::Month => {
+                let (next_year, next_month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                date(year, month).zip(date(next_year, next_month))
+            },
+            TemporalPrecision::Day => {
+                let start = truncated(dt.timestamp() - i64::from(dt.num_seconds_from_midnight()));
+                Some((start, start + Duration::days(1)))
+            },
+            TemporalPrecision::Hour => {
+                let start = truncated(dt.timestamp() - i64::from(dt.minute() * 60 + dt.second()));
+                Some((start, start + Duration::hours(1)))
+            },
+            TemporalPrecision::Minute => {
+                let start = truncated(dt.timestamp() - i64::from(dt.second()));
+                Some((start, start + Duration::minutes(1)))
+            },
+            TemporalPrecision::Second => {
+                let start = truncated(dt.timestamp());
+                Some((start, start + Duration::seconds(1)))
+            },
+            TemporalPrecision::Millisecond => {
+                DateTime::from_timestamp_millis(dt.timestamp_millis())
+                    .map(|start| (start, start + Duration::milliseconds(1)))
+            },
+        };
+
+        bounds.unwrap_or((dt, dt))
+    }
+}
+
+/// Formats an astronomical year the way ISO 8601 and chrono do.
+fn format_year(year: i32) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{:04}", year)
+    } else if year < 0 {
+        format!("-{:04}", year.unsigned_abs())
+    } else {
+        format!("+{}", year)
+    }
 }
 
 impl Default for Timestamp {
@@ -223,8 +493,19 @@ impl PartialOrd for Timestamp {
 }
 
 impl Ord for Timestamp {
+    /// Orders by datetime, then coarser precision first so that
+    /// ordering agrees with equality.
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.datetime.cmp(&other.datetime)
+        self.datetime
+            .cmp(&other.datetime)
+            .then(self.precision.cmp(&other.precision))
+            .then(self.approximate.cmp(&other.approximate))
+            .then(self.calendar.cmp(&other.calendar))
+            .then(self.utc_offset.cmp(&other.utc_offset))
+            .then_with(|| {
+                let name = |ts: &Self| ts.time_zone.map(|tz| tz.name());
+                name(self).cmp(&name(other))
+            })
     }
 }
 
@@ -232,6 +513,10 @@ impl std::hash::Hash for Timestamp {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.datetime.hash(state);
         self.precision.hash(state);
+        self.approximate.hash(state);
+        self.calendar.hash(state);
+        self.utc_offset.hash(state);
+        self.time_zone.hash(state);
     }
 }
 
@@ -250,6 +535,7 @@ impl From<DateTime<Utc>> for Timestamp {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Datelike;
 
     #[test]
     fn test_timestamp_now() {
@@ -332,5 +618,129 @@ mod tests {
         assert_eq!(ts.datetime, parsed.datetime);
     }
 
-    use chrono::Datelike;
+    #[test]
+    fn test_timestamp_implied_range() {
+        let year = Timestamp::parse("1943").unwrap();
+        assert_eq!(year.earliest().to_rfc3339(), "1943-01-01T00:00:00+00:00");
+        assert_eq!(year.latest().to_rfc3339(), "1943-12-31T23:59:59.999+00:00");
+
+        let month = Timestamp::parse("2024-02").unwrap();
+        assert_eq!(month.latest().datetime.day(), 29);
+
+        let precise = Timestamp::parse("2024-03-15T14:30:00Z").unwrap();
+        assert_eq!(precise.implied_range().duration().num_milliseconds(), 999);
+    }
+
+    #[test]
+    fn test_timestamp_definitely_possibly_before() {
+        let year = Timestamp::parse("1943").unwrap();
+        let march = Timestamp::parse("1943-03").unwrap();
+        let later = Timestamp::parse("1944-06-06").unwrap();
+
+        // "1943" may or may not precede March 1943
+        assert!(!year.definitely_before(&march));
+        assert!(year.possibly_before(&march));
+        assert!(year.possibly_after(&march));
+
+        assert!(year.definitely_before(&later));
+        assert!(later.definitely_after(&march));
+        assert!(!later.possibly_before(&year));
+    }
+
+    #[test]
+    fn test_timestamp_relation() {
+        let year = Timestamp::parse("1943").unwrap();
+        let march = Timestamp::parse("1943-03").unwrap();
+        let january = Timestamp::parse("1943-01").unwrap();
+        let next_year = Timestamp::parse("1944").unwrap();
+
+        assert_eq!(year.relation(&march), AllenRelation::Contains);
+        assert_eq!(january.relation(&year), AllenRelation::Starts);
+        assert_eq!(year.relation(&next_year), AllenRelation::Meets);
+    }
+
+    #[test]
+    fn test_timestamp_parse_span_notation() {
+        let spring = Timestamp::parse("spring 1943").unwrap();
+        assert_eq!(spring.precision, TemporalPrecision::Year);
+        assert_eq!(spring.datetime.month(), 1);
+
+        let early = Timestamp::parse("early March 1916").unwrap();
+        assert_eq!(early.precision, TemporalPrecision::Month);
+
+        for range in [
+            "1840-1845",
+            "between May 1940 and June 1941",
+            "100 BC – 44 BC",
+        ] {
+            let err = Timestamp::parse(range).unwrap_err();
+            assert!(err.to_string().contains("TimeRange::parse"), "{err}");
+            assert!(TimeRange::parse(range).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_timestamp_historical_format_roundtrip() {
+        for text in [
+            "c. 1850",
+            "the 1840s",
+            "19th century",
+            "44 BCE",
+            "23 February 1917 (Julian)",
+            "March 1700 O.S.",
+            "1916-03-12T08:00:00Z",
+        ] {
+            let ts = Timestamp::parse(text).unwrap();
+            let formatted = ts.format_with_precision();
+            assert_eq!(Timestamp::parse(&formatted).unwrap(), ts, "{}", formatted);
+        }
+
+        let julian = Timestamp::parse("23 February 1917 (Julian)").unwrap();
+        assert_eq!(julian.format_with_precision(), "1917-02-23[u-ca=julian]");
+        assert_eq!(Timestamp::parse("c. 1850").unwrap().to_string(), "1850~");
+    }
+
+    #[test]
+    fn test_timestamp_julian_implied_range() {
+        // Julian March 1700 runs from 12 March to 11 April (Gregorian)
+        let ts = Timestamp::parse("March 1700 (Julian)").unwrap();
+        let range = ts.implied_range();
+        assert_eq!(range.start.datetime.day(), 12);
+        assert_eq!(range.end.datetime.month(), 4);
+        assert_eq!(range.end.datetime.day(), 11);
+    }
+
+    #[test]
+    fn test_timestamp_local_time() {
+        let ts = Timestamp::parse("2024-03-15T22:30:00-05:00").unwrap();
+        assert_eq!(ts.datetime.hour(), 3);
+        assert_eq!(ts.local_hour(), 22);
+        assert_eq!(ts.local_date().day(), 15);
+        assert_eq!(ts.format_with_precision(), "2024-03-15T22:30:00-05:00");
+
+        let zoned = Timestamp::parse("2024-07-01T12:00:00Z")
+            .unwrap()
+            .with_time_zone(chrono_tz::Europe::Berlin);
+        assert_eq!(zoned.local_hour(), 14);
+        assert_eq!(
+            zoned.format_with_precision(),
+            "2024-07-01T14:00:00+02:00[Europe/Berlin]"
+        );
+        assert_eq!(
+            Timestamp::parse(&zoned.format_with_precision()).unwrap(),
+            zoned
+        );
+
+        let json = serde_json::to_string(&zoned).unwrap();
+        let parsed: Timestamp = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, zoned);
+    }
+
+    #[test]
+    fn test_timestamp_ordering_consistent_with_eq() {
+        let year = Timestamp::parse("1943").unwrap();
+        let day = Timestamp::parse("1943-01-01").unwrap();
+        assert_ne!(year, day);
+        assert!(year < day);
+    }
 }
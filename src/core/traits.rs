@@ -1,6 +1,8 @@
 //! Core traits for spatial and temporal entities.
 
-use crate::core::{GeoBounds, Location, TimeRange, Timestamp};
+use crate::core::{AllenRelation, GeoBounds, Location, TemporalMatch, TimeRange, Timestamp};
+use geo::Intersects;
+use geo_types::Geometry;
 
 /// Trait for entities with a spatial location.
 ///
@@ -10,12 +12,23 @@ pub trait SpatialEntity {
     /// Returns the primary location of this entity.
     fn location(&self) -> &Location;
 
+    /// Returns the extended geometry of this entity, if any.
+    ///
+    /// Point entities return `None` and are represented by
+    /// [`location`](Self::location) alone.
+    fn geometry(&self) -> Option<&Geometry<f64>> {
+        None
+    }
+
     /// Returns the geographic bounds of this entity.
     ///
     /// For point entities, this returns a zero-size bounding box
     /// at the location. For entities with extent, this returns
     /// the full bounding box.
     fn bounds(&self) -> GeoBounds {
+        if let Some(bounds) = self.geometry().and_then(GeoBounds::from_geometry) {
+            return bounds;
+        }
         let loc = self.location();
         GeoBounds::new(loc.lat, loc.lon, loc.lat, loc.lon)
     }
@@ -26,8 +39,18 @@ pub trait SpatialEntity {
     }
 
     /// Checks if this entity is within the given bounds.
+    ///
+    /// Entities with an extended geometry match if any part of the
+    /// geometry intersects the bounds, on either side of the antimeridian
+    /// for bounds that wrap.
     fn is_within_bounds(&self, bounds: &GeoBounds) -> bool {
-        bounds.contains(self.location())
+        match self.geometry() {
+            Some(geometry) => bounds
+                .split_antimeridian()
+                .iter()
+                .any(|part| geometry.intersects(&part.to_geo_rect())),
+            None => bounds.contains(self.location()),
+        }
     }
 }
 
@@ -37,8 +60,17 @@ pub trait SpatialEntity {
 /// a timestamp, enabling temporal indexing and queries.
 pub trait TemporalEntity {
     /// Returns the primary timestamp of this entity.
+    ///
+    /// For entities with duration, this is the start time.
     fn timestamp(&self) -> &Timestamp;
 
+    /// Returns the end timestamp for entities with duration.
+    ///
+    /// Instantaneous entities return `None`.
+    fn end_timestamp(&self) -> Option<&Timestamp> {
+        None
+    }
+
     /// Returns the time range of this entity.
     ///
     /// For instantaneous events, this returns a range where
@@ -46,12 +78,73 @@ pub trait TemporalEntity {
     /// returns the full time span.
     fn time_range(&self) -> TimeRange {
         let ts = self.timestamp();
-        TimeRange::new(ts.clone(), ts.clone())
+        let end = self.end_timestamp().unwrap_or(ts);
+        TimeRange::new(ts.clone(), end.clone())
     }
 
     /// Checks if this entity falls within the given time range.
+    ///
+    /// Entities with duration must lie entirely inside the range. Like
+    /// [`TimeRange::contains`], this compares the stored instants; use
+    /// [`matches_time_range`](Self::matches_time_range) to account for
+    /// precision.
     fn is_within_time_range(&self, range: &TimeRange) -> bool {
-        range.contains(self.timestamp())
+        let time_range = self.time_range();
+        range.contains(&time_range.start) && range.contains(&time_range.end)
+    }
+
+    /// Checks if any part of this entity's time span overlaps the range.
+    ///
+    /// For instantaneous entities this is the same as
+    /// [`is_within_time_range`](Self::is_within_time_range). Compares
+    /// the stored instants.
+    fn overlaps_time_range(&self, range: &TimeRange) -> bool {
+        range.overlaps(&self.time_range())
+    }
+
+    /// Checks if this entity's time span overlaps the range, given the
+    /// precision of its timestamps.
+    ///
+    /// See [`TemporalMatch`] for what each mode accepts.
+    fn matches_time_range(&self, range: &TimeRange, mode: TemporalMatch) -> bool {
+        let start = self.timestamp();
+        let end = self.end_timestamp().unwrap_or(start);
+        let (start, end) = match mode {
+            TemporalMatch::Possibly => (start.earliest(), end.latest()),
+            TemporalMatch::Definitely => (start.latest(), end.earliest()),
+        };
+        start.datetime <= range.end.datetime && end.datetime >= range.start.datetime
+    }
+
+    /// Returns the widest time span this entity could occupy, taking
+    /// the precision of its timestamps into account.
+    fn implied_time_range(&self) -> TimeRange {
+        let start = self.timestamp();
+        let end = self.end_timestamp().unwrap_or(start);
+        TimeRange::new(start.earliest(), end.latest())
+    }
+
+    /// Returns the Allen relation between this entity and another.
+    ///
+    /// Both entities are compared by their
+    /// [`implied_time_range`](Self::implied_time_range).
+    fn allen_relation(&self, other: &dyn TemporalEntity) -> AllenRelation {
+        self.implied_time_range()
+            .relation(&other.implied_time_range())
+    }
+
+    /// Checks if this entity ends before the other starts under every
+    /// reading of their precisions.
+    fn definitely_before(&self, other: &dyn TemporalEntity) -> bool {
+        let end = self.end_timestamp().unwrap_or(self.timestamp());
+        end.definitely_before(other.timestamp())
+    }
+
+    /// Checks if this entity could end before the other starts given
+    /// their precisions.
+    fn possibly_before(&self, other: &dyn TemporalEntity) -> bool {
+        let end = self.end_timestamp().unwrap_or(self.timestamp());
+        end.possibly_before(other.timestamp())
     }
 }
 
@@ -70,6 +163,12 @@ pub trait SpatiotemporalEntity: SpatialEntity + TemporalEntity {
 // Blanket implementation for anything that implements both traits
 impl<T: SpatialEntity + TemporalEntity> SpatiotemporalEntity for T {}
 
+impl TemporalEntity for Timestamp {
+    fn timestamp(&self) -> &Timestamp {
+        self
+    }
+}
+
 // Implement traits for Event
 use crate::core::Event;
 
@@ -77,17 +176,26 @@ impl SpatialEntity for Event {
     fn location(&self) -> &Location {
         &self.location
     }
+
+    fn geometry(&self) -> Option<&Geometry<f64>> {
+        self.geometry.as_ref()
+    }
 }
 
 impl TemporalEntity for Event {
     fn timestamp(&self) -> &Timestamp {
         &self.timestamp
     }
+
+    fn end_timestamp(&self) -> Option<&Timestamp> {
+        self.end_timestamp.as_ref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use geo_types::line_string;
 
     #[test]
     fn test_event_spatial_entity() {
@@ -116,6 +224,45 @@ mod tests {
         assert!(!event.is_within_bounds(&la_bounds));
     }
 
+    #[test]
+    fn test_event_geometry_bounds() {
+        let route = line_string![
+            (x: -75.5, y: 40.0),
+            (x: -72.5, y: 41.0),
+        ];
+        let event = Event::builder()
+            .geometry(route.into())
+            .timestamp(Timestamp::now())
+            .text("March route")
+            .build();
+
+        let bounds = event.bounds();
+        assert_eq!(bounds.min_lon, -75.5);
+        assert_eq!(bounds.max_lon, -72.5);
+
+        // Only the western end of the route is inside these bounds
+        let west = GeoBounds::new(39.0, -76.0, 42.0, -75.0);
+        let far = GeoBounds::new(33.0, -119.0, 35.0, -117.0);
+        assert!(event.is_within_bounds(&west));
+        assert!(!event.is_within_bounds(&far));
+    }
+
+    #[test]
+    fn test_event_geometry_within_wrapping_bounds() {
+        let pacific = GeoBounds::new(-10.0, 170.0, 10.0, -170.0);
+        let line = |from: f64, to: f64| {
+            Event::builder()
+                .geometry(line_string![(x: from, y: 0.0), (x: to, y: 0.0)].into())
+                .timestamp(Timestamp::now())
+                .text("Voyage")
+                .build()
+        };
+
+        assert!(line(174.0, 176.0).is_within_bounds(&pacific));
+        assert!(line(-176.0, -174.0).is_within_bounds(&pacific));
+        assert!(!line(-1.0, 1.0).is_within_bounds(&pacific));
+    }
+
     #[test]
     fn test_event_temporal_entity() {
         let event = Event::builder()
@@ -131,6 +278,52 @@ mod tests {
         assert!(!event.is_within_time_range(&april));
     }
 
+    #[test]
+    fn test_event_interval_temporal_entity() {
+        let event = Event::builder()
+            .location(Location::new(40.0, -74.0))
+            .timestamp(Timestamp::parse("2024-03-25T00:00:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-04-05T00:00:00Z").unwrap())
+            .text("Strike")
+            .build();
+
+        let march = TimeRange::month(2024, 3);
+        let april = TimeRange::month(2024, 4);
+        let may = TimeRange::month(2024, 5);
+        let spring = TimeRange::new(
+            Timestamp::parse("2024-03-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-05-31T00:00:00Z").unwrap(),
+        );
+
+        assert_eq!(event.time_range().duration().num_days(), 11);
+        assert!(event.overlaps_time_range(&march));
+        assert!(event.overlaps_time_range(&april));
+        assert!(!event.overlaps_time_range(&may));
+        assert!(!event.is_within_time_range(&march));
+        assert!(event.is_within_time_range(&spring));
+    }
+
+    #[test]
+    fn test_event_precision_aware_relations() {
+        let battle = Event::builder()
+            .location(Location::new(49.0, 2.0))
+            .timestamp(Timestamp::parse("1916-07").unwrap())
+            .text("Offensive begins")
+            .build();
+        let letter = Event::builder()
+            .location(Location::new(51.5, -0.1))
+            .timestamp(Timestamp::parse("1916").unwrap())
+            .text("Undated letter")
+            .build();
+        let armistice = Timestamp::parse("1918-11-11T11:00:00Z").unwrap();
+
+        assert_eq!(battle.allen_relation(&letter), AllenRelation::During);
+        assert!(battle.possibly_before(&letter));
+        assert!(!battle.definitely_before(&letter));
+        assert!(battle.definitely_before(&armistice));
+        assert!(letter.implied_time_range().contains(&battle.timestamp));
+    }
+
     #[test]
     fn test_event_spatiotemporal() {
         let event = Event::builder()
@@ -0,0 +1,604 @@
+//! Narrative validation and lint reports.
+
+use chrono::Duration;
+use geo::HaversineDistance;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::core::{EventId, Narrative, TemporalEntity, Timestamp};
+
+/// How serious a validation finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Worth knowing about, but not a problem by itself.
+    Info,
+    /// Suspicious data that may be intentional.
+    Warning,
+    /// Data that is definitely wrong.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Machine-readable code identifying the kind of finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// Latitude or longitude is outside the WGS84 range.
+    InvalidCoordinates,
+    /// Several events share the same ID.
+    DuplicateEventId,
+    /// An interval event ends before it starts.
+    InvalidInterval,
+    /// An event cites no sources.
+    MissingSources,
+    /// An event is dated in the future.
+    FutureTimestamp,
+    /// An event is dated implausibly far in the past.
+    FarPastTimestamp,
+    /// Consecutive events are too far apart for the time between them.
+    ImplausibleJump,
+    /// Tags differ only by letter case.
+    TagCaseConflict,
+    /// A section references an event that is not in the narrative.
+    DanglingSectionEvent,
+}
+
+impl ValidationCode {
+    /// Returns the code as a stable string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationCode::InvalidCoordinates => "invalid_coordinates",
+            ValidationCode::DuplicateEventId => "duplicate_event_id",
+            ValidationCode::InvalidInterval => "invalid_interval",
+            ValidationCode::MissingSources => "missing_sources",
+            ValidationCode::FutureTimestamp => "future_timestamp",
+            ValidationCode::FarPastTimestamp => "far_past_timestamp",
+            ValidationCode::ImplausibleJump => "implausible_jump",
+            ValidationCode::TagCaseConflict => "tag_case_conflict",
+            ValidationCode::DanglingSectionEvent => "dangling_section_event",
+        }
+    }
+
+    /// Returns the severity reported for this code.
+    pub fn severity(&self) -> Severity {
+        match self {
+            ValidationCode::InvalidCoordinates
+            | ValidationCode::DuplicateEventId
+            | ValidationCode::InvalidInterval => Severity::Error,
+            ValidationCode::MissingSources
+            | ValidationCode::FutureTimestamp
+            | ValidationCode::FarPastTimestamp
+            | ValidationCode::ImplausibleJump
+            | ValidationCode::DanglingSectionEvent => Severity::Warning,
+            ValidationCode::TagCaseConflict => Severity::Info,
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single problem found while validating a narrative.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationFinding {
+    /// Kind of problem.
+    pub code: ValidationCode,
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// Events affected by the problem.
+    pub event_ids: Vec<EventId>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl ValidationFinding {
+    fn new(code: ValidationCode, event_ids: Vec<EventId>, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            event_ids,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Structured result of validating a narrative.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, Timestamp, ValidationCode};
+///
+/// let mut narrative = Narrative::new("Import");
+/// narrative.add_event(Event::new(
+///     Location::new(95.0, 0.0),
+///     Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+///     "Bad latitude",
+/// ));
+///
+/// let report = narrative.validate();
+/// assert!(!report.is_valid());
+/// assert_eq!(report.with_code(ValidationCode::InvalidCoordinates).count(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// All findings, in the order the checks ran.
+    pub findings: Vec<ValidationFinding>,
+}
+
+impl ValidationReport {
+    /// Returns true if there are no error-level findings.
+    pub fn is_valid(&self) -> bool {
+        !self.has_errors()
+    }
+
+    /// Returns true if any finding is an error.
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|f| f.severity == Severity::Error)
+    }
+
+    /// Returns the number of findings.
+    pub fn len(&self) -> usize {
+        self.findings.len()
+    }
+
+    /// Returns true if there are no findings at all.
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Returns the highest severity in the report.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+
+    /// Returns findings at or above the given severity.
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings.iter().filter(move |f| f.severity >= severity)
+    }
+
+    /// Returns error-level findings.
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == Severity::Error)
+    }
+
+    /// Returns warning-level findings.
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == Severity::Warning)
+    }
+
+    /// Returns findings with the given code.
+    pub fn with_code(&self, code: ValidationCode) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings.iter().filter(move |f| f.code == code)
+    }
+
+    /// Returns findings that affect the given event.
+    pub fn for_event<'a>(
+        &'a self,
+        id: &'a EventId,
+    ) -> impl Iterator<Item = &'a ValidationFinding> + 'a {
+        self.findings
+            .iter()
+            .filter(move |f| f.event_ids.contains(id))
+    }
+}
+
+/// Configuration for narrative validation.
+#[derive(Debug, Clone)]
+pub struct ValidationConfig {
+    /// Flag events that cite no sources.
+    pub require_sources: bool,
+    /// Time treated as "now" for the future check (defaults to the current time).
+    pub reference_time: Option<Timestamp>,
+    /// How far past "now" an event may be dated before it is flagged.
+    pub future_tolerance: Duration,
+    /// Events dated before this are flagged as far past.
+    ///
+    /// `None` (the default) disables the check, since what counts as
+    /// implausibly early depends on the narrative: BCE dates are normal in
+    /// a history of Rome.
+    pub earliest_plausible: Option<Timestamp>,
+    /// Maximum plausible speed between consecutive events in meters per second.
+    pub max_speed_mps: f64,
+    /// Jumps shorter than this many meters are never flagged.
+    pub min_jump_distance_m: f64,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            require_sources: true,
+            reference_time: None,
+            future_tolerance: Duration::days(1),
+            earliest_plausible: None,
+            max_speed_mps: 340.0, // roughly airliner cruise speed
+            min_jump_distance_m: 1000.0,
+        }
+    }
+}
+
+/// Runs all checks against a narrative.
+pub(crate) fn validate(narrative: &Narrative, config: &ValidationConfig) -> ValidationReport {
+    let mut findings = Vec::new();
+
+    check_events(narrative, config, &mut findings);
+    check_duplicate_ids(narrative, &mut findings);
+    check_jumps(narrative, config, &mut findings);
+    check_tag_case(narrative, &mut findings);
+    check_sections(narrative, &mut findings);
+
+    ValidationReport { findings }
+}
+
+fn check_events(
+    narrative: &Narrative,
+    config: &ValidationConfig,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    let now = config.reference_time.clone().unwrap_or_else(Timestamp::now);
+    let future_limit = now.datetime + config.future_tolerance;
+
+    for event in narrative.events() {
+        if let Err(e) = event.location.validate() {
+            findings.push(ValidationFinding::new(
+                ValidationCode::InvalidCoordinates,
+                vec![event.id.clone()],
+                format!("event {} has {}", event.id, e),
+            ));
+        }
+
+        if let Some(end) = &event.end_timestamp {
+            if end.datetime < event.timestamp.datetime {
+                findings.push(ValidationFinding::new(
+                    ValidationCode::InvalidInterval,
+                    vec![event.id.clone()],
+                    format!("event {} ends before it starts", event.id),
+                ));
+            }
+        }
+
+        if config.require_sources && event.sources.is_empty() {
+            findings.push(ValidationFinding::new(
+                ValidationCode::MissingSources,
+                vec![event.id.clone()],
+                format!("event {} has no sources", event.id),
+            ));
+        }
+
+        // Precision-aware: only flag what is definitely in the future or past
+        if event.timestamp.earliest().datetime > future_limit {
+            findings.push(ValidationFinding::new(
+                ValidationCode::FutureTimestamp,
+                vec![event.id.clone()],
+                format!(
+                    "event {} is dated in the future ({})",
+                    event.id,
+                    event.timestamp.format_with_precision()
+                ),
+            ));
+        }
+
+        if let Some(earliest) = &config.earliest_plausible {
+            if event.timestamp.latest().datetime < earliest.datetime {
+                findings.push(ValidationFinding::new(
+                    ValidationCode::FarPastTimestamp,
+                    vec![event.id.clone()],
+                    format!(
+                        "event {} is dated before {} ({})",
+                        event.id,
+                        earliest.format_with_precision(),
+                        event.timestamp.format_with_precision()
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_duplicate_ids(narrative: &Narrative, findings: &mut Vec<ValidationFinding>) {
+    let mut counts: HashMap<&EventId, usize> = HashMap::new();
+    let mut order = Vec::new();
+    for event in narrative.events() {
+        let count = counts.entry(&event.id).or_insert(0);
+        if *count == 0 {
+            order.push(&event.id);
+        }
+        *count += 1;
+    }
+
+    for id in order {
+        let count = counts[id];
+        if count > 1 {
+            findings.push(ValidationFinding::new(
+                ValidationCode::DuplicateEventId,
+                vec![id.clone()],
+                format!("event ID {} is used by {} events", id, count),
+            ));
+        }
+    }
+}
+
+fn check_jumps(
+    narrative: &Narrative,
+    config: &ValidationConfig,
+    findings: &mut Vec<ValidationFinding>,
+) {
+    let events: Vec<_> = narrative
+        .events_chronological()
+        .into_iter()
+        .filter(|e| e.location.is_valid())
+        .collect();
+
+    for pair in events.windows(2) {
+        let (prev, next) = (pair[0], pair[1]);
+        let distance = prev
+            .location
+            .to_geo_point()
+            .haversine_distance(&next.location.to_geo_point());
+        if distance < config.min_jump_distance_m {
+            continue;
+        }
+
+        // Use the longest elapsed time the precisions allow, so coarse
+        // timestamps never produce false alarms.
+        let prev_start = prev.timestamp.earliest();
+        let next_end = next.end_timestamp().unwrap_or(&next.timestamp).latest();
+        let elapsed_s = (next_end.to_unix_millis() - prev_start.to_unix_millis()) as f64 / 1000.0;
+
+        if elapsed_s <= 0.0 || distance / elapsed_s > config.max_speed_mps {
+            findings.push(ValidationFinding::new(
+                ValidationCode::ImplausibleJump,
+                vec![prev.id.clone(), next.id.clone()],
+                format!(
+                    "events {} and {} are {:.0} km apart but only {:.0} s apart in time",
+                    prev.id,
+                    next.id,
+                    distance / 1000.0,
+                    elapsed_s.max(0.0)
+                ),
+            ));
+        }
+    }
+}
+
+fn check_tag_case(narrative: &Narrative, findings: &mut Vec<ValidationFinding>) {
+    let mut groups: BTreeMap<String, (BTreeSet<&str>, Vec<EventId>)> = BTreeMap::new();
+    for event in narrative.events() {
+        for tag in &event.tags {
+            let (variants, ids) = groups.entry(tag.to_lowercase()).or_default();
+            variants.insert(tag.as_str());
+            if !ids.contains(&event.id) {
+                ids.push(event.id.clone());
+            }
+        }
+    }
+
+    for (variants, ids) in groups.into_values() {
+        if variants.len() > 1 {
+            let variants: Vec<_> = variants.into_iter().collect();
+            findings.push(ValidationFinding::new(
+                ValidationCode::TagCaseConflict,
+                ids,
+                format!("tags differ only by case: {}", variants.join(", ")),
+            ));
+        }
+    }
+}
+
+fn check_sections(narrative: &Narrative, findings: &mut Vec<ValidationFinding>) {
+    let known: HashSet<&EventId> = narrative.events.iter().map(|e| &e.id).collect();
+    for section in narrative.sections.iter().flat_map(|s| s.flatten()) {
+        let missing: Vec<EventId> = section
+            .events
+            .iter()
+            .filter(|id| !known.contains(id))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            findings.push(ValidationFinding::new(
+                ValidationCode::DanglingSectionEvent,
+                missing,
+                format!(
+                    "section '{}' references events not in the narrative",
+                    section.title
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Event, Location, SourceRef};
+
+    fn event(lat: f64, lon: f64, time: &str) -> Event {
+        Event::builder()
+            .location(Location::new(lat, lon))
+            .timestamp(Timestamp::parse(time).unwrap())
+            .source(SourceRef::article("https://example.com"))
+            .build()
+    }
+
+    fn config() -> ValidationConfig {
+        ValidationConfig {
+            reference_time: Some(Timestamp::parse("2024-06-01T00:00:00Z").unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn codes(report: &ValidationReport) -> Vec<ValidationCode> {
+        report.findings.iter().map(|f| f.code).collect()
+    }
+
+    #[test]
+    fn test_clean_narrative() {
+        let narrative = Narrative::builder()
+            .event(event(40.7, -74.0, "2024-01-01T10:00:00Z"))
+            .event(event(40.8, -74.1, "2024-01-01T12:00:00Z"))
+            .build();
+
+        let report = narrative.validate_with(&config());
+        assert!(report.is_empty());
+        assert!(report.is_valid());
+        assert_eq!(report.max_severity(), None);
+    }
+
+    #[test]
+    fn test_invalid_coordinates_and_duplicates() {
+        let bad = event(95.0, 0.0, "2024-01-01T10:00:00Z");
+        let mut dup = event(10.0, 10.0, "2024-01-01T10:00:00Z");
+        dup.id = bad.id.clone();
+
+        let narrative = Narrative::builder().event(bad.clone()).event(dup).build();
+        let report = narrative.validate_with(&config());
+
+        assert!(!report.is_valid());
+        assert_eq!(
+            codes(&report),
+            vec![
+                ValidationCode::InvalidCoordinates,
+                ValidationCode::DuplicateEventId
+            ]
+        );
+        assert_eq!(report.errors().count(), 2);
+        assert_eq!(report.for_event(&bad.id).count(), 2);
+    }
+
+    #[test]
+    fn test_sources_and_timestamps() {
+        let mut unsourced = event(0.0, 0.0, "2024-01-01T00:00:00Z");
+        unsourced.sources.clear();
+        let future = event(0.0, 0.0, "2030-01-01T00:00:00Z");
+        let ancient = event(0.0, 0.0, "1850-01-01T00:00:00Z");
+        // A coarse timestamp whose range reaches the threshold is not flagged
+        let century = event(0.0, 0.0, "20th century");
+
+        let narrative = Narrative::builder()
+            .events([unsourced, future, ancient, century])
+            .build();
+        let report = narrative.validate_with(&ValidationConfig {
+            earliest_plausible: Some(Timestamp::parse("1950-01-01T00:00:00Z").unwrap()),
+            ..config()
+        });
+
+        assert!(report.is_valid());
+        assert_eq!(report.with_code(ValidationCode::MissingSources).count(), 1);
+        assert_eq!(report.with_code(ValidationCode::FutureTimestamp).count(), 1);
+        assert_eq!(
+            report.with_code(ValidationCode::FarPastTimestamp).count(),
+            1
+        );
+
+        // Without a cutoff, ancient dates are not flagged
+        let narrative = Narrative::builder()
+            .event(event(41.89, 12.49, "44 BCE"))
+            .event(event(31.78, 35.23, "the 10th century BCE"))
+            .build();
+        let report = narrative.validate_with(&config());
+        assert_eq!(
+            report.with_code(ValidationCode::FarPastTimestamp).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_implausible_jump() {
+        // New York to Paris in ten minutes
+        let a = event(40.7128, -74.0060, "2024-01-01T10:00:00Z");
+        let b = event(48.8566, 2.3522, "2024-01-01T10:10:00Z");
+        // Paris to Berlin over a day is fine
+        let c = event(52.52, 13.405, "2024-01-02T10:10:00Z");
+
+        let narrative = Narrative::builder()
+            .events([c, b.clone(), a.clone()])
+            .build();
+        let report = narrative.validate_with(&config());
+
+        let jumps: Vec<_> = report.with_code(ValidationCode::ImplausibleJump).collect();
+        assert_eq!(jumps.len(), 1);
+        assert_eq!(jumps[0].event_ids, vec![a.id, b.id]);
+        assert_eq!(jumps[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_jump_respects_precision() {
+        // Day precision allows up to two days between these events
+        let a = event(40.7128, -74.0060, "2024-01-01");
+        let b = event(48.8566, 2.3522, "2024-01-01");
+
+        let narrative = Narrative::builder().events([a, b]).build();
+        let report = narrative.validate_with(&config());
+        assert_eq!(report.with_code(ValidationCode::ImplausibleJump).count(), 0);
+    }
+
+    #[test]
+    fn test_dangling_section_event() {
+        let a = event(0.0, 0.0, "2024-01-01T00:00:00Z");
+        let missing = EventId::new();
+        let chapter = crate::core::Section::new("Chapter").with_section(
+            crate::core::Section::new("Scene").with_events([a.id.clone(), missing.clone()]),
+        );
+
+        let narrative = Narrative::builder().event(a).section(chapter).build();
+        let report = narrative.validate_with(&config());
+
+        let findings: Vec<_> = report
+            .with_code(ValidationCode::DanglingSectionEvent)
+            .collect();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].event_ids, vec![missing]);
+    }
+
+    #[test]
+    fn test_tag_case_conflict() {
+        let mut a = event(0.0, 0.0, "2024-01-01T00:00:00Z");
+        a.add_tag("Protest");
+        let mut b = event(0.0, 0.0, "2024-01-01T01:00:00Z");
+        b.add_tag("protest");
+        b.add_tag("march");
+
+        let narrative = Narrative::builder().events([a, b]).build();
+        let report = narrative.validate_with(&config());
+
+        let conflicts: Vec<_> = report.with_code(ValidationCode::TagCaseConflict).collect();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].event_ids.len(), 2);
+        assert_eq!(conflicts[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_report_serialization() {
+        let narrative = Narrative::builder()
+            .event(event(95.0, 0.0, "2024-01-01T00:00:00Z"))
+            .build();
+        let report = narrative.validate_with(&config());
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["findings"][0]["code"], "invalid_coordinates");
+        assert_eq!(json["findings"][0]["severity"], "error");
+    }
+}
@@ -0,0 +1,34 @@
+//! Collections of narratives that share events.
+//!
+//! A [`NarrativeCorpus`] stores each event once in a shared pool, and
+//! narratives reference pooled events by [`EventId`](crate::core::EventId).
+//! Several accounts of the same battle can cite the same events without
+//! copying them, and edits to a shared event are seen by every narrative.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::core::{Event, GeoBounds, Location, Narrative, TimeRange, Timestamp};
+//! use spatial_narrative::corpus::NarrativeCorpus;
+//!
+//! let battle = Event::new(
+//!     Location::new(50.68, 4.41),
+//!     Timestamp::parse("1815-06-18").unwrap(),
+//!     "Battle of Waterloo",
+//! );
+//! let battle_id = battle.id.clone();
+//!
+//! let mut corpus = NarrativeCorpus::new();
+//! let british = corpus.add_narrative(Narrative::builder().title("British account").event(battle).build());
+//! let french = corpus.create_narrative("French account", [battle_id.clone()]).unwrap();
+//!
+//! assert_eq!(corpus.event_count(), 1);
+//! assert_eq!(corpus.narratives_containing(&battle_id), [british, french]);
+//!
+//! let belgium = GeoBounds::new(49.5, 2.5, 51.5, 6.4);
+//! assert_eq!(corpus.query(&belgium, &TimeRange::year(1815)).len(), 1);
+//! ```
+
+mod narrative_corpus;
+
+pub use narrative_corpus::{CorpusStats, NarrativeCorpus};
@@ -0,0 +1,528 @@
+//! Narrative corpus with a shared event pool.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::{SpatialMetrics, TemporalMetrics};
+use crate::core::{Event, EventId, GeoBounds, Narrative, NarrativeId, TimeRange, Timestamp};
+use crate::error::{Error, Result};
+use crate::index::{ItemKey, SpatiotemporalIndex};
+
+/// A narrative stored without its events, which live in the pool.
+#[derive(Debug, Clone)]
+struct CorpusEntry {
+    /// The narrative with an empty `events` list.
+    shell: Narrative,
+    /// Referenced events, in the narrative's own order.
+    event_ids: Vec<EventId>,
+}
+
+/// Summary statistics for a corpus.
+#[derive(Debug, Clone)]
+pub struct CorpusStats {
+    /// Number of pooled events.
+    pub event_count: usize,
+    /// Number of narratives.
+    pub narrative_count: usize,
+    /// Events referenced by more than one narrative.
+    pub shared_event_count: usize,
+    /// Events referenced by no narrative.
+    pub orphan_event_count: usize,
+    /// Spatial metrics over all pooled events, in chronological order.
+    pub spatial: SpatialMetrics,
+    /// Temporal metrics over all pooled events.
+    pub temporal: TemporalMetrics,
+}
+
+/// A collection of narratives sharing one pool of events.
+///
+/// Events are stored once and indexed in a [`SpatiotemporalIndex`] for
+/// corpus-wide queries. Narratives keep their title, metadata, actors,
+/// sections and telling order, and reference events by ID.
+#[derive(Debug)]
+pub struct NarrativeCorpus {
+    events: Vec<Event>,
+    positions: HashMap<EventId, usize>,
+    narratives: Vec<CorpusEntry>,
+    memberships: HashMap<EventId, Vec<NarrativeId>>,
+    index: SpatiotemporalIndex<EventId>,
+    index_keys: HashMap<EventId, ItemKey>,
+}
+
+impl NarrativeCorpus {
+    /// Creates an empty corpus.
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            positions: HashMap::new(),
+            narratives: Vec::new(),
+            memberships: HashMap::new(),
+            index: SpatiotemporalIndex::new(),
+            index_keys: HashMap::new(),
+        }
+    }
+
+    /// Creates a corpus from narratives, pooling their events.
+    pub fn from_narratives(narratives: impl IntoIterator<Item = Narrative>) -> Self {
+        let mut corpus = Self::new();
+        for narrative in narratives {
+            corpus.add_narrative(narrative);
+        }
+        corpus
+    }
+
+    /// Adds an event to the pool, replacing any event with the same ID.
+    pub fn add_event(&mut self, event: Event) -> EventId {
+        let id = event.id.clone();
+        match self.positions.get(&id) {
+            Some(&pos) => {
+                self.index_event(&event);
+                self.events[pos] = event;
+            },
+            None => {
+                self.index_event(&event);
+                self.positions.insert(id.clone(), self.events.len());
+                self.events.push(event);
+            },
+        }
+        id
+    }
+
+    /// Adds a narrative, moving its events into the pool.
+    ///
+    /// Events already in the pool are shared: the pooled version is kept
+    /// and the narrative's copy is dropped.
+    pub fn add_narrative(&mut self, mut narrative: Narrative) -> NarrativeId {
+        let events = std::mem::take(&mut narrative.events);
+        let event_ids = events.iter().map(|e| e.id.clone()).collect();
+        for event in events {
+            if !self.positions.contains_key(&event.id) {
+                self.add_event(event);
+            }
+        }
+        self.insert_entry(narrative, event_ids)
+    }
+
+    /// Creates a narrative from events already in the pool.
+    pub fn create_narrative(
+        &mut self,
+        title: impl Into<String>,
+        event_ids: impl IntoIterator<Item = EventId>,
+    ) -> Result<NarrativeId> {
+        let event_ids: Vec<EventId> = event_ids.into_iter().collect();
+        if let Some(missing) = event_ids.iter().find(|id| !self.positions.contains_key(id)) {
+            return Err(Error::EventNotFound(missing.to_string()));
+        }
+        Ok(self.insert_entry(Narrative::new(title), event_ids))
+    }
+
+    /// Adds a pooled event to a narrative.
+    pub fn include_event(&mut self, narrative: &NarrativeId, event: &EventId) -> Result<()> {
+        if !self.positions.contains_key(event) {
+            return Err(Error::EventNotFound(event.to_string()));
+        }
+        let entry = self
+            .entry_mut(narrative)
+            .ok_or_else(|| Error::NarrativeNotFound(narrative.to_string()))?;
+        if !entry.event_ids.contains(event) {
+            entry.event_ids.push(event.clone());
+            entry.shell.metadata.modified = Some(Timestamp::now());
+            self.memberships
+                .entry(event.clone())
+                .or_default()
+                .push(narrative.clone());
+        }
+        Ok(())
+    }
+
+    /// Removes an event from a narrative, keeping it in the pool.
+    ///
+    /// Returns true if the narrative referenced the event.
+    pub fn exclude_event(&mut self, narrative: &NarrativeId, event: &EventId) -> bool {
+        let Some(entry) = self.entry_mut(narrative) else {
+            return false;
+        };
+        let before = entry.event_ids.len();
+        entry.event_ids.retain(|id| id != event);
+        if entry.event_ids.len() == before {
+            return false;
+        }
+        for section in &mut entry.shell.sections {
+            section.remove_event(event);
+        }
+        entry.shell.discourse.retain(|id| id != event);
+        if let Some(owners) = self.memberships.get_mut(event) {
+            owners.retain(|n| n != narrative);
+        }
+        true
+    }
+
+    /// Removes an event from the pool and from every narrative.
+    pub fn remove_event(&mut self, id: &EventId) -> Option<Event> {
+        let pos = self.positions.remove(id)?;
+        let event = self.events.swap_remove(pos);
+        if let Some(moved) = self.events.get(pos) {
+            self.positions.insert(moved.id.clone(), pos);
+        }
+
+        for narrative in self.memberships.remove(id).unwrap_or_default() {
+            if let Some(entry) = self.entry_mut(&narrative) {
+                entry.event_ids.retain(|e| e != id);
+                for section in &mut entry.shell.sections {
+                    section.remove_event(id);
+                }
+                entry.shell.discourse.retain(|e| e != id);
+            }
+        }
+        if let Some(key) = self.index_keys.remove(id) {
+            self.index.remove(key);
+        }
+        Some(event)
+    }
+
+    /// Removes a narrative and returns it with its events.
+    ///
+    /// The events stay in the pool.
+    pub fn remove_narrative(&mut self, id: &NarrativeId) -> Option<Narrative> {
+        let pos = self.narratives.iter().position(|e| &e.shell.id == id)?;
+        let narrative = self.materialize(&self.narratives[pos]);
+        let entry = self.narratives.remove(pos);
+        for event in &entry.event_ids {
+            if let Some(owners) = self.memberships.get_mut(event) {
+                owners.retain(|n| n != id);
+            }
+        }
+        Some(narrative)
+    }
+
+    /// Finds a pooled event by ID.
+    pub fn event(&self, id: &EventId) -> Option<&Event> {
+        self.positions.get(id).map(|&pos| &self.events[pos])
+    }
+
+    /// Finds a pooled event by ID (mutable).
+    ///
+    /// Call [`rebuild_index`](Self::rebuild_index) after changing an
+    /// event's location or time; use [`add_event`](Self::add_event) to
+    /// replace an event and keep the index current.
+    pub fn event_mut(&mut self, id: &EventId) -> Option<&mut Event> {
+        self.positions.get(id).map(|&pos| &mut self.events[pos])
+    }
+
+    /// Returns all pooled events.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the number of pooled events.
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns the number of narratives.
+    pub fn narrative_count(&self) -> usize {
+        self.narratives.len()
+    }
+
+    /// Returns true if the corpus has no events and no narratives.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.narratives.is_empty()
+    }
+
+    /// Returns the IDs of all narratives, in the order they were added.
+    pub fn narrative_ids(&self) -> Vec<&NarrativeId> {
+        self.narratives.iter().map(|e| &e.shell.id).collect()
+    }
+
+    /// Returns a narrative with its events resolved from the pool.
+    pub fn narrative(&self, id: &NarrativeId) -> Option<Narrative> {
+        self.entry(id).map(|entry| self.materialize(entry))
+    }
+
+    /// Returns the pooled events of a narrative, in the narrative's order.
+    pub fn narrative_events(&self, id: &NarrativeId) -> Vec<&Event> {
+        self.entry(id)
+            .map(|entry| {
+                entry
+                    .event_ids
+                    .iter()
+                    .filter_map(|e| self.event(e))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the narratives that include an event, in the order they were added.
+    pub fn narratives_containing(&self, event: &EventId) -> Vec<NarrativeId> {
+        let owners = match self.memberships.get(event) {
+            Some(owners) => owners,
+            None => return Vec::new(),
+        };
+        self.narratives
+            .iter()
+            .map(|e| &e.shell.id)
+            .filter(|id| owners.contains(id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns events within both bounds and time range, sorted by timestamp.
+    pub fn query(&self, bounds: &GeoBounds, range: &TimeRange) -> Vec<&Event> {
+        self.resolve(self.index.query(bounds, range))
+    }
+
+    /// Returns events within bounds, sorted by timestamp.
+    pub fn query_spatial(&self, bounds: &GeoBounds) -> Vec<&Event> {
+        self.resolve(self.index.query_spatial(bounds))
+    }
+
+    /// Returns events overlapping a time range, sorted by timestamp.
+    pub fn query_temporal(&self, range: &TimeRange) -> Vec<&Event> {
+        self.resolve(self.index.query_temporal(range))
+    }
+
+    /// Returns the geographic bounds of all pooled events.
+    pub fn bounds(&self) -> Option<GeoBounds> {
+        self.index.bounds()
+    }
+
+    /// Computes corpus-wide statistics with the [`analysis`](crate::analysis) functions.
+    pub fn stats(&self) -> CorpusStats {
+        let mut chronological = self.events.clone();
+        chronological.sort_by_key(|e| e.timestamp.to_unix_millis());
+
+        let counts: Vec<usize> = self
+            .events
+            .iter()
+            .map(|e| self.memberships.get(&e.id).map_or(0, Vec::len))
+            .collect();
+
+        CorpusStats {
+            event_count: self.events.len(),
+            narrative_count: self.narratives.len(),
+            shared_event_count: counts.iter().filter(|&&c| c > 1).count(),
+            orphan_event_count: counts.iter().filter(|&&c| c == 0).count(),
+            spatial: SpatialMetrics::from_events(&chronological),
+            temporal: TemporalMetrics::from_events(&self.events),
+        }
+    }
+
+    /// Rebuilds the spatiotemporal index from the pool.
+    pub fn rebuild_index(&mut self) {
+        self.index = SpatiotemporalIndex::new();
+        self.index_keys.clear();
+        for pos in 0..self.events.len() {
+            let event = self.events[pos].clone();
+            self.index_event(&event);
+        }
+    }
+
+    /// Indexes an event, replacing the entry of an event with the same ID.
+    fn index_event(&mut self, event: &Event) {
+        if let Some(key) = self.index_keys.remove(&event.id) {
+            self.index.remove(key);
+        }
+        let key = match &event.end_timestamp {
+            Some(end) => {
+                self.index
+                    .insert_interval(event.id.clone(), &event.location, &event.timestamp, end)
+            },
+            None => self
+                .index
+                .insert(event.id.clone(), &event.location, &event.timestamp),
+        };
+        self.index_keys.insert(event.id.clone(), key);
+    }
+
+    fn insert_entry(&mut self, shell: Narrative, event_ids: Vec<EventId>) -> NarrativeId {
+        let id = shell.id.clone();
+        if let Some(pos) = self.narratives.iter().position(|e| e.shell.id == id) {
+            // Replacing a narrative with the same ID drops its old references
+            let old = self.narratives.remove(pos);
+            for event in &old.event_ids {
+                if let Some(owners) = self.memberships.get_mut(event) {
+                    owners.retain(|n| n != &id);
+                }
+            }
+        }
+
+        let mut seen: HashSet<EventId> = HashSet::with_capacity(event_ids.len());
+        let mut unique: Vec<EventId> = Vec::with_capacity(event_ids.len());
+        for event in event_ids {
+            if seen.insert(event.clone()) {
+                self.memberships
+                    .entry(event.clone())
+                    .or_default()
+                    .push(id.clone());
+                unique.push(event);
+            }
+        }
+        self.narratives.push(CorpusEntry {
+            shell,
+            event_ids: unique,
+        });
+        id
+    }
+
+    fn entry(&self, id: &NarrativeId) -> Option<&CorpusEntry> {
+        self.narratives.iter().find(|e| &e.shell.id == id)
+    }
+
+    fn entry_mut(&mut self, id: &NarrativeId) -> Option<&mut CorpusEntry> {
+        self.narratives.iter_mut().find(|e| &e.shell.id == id)
+    }
+
+    fn materialize(&self, entry: &CorpusEntry) -> Narrative {
+        let mut narrative = entry.shell.clone();
+        narrative.events = entry
+            .event_ids
+            .iter()
+            .filter_map(|id| self.event(id).cloned())
+            .collect();
+        narrative
+    }
+
+    fn resolve(&self, ids: Vec<&EventId>) -> Vec<&Event> {
+        let mut events: Vec<&Event> = ids.into_iter().filter_map(|id| self.event(id)).collect();
+        events.sort_by_key(|e| e.timestamp.to_unix_millis());
+        events
+    }
+}
+
+impl Default for NarrativeCorpus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Location;
+
+    fn make_event(lat: f64, lon: f64, time: &str, text: &str) -> Event {
+        Event::new(
+            Location::new(lat, lon),
+            Timestamp::parse(time).unwrap(),
+            text,
+        )
+    }
+
+    fn corpus() -> (NarrativeCorpus, Vec<EventId>, NarrativeId, NarrativeId) {
+        let a = make_event(50.0, 4.0, "1815-06-16T12:00:00Z", "Ligny");
+        let b = make_event(50.7, 4.4, "1815-06-18T12:00:00Z", "Waterloo");
+        let c = make_event(48.9, 2.3, "1815-06-22T12:00:00Z", "Abdication");
+        let ids = vec![a.id.clone(), b.id.clone(), c.id.clone()];
+
+        let first = Narrative::builder()
+            .title("Campaign")
+            .events([a, b.clone()])
+            .build();
+        let second = Narrative::builder()
+            .title("Aftermath")
+            .events([b, c])
+            .build();
+
+        let mut corpus = NarrativeCorpus::new();
+        let first = corpus.add_narrative(first);
+        let second = corpus.add_narrative(second);
+        (corpus, ids, first, second)
+    }
+
+    #[test]
+    fn test_shared_pool() {
+        let (corpus, ids, first, second) = corpus();
+
+        assert_eq!(corpus.event_count(), 3);
+        assert_eq!(corpus.narrative_count(), 2);
+        assert_eq!(
+            corpus.narratives_containing(&ids[1]),
+            vec![first.clone(), second.clone()]
+        );
+        assert_eq!(corpus.narratives_containing(&ids[2]), vec![second.clone()]);
+
+        let campaign = corpus.narrative(&first).unwrap();
+        assert_eq!(campaign.title, "Campaign");
+        assert_eq!(campaign.events.len(), 2);
+        assert_eq!(corpus.narrative_events(&second)[1].text, "Abdication");
+    }
+
+    #[test]
+    fn test_shared_event_updates_all_narratives() {
+        let (mut corpus, ids, first, second) = corpus();
+
+        let mut waterloo = corpus.event(&ids[1]).unwrap().clone();
+        waterloo.text = "Battle of Waterloo".into();
+        corpus.add_event(waterloo);
+
+        for narrative in [&first, &second] {
+            assert!(corpus
+                .narrative_events(narrative)
+                .iter()
+                .any(|e| e.text == "Battle of Waterloo"));
+        }
+    }
+
+    #[test]
+    fn test_membership_changes() {
+        let (mut corpus, ids, first, second) = corpus();
+
+        corpus.include_event(&first, &ids[2]).unwrap();
+        assert_eq!(corpus.narrative_events(&first).len(), 3);
+        assert!(corpus.include_event(&first, &EventId::new()).is_err());
+
+        assert!(corpus.exclude_event(&second, &ids[1]));
+        assert_eq!(corpus.narratives_containing(&ids[1]), vec![first.clone()]);
+
+        let removed = corpus.remove_event(&ids[2]).unwrap();
+        assert_eq!(removed.text, "Abdication");
+        assert_eq!(corpus.narrative_events(&first).len(), 2);
+        assert!(corpus.narrative_events(&second).is_empty());
+        assert!(corpus.event(&ids[0]).is_some() && corpus.event(&ids[1]).is_some());
+
+        let narrative = corpus.remove_narrative(&first).unwrap();
+        assert_eq!(narrative.events.len(), 2);
+        assert_eq!(corpus.event_count(), 2);
+        assert!(corpus.narratives_containing(&ids[0]).is_empty());
+    }
+
+    #[test]
+    fn test_queries_and_stats() {
+        let (mut corpus, ids, _, _) = corpus();
+
+        let belgium = GeoBounds::new(49.5, 2.5, 51.5, 6.4);
+        let june = TimeRange::new(
+            Timestamp::parse("1815-06-01T00:00:00Z").unwrap(),
+            Timestamp::parse("1815-06-30T00:00:00Z").unwrap(),
+        );
+        let hits: Vec<_> = corpus
+            .query(&belgium, &june)
+            .iter()
+            .map(|e| e.text.clone())
+            .collect();
+        assert_eq!(hits, ["Ligny", "Waterloo"]);
+        assert_eq!(corpus.query_temporal(&june).len(), 3);
+
+        corpus.add_event(make_event(0.0, 0.0, "1816-01-01T00:00:00Z", "Unrelated"));
+        let stats = corpus.stats();
+        assert_eq!(stats.event_count, 4);
+        assert_eq!(stats.shared_event_count, 1);
+        assert_eq!(stats.orphan_event_count, 1);
+        assert_eq!(stats.temporal.event_count, 4);
+        assert!(stats.spatial.total_distance > 0.0);
+
+        corpus.remove_event(&ids[0]);
+        assert_eq!(corpus.query_spatial(&belgium).len(), 1);
+
+        // Replacing an event moves its index entry
+        let mut waterloo = corpus.event(&ids[1]).unwrap().clone();
+        waterloo.location = Location::new(48.85, 2.35);
+        corpus.add_event(waterloo);
+        assert!(corpus.query_spatial(&belgium).is_empty());
+        assert_eq!(
+            corpus
+                .query(&GeoBounds::new(48.0, 2.0, 49.5, 3.0), &june)
+                .len(),
+            2
+        );
+    }
+}
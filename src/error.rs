@@ -64,10 +64,21 @@ pub enum Error {
     #[error("analysis error: {0}")]
     AnalysisError(String),
 
+    /// Patch does not match the narrative it is applied to.
+    #[error("patch conflict: {0}")]
+    PatchConflict(String),
+
     /// Parse error.
     #[error("parse error: {0}")]
     ParseError(String),
 
+    /// Document does not match its schema.
+    #[error(
+        "schema validation failed: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    SchemaValidation(Vec<crate::io::SchemaViolation>),
+
     /// Generic error with context.
     #[error("{context}: {source}")]
     WithContext {
@@ -8,7 +8,7 @@ use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use std::collections::HashMap;
 
-use crate::core::{Event, EventId, GeoBounds, Location, TimeRange};
+use crate::core::{Event, EventId, GeoBounds, Location, TemporalEntity, TemporalMatch, TimeRange};
 
 /// Unique identifier for a node in the narrative graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -350,10 +350,12 @@ impl NarrativeGraph {
     }
 
     /// Extract a subgraph containing only events within a time range.
-    pub fn subgraph_temporal(&self, range: &TimeRange) -> SubgraphResult {
+    ///
+    /// Events match as in [`Narrative::filter_temporal`].
+    pub fn subgraph_temporal(&self, range: &TimeRange, mode: TemporalMatch) -> SubgraphResult {
         let nodes: Vec<NodeId> = self
             .nodes()
-            .filter(|(_, event)| range.contains(&event.timestamp))
+            .filter(|(_, event)| event.matches_time_range(range, mode))
             .map(|(id, _)| id)
             .collect();
 
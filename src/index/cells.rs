@@ -0,0 +1,817 @@
+//! Hierarchical discrete global grids.
+//!
+//! A [`CellSystem`] divides the globe into cells at several levels of
+//! detail. Each [`Cell`] has a string ID and an integer ID that are stable
+//! across datasets, so cells make consistent bins and a join key with
+//! other systems that use the same grid.
+//!
+//! - [`CellSystem::Geohash`] - the standard base-32 geohash, levels 1 to 12
+//! - [`CellSystem::Quadkey`] - Bing Maps / slippy-map tiles, levels 1 to 23
+//! - [`CellSystem::Hex`] - hexagons on the Web Mercator plane, levels 0 to 20
+//!
+//! Hex cells are regular on the Web Mercator plane, not on the globe, so
+//! they are not equal-area: like map tiles, a cell covers less ground
+//! towards the poles. Their IDs are this crate's own and are not
+//! compatible with H3.
+//!
+//! # Example
+//!
+//! ```rust
+//! use spatial_narrative::core::Location;
+//! use spatial_narrative::index::CellSystem;
+//!
+//! let paris = Location::new(48.8566, 2.3522);
+//! let cell = CellSystem::Geohash.cell(&paris, 5).unwrap();
+//! assert_eq!(cell.id(), "u09tv");
+//! assert_eq!(cell.parent().unwrap().id(), "u09t");
+//! assert!(cell.contains(&paris));
+//! ```
+
+use crate::core::{GeoBounds, Location};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Latitude limit of the Web Mercator projection.
+const MERCATOR_MAX_LAT: f64 = 85.051_128_779_806_59;
+
+/// Hex grid columns around the globe at level 0; doubles at each level.
+const HEX_BASE_COLUMNS: u32 = 6;
+
+/// Hex grid row spacings from the north to the south edge of the Web
+/// Mercator square at level 0; doubles at each level.
+const HEX_BASE_ROWS: u32 = 7;
+
+/// Axial offsets of the six hexagon neighbours.
+const HEX_DIRECTIONS: [(i64, i64); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+/// A hierarchical grid system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CellSystem {
+    /// Base-32 geohash; each level splits a cell into 32.
+    Geohash,
+    /// Web Mercator quadtree tiles; each level splits a cell into 4.
+    Quadkey,
+    /// Pointy-top hexagons on the Web Mercator plane; each level has
+    /// four times as many cells.
+    ///
+    /// Hexagons cannot nest exactly. A cell's children cover roughly its
+    /// area: the child at its centre and three of the six children on its
+    /// edges. Because of this, [`Cell::parent`] often differs from the
+    /// coarser cell a location falls in; see its docs.
+    Hex,
+}
+
+impl CellSystem {
+    /// All cell systems.
+    pub const ALL: [CellSystem; 3] = [CellSystem::Geohash, CellSystem::Quadkey, CellSystem::Hex];
+
+    /// Returns the system's name as used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CellSystem::Geohash => "geohash",
+            CellSystem::Quadkey => "quadkey",
+            CellSystem::Hex => "hex",
+        }
+    }
+
+    /// The coarsest level.
+    pub fn min_level(&self) -> u8 {
+        match self {
+            CellSystem::Geohash | CellSystem::Quadkey => 1,
+            CellSystem::Hex => 0,
+        }
+    }
+
+    /// The finest level.
+    pub fn max_level(&self) -> u8 {
+        match self {
+            CellSystem::Geohash => 12,
+            CellSystem::Quadkey => 23,
+            CellSystem::Hex => 20,
+        }
+    }
+
+    /// Returns the cell containing a location at a level.
+    ///
+    /// Quadkey and hex cells cover latitudes up to about ±85.05°; points
+    /// closer to the poles fall in the northernmost or southernmost cells.
+    ///
+    /// Fails if the level is out of range or the location's coordinates
+    /// are not valid (NaN or outside ±90°/±180°).
+    pub fn cell(&self, location: &Location, level: u8) -> Result<Cell> {
+        self.check_level(level)?;
+        location.validate()?;
+        let (x, y) = match self {
+            CellSystem::Geohash => {
+                let (lon_bits, lat_bits) = geohash_bits(level);
+                (
+                    grid_index((location.lon + 180.0) / 360.0, lon_bits),
+                    grid_index((location.lat + 90.0) / 180.0, lat_bits),
+                )
+            },
+            CellSystem::Quadkey => {
+                let bits = u32::from(level);
+                (
+                    grid_index((location.lon + 180.0) / 360.0, bits),
+                    grid_index(mercator_y(location.lat), bits),
+                )
+            },
+            CellSystem::Hex => hex_locate(
+                (location.lon + 180.0) / 360.0,
+                mercator_y(location.lat),
+                level,
+            ),
+        };
+        Ok(Cell {
+            system: *self,
+            level,
+            x,
+            y,
+        })
+    }
+
+    /// Parses a cell from its string ID.
+    pub fn parse(&self, id: &str) -> Result<Cell> {
+        let invalid = || Error::IndexError(format!("invalid {} cell ID: {id:?}", self.as_str()));
+        let cell = match self {
+            CellSystem::Geohash => {
+                let mut code = 0u64;
+                for c in id.bytes() {
+                    let digit = GEOHASH_ALPHABET
+                        .iter()
+                        .position(|&a| a == c.to_ascii_lowercase())
+                        .ok_or_else(invalid)?;
+                    code = (code << 5) | digit as u64;
+                }
+                let level = u8::try_from(id.len()).map_err(|_| invalid())?;
+                self.path_cell(code, level)
+            },
+            CellSystem::Quadkey => {
+                let mut code = 0u64;
+                for c in id.chars() {
+                    let digit = c.to_digit(4).ok_or_else(invalid)?;
+                    code = (code << 2) | u64::from(digit);
+                }
+                let level = u8::try_from(id.len()).map_err(|_| invalid())?;
+                self.path_cell(code, level)
+            },
+            CellSystem::Hex => {
+                if id.len() != 14 {
+                    return Err(invalid());
+                }
+                let packed = u64::from_str_radix(id, 16).map_err(|_| invalid())?;
+                self.cell_from_u64(packed)
+            },
+        };
+        cell.map_err(|_| invalid())
+    }
+
+    /// Returns a cell from its integer ID (see [`Cell::to_u64`]).
+    pub fn cell_from_u64(&self, id: u64) -> Result<Cell> {
+        let invalid = || Error::IndexError(format!("invalid {} cell ID: {id}", self.as_str()));
+        match self {
+            CellSystem::Geohash | CellSystem::Quadkey => {
+                let digit_bits = self.path_digit_bits();
+                if id == 0 {
+                    return Err(invalid());
+                }
+                // The highest set bit marks where the path starts
+                let path_bits = 63 - id.leading_zeros();
+                if path_bits % digit_bits != 0 {
+                    return Err(invalid());
+                }
+                let level = (path_bits / digit_bits) as u8;
+                self.path_cell(id & !(1 << path_bits), level)
+                    .map_err(|_| invalid())
+            },
+            CellSystem::Hex => {
+                let level = (id >> 48) as u8;
+                let row = ((id >> 24) & 0xff_ffff) as u32;
+                let col = (id & 0xff_ffff) as u32;
+                self.check_level(level).map_err(|_| invalid())?;
+                if id >> 56 != 0 || col >= hex_columns(level) || row >= hex_rows(level) {
+                    return Err(invalid());
+                }
+                Ok(Cell {
+                    system: *self,
+                    level,
+                    x: col,
+                    y: row,
+                })
+            },
+        }
+    }
+
+    fn check_level(&self, level: u8) -> Result<()> {
+        if level < self.min_level() || level > self.max_level() {
+            return Err(Error::IndexError(format!(
+                "{} level {level} out of range {}..={}",
+                self.as_str(),
+                self.min_level(),
+                self.max_level()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Bits per path digit for the nested systems.
+    fn path_digit_bits(&self) -> u32 {
+        match self {
+            CellSystem::Geohash => 5,
+            _ => 2,
+        }
+    }
+
+    /// Builds a geohash or quadkey cell from its interleaved path bits.
+    fn path_cell(&self, code: u64, level: u8) -> Result<Cell> {
+        self.check_level(level)?;
+        let (x, y) = match self {
+            CellSystem::Geohash => {
+                let (lon_bits, lat_bits) = geohash_bits(level);
+                deinterleave(code, lon_bits, lat_bits)
+            },
+            // Quadkey digits are (y bit, x bit) pairs
+            _ => {
+                let (y, x) = deinterleave(code, u32::from(level), u32::from(level));
+                (x, y)
+            },
+        };
+        Ok(Cell {
+            system: *self,
+            level,
+            x,
+            y,
+        })
+    }
+}
+
+impl std::fmt::Display for CellSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A cell in a hierarchical grid.
+///
+/// Cells are cheap to copy and compare, and can be used as map keys.
+/// [`Display`](std::fmt::Display) writes the string ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cell {
+    system: CellSystem,
+    level: u8,
+    /// Column: longitude index for geohash, tile x for quadkey, offset
+    /// column for hex.
+    x: u32,
+    /// Row: latitude index from the south for geohash, tile y from the
+    /// north for quadkey, offset row from the north for hex.
+    y: u32,
+}
+
+impl Cell {
+    /// The grid system of this cell.
+    pub fn system(&self) -> CellSystem {
+        self.system
+    }
+
+    /// The level of this cell.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// The string ID: a geohash, a quadkey, or 14 hex digits for hex cells.
+    pub fn id(&self) -> String {
+        match self.system {
+            CellSystem::Geohash => {
+                let code = self.path();
+                (0..self.level)
+                    .rev()
+                    .map(|i| GEOHASH_ALPHABET[((code >> (5 * u32::from(i))) & 31) as usize] as char)
+                    .collect()
+            },
+            CellSystem::Quadkey => {
+                let code = self.path();
+                (0..self.level)
+                    .rev()
+                    .map(|i| char::from(b'0' + ((code >> (2 * u32::from(i))) & 3) as u8))
+                    .collect()
+            },
+            CellSystem::Hex => format!("{:014x}", self.to_u64()),
+        }
+    }
+
+    /// The integer ID.
+    ///
+    /// Geohash and quadkey IDs are the path bits behind a leading 1, so
+    /// IDs of different levels never collide. Hex IDs pack the level, row
+    /// and column.
+    pub fn to_u64(&self) -> u64 {
+        match self.system {
+            CellSystem::Geohash | CellSystem::Quadkey => {
+                let path_bits = self.system.path_digit_bits() * u32::from(self.level);
+                (1 << path_bits) | self.path()
+            },
+            CellSystem::Hex => {
+                (u64::from(self.level) << 48) | (u64::from(self.y) << 24) | u64::from(self.x)
+            },
+        }
+    }
+
+    /// Returns true if the location falls in this cell.
+    pub fn contains(&self, location: &Location) -> bool {
+        self.system
+            .cell(location, self.level)
+            .is_ok_and(|cell| cell == *self)
+    }
+
+    /// The centre of the cell.
+    pub fn center(&self) -> Location {
+        match self.system {
+            CellSystem::Hex => {
+                let (px, py) = hex_center(self.x, self.y, self.level);
+                Location::new(mercator_lat(py), px * 360.0 - 180.0)
+            },
+            _ => self.bounds().center(),
+        }
+    }
+
+    /// The bounding box of the cell.
+    ///
+    /// Hex cells on the antimeridian have wrapping bounds.
+    pub fn bounds(&self) -> GeoBounds {
+        match self.system {
+            CellSystem::Geohash => {
+                let (lon_bits, lat_bits) = geohash_bits(self.level);
+                let lon_step = 360.0 / f64::from(1u32 << lon_bits);
+                let lat_step = 180.0 / f64::from(1u32 << lat_bits);
+                GeoBounds::new(
+                    -90.0 + f64::from(self.y) * lat_step,
+                    -180.0 + f64::from(self.x) * lon_step,
+                    -90.0 + f64::from(self.y + 1) * lat_step,
+                    -180.0 + f64::from(self.x + 1) * lon_step,
+                )
+            },
+            CellSystem::Quadkey => {
+                let n = f64::from(1u32 << self.level);
+                GeoBounds::new(
+                    mercator_lat(f64::from(self.y + 1) / n),
+                    f64::from(self.x) / n * 360.0 - 180.0,
+                    mercator_lat(f64::from(self.y) / n),
+                    f64::from(self.x + 1) / n * 360.0 - 180.0,
+                )
+            },
+            CellSystem::Hex => {
+                let size = hex_size(self.level);
+                let (_, py) = hex_center(self.x, self.y, self.level);
+                let half_width = 3f64.sqrt() / 2.0 * size * 360.0;
+                let lons = GeoBounds::from_center_degrees(&self.center(), 0.0, half_width);
+                GeoBounds::new(
+                    mercator_lat(py + size),
+                    lons.min_lon,
+                    mercator_lat(py - size),
+                    lons.max_lon,
+                )
+            },
+        }
+    }
+
+    /// The corners of the cell, counter-clockwise as seen on a map.
+    ///
+    /// Four corners for geohash and quadkey cells, six for hex cells.
+    /// Vertices of cells on the antimeridian may have longitudes beyond
+    /// ±180 so that the ring stays continuous.
+    pub fn boundary(&self) -> Vec<Location> {
+        match self.system {
+            CellSystem::Hex => {
+                let size = hex_size(self.level);
+                let (px, py) = hex_center(self.x, self.y, self.level);
+                // Mercator y grows southwards, so clockwise angles are
+                // counter-clockwise on the map
+                (0..6)
+                    .map(|k| {
+                        let angle = (30.0 - 60.0 * f64::from(k)).to_radians();
+                        let x = px + size * angle.cos();
+                        let y = py + size * angle.sin();
+                        Location::new(mercator_lat(y), x * 360.0 - 180.0)
+                    })
+                    .collect()
+            },
+            _ => {
+                let b = self.bounds();
+                vec![
+                    Location::new(b.min_lat, b.min_lon),
+                    Location::new(b.min_lat, b.max_lon),
+                    Location::new(b.max_lat, b.max_lon),
+                    Location::new(b.max_lat, b.min_lon),
+                ]
+            },
+        }
+    }
+
+    /// The cell one level up that this cell belongs to, or `None` at the
+    /// coarsest level.
+    ///
+    /// Geohash and quadkey parents contain the whole cell. A hex parent is
+    /// only the logical parent in the hierarchy: it does not contain all
+    /// of the cell, and for about 38% of locations it is not the cell the
+    /// location falls in one level up. To bin a location at several hex
+    /// levels, look each level up directly with [`CellSystem::cell`].
+    pub fn parent(&self) -> Option<Cell> {
+        if self.level == self.system.min_level() {
+            return None;
+        }
+        let level = self.level - 1;
+        match self.system {
+            CellSystem::Geohash | CellSystem::Quadkey => {
+                let digit_bits = self.system.path_digit_bits();
+                self.system.path_cell(self.path() >> digit_bits, level).ok()
+            },
+            CellSystem::Hex => {
+                let (q, r) = hex_axial(self.x, self.y);
+                // Edge children belong to the parent on their D0..D2 side
+                let (dq, dr) = [
+                    (0, 0),
+                    HEX_DIRECTIONS[0],
+                    HEX_DIRECTIONS[1],
+                    HEX_DIRECTIONS[2],
+                ]
+                .into_iter()
+                .find(|(dq, dr)| (q - dq) % 2 == 0 && (r - dr) % 2 == 0)?;
+                self.hex_cell((q - dq) / 2, (r - dr) / 2, level)
+            },
+        }
+    }
+
+    /// The cell at a coarser level that this cell belongs to.
+    ///
+    /// Returns the cell itself at its own level, and `None` for a finer
+    /// level or one below the system's minimum. Hex ancestors follow
+    /// [`Cell::parent`], so they need not contain the cell's locations.
+    pub fn ancestor(&self, level: u8) -> Option<Cell> {
+        if level > self.level || level < self.system.min_level() {
+            return None;
+        }
+        let mut cell = *self;
+        while cell.level > level {
+            cell = cell.parent()?;
+        }
+        Some(cell)
+    }
+
+    /// The cells one level down whose parent is this cell.
+    ///
+    /// Geohash cells have 32 children and quadkey cells 4, tiling the
+    /// parent exactly. Hex cells have 4, covering roughly the parent's
+    /// area, or fewer on the polar edges of the grid. Empty at the
+    /// finest level.
+    pub fn children(&self) -> Vec<Cell> {
+        if self.level == self.system.max_level() {
+            return Vec::new();
+        }
+        let level = self.level + 1;
+        match self.system {
+            CellSystem::Geohash | CellSystem::Quadkey => {
+                let digit_bits = self.system.path_digit_bits();
+                (0..1u64 << digit_bits)
+                    .filter_map(|digit| {
+                        self.system
+                            .path_cell((self.path() << digit_bits) | digit, level)
+                            .ok()
+                    })
+                    .collect()
+            },
+            CellSystem::Hex => {
+                let (q, r) = hex_axial(self.x, self.y);
+                [
+                    (0, 0),
+                    HEX_DIRECTIONS[0],
+                    HEX_DIRECTIONS[1],
+                    HEX_DIRECTIONS[2],
+                ]
+                .into_iter()
+                .filter_map(|(dq, dr)| self.hex_cell(2 * q + dq, 2 * r + dr, level))
+                .collect()
+            },
+        }
+    }
+
+    /// The adjacent cells at the same level.
+    ///
+    /// Up to eight for geohash and quadkey cells (including diagonals)
+    /// and six for hex cells. Neighbours wrap across the antimeridian;
+    /// cells on the polar edge of the grid have fewer.
+    pub fn neighbors(&self) -> Vec<Cell> {
+        let mut neighbors = Vec::new();
+        let mut push = |cell: Option<Cell>| {
+            if let Some(cell) = cell.filter(|c| c != self && !neighbors.contains(c)) {
+                neighbors.push(cell);
+            }
+        };
+        match self.system {
+            CellSystem::Hex => {
+                let (q, r) = hex_axial(self.x, self.y);
+                for (dq, dr) in HEX_DIRECTIONS {
+                    push(self.hex_cell(q + dq, r + dr, self.level));
+                }
+            },
+            _ => {
+                let (cols, rows) = self.dimensions();
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        let y = i64::from(self.y) + dy;
+                        let x = (i64::from(self.x) + dx).rem_euclid(cols);
+                        push((0..rows).contains(&y).then_some(Cell {
+                            x: x as u32,
+                            y: y as u32,
+                            ..*self
+                        }));
+                    }
+                }
+            },
+        }
+        neighbors
+    }
+
+    /// The hex cell at axial coordinates, wrapping around the globe.
+    fn hex_cell(&self, q: i64, r: i64, level: u8) -> Option<Cell> {
+        if !(0..i64::from(hex_rows(level))).contains(&r) {
+            return None;
+        }
+        let col = (q + (r - (r & 1)) / 2).rem_euclid(i64::from(hex_columns(level)));
+        Some(Cell {
+            system: self.system,
+            level,
+            x: col as u32,
+            y: r as u32,
+        })
+    }
+
+    /// Interleaved path bits of a geohash or quadkey cell.
+    fn path(&self) -> u64 {
+        match self.system {
+            CellSystem::Geohash => {
+                let (lon_bits, lat_bits) = geohash_bits(self.level);
+                interleave(self.x, lon_bits, self.y, lat_bits)
+            },
+            _ => {
+                let bits = u32::from(self.level);
+                interleave(self.y, bits, self.x, bits)
+            },
+        }
+    }
+
+    /// Number of columns and rows of a geohash or quadkey level.
+    fn dimensions(&self) -> (i64, i64) {
+        match self.system {
+            CellSystem::Geohash => {
+                let (lon_bits, lat_bits) = geohash_bits(self.level);
+                (1 << lon_bits, 1 << lat_bits)
+            },
+            _ => (1 << self.level, 1 << self.level),
+        }
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Longitude and latitude bits in a geohash of `level` characters.
+fn geohash_bits(level: u8) -> (u32, u32) {
+    let bits = 5 * u32::from(level);
+    (bits.div_ceil(2), bits / 2)
+}
+
+/// Index of the bin containing a fraction in [0, 1], with `bits` bits.
+fn grid_index(fraction: f64, bits: u32) -> u32 {
+    let n = f64::from(1u32 << bits);
+    (fraction * n).floor().clamp(0.0, n - 1.0) as u32
+}
+
+/// Interleaves two bit strings, most significant first, starting with `a`.
+fn interleave(a: u32, a_bits: u32, b: u32, b_bits: u32) -> u64 {
+    let mut code = 0u64;
+    for i in 0..a_bits + b_bits {
+        let bit = if i % 2 == 0 {
+            (a >> (a_bits - 1 - i / 2)) & 1
+        } else {
+            (b >> (b_bits - 1 - i / 2)) & 1
+        };
+        code = (code << 1) | u64::from(bit);
+    }
+    code
+}
+
+/// Splits interleaved bits back into the two strings.
+fn deinterleave(code: u64, a_bits: u32, b_bits: u32) -> (u32, u32) {
+    let (mut a, mut b) = (0u32, 0u32);
+    let total = a_bits + b_bits;
+    for i in 0..total {
+        let bit = ((code >> (total - 1 - i)) & 1) as u32;
+        if i % 2 == 0 {
+            a = (a << 1) | bit;
+        } else {
+            b = (b << 1) | bit;
+        }
+    }
+    (a, b)
+}
+
+/// Web Mercator y in [0, 1], from the north edge.
+fn mercator_y(lat: f64) -> f64 {
+    let sin = lat
+        .clamp(-MERCATOR_MAX_LAT, MERCATOR_MAX_LAT)
+        .to_radians()
+        .sin();
+    0.5 - ((1.0 + sin) / (1.0 - sin)).ln() / (4.0 * std::f64::consts::PI)
+}
+
+/// Latitude of a Web Mercator y.
+fn mercator_lat(y: f64) -> f64 {
+    (std::f64::consts::PI * (1.0 - 2.0 * y))
+        .sinh()
+        .atan()
+        .to_degrees()
+}
+
+fn hex_columns(level: u8) -> u32 {
+    HEX_BASE_COLUMNS << level
+}
+
+/// Hexagon circumradius in Web Mercator units (the world is 1 wide).
+fn hex_size(level: u8) -> f64 {
+    1.0 / (3f64.sqrt() * f64::from(hex_columns(level)))
+}
+
+/// Rows needed to cover the Web Mercator square.
+fn hex_rows(level: u8) -> u32 {
+    (HEX_BASE_ROWS << level) + 1
+}
+
+/// Axial coordinates of a hex cell (odd rows are shifted right).
+fn hex_axial(col: u32, row: u32) -> (i64, i64) {
+    let (col, row) = (i64::from(col), i64::from(row));
+    (col - (row - (row & 1)) / 2, row)
+}
+
+/// Web Mercator centre of a hex cell.
+fn hex_center(col: u32, row: u32, level: u8) -> (f64, f64) {
+    let size = hex_size(level);
+    let x = 3f64.sqrt() * size * (f64::from(col) + 0.5 * f64::from(row & 1));
+    (x.rem_euclid(1.0), 1.5 * size * f64::from(row))
+}
+
+/// Column and row of the hex cell containing a Web Mercator point.
+fn hex_locate(x: f64, y: f64, level: u8) -> (u32, u32) {
+    let size = hex_size(level);
+    let q = (3f64.sqrt() / 3.0 * x - y / 3.0) / size;
+    let r = (2.0 / 3.0 * y) / size;
+
+    // Round cube coordinates, fixing the component with the largest error
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+
+    let (q, r) = (rq as i64, rr as i64);
+    let row = r.clamp(0, i64::from(hex_rows(level)) - 1);
+    let col = (q + (r - (r & 1)) / 2).rem_euclid(i64::from(hex_columns(level)));
+    (col as u32, row as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_rejects_invalid_locations() {
+        let invalid = [
+            Location::new(f64::NAN, 0.0),
+            Location::new(0.0, f64::NAN),
+            Location::new(91.0, 0.0),
+            Location::new(0.0, -180.5),
+        ];
+        for system in CellSystem::ALL {
+            let cell = system.cell(&Location::new(0.0, 0.0), 4).unwrap();
+            for location in &invalid {
+                assert!(system.cell(location, 4).is_err(), "{system} {location:?}");
+                assert!(!cell.contains(location), "{system} {location:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_geohash_known_values() {
+        let cell = CellSystem::Geohash
+            .cell(&Location::new(57.64911, 10.40744), 11)
+            .unwrap();
+        assert_eq!(cell.id(), "u4pruydqqvj");
+        assert!(cell.bounds().contains(&Location::new(57.64911, 10.40744)));
+
+        let parsed = CellSystem::Geohash.parse("u4pruydqqvj").unwrap();
+        assert_eq!(parsed, cell);
+        assert_eq!(
+            CellSystem::Geohash.cell_from_u64(cell.to_u64()).unwrap(),
+            cell
+        );
+        assert_eq!(cell.ancestor(3).unwrap().id(), "u4p");
+        assert_eq!(cell.parent().unwrap().children().len(), 32);
+        assert!(CellSystem::Geohash.parse("u4pa").is_err());
+        assert!(CellSystem::Geohash
+            .cell(&Location::new(0.0, 0.0), 13)
+            .is_err());
+    }
+
+    #[test]
+    fn test_quadkey_known_values() {
+        // Tile z=3, x=2, y=3 in slippy-map coordinates
+        let cell = CellSystem::Quadkey.parse("032").unwrap();
+        assert_eq!((cell.x, cell.y), (2, 3));
+
+        let center = cell.center();
+        assert_eq!(CellSystem::Quadkey.cell(&center, 3).unwrap(), cell);
+        assert_eq!(cell.parent().unwrap().id(), "03");
+        let children: Vec<String> = cell.children().iter().map(Cell::id).collect();
+        assert_eq!(children, ["0320", "0321", "0322", "0323"]);
+        assert_eq!(
+            CellSystem::Quadkey.cell_from_u64(cell.to_u64()).unwrap(),
+            cell
+        );
+        assert!(CellSystem::Quadkey.parse("0324").is_err());
+    }
+
+    #[test]
+    fn test_neighbors_wrap_antimeridian() {
+        let fiji = Location::new(-17.0, 179.99);
+        for system in CellSystem::ALL {
+            let cell = system.cell(&fiji, 4).unwrap();
+            let across = system.cell(&Location::new(-17.0, -179.99), 4).unwrap();
+            if cell == across {
+                // Some hexagons straddle the antimeridian
+                assert_eq!(system, CellSystem::Hex);
+                assert!(cell.bounds().crosses_antimeridian());
+                continue;
+            }
+            assert!(cell.neighbors().contains(&across), "{system}");
+            assert!(across.neighbors().contains(&cell), "{system}");
+        }
+
+        // A hexagon has six neighbours; a top-row tile has five
+        let hex = CellSystem::Hex.cell(&Location::new(10.0, 10.0), 6).unwrap();
+        assert_eq!(hex.neighbors().len(), 6);
+        let top = CellSystem::Quadkey.parse("0000").unwrap();
+        assert_eq!(top.neighbors().len(), 5);
+    }
+
+    #[test]
+    fn test_hex_hierarchy() {
+        let location = Location::new(51.5074, -0.1278);
+        let cell = CellSystem::Hex.cell(&location, 12).unwrap();
+        assert!(cell.contains(&location));
+        assert_eq!(cell.id().len(), 14);
+        assert_eq!(CellSystem::Hex.parse(&cell.id()).unwrap(), cell);
+
+        // Every child names this cell as its parent
+        let children = cell.children();
+        assert_eq!(children.len(), 4);
+        assert!(children.iter().all(|c| c.parent() == Some(cell)));
+        assert_eq!(cell.ancestor(0).unwrap().level(), 0);
+        assert_eq!(cell.boundary().len(), 6);
+
+        // Points near the centre share the cell; its neighbours do not
+        assert!(cell.bounds().contains(&cell.center()));
+        for neighbor in cell.neighbors() {
+            assert!(!cell.contains(&neighbor.center()));
+        }
+    }
+
+    #[test]
+    fn test_hex_parent_differs_from_lookup() {
+        // The parent is the containing cell one level up for most, but not
+        // all, locations; direct lookup always contains the location
+        let mut differs = 0;
+        let mut total = 0;
+        for i in 0..40 {
+            for j in 0..40 {
+                let location = Location::new(51.0 + i as f64 * 0.01, j as f64 * 0.01);
+                let cell = CellSystem::Hex.cell(&location, 12).unwrap();
+                let direct = CellSystem::Hex.cell(&location, 11).unwrap();
+                assert!(direct.contains(&location));
+                total += 1;
+                if cell.parent() != Some(direct) {
+                    differs += 1;
+                }
+            }
+        }
+        let share = differs as f64 / total as f64;
+        assert!(share > 0.2 && share < 0.5, "{share}");
+    }
+}
@@ -8,6 +8,10 @@
 //! - [`SpatialIndex`] - R-tree based spatial indexing for geographic queries
 //! - [`TemporalIndex`] - B-tree based temporal indexing for time-range queries
 //! - [`SpatiotemporalIndex`] - Combined space-time indexing
+//! - [`CellSystem`] - Hierarchical geohash, quadkey and hex grid cells
+//!
+//! Indexes can be saved to a compact, versioned binary file with `save`
+//! and loaded again with `load`, keeping their item keys.
 //!
 //! # Example
 //!
@@ -34,10 +38,16 @@
 //! let combined_results = index.query(&bounds, &range);
 //! ```
 
+mod cells;
+mod persist;
+mod slots;
 mod spatial;
 mod spatiotemporal;
 mod temporal;
 
-pub use spatial::{IndexedLocation, SpatialIndex};
+pub use cells::{Cell, CellSystem};
+pub use persist::INDEX_FORMAT_VERSION;
+pub use slots::ItemKey;
+pub use spatial::{IndexedLocation, SpatialIndex, UncertainMatch};
 pub use spatiotemporal::{GridSpec, Heatmap, SpatiotemporalIndex};
 pub use temporal::{SlidingWindowIter, TemporalIndex};
@@ -0,0 +1,206 @@
+//! Versioned binary files for saved indexes.
+//!
+//! A saved index starts with a short header: the magic bytes `SNIX`, the
+//! format version as a little-endian `u16`, and a byte naming the kind of
+//! index. The index contents follow as CBOR: the stored items as columns,
+//! the R-trees node by node, and the start-time B-tree of temporal indexes
+//! as a sorted run. Loading restores the trees as they were saved, so no
+//! tree is rebuilt; only the key lookup table and the B-tree (in a single
+//! pass) are reconstructed.
+//!
+//! Files are decoded straight from the reader in one pass; memory-mapped
+//! access is not supported.
+
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{ErrorKind, Read, Write};
+
+/// Version of the on-disk index format written by this crate.
+///
+/// Files with a newer version are rejected on load.
+pub const INDEX_FORMAT_VERSION: u16 = 1;
+
+const MAGIC: [u8; 4] = *b"SNIX";
+
+/// The kind of index stored in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IndexKind {
+    Spatial = 1,
+    Temporal = 2,
+    Spatiotemporal = 3,
+}
+
+impl IndexKind {
+    fn name(self) -> &'static str {
+        match self {
+            IndexKind::Spatial => "spatial",
+            IndexKind::Temporal => "temporal",
+            IndexKind::Spatiotemporal => "spatiotemporal",
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            1 => Some(IndexKind::Spatial),
+            2 => Some(IndexKind::Temporal),
+            3 => Some(IndexKind::Spatiotemporal),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the header and payload of an index file.
+pub(crate) fn write<W: Write, S: Serialize>(
+    mut writer: W,
+    kind: IndexKind,
+    payload: &S,
+) -> Result<()> {
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&INDEX_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&[kind as u8])?;
+    ciborium::into_writer(payload, &mut writer).map_err(|e| match e {
+        ciborium::ser::Error::Io(e) => Error::Io(e),
+        ciborium::ser::Error::Value(msg) => Error::InvalidFormat(msg),
+    })?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads an index file, checking its header.
+pub(crate) fn read<R: Read, S: DeserializeOwned>(mut reader: R, kind: IndexKind) -> Result<S> {
+    let mut header = [0u8; 7];
+    reader.read_exact(&mut header).map_err(truncated)?;
+    if header[..4] != MAGIC {
+        return Err(Error::InvalidFormat("not an index file".to_string()));
+    }
+
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    if version == 0 || version > INDEX_FORMAT_VERSION {
+        return Err(Error::InvalidFormat(format!(
+            "unsupported index format version {version}"
+        )));
+    }
+
+    let found = IndexKind::from_byte(header[6])
+        .ok_or_else(|| Error::InvalidFormat(format!("unknown index kind {}", header[6])))?;
+    if found != kind {
+        return Err(Error::InvalidFormat(format!(
+            "file holds a {} index, not a {} index",
+            found.name(),
+            kind.name()
+        )));
+    }
+
+    ciborium::from_reader(reader).map_err(|e| match e {
+        ciborium::de::Error::Io(e) => truncated(e),
+        other => Error::InvalidFormat(format!("corrupt index file: {other}")),
+    })
+}
+
+fn truncated(error: std::io::Error) -> Error {
+    if error.kind() == ErrorKind::UnexpectedEof {
+        Error::InvalidFormat("truncated index file".to_string())
+    } else {
+        Error::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Location, Timestamp};
+    use crate::index::{ItemKey, SpatialIndex, SpatiotemporalIndex, TemporalIndex};
+    use geo_types::{line_string, Geometry};
+
+    fn ts(hour: u32) -> Timestamp {
+        Timestamp::parse(&format!("2024-01-01T{hour:02}:00:00Z")).unwrap()
+    }
+
+    #[test]
+    fn test_spatial_index_round_trip() {
+        let mut index = SpatialIndex::new();
+        let nyc = index.insert("NYC".to_string(), &Location::new(40.7128, -74.0060));
+        let la = index.insert("LA".to_string(), &Location::new(34.0522, -118.2437));
+        let road: Geometry<f64> = line_string![(x: -74.1, y: 40.6), (x: -73.9, y: 40.8)].into();
+        let road_key = index.insert_geometry("Road".to_string(), &road).unwrap();
+        index.remove(la);
+
+        let mut bytes = Vec::new();
+        index.write_to(&mut bytes).unwrap();
+        let mut loaded: SpatialIndex<String> = SpatialIndex::read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.get(nyc).map(String::as_str), Some("NYC"));
+        assert_eq!(loaded.get(road_key).map(String::as_str), Some("Road"));
+        assert!(!loaded.contains_key(la));
+        assert_eq!(loaded.query_bbox(40.7, -74.05, 40.75, -73.95).len(), 2);
+        assert_eq!(
+            loaded.query_within_distance_of_geometry(&road, 1.0).len(),
+            1
+        );
+
+        // Keys are not reused after loading
+        let next = loaded.insert("LA".to_string(), &Location::new(34.0522, -118.2437));
+        assert_ne!(next, la);
+        assert_eq!(loaded.remove(nyc).as_deref(), Some("NYC"));
+    }
+
+    #[test]
+    fn test_temporal_and_spatiotemporal_round_trip() {
+        let mut temporal = TemporalIndex::new();
+        temporal.insert("a", &ts(9));
+        let b = temporal.insert_interval("b", &ts(10), &ts(14));
+
+        let mut bytes = Vec::new();
+        temporal.write_to(&mut bytes).unwrap();
+        let loaded: TemporalIndex<String> = TemporalIndex::read_from(bytes.as_slice()).unwrap();
+        let hits = loaded.query_range(
+            &crate::core::TimeRange::new(ts(12), ts(13)),
+            crate::core::TemporalMatch::Possibly,
+        );
+        assert_eq!(hits, vec!["b"]);
+        assert_eq!(loaded.get(b).map(String::as_str), Some("b"));
+        assert_eq!(loaded.chronological(), vec!["a", "b"]);
+
+        let mut combined = SpatiotemporalIndex::new();
+        let key = combined.insert(1u32, &Location::new(40.7, -74.0), &ts(9));
+        combined.insert_interval(2u32, &Location::new(34.0, -118.2), &ts(10), &ts(14));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.snix");
+        combined.save(&path).unwrap();
+        let mut loaded: SpatiotemporalIndex<u32> = SpatiotemporalIndex::load(&path).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.time_range(), combined.time_range());
+        assert_eq!(loaded.location(key), combined.location(key));
+        let range = crate::core::TimeRange::new(ts(12), ts(13));
+        assert_eq!(loaded.query_temporal(&range), vec![&2]);
+        assert_eq!(
+            loaded.update(key, 3, &Location::new(0.0, 0.0), &ts(1)),
+            Some(1)
+        );
+        assert_eq!(loaded.nearest_in_range(0.0, 0.0, 1, &range), vec![&2]);
+    }
+
+    #[test]
+    fn test_rejects_bad_files() {
+        let index: SpatialIndex<ItemKey> = SpatialIndex::new();
+        let mut bytes = Vec::new();
+        index.write_to(&mut bytes).unwrap();
+
+        // Wrong kind of index
+        let err = TemporalIndex::<ItemKey>::read_from(bytes.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("spatial index"));
+
+        // Newer format version
+        let mut newer = bytes.clone();
+        newer[4..6].copy_from_slice(&(INDEX_FORMAT_VERSION + 1).to_le_bytes());
+        let err = SpatialIndex::<ItemKey>::read_from(newer.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("version"));
+
+        // Not an index file, or cut short
+        assert!(SpatialIndex::<ItemKey>::read_from(&b"{\"events\": []}"[..]).is_err());
+        assert!(SpatialIndex::<ItemKey>::read_from(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Stable keys for items stored in an index.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Stable handle to an item in an index.
+///
+/// Returned when an item is inserted, and valid until that item is
+/// removed. Removing other items never changes a key, and keys are not
+/// reused within an index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ItemKey(pub(crate) usize);
+
+impl std::fmt::Display for ItemKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Densely stored items addressed by stable keys.
+///
+/// Removal swaps the last item into the freed position, so storage stays
+/// compact while keys stay stable. Each item carries index-specific
+/// metadata `M` needed to find its entries again.
+#[derive(Debug, Clone)]
+pub(crate) struct Slots<T, M> {
+    items: Vec<T>,
+    meta: Vec<M>,
+    keys: Vec<ItemKey>,
+    positions: HashMap<ItemKey, usize>,
+    next_key: usize,
+}
+
+impl<T, M> Slots<T, M> {
+    pub(crate) fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            meta: Vec::new(),
+            keys: Vec::new(),
+            positions: HashMap::new(),
+            next_key: 0,
+        }
+    }
+
+    /// Restores slots from saved columns and the next unused key.
+    ///
+    /// Fails if the columns differ in length, or a key is repeated or not
+    /// below `next_key`.
+    fn from_columns(
+        next_key: usize,
+        keys: Vec<ItemKey>,
+        items: Vec<T>,
+        meta: Vec<M>,
+    ) -> Result<Self> {
+        if keys.len() != items.len() || keys.len() != meta.len() {
+            return Err(Error::InvalidFormat(
+                "item columns differ in length".to_string(),
+            ));
+        }
+        let mut positions = HashMap::with_capacity(keys.len());
+        for (position, &key) in keys.iter().enumerate() {
+            if key.0 >= next_key || positions.insert(key, position).is_some() {
+                return Err(Error::InvalidFormat(format!("invalid item key {key}")));
+            }
+        }
+        Ok(Self {
+            items,
+            meta,
+            keys,
+            positions,
+            next_key,
+        })
+    }
+
+    /// The key the next pushed item will get.
+    pub(crate) fn next_key(&self) -> ItemKey {
+        ItemKey(self.next_key)
+    }
+
+    /// Stores an item and returns its new key.
+    pub(crate) fn push(&mut self, item: T, meta: M) -> ItemKey {
+        let key = ItemKey(self.next_key);
+        self.next_key += 1;
+        self.positions.insert(key, self.items.len());
+        self.items.push(item);
+        self.meta.push(meta);
+        self.keys.push(key);
+        key
+    }
+
+    /// Removes an item, returning it with its metadata.
+    pub(crate) fn remove(&mut self, key: ItemKey) -> Option<(T, M)> {
+        let position = self.positions.remove(&key)?;
+        self.keys.swap_remove(position);
+        if let Some(moved) = self.keys.get(position) {
+            self.positions.insert(*moved, position);
+        }
+        Some((
+            self.items.swap_remove(position),
+            self.meta.swap_remove(position),
+        ))
+    }
+
+    /// Replaces an item and its metadata, returning the old ones.
+    pub(crate) fn replace(&mut self, key: ItemKey, item: T, meta: M) -> Option<(T, M)> {
+        let position = *self.positions.get(&key)?;
+        Some((
+            std::mem::replace(&mut self.items[position], item),
+            std::mem::replace(&mut self.meta[position], meta),
+        ))
+    }
+
+    pub(crate) fn get(&self, key: ItemKey) -> Option<&T> {
+        self.positions.get(&key).map(|&p| &self.items[p])
+    }
+
+    pub(crate) fn get_mut(&mut self, key: ItemKey) -> Option<&mut T> {
+        self.positions.get(&key).map(|&p| &mut self.items[p])
+    }
+
+    pub(crate) fn meta(&self, key: ItemKey) -> Option<&M> {
+        self.positions.get(&key).map(|&p| &self.meta[p])
+    }
+
+    /// Returns the item for a key known to be present.
+    ///
+    /// Index structures only hold keys of stored items, so a missing key
+    /// is a bug.
+    pub(crate) fn item(&self, key: ItemKey) -> &T {
+        &self.items[self.positions[&key]]
+    }
+
+    pub(crate) fn contains(&self, key: ItemKey) -> bool {
+        self.positions.contains_key(&key)
+    }
+
+    pub(crate) fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    pub(crate) fn metas(&self) -> &[M] {
+        &self.meta
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (ItemKey, &T)> {
+        self.keys.iter().copied().zip(&self.items)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// Slots are saved as columns; key positions are rebuilt on load.
+impl<T: Serialize, M: Serialize> Serialize for Slots<T, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        (self.next_key, &self.keys, &self.items, &self.meta).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, M: Deserialize<'de>> Deserialize<'de> for Slots<T, M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let (next_key, keys, items, meta) = Deserialize::deserialize(deserializer)?;
+        Self::from_columns(next_key, keys, items, meta).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_stay_stable() {
+        let mut slots: Slots<&str, ()> = Slots::new();
+        let a = slots.push("a", ());
+        let b = slots.push("b", ());
+        let c = slots.push("c", ());
+
+        assert_eq!(slots.remove(a), Some(("a", ())));
+        assert_eq!(slots.remove(a), None);
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots.get(b), Some(&"b"));
+        assert_eq!(slots.get(c), Some(&"c"));
+
+        // Keys are not reused
+        let d = slots.push("d", ());
+        assert_ne!(d, a);
+        assert_eq!(slots.replace(d, "e", ()), Some(("d", ())));
+        assert_eq!(slots.item(d), &"e");
+    }
+}
@@ -4,6 +4,7 @@
 //! enabling fast queries like:
 //! - Bounding box queries
 //! - Radius (distance) queries  
+//! - Polygon and distance-to-geometry queries
 //! - K-nearest neighbor searches
 //!
 //! # Example
@@ -23,22 +24,81 @@
 //! assert!(!results.is_empty());
 //! ```
 
-use crate::core::{GeoBounds, Location};
-use rstar::{PointDistance, RTree, RTreeObject, AABB};
+use super::persist::{self, IndexKind};
+use super::slots::{ItemKey, Slots};
+use crate::core::{degrees_per_meter, GeoBounds, Location};
+use crate::error::{Error, Result};
+use geo::{Closest, CoordsIter, HaversineClosestPoint, HaversineDistance, Intersects};
+use geo_types::{Coord, Geometry, MultiPolygon, Point, Polygon};
+use rstar::{Envelope, PointDistance, RTree, RTreeObject, SelectionFunction, AABB};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
 /// A wrapper that makes Location compatible with R-tree indexing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexedLocation {
     /// The geographic location
     pub location: Location,
-    /// Optional associated data index
+    /// Raw value of the item's [`ItemKey`]
     pub index: usize,
+    /// Extent of the item, for items with an extended geometry
+    pub bounds: Option<GeoBounds>,
 }
 
 impl IndexedLocation {
     /// Create a new indexed location.
     pub fn new(location: Location, index: usize) -> Self {
-        Self { location, index }
+        Self {
+            location,
+            index,
+            bounds: None,
+        }
+    }
+
+    /// Create an indexed location covering the given bounds.
+    pub fn with_bounds(location: Location, bounds: GeoBounds, index: usize) -> Self {
+        Self {
+            location,
+            index,
+            bounds: Some(bounds),
+        }
+    }
+}
+
+impl IndexedLocation {
+    /// Great-circle distance in meters from a point to this entry.
+    ///
+    /// Entries with an extent are measured to the nearest point of their
+    /// bounding box, and are at distance 0 from points inside it.
+    pub fn distance_meters(&self, point: &Location) -> f64 {
+        let Some(bounds) = &self.bounds else {
+            return self.location.distance_to(point);
+        };
+        if bounds.contains(point) {
+            return 0.0;
+        }
+
+        let lat = point.lat.clamp(bounds.min_lat, bounds.max_lat);
+        let lon_span = GeoBounds::new(-90.0, bounds.min_lon, 90.0, bounds.max_lon);
+        let lon = if lon_span.contains(&Location::new(0.0, point.lon)) {
+            point.lon
+        } else {
+            // Nearer edge, measured around the globe
+            let gap = |edge: f64| {
+                let d = (point.lon - edge).rem_euclid(360.0);
+                d.min(360.0 - d)
+            };
+            if gap(bounds.min_lon) <= gap(bounds.max_lon) {
+                bounds.min_lon
+            } else {
+                bounds.max_lon
+            }
+        };
+        Location::new(lat, lon).distance_to(point)
     }
 }
 
@@ -46,25 +106,114 @@ impl RTreeObject for IndexedLocation {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
-        AABB::from_point([self.location.lon, self.location.lat])
+        match &self.bounds {
+            Some(b) => AABB::from_corners([b.min_lon, b.min_lat], [b.max_lon, b.max_lat]),
+            None => AABB::from_point([self.location.lon, self.location.lat]),
+        }
     }
 }
 
 impl PointDistance for IndexedLocation {
     fn distance_2(&self, point: &[f64; 2]) -> f64 {
-        let dx = self.location.lon - point[0];
-        let dy = self.location.lat - point[1];
-        dx * dx + dy * dy
+        // Distance to the nearest edge of the envelope (0 if inside);
+        // for points this is the plain squared distance.
+        self.envelope().distance_2(point)
+    }
+}
+
+/// An item matched by an uncertainty-aware query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UncertainMatch<'a, T> {
+    /// The matched item.
+    pub item: &'a T,
+    /// Probability that the item's true position satisfies the query (0.0 to 1.0].
+    pub probability: f64,
+}
+
+impl<T> UncertainMatch<'_, T> {
+    /// Returns true if the item satisfies the query wherever it truly is.
+    pub fn is_certain(&self) -> bool {
+        self.probability >= 1.0
+    }
+}
+
+/// Storage-side record of an item's R-tree entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct Entry {
+    envelope: AABB<[f64; 2]>,
+    /// The extended geometry, for items inserted with one.
+    geometry: Option<Geometry<f64>>,
+}
+
+impl Entry {
+    fn point(location: &Location) -> Self {
+        Self {
+            envelope: AABB::from_point([location.lon, location.lat]),
+            geometry: None,
+        }
+    }
+
+    /// Entry and R-tree entry for an extended geometry, if it is not empty.
+    fn geometry(geometry: Geometry<f64>, key: ItemKey) -> Option<(Self, IndexedLocation)> {
+        let bounds = GeoBounds::from_geometry(&geometry)?;
+        let indexed = IndexedLocation::with_bounds(bounds.center(), bounds, key.0);
+        let entry = Self {
+            envelope: indexed.envelope(),
+            geometry: Some(geometry),
+        };
+        Some((entry, indexed))
+    }
+}
+
+/// Contents of a saved spatial index, borrowed for writing.
+#[derive(Serialize)]
+pub(super) struct SnapshotRef<'a, T> {
+    items: &'a Slots<T, Entry>,
+    tree: &'a RTree<IndexedLocation>,
+    max_uncertainty_m: f64,
+}
+
+/// Contents of a saved spatial index, as read back.
+///
+/// The R-tree is saved node by node, so loading restores it as it was
+/// instead of rebuilding it.
+#[derive(Deserialize)]
+pub(super) struct Snapshot<T> {
+    items: Slots<T, Entry>,
+    tree: RTree<IndexedLocation>,
+    max_uncertainty_m: f64,
+}
+
+/// Selects the R-tree entry of one item for removal.
+struct SelectEntry {
+    envelope: AABB<[f64; 2]>,
+    index: usize,
+}
+
+impl SelectionFunction<IndexedLocation> for SelectEntry {
+    fn should_unpack_parent(&self, envelope: &AABB<[f64; 2]>) -> bool {
+        envelope.contains_envelope(&self.envelope)
+    }
+
+    fn should_unpack_leaf(&self, leaf: &IndexedLocation) -> bool {
+        leaf.index == self.index
     }
 }
 
 /// Spatial index for efficient geographic queries.
 ///
 /// Uses an R-tree data structure for O(log n) query performance.
+/// Items are addressed by the [`ItemKey`] returned on insertion, and can
+/// be removed or updated without rebuilding the index.
 #[derive(Debug)]
 pub struct SpatialIndex<T> {
     tree: RTree<IndexedLocation>,
-    items: Vec<T>,
+    /// Items with their R-tree envelope and extended geometry.
+    items: Slots<T, Entry>,
+    /// Largest uncertainty radius among indexed locations, in meters.
+    ///
+    /// Not lowered on removal, so it is an upper bound.
+    max_uncertainty_m: f64,
 }
 
 impl<T: Clone> SpatialIndex<T> {
@@ -72,7 +221,8 @@ impl<T: Clone> SpatialIndex<T> {
     pub fn new() -> Self {
         Self {
             tree: RTree::new(),
-            items: Vec::new(),
+            items: Slots::new(),
+            max_uncertainty_m: 0.0,
         }
     }
 
@@ -82,50 +232,114 @@ impl<T: Clone> SpatialIndex<T> {
         I: IntoIterator<Item = T>,
         F: Fn(&T) -> &Location,
     {
-        let items: Vec<T> = iter.into_iter().collect();
-        let indexed: Vec<IndexedLocation> = items
+        let mut items = Slots::new();
+        let mut indexed = Vec::new();
+        for item in iter {
+            let location = location_fn(&item).clone();
+            let key = items.push(item, Entry::point(&location));
+            indexed.push(IndexedLocation::new(location, key.0));
+        }
+
+        let max_uncertainty_m = indexed
             .iter()
-            .enumerate()
-            .map(|(i, item)| IndexedLocation::new(location_fn(item).clone(), i))
-            .collect();
+            .map(|i| i.location.uncertainty_radius())
+            .fold(0.0, f64::max);
 
         Self {
             tree: RTree::bulk_load(indexed),
             items,
+            max_uncertainty_m,
         }
     }
 
-    /// Insert an item into the index.
-    pub fn insert(&mut self, item: T, location: &Location) {
-        let index = self.items.len();
-        self.items.push(item);
-        self.tree
-            .insert(IndexedLocation::new(location.clone(), index));
+    /// Insert an item into the index, returning its key.
+    pub fn insert(&mut self, item: T, location: &Location) -> ItemKey {
+        let key = self.items.push(item, Entry::point(location));
+        self.index_location(key, location);
+        key
+    }
+
+    /// Insert an item with an extended geometry into the index.
+    ///
+    /// The item is indexed by the geometry's bounding box, so bounding box
+    /// queries match it if any part of that box overlaps the query.
+    /// Polygon and distance-to-geometry queries use the geometry itself.
+    /// Returns an error, without inserting, for an empty geometry, which
+    /// has no extent to index.
+    pub fn insert_geometry(&mut self, item: T, geometry: &Geometry<f64>) -> Result<ItemKey> {
+        let (entry, indexed) = Entry::geometry(geometry.clone(), self.items.next_key())
+            .ok_or_else(|| Error::IndexError("cannot index an empty geometry".to_string()))?;
+        let key = self.items.push(item, entry);
+        self.tree.insert(indexed);
+        Ok(key)
+    }
+
+    /// Remove an item, returning it if the key was present.
+    pub fn remove(&mut self, key: ItemKey) -> Option<T> {
+        let (item, entry) = self.items.remove(key)?;
+        self.tree.remove_with_selection_function(SelectEntry {
+            envelope: entry.envelope,
+            index: key.0,
+        });
+        Some(item)
+    }
+
+    /// Replace an item and move it to a new location, keeping its key.
+    ///
+    /// Returns the previous item, or `None` (changing nothing) if the key
+    /// is not present. An item indexed with an extended geometry becomes
+    /// a point item.
+    pub fn update(&mut self, key: ItemKey, item: T, location: &Location) -> Option<T> {
+        let (old, old_entry) = self.items.replace(key, item, Entry::point(location))?;
+        self.tree.remove_with_selection_function(SelectEntry {
+            envelope: old_entry.envelope,
+            index: key.0,
+        });
+        self.index_location(key, location);
+        Some(old)
+    }
+
+    /// Get an item by key.
+    pub fn get(&self, key: ItemKey) -> Option<&T> {
+        self.items.get(key)
+    }
+
+    /// Get a mutable reference to an item by key.
+    ///
+    /// Changing the item does not move it; use [`update`](Self::update)
+    /// to change its location.
+    pub fn get_mut(&mut self, key: ItemKey) -> Option<&mut T> {
+        self.items.get_mut(key)
+    }
+
+    /// Returns true if an item with this key is indexed.
+    pub fn contains_key(&self, key: ItemKey) -> bool {
+        self.items.contains(key)
     }
 
     /// Query items within a bounding box.
     ///
+    /// Items indexed with an extended geometry are returned if their
+    /// extent intersects the box. A box with `min_lon` greater than
+    /// `max_lon` wraps across the antimeridian.
+    ///
     /// # Arguments
     /// * `min_lat` - Minimum latitude
     /// * `min_lon` - Minimum longitude
     /// * `max_lat` - Maximum latitude
     /// * `max_lon` - Maximum longitude
     pub fn query_bbox(&self, min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> Vec<&T> {
-        let envelope = AABB::from_corners([min_lon, min_lat], [max_lon, max_lat]);
-        self.tree
-            .locate_in_envelope(&envelope)
-            .map(|indexed| &self.items[indexed.index])
-            .collect()
+        self.query_bounds(&GeoBounds::new(min_lat, min_lon, max_lat, max_lon))
     }
 
     /// Query items within geographic bounds.
+    ///
+    /// Bounds that wrap across the antimeridian are split and both
+    /// parts are searched.
     pub fn query_bounds(&self, bounds: &GeoBounds) -> Vec<&T> {
-        self.query_bbox(
-            bounds.min_lat,
-            bounds.min_lon,
-            bounds.max_lat,
-            bounds.max_lon,
-        )
+        self.locate_in_bounds(bounds)
+            .map(|indexed| self.item_of(indexed))
+            .collect()
     }
 
     /// Query items within a radius of a point.
@@ -136,40 +350,220 @@ impl<T: Clone> SpatialIndex<T> {
         let radius_sq = radius_degrees * radius_degrees;
         self.tree
             .locate_within_distance([lon, lat], radius_sq)
-            .map(|indexed| &self.items[indexed.index])
+            .map(|indexed| self.item_of(indexed))
             .collect()
     }
 
     /// Query items within a radius in meters.
     ///
-    /// Uses the Haversine formula for accurate great-circle distance.
+    /// Filters by exact great-circle (haversine) distance, including
+    /// near the poles and across the antimeridian. Items indexed with an
+    /// extended geometry match if their bounding box comes within the radius.
     pub fn query_radius_meters(&self, lat: f64, lon: f64, radius_meters: f64) -> Vec<&T> {
-        // Convert to approximate degree radius for initial R-tree query
-        // 1 degree latitude ≈ 111,320 meters
-        let degree_radius = radius_meters / 111_320.0 * 1.5; // Add buffer
+        self.within_meters(lat, lon, radius_meters)
+            .into_iter()
+            .map(|(indexed, _)| self.item_of(indexed))
+            .collect()
+    }
+
+    /// Query items inside a polygon.
+    ///
+    /// Points on the boundary count as inside, and holes are excluded.
+    /// Items indexed with an extended geometry match if the geometry
+    /// intersects the polygon. The R-tree narrows candidates to the
+    /// polygon's bounding box before the exact test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::polygon;
+    /// use spatial_narrative::core::Location;
+    /// use spatial_narrative::index::SpatialIndex;
+    ///
+    /// let mut index = SpatialIndex::new();
+    /// index.insert("inside", &Location::new(0.5, 0.5));
+    /// index.insert("in the box, outside the triangle", &Location::new(0.9, 0.1));
+    ///
+    /// let district = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)];
+    /// assert_eq!(index.query_polygon(&district), vec![&"inside"]);
+    /// ```
+    pub fn query_polygon(&self, polygon: &Polygon<f64>) -> Vec<&T> {
+        self.query_area(&Geometry::Polygon(polygon.clone()))
+    }
+
+    /// Query items inside any polygon of a multi-polygon.
+    ///
+    /// Like [`query_polygon`](Self::query_polygon), for areas made of
+    /// several parts, such as a district with islands.
+    pub fn query_multi_polygon(&self, area: &MultiPolygon<f64>) -> Vec<&T> {
+        self.query_area(&Geometry::MultiPolygon(area.clone()))
+    }
+
+    /// Query items within a distance in meters of a geometry.
+    ///
+    /// Distances are great-circle distances to the nearest point of the
+    /// geometry, such as the nearest point along a river line. Items
+    /// inside a polygon are at distance 0. Items indexed with an extended
+    /// geometry are measured between the nearest points of both geometries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    /// use spatial_narrative::core::Location;
+    /// use spatial_narrative::index::SpatialIndex;
+    ///
+    /// let mut index = SpatialIndex::new();
+    /// index.insert("on the bank", &Location::new(0.01, 0.5));
+    /// index.insert("inland", &Location::new(0.1, 0.5));
+    ///
+    /// // A river along the equator; the bank is ~1.1 km away, inland ~11 km
+    /// let river = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0)].into();
+    /// assert_eq!(index.query_within_distance_of_geometry(&river, 2_000.0), vec![&"on the bank"]);
+    /// ```
+    pub fn query_within_distance_of_geometry(
+        &self,
+        geometry: &Geometry<f64>,
+        meters: f64,
+    ) -> Vec<&T> {
+        let Some(bounds) = GeoBounds::from_geometry(geometry) else {
+            return Vec::new();
+        };
+        self.locate_in_bounds(&pad_bounds(&bounds, meters))
+            .filter(|indexed| {
+                let distance = match &self.entry_of(indexed).geometry {
+                    Some(extent) => geometry_distance_meters(extent, geometry),
+                    None => point_distance_meters(&indexed.location, geometry),
+                };
+                distance.is_some_and(|d| d <= meters)
+            })
+            .map(|indexed| self.item_of(indexed))
+            .collect()
+    }
+
+    /// Query items that possibly lie within a bounding box, given their
+    /// location uncertainty.
+    ///
+    /// Each match carries the probability that the item's true position
+    /// is inside the box; items whose uncertainty circle misses the box
+    /// are left out. Items indexed with an extended geometry match with
+    /// probability 1.0 when their extent intersects the box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_narrative::core::{GeoBounds, Location};
+    /// use spatial_narrative::index::SpatialIndex;
+    ///
+    /// let mut index = SpatialIndex::new();
+    /// index.insert("exact", &Location::new(40.0, -74.0));
+    /// let city = Location::builder()
+    ///     .coordinates(40.0, -75.0)
+    ///     .uncertainty_meters(20_000.0)
+    ///     .build()
+    ///     .unwrap();
+    /// index.insert("city centroid", &city);
+    ///
+    /// // The box ends at the city centroid, so about half its circle is inside
+    /// let matches = index.query_bounds_uncertain(&GeoBounds::new(39.0, -75.0, 41.0, -73.0));
+    /// assert_eq!(matches.len(), 2);
+    /// let city = matches.iter().find(|m| *m.item == "city centroid").unwrap();
+    /// assert!(!city.is_certain() && city.probability > 0.4);
+    /// ```
+    pub fn query_bounds_uncertain(&self, bounds: &GeoBounds) -> Vec<UncertainMatch<'_, T>> {
+        let widest_lat = bounds.min_lat.abs().max(bounds.max_lat.abs());
+        let (lat_per_m, lon_per_m) = degrees_per_meter(widest_lat);
+        let lat_pad = self.max_uncertainty_m * lat_per_m;
+        let lon_pad = self.max_uncertainty_m * lon_per_m;
+        let expanded = if bounds.width() + 2.0 * lon_pad >= 360.0 {
+            GeoBounds::new(
+                bounds.min_lat - lat_pad,
+                -180.0,
+                bounds.max_lat + lat_pad,
+                180.0,
+            )
+        } else {
+            GeoBounds::from_center_degrees(
+                &bounds.center(),
+                bounds.height() / 2.0 + lat_pad,
+                bounds.width() / 2.0 + lon_pad,
+            )
+        };
 
-        // Get candidates from R-tree
-        let candidates = self.query_radius(lat, lon, degree_radius);
+        self.locate_in_bounds(&expanded)
+            .filter_map(|indexed| {
+                let probability = match &indexed.bounds {
+                    Some(extent) if extent.intersects(bounds) => 1.0,
+                    Some(_) => 0.0,
+                    None => indexed.location.probability_within_bounds(bounds),
+                };
+                (probability > 0.0).then(|| UncertainMatch {
+                    item: self.item_of(indexed),
+                    probability,
+                })
+            })
+            .collect()
+    }
 
-        // Return all candidates within the approximate radius
-        // (precise Haversine filtering would require storing locations)
-        candidates
+    /// Query items that possibly lie within a radius in meters, given
+    /// their location uncertainty.
+    ///
+    /// Each match carries the probability that the item's true position
+    /// is within the radius, using great-circle distance. Items indexed
+    /// with an extended geometry are measured from the centre of their extent.
+    pub fn query_radius_uncertain(
+        &self,
+        lat: f64,
+        lon: f64,
+        radius_meters: f64,
+    ) -> Vec<UncertainMatch<'_, T>> {
+        let center = Location::new(lat, lon);
+        self.locate_near(lat, lon, radius_meters + self.max_uncertainty_m)
+            .filter_map(|indexed| {
+                let probability = indexed
+                    .location
+                    .probability_within_radius(&center, radius_meters);
+                (probability > 0.0).then(|| UncertainMatch {
+                    item: self.item_of(indexed),
+                    probability,
+                })
+            })
+            .collect()
     }
 
     /// Find the k nearest neighbors to a point.
+    ///
+    /// Neighbors are ranked by great-circle distance, so results are
+    /// exact near the poles and across the antimeridian.
     pub fn nearest(&self, lat: f64, lon: f64, k: usize) -> Vec<&T> {
-        self.tree
-            .nearest_neighbor_iter(&[lon, lat])
-            .take(k)
-            .map(|indexed| &self.items[indexed.index])
+        self.nearest_where(lat, lon, k, |_| true)
+    }
+
+    /// Find the k nearest neighbors to a point, with their distances in meters.
+    pub fn nearest_with_distances(&self, lat: f64, lon: f64, k: usize) -> Vec<(&T, f64)> {
+        self.nearest_matching(lat, lon, k, |_| true)
+            .into_iter()
+            .map(|(indexed, d)| (self.item_of(indexed), d))
+            .collect()
+    }
+
+    /// Find the k nearest items to a point among those matching a predicate.
+    ///
+    /// Unlike filtering the result of [`nearest`](Self::nearest), this
+    /// always returns k items when at least k match.
+    pub fn nearest_where<F>(&self, lat: f64, lon: f64, k: usize, predicate: F) -> Vec<&T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.nearest_matching(lat, lon, k, |indexed| predicate(self.item_of(indexed)))
+            .into_iter()
+            .map(|(indexed, _)| self.item_of(indexed))
             .collect()
     }
 
     /// Find the single nearest item to a point.
     pub fn nearest_one(&self, lat: f64, lon: f64) -> Option<&T> {
-        self.tree
-            .nearest_neighbor(&[lon, lat])
-            .map(|indexed| &self.items[indexed.index])
+        self.nearest(lat, lon, 1).into_iter().next()
     }
 
     /// Returns the number of indexed items.
@@ -183,8 +577,267 @@ impl<T: Clone> SpatialIndex<T> {
     }
 
     /// Get all items in the index.
+    ///
+    /// Removing an item may change the order of the others.
     pub fn items(&self) -> &[T] {
-        &self.items
+        self.items.items()
+    }
+
+    /// Iterate over all items with their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemKey, &T)> {
+        self.items.iter()
+    }
+
+    /// Borrows the index's contents for saving.
+    pub(super) fn snapshot(&self) -> SnapshotRef<'_, T> {
+        SnapshotRef {
+            items: &self.items,
+            tree: &self.tree,
+            max_uncertainty_m: self.max_uncertainty_m,
+        }
+    }
+
+    /// Restores an index from saved contents.
+    ///
+    /// Fails if the tree and the stored items do not match one to one.
+    pub(super) fn from_snapshot(snapshot: Snapshot<T>) -> Result<Self> {
+        let Snapshot {
+            items,
+            tree,
+            max_uncertainty_m,
+        } = snapshot;
+        if tree.size() != items.len() {
+            return Err(Error::InvalidFormat(format!(
+                "spatial index has {} tree entries for {} items",
+                tree.size(),
+                items.len()
+            )));
+        }
+        let mut seen = HashSet::with_capacity(items.len());
+        for indexed in tree.iter() {
+            let key = ItemKey(indexed.index);
+            if !items.contains(key) || !seen.insert(key) {
+                return Err(Error::InvalidFormat(format!(
+                    "spatial index tree has a bad entry for item {key}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            tree,
+            items,
+            max_uncertainty_m,
+        })
+    }
+
+    fn index_location(&mut self, key: ItemKey, location: &Location) {
+        self.max_uncertainty_m = self.max_uncertainty_m.max(location.uncertainty_radius());
+        self.tree
+            .insert(IndexedLocation::new(location.clone(), key.0));
+    }
+
+    fn item_of(&self, indexed: &IndexedLocation) -> &T {
+        self.items.item(ItemKey(indexed.index))
+    }
+
+    fn entry_of(&self, indexed: &IndexedLocation) -> &Entry {
+        self.items
+            .meta(ItemKey(indexed.index))
+            .expect("indexed entries have stored items")
+    }
+
+    /// Items intersecting an area, prefiltered by its bounding box.
+    fn query_area(&self, area: &Geometry<f64>) -> Vec<&T> {
+        let Some(bounds) = GeoBounds::from_geometry(area) else {
+            return Vec::new();
+        };
+        self.locate_in_bounds(&bounds)
+            .filter(|indexed| match &self.entry_of(indexed).geometry {
+                Some(extent) => extent.intersects(area),
+                None => area.intersects(&location_point(&indexed.location)),
+            })
+            .map(|indexed| self.item_of(indexed))
+            .collect()
+    }
+
+    /// Indexed entries whose extent intersects the bounds, searching
+    /// both sides of the antimeridian for wrapping bounds.
+    ///
+    /// The R-tree envelope of an extent that wraps spans the whole
+    /// longitude range between its edges, so extents are rechecked.
+    fn locate_in_bounds(&self, bounds: &GeoBounds) -> impl Iterator<Item = &IndexedLocation> {
+        let bounds = *bounds;
+        let mut seen = HashSet::new();
+        bounds
+            .split_antimeridian()
+            .into_iter()
+            .flat_map(|part| {
+                let envelope =
+                    AABB::from_corners([part.min_lon, part.min_lat], [part.max_lon, part.max_lat]);
+                self.tree.locate_in_envelope_intersecting(&envelope)
+            })
+            .filter(move |indexed| seen.insert(indexed.index))
+            .filter(move |indexed| {
+                indexed
+                    .bounds
+                    .as_ref()
+                    .map_or(true, |extent| extent.intersects(&bounds))
+            })
+    }
+
+    /// Candidate entries that may lie within `meters` of a point.
+    fn locate_near(
+        &self,
+        lat: f64,
+        lon: f64,
+        meters: f64,
+    ) -> impl Iterator<Item = &IndexedLocation> {
+        self.locate_in_bounds(&radius_bounds(lat, lon, meters))
+    }
+
+    /// Entries within `meters` of a point, with their great-circle distances.
+    fn within_meters(&self, lat: f64, lon: f64, meters: f64) -> Vec<(&IndexedLocation, f64)> {
+        let center = Location::new(lat, lon);
+        self.locate_near(lat, lon, meters)
+            .map(|indexed| (indexed, indexed.distance_meters(&center)))
+            .filter(|(_, d)| *d <= meters)
+            .collect()
+    }
+
+    /// The k nearest entries matching a predicate, by great-circle distance.
+    fn nearest_matching<F>(
+        &self,
+        lat: f64,
+        lon: f64,
+        k: usize,
+        predicate: F,
+    ) -> Vec<(&IndexedLocation, f64)>
+    where
+        F: Fn(&IndexedLocation) -> bool,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        let center = Location::new(lat, lon);
+
+        // Any k matching entries bound the search radius; planar nearest
+        // neighbours are a cheap way to find k that are close.
+        let search_radius = self
+            .tree
+            .nearest_neighbor_iter(&[lon, lat])
+            .filter(|indexed| predicate(indexed))
+            .take(k)
+            .map(|indexed| indexed.distance_meters(&center))
+            .reduce(f64::max);
+        let Some(search_radius) = search_radius else {
+            return Vec::new();
+        };
+
+        let mut found: Vec<(&IndexedLocation, f64)> = self
+            .within_meters(lat, lon, search_radius)
+            .into_iter()
+            .filter(|(indexed, _)| predicate(indexed))
+            .collect();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.index.cmp(&b.0.index)));
+        found.truncate(k);
+        found
+    }
+}
+
+/// Earth radius used to size search boxes; slightly smaller than the
+/// radius used for distances, so boxes err on the large side.
+const SEARCH_EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// Bounds containing every point within `meters` great-circle distance
+/// of a point.
+fn radius_bounds(lat: f64, lon: f64, meters: f64) -> GeoBounds {
+    pad_bounds(&GeoBounds::new(lat, lon, lat, lon), meters)
+}
+
+/// Bounds containing every point within `meters` great-circle distance
+/// of a box.
+///
+/// Reaching a pole covers all longitudes; otherwise the box widens by the
+/// longitude reach at its most poleward latitude, wrapping across the
+/// antimeridian where needed.
+fn pad_bounds(bounds: &GeoBounds, meters: f64) -> GeoBounds {
+    let angular = (meters / SEARCH_EARTH_RADIUS_M).to_degrees() * (1.0 + 1e-9);
+    let min_lat = (bounds.min_lat - angular).max(-90.0);
+    let max_lat = (bounds.max_lat + angular).min(90.0);
+    let widest_lat = bounds.min_lat.abs().max(bounds.max_lat.abs());
+    if widest_lat + angular >= 90.0 {
+        return GeoBounds::new(min_lat, -180.0, max_lat, 180.0);
+    }
+
+    let lon_reach = (angular.to_radians().sin() / widest_lat.to_radians().cos())
+        .min(1.0)
+        .asin()
+        .to_degrees()
+        * (1.0 + 1e-9);
+    let lons =
+        GeoBounds::from_center_degrees(&bounds.center(), 0.0, bounds.width() / 2.0 + lon_reach);
+    GeoBounds::new(min_lat, lons.min_lon, max_lat, lons.max_lon)
+}
+
+fn location_point(location: &Location) -> Point<f64> {
+    Point::new(location.lon, location.lat)
+}
+
+/// Great-circle distance in meters from a location to the nearest point
+/// of a geometry, or `None` for an empty or degenerate geometry.
+fn point_distance_meters(location: &Location, geometry: &Geometry<f64>) -> Option<f64> {
+    let point = location_point(location);
+    match geometry.haversine_closest_point(&point) {
+        Closest::Intersection(_) => Some(0.0),
+        Closest::SinglePoint(nearest) => Some(nearest.haversine_distance(&point)),
+        Closest::Indeterminate => None,
+    }
+}
+
+/// Great-circle distance in meters between the nearest points of two
+/// geometries.
+///
+/// Non-intersecting geometries are nearest at a vertex of one of them,
+/// so checking every vertex against the other geometry is enough.
+fn geometry_distance_meters(a: &Geometry<f64>, b: &Geometry<f64>) -> Option<f64> {
+    if a.intersects(b) {
+        return Some(0.0);
+    }
+    let mut nearest: Option<f64> = None;
+    for (from, to) in [(a, b), (b, a)] {
+        for Coord { x, y } in from.coords_iter() {
+            let d = point_distance_meters(&Location::new(y, x), to)?;
+            nearest = Some(nearest.map_or(d, |n| n.min(d)));
+        }
+    }
+    nearest
+}
+
+impl<T: Clone + Serialize> SpatialIndex<T> {
+    /// Write the index in the binary index format.
+    ///
+    /// Items keep their keys when the index is read back.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<()> {
+        persist::write(writer, IndexKind::Spatial, &self.snapshot())
+    }
+
+    /// Save the index to a file, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T: Clone + DeserializeOwned> SpatialIndex<T> {
+    /// Read an index written by [`write_to`](Self::write_to).
+    ///
+    /// The R-tree is restored as saved rather than rebuilt.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self> {
+        Self::from_snapshot(persist::read(reader, IndexKind::Spatial)?)
+    }
+
+    /// Load an index saved with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
     }
 }
 
@@ -198,6 +851,7 @@ impl<T: Clone> Default for SpatialIndex<T> {
 mod tests {
     use super::*;
     use crate::core::Location;
+    use geo_types::{line_string, polygon, MultiLineString};
 
     #[test]
     fn test_spatial_index_new() {
@@ -234,6 +888,267 @@ mod tests {
         assert_eq!(*results[0], "NYC");
     }
 
+    #[test]
+    fn test_spatial_index_insert_geometry() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("NYC", &Location::new(40.7128, -74.0060));
+
+        let river = line_string![
+            (x: -90.0, y: 29.0),
+            (x: -91.0, y: 35.0),
+            (x: -90.2, y: 38.6),
+        ];
+        index.insert_geometry("Mississippi", &river.into()).unwrap();
+
+        // An empty geometry has nothing to index
+        let empty = geo_types::GeometryCollection::<f64>(vec![]);
+        let empty = geo_types::Geometry::GeometryCollection(empty);
+        assert!(index.insert_geometry("nothing", &empty).is_err());
+        assert_eq!(index.len(), 2);
+
+        // Box around St. Louis touches the northern end of the river only
+        let results = index.query_bbox(38.0, -91.0, 39.0, -89.5);
+        assert_eq!(results, vec![&"Mississippi"]);
+
+        // Query points inside the river's extent have zero distance to it
+        let nearest = index.nearest_one(33.0, -90.5);
+        assert_eq!(nearest, Some(&"Mississippi"));
+    }
+
+    #[test]
+    fn test_spatial_index_remove_update() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        let nyc = index.insert("NYC", &Location::new(40.7128, -74.0060));
+        let la = index.insert("LA", &Location::new(34.0522, -118.2437));
+        let chicago = index.insert("Chicago", &Location::new(41.8781, -87.6298));
+
+        assert_eq!(index.remove(nyc), Some("NYC"));
+        assert_eq!(index.remove(nyc), None);
+        assert_eq!(index.len(), 2);
+        assert!(index.query_bbox(35.0, -80.0, 45.0, -70.0).is_empty());
+        // Other keys still resolve after the removal compacted storage
+        assert_eq!(index.get(chicago), Some(&"Chicago"));
+        assert_eq!(index.nearest_one(40.0, -74.0), Some(&"Chicago"));
+
+        // Move LA to Boston under the same key
+        let boston = Location::new(42.3601, -71.0589);
+        assert_eq!(index.update(la, "Boston", &boston), Some("LA"));
+        assert_eq!(index.query_bbox(35.0, -80.0, 45.0, -70.0), vec![&"Boston"]);
+        assert!(index.query_bbox(30.0, -125.0, 40.0, -110.0).is_empty());
+        assert_eq!(index.update(nyc, "NYC", &boston), None);
+        assert_eq!(index.len(), 2);
+
+        let river = line_string![(x: -90.0, y: 29.0), (x: -90.2, y: 38.6)];
+        let mississippi = index.insert_geometry("Mississippi", &river.into()).unwrap();
+        assert_eq!(index.remove(mississippi), Some("Mississippi"));
+        assert!(index.query_bbox(30.0, -91.0, 31.0, -89.0).is_empty());
+    }
+
+    #[test]
+    fn test_spatial_index_polygon_queries() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("centre", &Location::new(5.0, 5.0));
+        index.insert("in hole", &Location::new(2.0, 2.0));
+        index.insert("on edge", &Location::new(0.0, 5.0));
+        index.insert("island", &Location::new(20.5, 20.5));
+        index.insert("outside", &Location::new(15.0, 15.0));
+        let road = line_string![(x: 9.0, y: 9.0), (x: 12.0, y: 12.0)];
+        index.insert_geometry("road", &road.into()).unwrap();
+
+        let district = polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 3.0, y: 1.0), (x: 3.0, y: 3.0), (x: 1.0, y: 3.0)]],
+        );
+        let mut found = index.query_polygon(&district);
+        found.sort();
+        assert_eq!(found, vec![&"centre", &"on edge", &"road"]);
+
+        let island = polygon![(x: 20.0, y: 20.0), (x: 21.0, y: 20.0), (x: 21.0, y: 21.0)];
+        let mut found = index.query_multi_polygon(&MultiPolygon::new(vec![district, island]));
+        found.sort();
+        assert_eq!(found, vec![&"centre", &"island", &"on edge", &"road"]);
+    }
+
+    #[test]
+    fn test_spatial_index_distance_to_geometry() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        // ~1.1 km and ~3.3 km north of a river along the equator
+        index.insert("near", &Location::new(0.01, 0.5));
+        index.insert("far", &Location::new(0.03, 0.5));
+        // Beyond the river's end, ~1.1 km from its last vertex
+        index.insert("past the mouth", &Location::new(0.0, 1.01));
+        // A parallel road ~1.1 km south, measured between the lines
+        let road = line_string![(x: 0.2, y: -0.01), (x: 0.8, y: -0.01)];
+        index.insert_geometry("road", &road.into()).unwrap();
+
+        let river: Geometry<f64> = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0)].into();
+        let mut found = index.query_within_distance_of_geometry(&river, 2_000.0);
+        found.sort();
+        assert_eq!(found, vec![&"near", &"past the mouth", &"road"]);
+        assert_eq!(
+            index
+                .query_within_distance_of_geometry(&river, 4_000.0)
+                .len(),
+            4
+        );
+        assert!(index
+            .query_within_distance_of_geometry(&river, 500.0)
+            .is_empty());
+
+        // Points inside a polygon are at distance zero
+        let area: Geometry<f64> =
+            polygon![(x: 0.4, y: 0.0), (x: 0.6, y: 0.0), (x: 0.5, y: 0.05)].into();
+        let mut found = index.query_within_distance_of_geometry(&area, 0.0);
+        found.sort();
+        assert_eq!(found, vec![&"far", &"near"]);
+    }
+
+    #[test]
+    fn test_spatial_index_radius_meters_exact() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("origin", &Location::new(0.0, 0.0));
+        // ~1.1 km east and north; the old degree buffer returned both
+        index.insert("east", &Location::new(0.0, 0.01));
+        index.insert("diagonal", &Location::new(0.007, 0.007));
+        // Near the pole, longitude degrees are tiny
+        index.insert("polar a", &Location::new(89.9, 0.0));
+        index.insert("polar b", &Location::new(89.9, 180.0));
+
+        let mut near = index.query_radius_meters(0.0, 0.0, 1_200.0);
+        near.sort();
+        assert_eq!(near, vec![&"diagonal", &"east", &"origin"]);
+        assert_eq!(
+            index.query_radius_meters(0.0, 0.0, 1_000.0),
+            vec![&"origin"]
+        );
+
+        // The two polar points are ~22 km apart across the pole
+        let mut polar = index.query_radius_meters(89.9, 0.0, 25_000.0);
+        polar.sort();
+        assert_eq!(polar, vec![&"polar a", &"polar b"]);
+    }
+
+    #[test]
+    fn test_spatial_index_read_restores_tree() {
+        let mut index = SpatialIndex::new();
+        for i in 0..200 {
+            let lat = (i as f64 * 7.3) % 160.0 - 80.0;
+            let lon = (i as f64 * 13.7) % 340.0 - 170.0;
+            index.insert(i, &Location::new(lat, lon));
+        }
+
+        let mut bytes = Vec::new();
+        index.write_to(&mut bytes).unwrap();
+        let loaded: SpatialIndex<i32> = SpatialIndex::read_from(bytes.as_slice()).unwrap();
+
+        // The tree comes back node for node, not rebuilt
+        let order = |index: &SpatialIndex<i32>| -> Vec<usize> {
+            index.tree.iter().map(|indexed| indexed.index).collect()
+        };
+        assert_eq!(order(&loaded), order(&index));
+    }
+
+    #[test]
+    fn test_spatial_index_antimeridian() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("Fiji east", &Location::new(-17.0, 179.9));
+        index.insert("Fiji west", &Location::new(-17.0, -179.9));
+        index.insert("Tonga", &Location::new(-21.1, -175.2));
+        index.insert("Greenwich", &Location::new(51.48, 0.0));
+
+        // A wrapping box finds both sides
+        let mut found = index.query_bounds(&GeoBounds::new(-20.0, 179.0, -15.0, -179.0));
+        found.sort();
+        assert_eq!(found, vec![&"Fiji east", &"Fiji west"]);
+
+        // ~21 km apart across the antimeridian
+        let near = index.query_radius_meters(-17.0, 179.9, 25_000.0);
+        assert_eq!(near.len(), 2);
+
+        assert_eq!(index.nearest(-17.0, 179.95, 2).len(), 2);
+        assert!(index
+            .nearest(-17.0, 179.95, 2)
+            .iter()
+            .all(|name| name.starts_with("Fiji")));
+        let (nearest, distance) = index.nearest_with_distances(-17.0, -179.95, 1)[0];
+        assert_eq!(*nearest, "Fiji west");
+        assert!((distance - 5_300.0).abs() < 100.0);
+
+        let tonga_only = index.nearest_where(-17.0, 179.95, 1, |name| !name.starts_with("Fiji"));
+        assert_eq!(tonga_only, vec![&"Tonga"]);
+    }
+
+    #[test]
+    fn test_spatial_index_wrapping_queries() {
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("Suva", &Location::new(-18.1, 178.4));
+        index.insert("Greenwich", &Location::new(51.48, 0.0));
+        let split_line = Geometry::MultiLineString(MultiLineString(vec![
+            line_string![(x: 178.0, y: -17.0), (x: 180.0, y: -17.0)],
+            line_string![(x: -180.0, y: -17.0), (x: -178.0, y: -17.0)],
+        ]));
+        index
+            .insert_geometry("dateline route", &split_line)
+            .unwrap();
+
+        // Uncertainty-aware queries see points inside wrapping bounds
+        let pacific = GeoBounds::new(-20.0, 170.0, 0.0, -170.0);
+        let mut matches: Vec<&str> = index
+            .query_bounds_uncertain(&pacific)
+            .iter()
+            .map(|m| *m.item)
+            .collect();
+        matches.sort();
+        assert_eq!(matches, vec!["Suva", "dateline route"]);
+
+        // The route's extent wraps, so boxes near 0° miss it
+        let atlantic = GeoBounds::new(-30.0, -10.0, 60.0, 10.0);
+        assert_eq!(index.query_bounds(&atlantic), vec![&"Greenwich"]);
+
+        // An area cut at the antimeridian
+        let east = polygon![(x: 175.0, y: -20.0), (x: 180.0, y: -20.0), (x: 180.0, y: -15.0), (x: 175.0, y: -15.0)];
+        let west = polygon![(x: -180.0, y: -20.0), (x: -175.0, y: -20.0), (x: -175.0, y: -15.0), (x: -180.0, y: -15.0)];
+        let fiji = MultiPolygon(vec![east, west]);
+        let mut found = index.query_multi_polygon(&fiji);
+        found.sort();
+        assert_eq!(found, vec![&"Suva", &"dateline route"]);
+
+        let near = index.query_within_distance_of_geometry(&Geometry::MultiPolygon(fiji), 1_000.0);
+        assert!(!near.contains(&&"Greenwich"));
+    }
+
+    #[test]
+    fn test_spatial_index_uncertain_queries() {
+        let vague = |lat: f64, lon: f64, meters: f64| {
+            let mut location = Location::new(lat, lon);
+            location.uncertainty_meters = Some(meters);
+            location
+        };
+
+        let mut index: SpatialIndex<&str> = SpatialIndex::new();
+        index.insert("exact", &Location::new(10.0, 10.0));
+        // Centroid just outside the box, but its circle reaches in
+        index.insert("vague", &vague(10.0, 11.005, 5_000.0));
+        index.insert("far", &vague(10.0, 20.0, 5_000.0));
+
+        let bounds = GeoBounds::new(9.0, 9.0, 11.0, 11.0);
+        assert_eq!(index.query_bounds(&bounds).len(), 1);
+        let matches = index.query_bounds_uncertain(&bounds);
+        assert_eq!(matches.len(), 2);
+        let vague_match = matches.iter().find(|m| *m.item == "vague").unwrap();
+        assert!(vague_match.probability > 0.0 && vague_match.probability < 0.5);
+        assert!(matches
+            .iter()
+            .find(|m| *m.item == "exact")
+            .unwrap()
+            .is_certain());
+
+        let near = index.query_radius_uncertain(10.0, 11.0, 1_000.0);
+        assert_eq!(near.len(), 1);
+        assert_eq!(*near[0].item, "vague");
+        assert!(near[0].probability < 0.1);
+    }
+
     #[test]
     fn test_spatial_index_nearest() {
         let mut index: SpatialIndex<&str> = SpatialIndex::new();
@@ -26,20 +26,58 @@
 //! let results = index.query(&bounds, &range);
 //! ```
 
+use super::persist::{self, IndexKind};
+use super::slots::{ItemKey, Slots};
+use super::{spatial, temporal};
 use super::{SpatialIndex, TemporalIndex};
-use crate::core::{GeoBounds, Location, TimeRange, Timestamp};
+use crate::core::{GeoBounds, Location, TemporalMatch, TimeRange, Timestamp};
+use crate::error::{Error, Result};
+use geo_types::{Geometry, MultiPolygon, Polygon};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Where an item is stored in the spatial and temporal indexes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    location: Location,
+    spatial: ItemKey,
+    temporal: ItemKey,
+}
+
+/// Contents of a saved spatiotemporal index, borrowed for writing.
+#[derive(Serialize)]
+struct SnapshotRef<'a, T> {
+    spatial: spatial::SnapshotRef<'a, ItemKey>,
+    temporal: temporal::SnapshotRef<'a, ItemKey>,
+    items: &'a Slots<T, Entry>,
+}
+
+/// Contents of a saved spatiotemporal index, as read back.
+#[derive(Deserialize)]
+struct Snapshot<T> {
+    spatial: spatial::Snapshot<ItemKey>,
+    temporal: temporal::Snapshot<ItemKey>,
+    items: Slots<T, Entry>,
+}
 
 /// Combined spatiotemporal index for efficient space-time queries.
 ///
 /// This index maintains both a spatial R-tree and a temporal B-tree,
 /// enabling queries that filter by both dimensions efficiently.
+/// Items are addressed by the [`ItemKey`] returned on insertion, and can
+/// be removed or updated without rebuilding the index.
+///
+/// Time ranges match every item that could overlap them given the
+/// precision of its timestamps, as with [`TemporalMatch::Possibly`].
 #[derive(Debug)]
 pub struct SpatiotemporalIndex<T> {
-    spatial: SpatialIndex<usize>,
-    temporal: TemporalIndex<usize>,
-    items: Vec<T>,
-    locations: Vec<Location>,
-    timestamps: Vec<Timestamp>,
+    spatial: SpatialIndex<ItemKey>,
+    temporal: TemporalIndex<ItemKey>,
+    items: Slots<T, Entry>,
 }
 
 impl<T: Clone> SpatiotemporalIndex<T> {
@@ -48,9 +86,7 @@ impl<T: Clone> SpatiotemporalIndex<T> {
         Self {
             spatial: SpatialIndex::new(),
             temporal: TemporalIndex::new(),
-            items: Vec::new(),
-            locations: Vec::new(),
-            timestamps: Vec::new(),
+            items: Slots::new(),
         }
     }
 
@@ -70,22 +106,137 @@ impl<T: Clone> SpatiotemporalIndex<T> {
         index
     }
 
-    /// Insert an item with its location and timestamp.
-    pub fn insert(&mut self, item: T, location: &Location, timestamp: &Timestamp) {
-        let idx = self.items.len();
+    /// Insert an item with its location and timestamp, returning its key.
+    pub fn insert(&mut self, item: T, location: &Location, timestamp: &Timestamp) -> ItemKey {
+        self.insert_span(item, location, timestamp, None)
+    }
+
+    /// Insert an item that spans a period of time.
+    ///
+    /// Temporal queries match the item if its span overlaps the range.
+    pub fn insert_interval(
+        &mut self,
+        item: T,
+        location: &Location,
+        start: &Timestamp,
+        end: &Timestamp,
+    ) -> ItemKey {
+        self.insert_span(item, location, start, Some(end))
+    }
 
-        self.items.push(item);
-        self.locations.push(location.clone());
-        self.timestamps.push(timestamp.clone());
+    /// Remove an item, returning it if the key was present.
+    pub fn remove(&mut self, key: ItemKey) -> Option<T> {
+        let (item, entry) = self.items.remove(key)?;
+        self.spatial.remove(entry.spatial);
+        self.temporal.remove(entry.temporal);
+        Some(item)
+    }
+
+    /// Replace an item and move it in space and time, keeping its key.
+    ///
+    /// Returns the previous item, or `None` (changing nothing) if the key
+    /// is not present.
+    pub fn update(
+        &mut self,
+        key: ItemKey,
+        item: T,
+        location: &Location,
+        timestamp: &Timestamp,
+    ) -> Option<T> {
+        self.update_span(key, item, location, timestamp, None)
+    }
 
-        self.spatial.insert(idx, location);
-        self.temporal.insert(idx, timestamp);
+    /// Replace an item and move it to a new location and time span,
+    /// keeping its key.
+    pub fn update_interval(
+        &mut self,
+        key: ItemKey,
+        item: T,
+        location: &Location,
+        start: &Timestamp,
+        end: &Timestamp,
+    ) -> Option<T> {
+        self.update_span(key, item, location, start, Some(end))
+    }
+
+    /// Get an item by key.
+    pub fn get(&self, key: ItemKey) -> Option<&T> {
+        self.items.get(key)
+    }
+
+    /// Get a mutable reference to an item by key.
+    ///
+    /// Changing the item does not move it; use [`update`](Self::update)
+    /// to change its location or time.
+    pub fn get_mut(&mut self, key: ItemKey) -> Option<&mut T> {
+        self.items.get_mut(key)
+    }
+
+    /// Returns true if an item with this key is indexed.
+    pub fn contains_key(&self, key: ItemKey) -> bool {
+        self.items.contains(key)
+    }
+
+    /// Get the location an item is indexed at.
+    pub fn location(&self, key: ItemKey) -> Option<&Location> {
+        self.items.meta(key).map(|entry| &entry.location)
+    }
+
+    fn resolve(&self, keys: Vec<&ItemKey>) -> Vec<&T> {
+        keys.into_iter().map(|&key| self.items.item(key)).collect()
+    }
+
+    fn insert_span(
+        &mut self,
+        item: T,
+        location: &Location,
+        start: &Timestamp,
+        end: Option<&Timestamp>,
+    ) -> ItemKey {
+        let key = self.items.next_key();
+        let entry = Entry {
+            location: location.clone(),
+            spatial: self.spatial.insert(key, location),
+            temporal: self.insert_temporal(key, start, end),
+        };
+        self.items.push(item, entry)
+    }
+
+    fn update_span(
+        &mut self,
+        key: ItemKey,
+        item: T,
+        location: &Location,
+        start: &Timestamp,
+        end: Option<&Timestamp>,
+    ) -> Option<T> {
+        let old_entry = self.items.meta(key)?.clone();
+        self.spatial.remove(old_entry.spatial);
+        self.temporal.remove(old_entry.temporal);
+        let entry = Entry {
+            location: location.clone(),
+            spatial: self.spatial.insert(key, location),
+            temporal: self.insert_temporal(key, start, end),
+        };
+        self.items.replace(key, item, entry).map(|(old, _)| old)
+    }
+
+    fn insert_temporal(
+        &mut self,
+        key: ItemKey,
+        start: &Timestamp,
+        end: Option<&Timestamp>,
+    ) -> ItemKey {
+        match end {
+            Some(end) => self.temporal.insert_interval(key, start, end),
+            None => self.temporal.insert(key, start),
+        }
     }
 
     /// Query items within both spatial bounds and time range.
     pub fn query(&self, bounds: &GeoBounds, range: &TimeRange) -> Vec<&T> {
         // Get spatial candidates
-        let spatial_indices: std::collections::HashSet<usize> = self
+        let spatial_indices: HashSet<ItemKey> = self
             .spatial
             .query_bounds(bounds)
             .into_iter()
@@ -93,9 +244,9 @@ impl<T: Clone> SpatiotemporalIndex<T> {
             .collect();
 
         // Get temporal candidates
-        let temporal_indices: std::collections::HashSet<usize> = self
+        let temporal_indices: HashSet<ItemKey> = self
             .temporal
-            .query_range(range)
+            .query_range(range, TemporalMatch::Possibly)
             .into_iter()
             .copied()
             .collect();
@@ -103,7 +254,7 @@ impl<T: Clone> SpatiotemporalIndex<T> {
         // Intersect the results
         spatial_indices
             .intersection(&temporal_indices)
-            .map(|&i| &self.items[i])
+            .map(|&key| self.items.item(key))
             .collect()
     }
 
@@ -112,36 +263,78 @@ impl<T: Clone> SpatiotemporalIndex<T> {
         self.spatial
             .query_bounds(bounds)
             .into_iter()
-            .map(|&i| &self.items[i])
+            .map(|&key| self.items.item(key))
             .collect()
     }
 
     /// Query items within time range only.
     pub fn query_temporal(&self, range: &TimeRange) -> Vec<&T> {
         self.temporal
-            .query_range(range)
+            .query_range(range, TemporalMatch::Possibly)
+            .into_iter()
+            .map(|&key| self.items.item(key))
+            .collect()
+    }
+
+    /// Query items inside a polygon.
+    ///
+    /// See [`SpatialIndex::query_polygon`].
+    pub fn query_polygon(&self, polygon: &Polygon<f64>) -> Vec<&T> {
+        self.resolve(self.spatial.query_polygon(polygon))
+    }
+
+    /// Query items inside any polygon of a multi-polygon.
+    pub fn query_multi_polygon(&self, area: &MultiPolygon<f64>) -> Vec<&T> {
+        self.resolve(self.spatial.query_multi_polygon(area))
+    }
+
+    /// Query items within a distance in meters of a geometry.
+    ///
+    /// See [`SpatialIndex::query_within_distance_of_geometry`].
+    pub fn query_within_distance_of_geometry(
+        &self,
+        geometry: &Geometry<f64>,
+        meters: f64,
+    ) -> Vec<&T> {
+        self.resolve(
+            self.spatial
+                .query_within_distance_of_geometry(geometry, meters),
+        )
+    }
+
+    /// Query items inside a polygon and within a time range.
+    pub fn query_polygon_in_range(&self, polygon: &Polygon<f64>, range: &TimeRange) -> Vec<&T> {
+        let temporal_keys: HashSet<ItemKey> = self
+            .temporal
+            .query_range(range, TemporalMatch::Possibly)
+            .into_iter()
+            .copied()
+            .collect();
+
+        self.spatial
+            .query_polygon(polygon)
             .into_iter()
-            .map(|&i| &self.items[i])
+            .filter(|key| temporal_keys.contains(key))
+            .map(|&key| self.items.item(key))
             .collect()
     }
 
     /// Find k nearest items to a point within a time range.
+    ///
+    /// Items are ranked by great-circle distance; k items are returned
+    /// whenever at least k fall in the time range.
     pub fn nearest_in_range(&self, lat: f64, lon: f64, k: usize, range: &TimeRange) -> Vec<&T> {
-        // Get temporal candidates first
-        let temporal_indices: std::collections::HashSet<usize> = self
+        let temporal_indices: HashSet<ItemKey> = self
             .temporal
-            .query_range(range)
+            .query_range(range, TemporalMatch::Possibly)
             .into_iter()
             .copied()
             .collect();
 
-        // Get spatial nearest and filter by temporal
         self.spatial
-            .nearest(lat, lon, k * 2) // Get more candidates to account for filtering
+            .nearest_where(lat, lon, k, |i| temporal_indices.contains(i))
             .into_iter()
-            .filter(|&i| temporal_indices.contains(i))
-            .take(k)
-            .map(|&i| &self.items[i])
+            .map(|&key| self.items.item(key))
             .collect()
     }
 
@@ -156,16 +349,20 @@ impl<T: Clone> SpatiotemporalIndex<T> {
     }
 
     /// Get all items in the index.
+    ///
+    /// Removing an item may change the order of the others.
     pub fn items(&self) -> &[T] {
-        &self.items
+        self.items.items()
+    }
+
+    /// Iterate over all items with their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemKey, &T)> {
+        self.items.iter()
     }
 
     /// Get the geographic bounds of all indexed items.
     pub fn bounds(&self) -> Option<GeoBounds> {
-        if self.locations.is_empty() {
-            return None;
-        }
-        GeoBounds::from_locations(&self.locations)
+        GeoBounds::from_locations(self.items.metas().iter().map(|entry| &entry.location))
     }
 
     /// Get the time range of all indexed items.
@@ -174,6 +371,58 @@ impl<T: Clone> SpatiotemporalIndex<T> {
     }
 }
 
+impl<T: Clone + Serialize> SpatiotemporalIndex<T> {
+    /// Write the index in the binary index format.
+    ///
+    /// Items keep their keys when the index is read back.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<()> {
+        let snapshot = SnapshotRef {
+            spatial: self.spatial.snapshot(),
+            temporal: self.temporal.snapshot(),
+            items: &self.items,
+        };
+        persist::write(writer, IndexKind::Spatiotemporal, &snapshot)
+    }
+
+    /// Save the index to a file, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T: Clone + DeserializeOwned> SpatiotemporalIndex<T> {
+    /// Read an index written by [`write_to`](Self::write_to).
+    ///
+    /// The spatial and temporal trees are restored as saved rather than
+    /// rebuilt.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self> {
+        let snapshot: Snapshot<T> = persist::read(reader, IndexKind::Spatiotemporal)?;
+        let spatial = SpatialIndex::from_snapshot(snapshot.spatial)?;
+        let temporal = TemporalIndex::from_snapshot(snapshot.temporal)?;
+        let items = snapshot.items;
+
+        let linked = items.metas().iter().all(|entry| {
+            spatial.contains_key(entry.spatial) && temporal.contains_key(entry.temporal)
+        });
+        if !linked || spatial.len() != items.len() || temporal.len() != items.len() {
+            return Err(Error::InvalidFormat(
+                "spatiotemporal index items do not match its inner indexes".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            spatial,
+            temporal,
+            items,
+        })
+    }
+
+    /// Load an index saved with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
 impl<T: Clone> Default for SpatiotemporalIndex<T> {
     fn default() -> Self {
         Self::new()
@@ -204,7 +453,7 @@ impl GridSpec {
     /// Create a grid with approximately square cells.
     pub fn square_cells(bounds: GeoBounds, target_cells: usize) -> Self {
         let lat_range = bounds.max_lat - bounds.min_lat;
-        let lon_range = bounds.max_lon - bounds.min_lon;
+        let lon_range = bounds.width();
         let aspect = lon_range / lat_range;
 
         let lat_cells = ((target_cells as f64 / aspect).sqrt() as usize).max(1);
@@ -220,7 +469,7 @@ impl GridSpec {
     /// Get the cell size in degrees.
     pub fn cell_size(&self) -> (f64, f64) {
         let lat_size = (self.bounds.max_lat - self.bounds.min_lat) / self.lat_cells as f64;
-        let lon_size = (self.bounds.max_lon - self.bounds.min_lon) / self.lon_cells as f64;
+        let lon_size = self.bounds.width() / self.lon_cells as f64;
         (lat_size, lon_size)
     }
 }
@@ -268,13 +517,13 @@ impl<T: Clone> SpatiotemporalIndex<T> {
         let mut counts = vec![0usize; grid.lat_cells * grid.lon_cells];
         let (lat_size, lon_size) = grid.cell_size();
 
-        for loc in &self.locations {
+        for loc in self.items.metas().iter().map(|entry| &entry.location) {
             if !grid.bounds.contains(loc) {
                 continue;
             }
 
             let lat_idx = ((loc.lat - grid.bounds.min_lat) / lat_size) as usize;
-            let lon_idx = ((loc.lon - grid.bounds.min_lon) / lon_size) as usize;
+            let lon_idx = (grid.bounds.lon_offset(loc.lon) / lon_size) as usize;
 
             let lat_idx = lat_idx.min(grid.lat_cells - 1);
             let lon_idx = lon_idx.min(grid.lon_cells - 1);
@@ -295,6 +544,7 @@ impl<T: Clone> SpatiotemporalIndex<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use geo_types::polygon;
 
     fn make_timestamp(day: u32) -> Timestamp {
         Timestamp::parse(&format!("2024-01-{:02}T12:00:00Z", day)).unwrap()
@@ -348,6 +598,86 @@ mod tests {
         assert_eq!(*results[0], "NYC Jan 1");
     }
 
+    #[test]
+    fn test_spatiotemporal_remove_update() {
+        let nyc = Location::new(40.7128, -74.0060);
+        let la = Location::new(34.0522, -118.2437);
+        let mut index = SpatiotemporalIndex::new();
+        let first = index.insert("first", &nyc, &make_timestamp(1));
+        let second = index.insert("second", &nyc, &make_timestamp(2));
+
+        let east = GeoBounds::new(35.0, -80.0, 45.0, -70.0);
+        let january = TimeRange::new(make_timestamp(1), make_timestamp(31));
+
+        assert_eq!(index.remove(first), Some("first"));
+        assert_eq!(index.query(&east, &january), vec![&"second"]);
+
+        // Correct the second report's location and date
+        index.update(second, "corrected", &la, &make_timestamp(10));
+        assert!(index.query_spatial(&east).is_empty());
+        assert_eq!(index.location(second), Some(&la));
+        assert_eq!(
+            index.query_temporal(&TimeRange::new(make_timestamp(9), make_timestamp(11))),
+            vec![&"corrected"]
+        );
+        assert_eq!(index.bounds(), GeoBounds::from_locations([&la]));
+
+        assert_eq!(index.remove(second), Some("corrected"));
+        assert!(index.is_empty() && index.bounds().is_none());
+        assert!(index.time_range().is_none());
+    }
+
+    #[test]
+    fn test_spatiotemporal_polygon_queries() {
+        let mut index = SpatiotemporalIndex::new();
+        index.insert("early inside", &Location::new(1.0, 1.0), &make_timestamp(1));
+        index.insert("late inside", &Location::new(1.5, 1.5), &make_timestamp(20));
+        index.insert(
+            "early outside",
+            &Location::new(5.0, 5.0),
+            &make_timestamp(1),
+        );
+
+        let district =
+            polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 2.0), (x: 0.0, y: 2.0)];
+        assert_eq!(index.query_polygon(&district).len(), 2);
+
+        let first_week = TimeRange::new(make_timestamp(1), make_timestamp(7));
+        assert_eq!(
+            index.query_polygon_in_range(&district, &first_week),
+            vec![&"early inside"]
+        );
+
+        let point: Geometry<f64> = geo_types::Point::new(5.0, 5.01).into();
+        assert_eq!(
+            index.query_within_distance_of_geometry(&point, 2_000.0),
+            vec![&"early outside"]
+        );
+    }
+
+    #[test]
+    fn test_nearest_in_range() {
+        let mut index = SpatiotemporalIndex::new();
+        // Many nearby events outside the range, two far ones inside it
+        for i in 0..10 {
+            index.insert(
+                "early",
+                &Location::new(0.0, i as f64 * 0.01),
+                &make_timestamp(1),
+            );
+        }
+        index.insert("late east", &Location::new(0.0, 179.9), &make_timestamp(20));
+        index.insert(
+            "late west",
+            &Location::new(0.0, -179.9),
+            &make_timestamp(20),
+        );
+
+        let range = TimeRange::new(make_timestamp(15), make_timestamp(25));
+        let nearest = index.nearest_in_range(0.0, -179.95, 2, &range);
+        assert_eq!(nearest, vec![&"late west", &"late east"]);
+    }
+
     #[test]
     fn test_heatmap_generation() {
         let mut index: SpatiotemporalIndex<&str> = SpatiotemporalIndex::new();
@@ -2,15 +2,19 @@
 //!
 //! This module provides temporal indexing using B-tree structures,
 //! enabling fast queries like:
-//! - Time range queries
+//! - Time range queries (matching on overlap for interval items)
 //! - Before/after queries
 //! - Sliding window iteration
 //!
+//! Items are indexed by the interval their timestamps' precision implies,
+//! so an item dated `1943` spans the whole year. Ordering queries use the
+//! start of that interval, and range queries take a [`TemporalMatch`].
+//!
 //! # Example
 //!
 //! ```rust
 //! use spatial_narrative::index::TemporalIndex;
-//! use spatial_narrative::core::{Timestamp, TimeRange};
+//! use spatial_narrative::core::{TemporalMatch, TimeRange, Timestamp};
 //!
 //! // Build temporal index
 //! let mut index: TemporalIndex<&str> = TemporalIndex::new();
@@ -21,24 +25,97 @@
 //! // Query events in a time range
 //! let start = Timestamp::parse("2024-01-01T12:00:00Z").unwrap();
 //! let end = Timestamp::parse("2024-01-01T18:00:00Z").unwrap();
-//! let results = index.query_range(&TimeRange::new(start, end));
+//! let results = index.query_range(&TimeRange::new(start, end), TemporalMatch::Possibly);
 //! assert!(!results.is_empty());
 //! ```
 
-use crate::core::{TimeRange, Timestamp};
-use std::collections::BTreeMap;
+use super::persist::{self, IndexKind};
+use super::slots::{ItemKey, Slots};
+use crate::core::{TemporalMatch, TimeRange, Timestamp};
+use crate::error::{Error, Result};
+use rstar::{RTree, RTreeObject, AABB};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// An item's time span in milliseconds.
+///
+/// `start..=end` covers every instant the item's timestamps could refer
+/// to given their precision. `latest_start` and `earliest_end` bound the
+/// span under the reading that starts last and ends first, which is what
+/// every reading is guaranteed to cover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Span {
+    start: i64,
+    end: i64,
+    latest_start: i64,
+    earliest_end: i64,
+}
+
+/// An item's time span, stored in the interval tree.
+///
+/// Spans are indexed as one-dimensional boxes in an R-tree, which acts
+/// as a dynamic interval tree for overlap queries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexedInterval {
+    start: i64,
+    end: i64,
+    key: ItemKey,
+}
+
+impl RTreeObject for IndexedInterval {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners([self.start as f64, 0.0], [self.end as f64, 0.0])
+    }
+}
+
+/// Contents of a saved temporal index, borrowed for writing.
+#[derive(Serialize)]
+pub(super) struct SnapshotRef<'a, T> {
+    items: &'a Slots<T, Span>,
+    #[serde(serialize_with = "sorted_run")]
+    starts: &'a BTreeMap<i64, Vec<ItemKey>>,
+    intervals: &'a RTree<IndexedInterval>,
+}
+
+/// Contents of a saved temporal index, as read back.
+///
+/// Start times are saved as a sorted run, so the B-tree is built in one
+/// pass, and the interval tree is restored as it was instead of rebuilt.
+#[derive(Deserialize)]
+pub(super) struct Snapshot<T> {
+    items: Slots<T, Span>,
+    starts: Vec<(i64, Vec<ItemKey>)>,
+    intervals: RTree<IndexedInterval>,
+}
+
+/// Writes the start-time B-tree as a sequence of (start, keys) pairs.
+fn sorted_run<S: Serializer>(
+    starts: &&BTreeMap<i64, Vec<ItemKey>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_seq(starts.iter())
+}
 
 /// Temporal index for efficient time-based queries.
 ///
-/// Uses a B-tree for O(log n) range queries.
+/// Uses a B-tree keyed by start time for ordered queries, and an
+/// interval tree for O(log n) overlap queries on items with duration.
+/// Items are addressed by the [`ItemKey`] returned on insertion, and can
+/// be removed or updated without rebuilding the index.
 #[derive(Debug)]
 pub struct TemporalIndex<T> {
-    /// B-tree mapping timestamps to item indices
-    tree: BTreeMap<i64, Vec<usize>>,
-    /// The actual items
-    items: Vec<T>,
-    /// Timestamps for each item (for iteration)
-    timestamps: Vec<Timestamp>,
+    /// B-tree mapping start timestamps to item keys
+    tree: BTreeMap<i64, Vec<ItemKey>>,
+    /// Interval tree over item time spans
+    intervals: RTree<IndexedInterval>,
+    /// The actual items, with their spans
+    items: Slots<T, Span>,
 }
 
 impl<T: Clone> TemporalIndex<T> {
@@ -46,8 +123,8 @@ impl<T: Clone> TemporalIndex<T> {
     pub fn new() -> Self {
         Self {
             tree: BTreeMap::new(),
-            items: Vec::new(),
-            timestamps: Vec::new(),
+            intervals: RTree::new(),
+            items: Slots::new(),
         }
     }
 
@@ -65,24 +142,182 @@ impl<T: Clone> TemporalIndex<T> {
         index
     }
 
-    /// Insert an item into the index.
-    pub fn insert(&mut self, item: T, timestamp: &Timestamp) {
-        let idx = self.items.len();
-        let key = timestamp.to_unix_millis();
+    /// Insert an item into the index, returning its key.
+    pub fn insert(&mut self, item: T, timestamp: &Timestamp) -> ItemKey {
+        self.insert_span(item, timestamp, None)
+    }
+
+    /// Insert an item that spans a period of time.
+    ///
+    /// Ordering queries (`before`, `chronological`, ...) use the start
+    /// time; [`query_range`](Self::query_range) matches on overlap.
+    pub fn insert_interval(&mut self, item: T, start: &Timestamp, end: &Timestamp) -> ItemKey {
+        self.insert_span(item, start, Some(end))
+    }
 
-        self.items.push(item);
-        self.timestamps.push(timestamp.clone());
-        self.tree.entry(key).or_default().push(idx);
+    /// Remove an item, returning it if the key was present.
+    pub fn remove(&mut self, key: ItemKey) -> Option<T> {
+        let (item, span) = self.items.remove(key)?;
+        self.unindex(key, span);
+        Some(item)
+    }
+
+    /// Replace an item and move it to a new timestamp, keeping its key.
+    ///
+    /// Returns the previous item, or `None` (changing nothing) if the key
+    /// is not present.
+    pub fn update(&mut self, key: ItemKey, item: T, timestamp: &Timestamp) -> Option<T> {
+        self.update_span(key, item, timestamp, None)
+    }
+
+    /// Replace an item and move it to a new time span, keeping its key.
+    pub fn update_interval(
+        &mut self,
+        key: ItemKey,
+        item: T,
+        start: &Timestamp,
+        end: &Timestamp,
+    ) -> Option<T> {
+        self.update_span(key, item, start, Some(end))
+    }
+
+    /// Get an item by key.
+    pub fn get(&self, key: ItemKey) -> Option<&T> {
+        self.items.get(key)
+    }
+
+    /// Get a mutable reference to an item by key.
+    ///
+    /// Changing the item does not move it; use [`update`](Self::update)
+    /// to change its time.
+    pub fn get_mut(&mut self, key: ItemKey) -> Option<&mut T> {
+        self.items.get_mut(key)
+    }
+
+    /// Returns true if an item with this key is indexed.
+    pub fn contains_key(&self, key: ItemKey) -> bool {
+        self.items.contains(key)
+    }
+
+    /// Borrows the index's contents for saving.
+    pub(super) fn snapshot(&self) -> SnapshotRef<'_, T> {
+        SnapshotRef {
+            items: &self.items,
+            starts: &self.tree,
+            intervals: &self.intervals,
+        }
+    }
+
+    /// Restores an index from saved contents.
+    ///
+    /// Fails if the start times are out of order, or if the start times
+    /// and interval tree do not match the stored items one to one.
+    pub(super) fn from_snapshot(snapshot: Snapshot<T>) -> Result<Self> {
+        let Snapshot {
+            items,
+            starts,
+            intervals,
+        } = snapshot;
+        let corrupt = |what: &str| Err(Error::InvalidFormat(format!("temporal index {what}")));
+
+        if starts.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return corrupt("start times are out of order");
+        }
+        let mut started = HashSet::with_capacity(items.len());
+        for (start, keys) in &starts {
+            for &key in keys {
+                if items.meta(key).map(|span| span.start) != Some(*start) || !started.insert(key) {
+                    return corrupt("start times do not match its items");
+                }
+            }
+        }
+        let mut spanned = HashSet::with_capacity(items.len());
+        for interval in intervals.iter() {
+            let span = items.meta(interval.key).map(|span| (span.start, span.end));
+            if span != Some((interval.start, interval.end)) || !spanned.insert(interval.key) {
+                return corrupt("interval tree does not match its items");
+            }
+        }
+        if started.len() != items.len() || spanned.len() != items.len() {
+            return corrupt("is missing items");
+        }
+
+        Ok(Self {
+            // Built in one pass from the sorted run
+            tree: starts.into_iter().collect(),
+            intervals,
+            items,
+        })
+    }
+
+    fn insert_span(&mut self, item: T, start: &Timestamp, end: Option<&Timestamp>) -> ItemKey {
+        let span = span_millis(start, end);
+        let key = self.items.push(item, span);
+        self.index(key, span);
+        key
+    }
+
+    fn update_span(
+        &mut self,
+        key: ItemKey,
+        item: T,
+        start: &Timestamp,
+        end: Option<&Timestamp>,
+    ) -> Option<T> {
+        let span = span_millis(start, end);
+        let (old, old_span) = self.items.replace(key, item, span)?;
+        self.unindex(key, old_span);
+        self.index(key, span);
+        Some(old)
+    }
+
+    fn index(&mut self, key: ItemKey, Span { start, end, .. }: Span) {
+        self.tree.entry(start).or_default().push(key);
+        self.intervals.insert(IndexedInterval { start, end, key });
+    }
+
+    fn unindex(&mut self, key: ItemKey, Span { start, end, .. }: Span) {
+        if let Some(keys) = self.tree.get_mut(&start) {
+            keys.retain(|k| *k != key);
+            if keys.is_empty() {
+                self.tree.remove(&start);
+            }
+        }
+        self.intervals.remove(&IndexedInterval { start, end, key });
     }
 
     /// Query items within a time range (inclusive).
-    pub fn query_range(&self, range: &TimeRange) -> Vec<&T> {
+    ///
+    /// Items match if their span, expanded by the precision of their
+    /// timestamps, overlaps the range under `mode`: with
+    /// [`TemporalMatch::Possibly`] an item dated `1943` matches March 1943,
+    /// with [`TemporalMatch::Definitely`] only ranges covering all of 1943.
+    /// Results are ordered by start time.
+    pub fn query_range(&self, range: &TimeRange, mode: TemporalMatch) -> Vec<&T> {
         let start_key = range.start.to_unix_millis();
         let end_key = range.end.to_unix_millis();
+        if start_key > end_key {
+            return Vec::new();
+        }
 
-        self.tree
-            .range(start_key..=end_key)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+        // The tree holds the widest spans, so it yields every possible match
+        let envelope = AABB::from_corners([start_key as f64, 0.0], [end_key as f64, 0.0]);
+        let mut matches: Vec<(i64, ItemKey)> = self
+            .intervals
+            .locate_in_envelope_intersecting(&envelope)
+            .filter(|interval| match mode {
+                TemporalMatch::Possibly => true,
+                TemporalMatch::Definitely => self.items.meta(interval.key).is_some_and(|span| {
+                    span.latest_start <= end_key && span.earliest_end >= start_key
+                }),
+            })
+            .map(|interval| (interval.start, interval.key))
+            .collect();
+        matches.sort_unstable();
+
+        matches
+            .into_iter()
+            .map(|(_, i)| self.items.item(i))
             .collect()
     }
 
@@ -92,7 +327,7 @@ impl<T: Clone> TemporalIndex<T> {
 
         self.tree
             .range(..key)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+            .flat_map(|(_, indices)| indices.iter().map(|&i| self.items.item(i)))
             .collect()
     }
 
@@ -102,7 +337,7 @@ impl<T: Clone> TemporalIndex<T> {
 
         self.tree
             .range((key + 1)..)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+            .flat_map(|(_, indices)| indices.iter().map(|&i| self.items.item(i)))
             .collect()
     }
 
@@ -112,7 +347,7 @@ impl<T: Clone> TemporalIndex<T> {
 
         self.tree
             .range(..=key)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+            .flat_map(|(_, indices)| indices.iter().map(|&i| self.items.item(i)))
             .collect()
     }
 
@@ -122,7 +357,7 @@ impl<T: Clone> TemporalIndex<T> {
 
         self.tree
             .range(key..)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+            .flat_map(|(_, indices)| indices.iter().map(|&i| self.items.item(i)))
             .collect()
     }
 
@@ -131,7 +366,7 @@ impl<T: Clone> TemporalIndex<T> {
         self.tree
             .iter()
             .next()
-            .and_then(|(_, indices)| indices.first().map(|&i| &self.items[i]))
+            .and_then(|(_, indices)| indices.first().map(|&i| self.items.item(i)))
     }
 
     /// Get the last (latest) item.
@@ -139,14 +374,14 @@ impl<T: Clone> TemporalIndex<T> {
         self.tree
             .iter()
             .next_back()
-            .and_then(|(_, indices)| indices.last().map(|&i| &self.items[i]))
+            .and_then(|(_, indices)| indices.last().map(|&i| self.items.item(i)))
     }
 
     /// Returns items in chronological order.
     pub fn chronological(&self) -> Vec<&T> {
         self.tree
-            .iter()
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.items[i]))
+            .values()
+            .flat_map(|indices| indices.iter().map(|&i| self.items.item(i)))
             .collect()
     }
 
@@ -155,17 +390,19 @@ impl<T: Clone> TemporalIndex<T> {
         self.tree
             .iter()
             .rev()
-            .flat_map(|(_, indices)| indices.iter().rev().map(|&i| &self.items[i]))
+            .flat_map(|(_, indices)| indices.iter().rev().map(|&i| self.items.item(i)))
             .collect()
     }
 
     /// Returns the time range spanned by all items.
+    ///
+    /// Interval items contribute their end time.
     pub fn time_range(&self) -> Option<TimeRange> {
         let first_key = self.tree.keys().next()?;
-        let last_key = self.tree.keys().next_back()?;
+        let last_key = self.intervals.iter().map(|interval| interval.end).max()?;
 
         let start = Timestamp::from_unix_millis(*first_key)?;
-        let end = Timestamp::from_unix_millis(*last_key)?;
+        let end = Timestamp::from_unix_millis(last_key)?;
 
         Some(TimeRange::new(start, end))
     }
@@ -190,8 +427,56 @@ impl<T: Clone> TemporalIndex<T> {
     }
 
     /// Get all items in the index.
+    ///
+    /// Removing an item may change the order of the others.
     pub fn items(&self) -> &[T] {
-        &self.items
+        self.items.items()
+    }
+
+    /// Iterate over all items with their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemKey, &T)> {
+        self.items.iter()
+    }
+}
+
+/// The span implied by a start and optional end; the end is never before
+/// the start.
+fn span_millis(start: &Timestamp, end: Option<&Timestamp>) -> Span {
+    let end = end.unwrap_or(start);
+    let earliest_start = start.earliest().to_unix_millis();
+    Span {
+        start: earliest_start,
+        end: end.latest().to_unix_millis().max(earliest_start),
+        latest_start: start.latest().to_unix_millis(),
+        earliest_end: end.earliest().to_unix_millis(),
+    }
+}
+
+impl<T: Clone + Serialize> TemporalIndex<T> {
+    /// Write the index in the binary index format.
+    ///
+    /// Items keep their keys when the index is read back.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<()> {
+        persist::write(writer, IndexKind::Temporal, &self.snapshot())
+    }
+
+    /// Save the index to a file, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T: Clone + DeserializeOwned> TemporalIndex<T> {
+    /// Read an index written by [`write_to`](Self::write_to).
+    ///
+    /// The trees are restored as saved rather than rebuilt.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self> {
+        Self::from_snapshot(persist::read(reader, IndexKind::Temporal)?)
+    }
+
+    /// Load an index saved with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
     }
 }
 
@@ -222,7 +507,7 @@ impl<'a, T: Clone> Iterator for SlidingWindowIter<'a, T> {
             .index
             .tree
             .range(start..end)
-            .flat_map(|(_, indices)| indices.iter().map(|&i| &self.index.items[i]))
+            .flat_map(|(_, indices)| indices.iter().map(|&i| self.index.items.item(i)))
             .collect();
 
         if items.is_empty() && self.current_start.is_some() {
@@ -268,11 +553,40 @@ mod tests {
         index.insert("6pm", &make_timestamp(18));
 
         let range = TimeRange::new(make_timestamp(11), make_timestamp(16));
-        let results = index.query_range(&range);
+        let results = index.query_range(&range, TemporalMatch::Possibly);
 
         assert_eq!(results.len(), 2);
     }
 
+    #[test]
+    fn test_temporal_index_interval_overlap() {
+        let mut index = TemporalIndex::new();
+        index.insert("9am", &make_timestamp(9));
+        index.insert_interval("Strike", &make_timestamp(6), &make_timestamp(12));
+        index.insert_interval("Shift", &make_timestamp(14), &make_timestamp(22));
+
+        // Range falls strictly inside the strike
+        let range = TimeRange::new(make_timestamp(10), make_timestamp(11));
+        assert_eq!(
+            index.query_range(&range, TemporalMatch::Possibly),
+            vec![&"Strike"]
+        );
+
+        // Range touches all three, ordered by start
+        let range = TimeRange::new(make_timestamp(8), make_timestamp(15));
+        assert_eq!(
+            index.query_range(&range, TemporalMatch::Possibly),
+            vec![&"Strike", &"9am", &"Shift"]
+        );
+
+        // The end is the last instant the second-precision end could mean
+        let time_range = index.time_range().unwrap();
+        assert_eq!(
+            time_range.end.to_unix_millis(),
+            make_timestamp(22).latest().to_unix_millis()
+        );
+    }
+
     #[test]
     fn test_temporal_index_before_after() {
         let mut index = TemporalIndex::new();
@@ -289,6 +603,44 @@ mod tests {
         assert_eq!(*after[0], "3pm");
     }
 
+    #[test]
+    fn test_temporal_index_query_range_precision() {
+        let mut index = TemporalIndex::new();
+        index.insert("Raid", &Timestamp::parse("1943").unwrap());
+        index.insert_interval(
+            "Siege",
+            &Timestamp::parse("1942").unwrap(),
+            &Timestamp::parse("February 1943").unwrap(),
+        );
+        index.insert("Exact", &Timestamp::parse("1943-03-10T12:00:00Z").unwrap());
+
+        let march = TimeRange::month(1943, 3);
+        assert_eq!(
+            index.query_range(&march, TemporalMatch::Possibly),
+            vec![&"Raid", &"Exact"]
+        );
+        assert_eq!(
+            index.query_range(&march, TemporalMatch::Definitely),
+            vec![&"Exact"]
+        );
+
+        // Every reading of the siege reaches into 1943
+        let year = TimeRange::year(1943);
+        assert_eq!(
+            index.query_range(&year, TemporalMatch::Definitely),
+            vec![&"Siege", &"Raid", &"Exact"]
+        );
+
+        // Ordering and the overall span use the implied intervals
+        assert_eq!(
+            index.before(&Timestamp::parse("1943-01-01").unwrap()),
+            vec![&"Siege"]
+        );
+        let span = index.time_range().unwrap();
+        assert_eq!(span.start.to_rfc3339(), "1942-01-01T00:00:00+00:00");
+        assert_eq!(span.end.to_rfc3339(), "1943-12-31T23:59:59.999+00:00");
+    }
+
     #[test]
     fn test_temporal_index_first_last() {
         let mut index = TemporalIndex::new();
@@ -311,4 +663,37 @@ mod tests {
         let ordered: Vec<_> = index.chronological();
         assert_eq!(ordered, vec![&"A", &"B", &"C"]);
     }
+
+    #[test]
+    fn test_temporal_index_remove_update() {
+        let mut index = TemporalIndex::new();
+        let morning = index.insert("Morning", &make_timestamp(9));
+        let also_morning = index.insert("Also morning", &make_timestamp(9));
+        let strike = index.insert_interval("Strike", &make_timestamp(6), &make_timestamp(22));
+
+        assert_eq!(index.remove(morning), Some("Morning"));
+        assert_eq!(index.chronological(), vec![&"Strike", &"Also morning"]);
+
+        // Shorten the strike; it no longer covers the evening
+        assert_eq!(
+            index.update_interval(strike, "Strike", &make_timestamp(6), &make_timestamp(12)),
+            Some("Strike")
+        );
+        let evening = TimeRange::new(make_timestamp(18), make_timestamp(20));
+        assert!(index
+            .query_range(&evening, TemporalMatch::Possibly)
+            .is_empty());
+        assert_eq!(
+            index.time_range().unwrap().end.to_unix_millis(),
+            make_timestamp(12).latest().to_unix_millis()
+        );
+
+        assert_eq!(
+            index.update(also_morning, "Night", &make_timestamp(23)),
+            Some("Also morning")
+        );
+        assert_eq!(index.last(), Some(&"Night"));
+        assert_eq!(index.get(also_morning), Some(&"Night"));
+        assert_eq!(index.len(), 2);
+    }
 }
@@ -0,0 +1,1056 @@
+//! Citation import and export for source references.
+//!
+//! Converts [`SourceRef`]s to and from BibTeX and CSL-JSON records, and
+//! builds a deduplicated [`Bibliography`] for a whole narrative.
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+use crate::core::{
+    normalize_doi, EventId, Narrative, SourceRef, SourceType, TemporalPrecision, Timestamp,
+};
+use crate::error::{Error, Result};
+
+/// A source reference together with its citation key.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{SourceRef, SourceType, Timestamp};
+/// use spatial_narrative::io::{parse_bibtex, CitationEntry};
+///
+/// let source = SourceRef::new(SourceType::Academic)
+///     .with_author("Ada Lovelace")
+///     .with_title("Notes on the Analytical Engine")
+///     .with_date(Timestamp::parse("1843").unwrap());
+///
+/// let entry = CitationEntry::from_source(source.clone());
+/// assert_eq!(entry.key, "lovelace1843notes");
+///
+/// let parsed = parse_bibtex(&entry.to_bibtex()).unwrap();
+/// assert_eq!(parsed[0].source, source);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CitationEntry {
+    /// Citation key (BibTeX key or CSL-JSON id).
+    pub key: String,
+    /// The cited source.
+    pub source: SourceRef,
+}
+
+impl CitationEntry {
+    /// Creates an entry with an explicit key.
+    pub fn new(key: impl Into<String>, source: SourceRef) -> Self {
+        Self {
+            key: key.into(),
+            source,
+        }
+    }
+
+    /// Creates an entry keyed by [`citation_key`].
+    pub fn from_source(source: SourceRef) -> Self {
+        Self {
+            key: citation_key(&source),
+            source,
+        }
+    }
+
+    /// Renders the entry as a BibTeX record.
+    ///
+    /// Credibility scores are not bibliographic and are not written.
+    pub fn to_bibtex(&self) -> String {
+        let s = &self.source;
+        let entry_type = bibtex_type(s.source_type);
+        let mut fields: Vec<(&str, String)> = Vec::new();
+
+        if let Some(author) = &s.author {
+            fields.push(("author", author.clone()));
+        }
+        if let Some(title) = &s.title {
+            fields.push(("title", title.clone()));
+        }
+        if let Some(publisher) = &s.publisher {
+            fields.push(("publisher", publisher.clone()));
+        }
+        if let Some(date) = &s.date {
+            fields.push(("date", date.format_with_precision()));
+            fields.push(("year", date.calendar_date().0.to_string()));
+        }
+        if let Some(pages) = &s.pages {
+            fields.push(("pages", pages.clone()));
+        }
+        if let Some(doi) = &s.doi {
+            fields.push(("doi", doi.clone()));
+        }
+        if let Some(url) = &s.url {
+            fields.push(("url", url.clone()));
+        }
+        if let Some(accessed) = &s.accessed {
+            fields.push(("urldate", accessed.format_with_precision()));
+        }
+        if let Some(notes) = &s.notes {
+            fields.push(("note", notes.clone()));
+        }
+        if bibtex_source_type(entry_type) != s.source_type {
+            fields.push(("sourcetype", s.source_type.to_string()));
+        }
+
+        let mut out = format!("@{}{{{}", entry_type, self.key);
+        for (name, value) in fields {
+            let verbatim = matches!(name, "doi" | "url");
+            out.push_str(&format!(
+                ",\n  {} = {{{}}}",
+                name,
+                escape_bibtex(&value, verbatim)
+            ));
+        }
+        out.push_str("\n}\n");
+        out
+    }
+
+    /// Renders the entry as a CSL-JSON item.
+    pub fn to_csl_json(&self) -> Value {
+        let s = &self.source;
+        let csl_type = csl_type(s.source_type);
+        let mut item = Map::new();
+
+        item.insert("id".into(), json!(self.key));
+        item.insert("type".into(), json!(csl_type));
+        if let Some(title) = &s.title {
+            item.insert("title".into(), json!(title));
+        }
+        if let Some(author) = &s.author {
+            let names: Vec<Value> = split_authors(author)
+                .map(|name| json!({ "literal": name }))
+                .collect();
+            item.insert("author".into(), Value::Array(names));
+        }
+        if let Some(publisher) = &s.publisher {
+            item.insert("publisher".into(), json!(publisher));
+        }
+        if let Some(pages) = &s.pages {
+            item.insert("page".into(), json!(pages));
+        }
+        if let Some(doi) = &s.doi {
+            item.insert("DOI".into(), json!(doi));
+        }
+        if let Some(url) = &s.url {
+            item.insert("URL".into(), json!(url));
+        }
+        if let Some(date) = &s.date {
+            item.insert("issued".into(), csl_date(date));
+        }
+        if let Some(accessed) = &s.accessed {
+            item.insert("accessed".into(), csl_date(accessed));
+        }
+        if let Some(notes) = &s.notes {
+            item.insert("note".into(), json!(notes));
+        }
+        if csl_source_type(csl_type) != s.source_type {
+            item.insert(
+                "custom".into(),
+                json!({ "source_type": s.source_type.to_string() }),
+            );
+        }
+
+        Value::Object(item)
+    }
+
+    /// Reads an entry from a CSL-JSON item.
+    pub fn from_csl_json(item: &Value) -> Result<Self> {
+        let obj = item
+            .as_object()
+            .ok_or_else(|| Error::InvalidFormat("CSL-JSON item must be an object".into()))?;
+        let text = |name: &str| match obj.get(name) {
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(Value::Number(n)) => Some(n.to_string()),
+            _ => None,
+        };
+
+        let key = text("id").ok_or(Error::MissingField("id"))?;
+        let source_type = obj
+            .get("custom")
+            .and_then(|c| c.get("source_type"))
+            .and_then(Value::as_str)
+            .and_then(SourceType::from_name)
+            .unwrap_or_else(|| {
+                csl_source_type(obj.get("type").and_then(Value::as_str).unwrap_or(""))
+            });
+
+        let mut source = SourceRef::new(source_type);
+        source.title = text("title");
+        source.author = obj
+            .get("author")
+            .and_then(Value::as_array)
+            .and_then(|names| {
+                let names: Vec<String> = names.iter().filter_map(csl_name).collect();
+                (!names.is_empty()).then(|| names.join(" and "))
+            });
+        source.publisher = text("publisher").or_else(|| text("container-title"));
+        source.pages = text("page");
+        source.doi = text("DOI").map(|d| normalize_doi(&d));
+        source.url = text("URL");
+        source.date = obj.get("issued").and_then(parse_csl_date);
+        source.accessed = obj.get("accessed").and_then(parse_csl_date);
+        source.notes = text("note");
+
+        Ok(Self { key, source })
+    }
+}
+
+/// Generates a citation key such as `smith2024flood` for a source.
+///
+/// The key combines the first author's surname, the publication year and
+/// the first significant word of the title. Keys are not guaranteed to be
+/// unique; [`Bibliography`] adds suffixes where needed.
+pub fn citation_key(source: &SourceRef) -> String {
+    const STOP_WORDS: [&str; 6] = ["a", "an", "the", "of", "on", "in"];
+    let ascii = |s: &str| -> String {
+        s.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase()
+    };
+
+    let surname = source
+        .author
+        .as_deref()
+        .and_then(|a| split_authors(a).next())
+        .map(|first| match first.split_once(',') {
+            Some((family, _)) => family.to_string(),
+            None => first.split_whitespace().last().unwrap_or("").to_string(),
+        })
+        .map(|s| ascii(&s))
+        .unwrap_or_default();
+    let year = source
+        .date
+        .as_ref()
+        .map(|d| d.calendar_date().0.to_string())
+        .unwrap_or_default();
+    let word = source
+        .title
+        .as_deref()
+        .and_then(|t| {
+            t.split_whitespace()
+                .map(ascii)
+                .find(|w| !w.is_empty() && !STOP_WORDS.contains(&w.as_str()))
+        })
+        .unwrap_or_default();
+
+    let key = format!("{}{}{}", surname, year, word);
+    if key.is_empty() {
+        "source".to_string()
+    } else {
+        key
+    }
+}
+
+/// Parses all entries from a BibTeX document.
+///
+/// `@comment`, `@preamble` and `@string` blocks, delimited by braces or
+/// parentheses, are skipped. String
+/// macros and `#` concatenation are not expanded.
+pub fn parse_bibtex(input: &str) -> Result<Vec<CitationEntry>> {
+    let mut parser = BibParser {
+        chars: input.chars().collect(),
+        pos: 0,
+    };
+    let mut entries = Vec::new();
+
+    while parser.skip_to('@') {
+        parser.pos += 1;
+        let entry_type = parser.ident().to_lowercase();
+        parser.skip_ws();
+        let close = match parser.next() {
+            Some('{') => '}',
+            Some('(') => ')',
+            _ => {
+                return Err(Error::ParseError(format!(
+                    "expected '{{' after @{} in BibTeX",
+                    entry_type
+                )))
+            },
+        };
+        if matches!(entry_type.as_str(), "comment" | "preamble" | "string") {
+            parser.pos -= 1;
+            let open = if close == '}' { '{' } else { '(' };
+            parser.group(open, close)?;
+            continue;
+        }
+
+        let key = parser.until(&[',', close]).trim().to_string();
+        let mut fields: HashMap<String, String> = HashMap::new();
+        loop {
+            parser.skip_ws();
+            match parser.peek() {
+                Some(',') => {
+                    parser.pos += 1;
+                    continue;
+                },
+                Some(c) if c == close => {
+                    parser.pos += 1;
+                    break;
+                },
+                None => {
+                    return Err(Error::ParseError(format!(
+                        "unterminated BibTeX entry {}",
+                        key
+                    )))
+                },
+                _ => {},
+            }
+            let name = parser.until(&['=', close]).trim().to_lowercase();
+            if parser.next() != Some('=') {
+                return Err(Error::ParseError(format!(
+                    "expected '=' in BibTeX entry {}",
+                    key
+                )));
+            }
+            parser.skip_ws();
+            let value = match parser.peek() {
+                Some('{') => parser.braced()?,
+                Some('"') => parser.quoted()?,
+                _ => parser.until(&[',', close]).trim().to_string(),
+            };
+            fields.insert(name, clean_bibtex(&value));
+        }
+
+        entries.push(CitationEntry {
+            key,
+            source: source_from_bibtex(&entry_type, &fields),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Parses CSL-JSON: either an array of items or a single item.
+pub fn parse_csl_json(input: &str) -> Result<Vec<CitationEntry>> {
+    match serde_json::from_str(input)? {
+        Value::Array(items) => items.iter().map(CitationEntry::from_csl_json).collect(),
+        item => Ok(vec![CitationEntry::from_csl_json(&item)?]),
+    }
+}
+
+/// The deduplicated bibliography of a narrative.
+///
+/// Sources are the same work when their DOIs match, or failing that their
+/// URLs and titles match, or failing that they are identical. Each work
+/// gets one unique citation key, and each event lists the keys it cites.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_narrative::core::{Event, Location, Narrative, SourceRef, Timestamp};
+/// use spatial_narrative::io::Bibliography;
+///
+/// let report = SourceRef::report("https://example.org/r1").with_title("Flood report");
+/// let mut a = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "A");
+/// a.add_source(report.clone());
+/// let mut b = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "B");
+/// b.add_source(report);
+/// let ids = (a.id.clone(), b.id.clone());
+///
+/// let narrative = Narrative::builder().event(a).event(b).build();
+/// let bibliography = Bibliography::from_narrative(&narrative);
+///
+/// assert_eq!(bibliography.len(), 1);
+/// assert_eq!(bibliography.keys_for(&ids.0), bibliography.keys_for(&ids.1));
+/// assert!(bibliography.to_bibtex().starts_with("@techreport{flood,"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Bibliography {
+    entries: Vec<CitationEntry>,
+    citations: HashMap<EventId, Vec<String>>,
+    /// Entry positions by citation key.
+    by_key: HashMap<String, usize>,
+    /// Entry positions by lowercased DOI.
+    by_doi: HashMap<String, usize>,
+    /// Entry positions by URL and title, which are equal for any two
+    /// sources `same_work` matches other than by DOI.
+    by_work: HashMap<(Option<String>, Option<String>), Vec<usize>>,
+}
+
+impl Bibliography {
+    /// Creates an empty bibliography.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the sources of every event in a narrative.
+    pub fn from_narrative(narrative: &Narrative) -> Self {
+        let mut bibliography = Self::new();
+        for event in narrative.events() {
+            for source in &event.sources {
+                let key = bibliography.add(source.clone());
+                let keys = bibliography.citations.entry(event.id.clone()).or_default();
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        bibliography
+    }
+
+    /// Adds a source, returning the key of the new or existing entry.
+    pub fn add(&mut self, source: SourceRef) -> String {
+        if let Some(pos) = self.find_work(&source) {
+            return self.entries[pos].key.clone();
+        }
+
+        let base = citation_key(&source);
+        let key = std::iter::once(base.clone())
+            .chain(('a'..='z').map(|c| format!("{}{}", base, c)))
+            .chain((2..).map(|n| format!("{}_{}", base, n)))
+            .find(|k| !self.by_key.contains_key(k))
+            .unwrap_or(base);
+
+        let pos = self.entries.len();
+        self.by_key.insert(key.clone(), pos);
+        if let Some(doi) = &source.doi {
+            self.by_doi.entry(doi.to_ascii_lowercase()).or_insert(pos);
+        }
+        self.by_work
+            .entry((source.url.clone(), source.title.clone()))
+            .or_default()
+            .push(pos);
+        self.entries.push(CitationEntry::new(key.clone(), source));
+        key
+    }
+
+    /// Returns the position of the first entry for the same work.
+    fn find_work(&self, source: &SourceRef) -> Option<usize> {
+        let by_doi = source
+            .doi
+            .as_ref()
+            .and_then(|doi| self.by_doi.get(&doi.to_ascii_lowercase()));
+        let by_work = self
+            .by_work
+            .get(&(source.url.clone(), source.title.clone()))
+            .into_iter()
+            .flatten();
+        by_doi
+            .into_iter()
+            .chain(by_work)
+            .copied()
+            .filter(|&pos| same_work(&self.entries[pos].source, source))
+            .min()
+    }
+
+    /// Returns the entries in the order they were first cited.
+    pub fn entries(&self) -> &[CitationEntry] {
+        &self.entries
+    }
+
+    /// Finds the source with a citation key.
+    pub fn get(&self, key: &str) -> Option<&SourceRef> {
+        self.by_key.get(key).map(|&pos| &self.entries[pos].source)
+    }
+
+    /// Returns the citation keys of an event's sources.
+    pub fn keys_for(&self, event: &EventId) -> &[String] {
+        self.citations.get(event).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Renders all entries as a BibTeX document.
+    pub fn to_bibtex(&self) -> String {
+        self.entries
+            .iter()
+            .map(CitationEntry::to_bibtex)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders all entries as a CSL-JSON array.
+    pub fn to_csl_json(&self) -> Value {
+        Value::Array(
+            self.entries
+                .iter()
+                .map(CitationEntry::to_csl_json)
+                .collect(),
+        )
+    }
+}
+
+fn same_work(a: &SourceRef, b: &SourceRef) -> bool {
+    match (&a.doi, &b.doi) {
+        (Some(x), Some(y)) => x.eq_ignore_ascii_case(y),
+        _ => match (&a.url, &b.url) {
+            (Some(x), Some(y)) => x == y && a.title == b.title,
+            _ => a == b,
+        },
+    }
+}
+
+fn split_authors(author: &str) -> impl Iterator<Item = &str> {
+    author
+        .split(" and ")
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+}
+
+fn bibtex_type(source_type: SourceType) -> &'static str {
+    match source_type {
+        SourceType::Article | SourceType::Social => "online",
+        SourceType::Report => "techreport",
+        SourceType::Sensor => "dataset",
+        SourceType::Academic => "article",
+        SourceType::Government => "report",
+        SourceType::Archive => "unpublished",
+        SourceType::Witness | SourceType::Other => "misc",
+    }
+}
+
+fn bibtex_source_type(entry_type: &str) -> SourceType {
+    match entry_type {
+        "online" | "electronic" | "www" => SourceType::Article,
+        "techreport" => SourceType::Report,
+        "dataset" => SourceType::Sensor,
+        "article" | "book" | "inbook" | "incollection" | "inproceedings" | "conference"
+        | "phdthesis" | "mastersthesis" | "thesis" => SourceType::Academic,
+        "report" => SourceType::Government,
+        "unpublished" | "manuscript" => SourceType::Archive,
+        _ => SourceType::Other,
+    }
+}
+
+fn csl_type(source_type: SourceType) -> &'static str {
+    match source_type {
+        SourceType::Article => "article-newspaper",
+        SourceType::Report | SourceType::Government => "report",
+        SourceType::Witness => "interview",
+        SourceType::Sensor => "dataset",
+        SourceType::Social => "post",
+        SourceType::Academic => "article-journal",
+        SourceType::Archive => "manuscript",
+        SourceType::Other => "document",
+    }
+}
+
+fn csl_source_type(csl_type: &str) -> SourceType {
+    match csl_type {
+        "article-newspaper" | "article-magazine" | "webpage" | "post-weblog" | "broadcast" => {
+            SourceType::Article
+        },
+        "report" => SourceType::Report,
+        "interview" | "personal_communication" => SourceType::Witness,
+        "dataset" => SourceType::Sensor,
+        "post" => SourceType::Social,
+        "article-journal" | "article" | "book" | "chapter" | "paper-conference" | "thesis" => {
+            SourceType::Academic
+        },
+        "legislation" | "legal_case" | "regulation" | "bill" | "treaty" => SourceType::Government,
+        "manuscript" => SourceType::Archive,
+        _ => SourceType::Other,
+    }
+}
+
+fn source_from_bibtex(entry_type: &str, fields: &HashMap<String, String>) -> SourceRef {
+    let field = |name: &str| fields.get(name).filter(|v| !v.is_empty()).cloned();
+    let source_type = field("sourcetype")
+        .and_then(|t| SourceType::from_name(&t))
+        .unwrap_or_else(|| bibtex_source_type(entry_type));
+
+    let mut source = SourceRef::new(source_type);
+    source.author = field("author").or_else(|| field("editor"));
+    source.title = field("title");
+    source.publisher = [
+        "publisher",
+        "journal",
+        "journaltitle",
+        "booktitle",
+        "institution",
+    ]
+    .into_iter()
+    .find_map(field)
+    .or_else(|| field("organization"))
+    .or_else(|| field("howpublished"));
+    source.pages = field("pages").map(|p| p.replace("--", "-"));
+    source.doi = field("doi").map(|d| normalize_doi(&d));
+    source.url = field("url");
+    source.date = field("date")
+        .and_then(|d| Timestamp::parse(&d).ok())
+        .or_else(|| bibtex_year_month(field("year")?, field("month")));
+    source.accessed = field("urldate").and_then(|d| Timestamp::parse(&d).ok());
+    source.notes = field("note");
+    source
+}
+
+fn bibtex_year_month(year: String, month: Option<String>) -> Option<Timestamp> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let year: i32 = year.trim().parse().ok()?;
+    let month = month.and_then(|m| {
+        let m = m.trim().to_lowercase();
+        m.parse::<u32>().ok().or_else(|| {
+            MONTHS
+                .iter()
+                .position(|name| m.starts_with(name))
+                .map(|i| i as u32 + 1)
+        })
+    });
+    date_from_parts(&[year, month.map_or(0, |m| m as i32)][..if month.is_some() { 2 } else { 1 }])
+}
+
+fn csl_date(timestamp: &Timestamp) -> Value {
+    let (year, month, day) = timestamp.calendar_date();
+    let parts = match timestamp.precision {
+        TemporalPrecision::Century | TemporalPrecision::Decade | TemporalPrecision::Year => {
+            json!([year])
+        },
+        TemporalPrecision::Month => json!([year, month]),
+        _ => json!([year, month, day]),
+    };
+
+    let mut date = json!({ "date-parts": [parts] });
+    let exact = matches!(
+        timestamp.precision,
+        TemporalPrecision::Year | TemporalPrecision::Month | TemporalPrecision::Day
+    ) && !timestamp.approximate
+        && timestamp.calendar.is_gregorian();
+    if !exact {
+        date["raw"] = json!(timestamp.format_with_precision());
+    }
+    date
+}
+
+fn parse_csl_date(value: &Value) -> Option<Timestamp> {
+    if let Some(raw) = value
+        .get("raw")
+        .or_else(|| value.get("literal"))
+        .and_then(Value::as_str)
+    {
+        if let Ok(timestamp) = Timestamp::parse(raw) {
+            return Some(timestamp);
+        }
+    }
+    let parts: Vec<i32> = value
+        .get("date-parts")?
+        .get(0)?
+        .as_array()?
+        .iter()
+        .filter_map(|p| match p {
+            Value::Number(n) => n.as_i64().map(|n| n as i32),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        })
+        .collect();
+    date_from_parts(&parts)
+}
+
+fn date_from_parts(parts: &[i32]) -> Option<Timestamp> {
+    let (precision, month, day) = match parts.len() {
+        0 => return None,
+        1 => (TemporalPrecision::Year, 1, 1),
+        2 => (TemporalPrecision::Month, parts[1] as u32, 1),
+        _ => (TemporalPrecision::Day, parts[1] as u32, parts[2] as u32),
+    };
+    let date = NaiveDate::from_ymd_opt(parts[0], month, day)?;
+    let datetime = Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?);
+    Some(Timestamp::with_precision(datetime, precision))
+}
+
+fn csl_name(name: &Value) -> Option<String> {
+    if let Some(literal) = name.get("literal").and_then(Value::as_str) {
+        return Some(literal.to_string());
+    }
+    let given = name.get("given").and_then(Value::as_str);
+    let family = name.get("family").and_then(Value::as_str);
+    match (given, family) {
+        (Some(g), Some(f)) => Some(format!("{} {}", g, f)),
+        (None, Some(f)) => Some(f.to_string()),
+        (Some(g), None) => Some(g.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// LaTeX commands for characters that have no backslash-symbol escape.
+const BIBTEX_COMMANDS: [(&str, char); 5] = [
+    ("textbackslash", '\\'),
+    ("textasciitilde", '~'),
+    ("textasciicircum", '^'),
+    ("textbraceleft", '{'),
+    ("textbraceright", '}'),
+];
+
+/// Escapes a field value for a braced BibTeX field.
+///
+/// LaTeX specials (`% & # $ _ ~ ^ \`) are escaped so the value typesets
+/// literally. BibTeX counts every brace, escaped or not, so matched brace
+/// pairs become `\{`/`\}` while unmatched ones are written as
+/// `\textbraceleft{}`/`\textbraceright{}` to keep the field balanced.
+/// `verbatim` fields (`doi`, `url`) are read literally by BibLaTeX and the
+/// `url` package, so only their braces are touched.
+fn escape_bibtex(value: &str, verbatim: bool) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut matched = vec![false; chars.len()];
+    let mut open = Vec::new();
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '{' => open.push(i),
+            '}' => {
+                if let Some(j) = open.pop() {
+                    matched[i] = true;
+                    matched[j] = true;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let mut out = String::with_capacity(value.len());
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '{' | '}' if matched[i] => {
+                out.push('\\');
+                out.push(c);
+            },
+            '{' => out.push_str("\\textbraceleft{}"),
+            '}' => out.push_str("\\textbraceright{}"),
+            _ if verbatim => out.push(c),
+            '%' | '&' | '#' | '$' | '_' => {
+                out.push('\\');
+                out.push(c);
+            },
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Undoes [`escape_bibtex`], drops case-protecting braces and collapses
+/// whitespace.
+fn clean_bibtex(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '\\' => {
+                if let Some(escaped) = rest.chars().next().filter(|e| "{}%&#$_".contains(*e)) {
+                    out.push(escaped);
+                    rest = &rest[1..];
+                } else if let Some((name, symbol)) = BIBTEX_COMMANDS.iter().find(|(name, _)| {
+                    rest.starts_with(name) && !rest[name.len()..].starts_with(char::is_alphabetic)
+                }) {
+                    out.push(*symbol);
+                    rest = &rest[name.len()..];
+                    rest = rest.strip_prefix("{}").unwrap_or(rest);
+                } else {
+                    out.push(c);
+                }
+            },
+            '{' | '}' => {},
+            _ => out.push(c),
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+struct BibParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl BibParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Advances to the next occurrence of `target`, returning false at the end.
+    fn skip_to(&mut self, target: char) -> bool {
+        while let Some(c) = self.peek() {
+            if c == target {
+                return true;
+            }
+            self.pos += 1;
+        }
+        false
+    }
+
+    fn ident(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Reads up to (not including) the first of `stops`.
+    fn until(&mut self, stops: &[char]) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !stops.contains(&c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Reads a `{...}` group, keeping inner braces and escapes.
+    fn braced(&mut self) -> Result<String> {
+        self.group('{', '}')
+    }
+
+    /// Reads a group delimited by `open` and `close`, keeping nested
+    /// delimiters and escapes.
+    fn group(&mut self, open: char, close: char) -> Result<String> {
+        let start = self.pos + 1;
+        let mut depth = 0;
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => self.pos += 1,
+                c if c == open => depth += 1,
+                c if c == close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(self.chars[start..self.pos - 1].iter().collect());
+                    }
+                },
+                _ => {},
+            }
+        }
+        Err(Error::ParseError(format!(
+            "unbalanced '{}{}' in BibTeX",
+            open, close
+        )))
+    }
+
+    /// Reads a `"..."` value; quotes inside braces do not end it.
+    fn quoted(&mut self) -> Result<String> {
+        self.pos += 1;
+        let start = self.pos;
+        let mut depth = 0;
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => self.pos += 1,
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                '"' if depth == 0 => {
+                    return Ok(self.chars[start..self.pos - 1].iter().collect());
+                },
+                _ => {},
+            }
+        }
+        Err(Error::ParseError(
+            "unterminated quoted value in BibTeX".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_source(source_type: SourceType) -> SourceRef {
+        SourceRef::builder()
+            .source_type(source_type)
+            .author("Smith, Jane and Li Wei")
+            .title("Flooding {in} the Delta")
+            .publisher("Delta Press")
+            .pages("12-15")
+            .doi("10.1000/delta.2024")
+            .url("https://example.org/delta")
+            .date(Timestamp::parse("2024-03-15").unwrap())
+            .accessed(Timestamp::parse("2024-04-01").unwrap())
+            .notes("Second edition")
+            .build()
+    }
+
+    #[test]
+    fn test_bibtex_roundtrip_all_types() {
+        for source_type in SourceType::ALL {
+            let entry = CitationEntry::from_source(full_source(source_type));
+            assert_eq!(entry.key, "smith2024flooding");
+
+            let parsed = parse_bibtex(&entry.to_bibtex()).unwrap();
+            assert_eq!(parsed.len(), 1);
+            assert_eq!(parsed[0], entry, "{}", entry.to_bibtex());
+        }
+    }
+
+    #[test]
+    fn test_bibtex_roundtrip_special_characters() {
+        let source = SourceRef::builder()
+            .source_type(SourceType::Report)
+            .author("O'Brien & Sons")
+            .title(r"50% of #1 fans pay $5 for a_b ~ x^2 \ {balanced} and } { stray")
+            .notes("{{nested}} and {unclosed")
+            .url("https://example.org/~user/a_b?q=50%25&x={y}")
+            .build();
+        let entry = CitationEntry::new("obrien", source);
+        let bib = entry.to_bibtex();
+
+        // Every brace BibTeX counts is balanced
+        let depth = bib.chars().try_fold(0i32, |depth, c| match c {
+            '{' => Some(depth + 1),
+            '}' if depth > 0 => Some(depth - 1),
+            '}' => None,
+            _ => Some(depth),
+        });
+        assert_eq!(depth, Some(0), "{}", bib);
+        assert!(
+            bib.contains(r"50\% of \#1 fans pay \$5 for a\_b"),
+            "{}",
+            bib
+        );
+        assert!(
+            bib.contains("url = {https://example.org/~user/a_b?q=50%25"),
+            "{}",
+            bib
+        );
+
+        let parsed = parse_bibtex(&bib).unwrap();
+        assert_eq!(parsed, vec![entry], "{}", bib);
+    }
+
+    #[test]
+    fn test_csl_json_roundtrip_all_types() {
+        for source_type in SourceType::ALL {
+            let entry = CitationEntry::from_source(full_source(source_type));
+            let json = serde_json::to_string(&entry.to_csl_json()).unwrap();
+            let parsed = parse_csl_json(&json).unwrap();
+            assert_eq!(parsed, vec![entry]);
+        }
+    }
+
+    #[test]
+    fn test_csl_json_fuzzy_dates() {
+        let source = SourceRef::new(SourceType::Archive)
+            .with_title("Parish register")
+            .with_date(Timestamp::parse("c. 1850").unwrap());
+        let item = CitationEntry::new("reg", source.clone()).to_csl_json();
+
+        assert_eq!(item["issued"]["date-parts"][0][0], 1850);
+        assert!(item["issued"]["raw"].is_string());
+        assert_eq!(CitationEntry::from_csl_json(&item).unwrap().source, source);
+    }
+
+    #[test]
+    fn test_parse_external_bibtex() {
+        let bib = r#"
+            @comment{ exported by a reference manager }
+            @comment( generated (2024) by a script )
+            @preamble( "\newcommand{\noopsort}[1]{}" )
+            @string(jnav = "Journal of Navigation")
+            @Article{doe2020,
+              author    = "Doe, John",
+              title     = {The {GPS} Revolution},
+              journal   = {Journal of Navigation},
+              year      = 2020,
+              month     = mar,
+              pages     = {1--10},
+              doi       = {https://doi.org/10.1/nav}
+            }
+        "#;
+
+        let entries = parse_bibtex(bib).unwrap();
+        assert_eq!(entries.len(), 1);
+        let source = &entries[0].source;
+        assert_eq!(entries[0].key, "doe2020");
+        assert_eq!(source.source_type, SourceType::Academic);
+        assert_eq!(source.title.as_deref(), Some("The GPS Revolution"));
+        assert_eq!(source.publisher.as_deref(), Some("Journal of Navigation"));
+        assert_eq!(source.pages.as_deref(), Some("1-10"));
+        assert_eq!(source.doi.as_deref(), Some("10.1/nav"));
+        assert_eq!(
+            source.date.as_ref().unwrap().precision,
+            TemporalPrecision::Month
+        );
+    }
+
+    #[test]
+    fn test_parse_external_csl_json() {
+        let json = r#"{
+            "id": "item1",
+            "type": "article-journal",
+            "author": [{"family": "Curie", "given": "Marie"}],
+            "container-title": "Comptes rendus",
+            "issued": {"date-parts": [["1898", 7]]}
+        }"#;
+
+        let entry = &parse_csl_json(json).unwrap()[0];
+        assert_eq!(entry.source.author.as_deref(), Some("Marie Curie"));
+        assert_eq!(entry.source.publisher.as_deref(), Some("Comptes rendus"));
+        assert_eq!(
+            entry.source.date.as_ref().unwrap().calendar_date(),
+            (1898, 7, 1)
+        );
+    }
+
+    #[test]
+    fn test_bibliography_dedup_and_keys() {
+        use crate::core::{Event, Location};
+
+        let paper = full_source(SourceType::Academic);
+        let same_paper = SourceRef::new(SourceType::Other).with_doi("doi:10.1000/DELTA.2024");
+        let other = full_source(SourceType::Report).with_doi("10.1000/other");
+
+        let mut a = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "A");
+        a.add_source(paper);
+        a.add_source(other);
+        let mut b = Event::new(Location::new(0.0, 0.0), Timestamp::now(), "B");
+        b.add_source(same_paper);
+        let (id_a, id_b) = (a.id.clone(), b.id.clone());
+
+        let narrative = Narrative::builder().event(a).event(b).build();
+        let bibliography = Bibliography::from_narrative(&narrative);
+
+        assert_eq!(bibliography.len(), 2);
+        assert_eq!(
+            bibliography.keys_for(&id_a),
+            ["smith2024flooding", "smith2024floodinga"]
+        );
+        assert_eq!(bibliography.keys_for(&id_b), ["smith2024flooding"]);
+        assert_eq!(
+            bibliography.get("smith2024floodinga").unwrap().source_type,
+            SourceType::Report
+        );
+        assert_eq!(bibliography.to_csl_json().as_array().unwrap().len(), 2);
+        assert_eq!(parse_bibtex(&bibliography.to_bibtex()).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_bibliography_add_matches_url_and_title() {
+        let mut bibliography = Bibliography::new();
+        let page = SourceRef::article("https://example.com/flood").with_title("Flood");
+        let key = bibliography.add(page.clone());
+
+        // Same URL and title is the same work, even with other details
+        assert_eq!(bibliography.add(page.clone().with_author("Smith")), key);
+        // A different title at the same URL is not
+        let retitled = SourceRef::article("https://example.com/flood").with_title("Drought");
+        assert_ne!(bibliography.add(retitled), key);
+        // Sources without a URL or DOI match only when equal
+        let note = SourceRef::new(SourceType::Witness).with_title("Flood");
+        let note_key = bibliography.add(note.clone());
+        assert_eq!(bibliography.add(note), note_key);
+        assert_eq!(bibliography.len(), 3);
+        assert_eq!(bibliography.get(&key), Some(&page));
+    }
+}
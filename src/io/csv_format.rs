@@ -2,10 +2,12 @@
 
 use super::format::Format;
 use crate::core::{
-    EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
+    check_interval, EventBuilder, IdStrategy, ImportIds, Location, MetadataValue, Narrative,
+    NarrativeBuilder, SourceRef, SourceType, Timestamp,
 };
 use crate::{Error, Result};
 use csv::StringRecord;
+use std::collections::BTreeSet;
 use std::io::{Read, Write};
 
 /// CSV format handler.
@@ -45,6 +47,9 @@ pub struct CsvOptions {
     /// Column name for timestamp (defaults to "timestamp")
     pub timestamp_column: String,
 
+    /// Column name for the end time of interval events (optional)
+    pub end_timestamp_column: Option<String>,
+
     /// Column name for elevation (optional)
     pub elevation_column: Option<String>,
 
@@ -60,11 +65,17 @@ pub struct CsvOptions {
     /// Column name for source type (optional)
     pub source_type_column: Option<String>,
 
+    /// Whether to map metadata to and from extra columns
+    pub include_metadata: bool,
+
     /// Whether to include headers in exported CSV
     pub include_headers: bool,
 
     /// CSV delimiter character
     pub delimiter: u8,
+
+    /// How to assign IDs to imported events
+    pub id_strategy: IdStrategy,
 }
 
 impl Default for CsvOptions {
@@ -73,13 +84,16 @@ impl Default for CsvOptions {
             lat_column: "lat".to_string(),
             lon_column: "lon".to_string(),
             timestamp_column: "timestamp".to_string(),
+            end_timestamp_column: Some("end_timestamp".to_string()),
             elevation_column: Some("elevation".to_string()),
             text_column: Some("text".to_string()),
             tags_column: Some("tags".to_string()),
             source_name_column: Some("source".to_string()),
             source_type_column: Some("source_type".to_string()),
+            include_metadata: true,
             include_headers: true,
             delimiter: b',',
+            id_strategy: IdStrategy::Random,
         }
     }
 }
@@ -108,6 +122,29 @@ impl CsvFormat {
         headers.iter().position(|h| h.eq_ignore_ascii_case(name))
     }
 
+    /// Names of the columns used for built-in event fields
+    fn field_columns(&self) -> Vec<&str> {
+        let mut columns = vec![
+            self.options.lat_column.as_str(),
+            self.options.lon_column.as_str(),
+            self.options.timestamp_column.as_str(),
+        ];
+        columns.extend(
+            [
+                &self.options.end_timestamp_column,
+                &self.options.elevation_column,
+                &self.options.text_column,
+                &self.options.tags_column,
+                &self.options.source_name_column,
+                &self.options.source_type_column,
+            ]
+            .into_iter()
+            .flatten()
+            .map(String::as_str),
+        );
+        columns
+    }
+
     /// Helper to get optional string value from record
     fn get_optional(&self, record: &StringRecord, index: Option<usize>) -> Option<String> {
         index.and_then(|i| record.get(i).filter(|s| !s.is_empty()).map(String::from))
@@ -151,6 +188,12 @@ impl Format for CsvFormat {
             })?;
 
         // Find optional columns
+        let end_ts_idx = self
+            .options
+            .end_timestamp_column
+            .as_ref()
+            .and_then(|col| self.find_column(&headers, col));
+
         let elev_idx = self
             .options
             .elevation_column
@@ -181,7 +224,20 @@ impl Format for CsvFormat {
             .as_ref()
             .and_then(|col| self.find_column(&headers, col));
 
+        // Any other column holds metadata
+        let field_columns = self.field_columns();
+        let metadata_idx: Vec<(usize, &str)> = if self.options.include_metadata {
+            headers
+                .iter()
+                .enumerate()
+                .filter(|(_, h)| !field_columns.iter().any(|c| h.eq_ignore_ascii_case(c)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         let mut builder = NarrativeBuilder::new();
+        let mut ids = ImportIds::new(self.options.id_strategy);
 
         // Process each record
         for (row_num, result) in csv_reader.records().enumerate() {
@@ -217,9 +273,21 @@ impl Format for CsvFormat {
             }
 
             // Build event
-            let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
+            let mut event_builder = EventBuilder::new()
+                .id_strategy(self.options.id_strategy)
+                .location(location)
+                .timestamp(timestamp.clone());
 
             // Add optional fields
+            if let Some(end_str) = self.get_optional(&record, end_ts_idx) {
+                let end = Timestamp::parse(&end_str).map_err(|_| {
+                    Error::InvalidFormat(format!("invalid end timestamp at row {}", row_num))
+                })?;
+                check_interval(&timestamp, Some(&end))
+                    .map_err(|e| Error::InvalidFormat(format!("{} at row {}", e, row_num)))?;
+                event_builder = event_builder.end_timestamp(end);
+            }
+
             if let Some(text) = self.get_optional(&record, text_idx) {
                 event_builder = event_builder.text(text);
             }
@@ -236,13 +304,7 @@ impl Format for CsvFormat {
             if let Some(source_name) = self.get_optional(&record, source_name_idx) {
                 let source_type = self
                     .get_optional(&record, source_type_idx)
-                    .and_then(|s| match s.to_lowercase().as_str() {
-                        "article" => Some(SourceType::Article),
-                        "report" => Some(SourceType::Report),
-                        "witness" => Some(SourceType::Witness),
-                        "sensor" => Some(SourceType::Sensor),
-                        _ => None,
-                    })
+                    .and_then(|s| SourceType::from_name(&s))
                     .unwrap_or(SourceType::Article);
 
                 let mut source = SourceRef::new(source_type);
@@ -250,7 +312,14 @@ impl Format for CsvFormat {
                 event_builder = event_builder.source(source);
             }
 
-            let event = event_builder.build();
+            for &(idx, key) in &metadata_idx {
+                if let Some(cell) = self.get_optional(&record, Some(idx)) {
+                    event_builder = event_builder.metadata(key, MetadataValue::from_text(&cell));
+                }
+            }
+
+            let mut event = event_builder.build();
+            event.id = ids.distinct(event.id);
             builder = builder.event(event);
         }
 
@@ -262,6 +331,22 @@ impl Format for CsvFormat {
             .delimiter(self.options.delimiter)
             .from_writer(writer);
 
+        // Metadata keys become extra columns, in sorted order
+        let metadata_keys: Vec<&str> = if self.options.include_metadata {
+            let field_columns = self.field_columns();
+            narrative
+                .events()
+                .iter()
+                .flat_map(|e| e.metadata.keys())
+                .map(String::as_str)
+                .filter(|k| !field_columns.iter().any(|c| k.eq_ignore_ascii_case(c)))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         // Write headers if enabled
         if self.options.include_headers {
             let mut headers = vec![
@@ -270,6 +355,9 @@ impl Format for CsvFormat {
                 self.options.timestamp_column.as_str(),
             ];
 
+            if let Some(ref col) = self.options.end_timestamp_column {
+                headers.push(col);
+            }
             if let Some(ref col) = self.options.elevation_column {
                 headers.push(col);
             }
@@ -285,6 +373,7 @@ impl Format for CsvFormat {
             if let Some(ref col) = self.options.source_type_column {
                 headers.push(col);
             }
+            headers.extend(&metadata_keys);
 
             csv_writer.write_record(&headers)?;
         }
@@ -295,9 +384,19 @@ impl Format for CsvFormat {
             let mut record = vec![
                 loc.lat.to_string(),
                 loc.lon.to_string(),
-                event.timestamp.to_rfc3339(),
+                event.timestamp.format_with_precision(),
             ];
 
+            if self.options.end_timestamp_column.is_some() {
+                record.push(
+                    event
+                        .end_timestamp
+                        .as_ref()
+                        .map(|t| t.format_with_precision())
+                        .unwrap_or_default(),
+                );
+            }
+
             if self.options.elevation_column.is_some() {
                 record.push(loc.elevation.map(|e| e.to_string()).unwrap_or_default());
             }
@@ -329,6 +428,15 @@ impl Format for CsvFormat {
                 record.push(type_str.to_string());
             }
 
+            for key in &metadata_keys {
+                record.push(
+                    event
+                        .get_metadata(key)
+                        .map(MetadataValue::to_text)
+                        .unwrap_or_default(),
+                );
+            }
+
             csv_writer.write_record(&record)?;
         }
 
@@ -369,6 +477,37 @@ mod tests {
         assert_eq!(narrative.events()[1].text, "Event in LA");
     }
 
+    #[test]
+    fn test_csv_content_ids() {
+        let csv_data = "lat,lon,timestamp,text\n\
+                       40.7128,-74.006,2024-01-15T14:30:00Z,Event in NYC\n\
+                       34.0522,-118.2437,2024-01-16T10:00:00Z,Event in LA";
+
+        let random = CsvFormat::new();
+        assert_ne!(
+            random.import_str(csv_data).unwrap().events()[0].id,
+            random.import_str(csv_data).unwrap().events()[0].id
+        );
+
+        let format = CsvFormat::with_options(CsvOptions {
+            id_strategy: IdStrategy::Content,
+            ..Default::default()
+        });
+        let first = format.import_str(csv_data).unwrap();
+        let second = format.import_str(csv_data).unwrap();
+        assert_eq!(first.events()[0].id, second.events()[0].id);
+        assert_eq!(first.events()[1].id, second.events()[1].id);
+        assert_ne!(first.events()[0].id, first.events()[1].id);
+
+        // Repeated rows get distinct IDs that are stable across imports
+        let repeated = format!("{csv_data}\n40.7128,-74.006,2024-01-15T14:30:00Z,Event in NYC");
+        let first = format.import_str(&repeated).unwrap();
+        let second = format.import_str(&repeated).unwrap();
+        assert_eq!(first.events()[0].id, first.events()[0].content_id());
+        assert_ne!(first.events()[0].id, first.events()[2].id);
+        assert_eq!(first.events()[2].id, second.events()[2].id);
+    }
+
     #[test]
     fn test_csv_roundtrip() {
         let event = Event::builder()
@@ -390,6 +529,105 @@ mod tests {
         assert_eq!(imported.events()[0].tags.len(), 2);
     }
 
+    #[test]
+    fn test_csv_metadata_roundtrip() {
+        let typed = Event::builder()
+            .location(Location::new(38.3, 142.4))
+            .timestamp(Timestamp::parse("2011-03-11T05:46:24Z").unwrap())
+            .metadata("magnitude", 9.1)
+            .metadata("deaths", 19_759)
+            .metadata("tsunami", true)
+            .metadata("region", "Tohoku, Japan")
+            .metadata("reported", Timestamp::parse("2011-03-11").unwrap())
+            .build();
+        let sparse = Event::builder()
+            .location(Location::new(0.0, 0.0))
+            .timestamp(Timestamp::parse("2011-03-12T00:00:00Z").unwrap())
+            .metadata("magnitude", 6.0)
+            .build();
+
+        let narrative = Narrative::builder().events([typed.clone(), sparse]).build();
+
+        let format = CsvFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with("deaths,magnitude,region,reported,tsunami"));
+
+        let imported = format.import_str(&exported).unwrap();
+        assert_eq!(imported.events()[0].metadata, typed.metadata);
+        assert_eq!(imported.events()[1].metadata.len(), 1);
+        assert_eq!(imported.events()[1].metadata_f64("magnitude"), Some(6.0));
+    }
+
+    #[test]
+    fn test_csv_interval_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-02-01T00:00:00Z").unwrap())
+            .text("Siege")
+            .build();
+        let instant = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-16T10:00:00Z").unwrap())
+            .text("Skirmish")
+            .build();
+
+        let narrative = Narrative::builder()
+            .event(event.clone())
+            .event(instant)
+            .build();
+
+        let format = CsvFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        let imported = format.import_str(&exported).unwrap();
+
+        assert_eq!(imported.events()[0].end_timestamp, event.end_timestamp);
+        assert!(imported.events()[1].end_timestamp.is_none());
+
+        // An interval that ends before it starts is rejected
+        let reversed = "lat,lon,timestamp,end_timestamp,text\n\
+                        40.7128,-74.006,2024-02-01T00:00:00Z,2024-01-15T14:30:00Z,Siege";
+        let err = format.import_str(reversed).unwrap_err();
+        assert!(err.to_string().contains("row 0"), "{err}");
+    }
+
+    #[test]
+    fn test_csv_historical_dates() {
+        let csv_data = "lat,lon,timestamp,text\n\
+                        41.8925,12.4853,-0043-03-15,Ides of March\n\
+                        48.8566,2.3522,the 1840s,Paris salons\n\
+                        51.5074,-0.1278,spring 1943,London";
+
+        let format = CsvFormat::new();
+        let narrative = format.import_str(csv_data).unwrap();
+        assert_eq!(narrative.events().len(), 3);
+
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported.contains("-0043-03-15"));
+        assert!(exported.contains("184X"));
+
+        let restored = format.import_str(&exported).unwrap();
+        for (original, restored) in narrative.events().iter().zip(restored.events()) {
+            assert_eq!(original.timestamp, restored.timestamp);
+        }
+    }
+
+    #[test]
+    fn test_csv_offset_roundtrip() {
+        let csv_data = "lat,lon,timestamp\n40.7128,-74.006,2024-01-15T09:30:00-05:00";
+
+        let format = CsvFormat::new();
+        let narrative = format.import_str(csv_data).unwrap();
+        assert_eq!(narrative.events()[0].timestamp.local_hour(), 9);
+
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported.contains("2024-01-15T09:30:00-05:00"));
+    }
+
     #[test]
     fn test_csv_missing_required_column() {
         let csv_data = "latitude,longitude\n40.7128,-74.006";
@@ -2,9 +2,11 @@
 
 use super::format::Format;
 use crate::core::{
-    EventBuilder, Location, Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
+    check_interval, EventBuilder, EventId, IdStrategy, ImportIds, Location, MetadataValue,
+    Narrative, NarrativeBuilder, SourceRef, SourceType, Timestamp,
 };
 use crate::{Error, Result};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::io::{Read, Write};
@@ -12,8 +14,10 @@ use std::io::{Read, Write};
 /// GeoJSON format handler.
 ///
 /// This format handler can import and export narratives in GeoJSON format,
-/// storing events as Features with Point geometries. Temporal and metadata
-/// information is stored in feature properties.
+/// storing events as Features. Point events become Point geometries, and
+/// events with an extended geometry are written as LineString, Polygon or
+/// Multi* geometries. Temporal and metadata information is stored in
+/// feature properties.
 ///
 /// # Example
 ///
@@ -62,11 +66,24 @@ pub struct GeoJsonOptions {
     /// Whether to include source references in exported GeoJSON
     pub include_sources: bool,
 
+    /// Whether to map metadata to and from extra feature properties
+    pub include_metadata: bool,
+
     /// Property name for timestamp field
     pub timestamp_property: String,
 
+    /// Property name for the end time of interval events
+    pub end_timestamp_property: String,
+
     /// Property name for text/description field
     pub text_property: String,
+
+    /// How to assign IDs to imported features without a UUID `id`
+    pub id_strategy: IdStrategy,
+
+    /// Whether import skips features with unsupported or malformed
+    /// geometry instead of failing on the first one
+    pub skip_invalid_geometry: bool,
 }
 
 impl Default for GeoJsonOptions {
@@ -75,8 +92,12 @@ impl Default for GeoJsonOptions {
             include_ids: true,
             include_tags: true,
             include_sources: true,
+            include_metadata: true,
             timestamp_property: "timestamp".to_string(),
+            end_timestamp_property: "end_timestamp".to_string(),
             text_property: "text".to_string(),
+            id_strategy: IdStrategy::Random,
+            skip_invalid_geometry: false,
         }
     }
 }
@@ -91,8 +112,20 @@ impl GeoJsonFormat {
     pub fn with_options(options: GeoJsonOptions) -> Self {
         Self { options }
     }
+
+    /// Returns true if a feature property is used for a built-in event field.
+    fn is_reserved_property(&self, name: &str) -> bool {
+        name == self.options.timestamp_property
+            || name == self.options.end_timestamp_property
+            || name == self.options.text_property
+            || name == "tags"
+            || name == "source"
+    }
 }
 
+/// Narrative-level properties used for built-in fields.
+const RESERVED_COLLECTION_PROPERTIES: [&str; 2] = ["title", "description"];
+
 /// Internal structure for GeoJSON FeatureCollection
 #[derive(Debug, Serialize, Deserialize)]
 struct FeatureCollection {
@@ -115,11 +148,163 @@ struct Feature {
 }
 
 /// Internal structure for GeoJSON Geometry
-#[derive(Debug, Serialize, Deserialize)]
-struct Geometry {
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(description = "A GeoJSON geometry.")]
+pub(super) struct Geometry {
+    /// GeoJSON geometry type, e.g. "LineString" or "Polygon"
     #[serde(rename = "type")]
     type_: String,
-    coordinates: Vec<f64>,
+    /// GeoJSON coordinates for the geometry type; absent for a
+    /// GeometryCollection
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    coordinates: Value,
+    /// Member geometries of a GeometryCollection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    geometries: Option<Vec<Geometry>>,
+}
+
+impl Geometry {
+    /// Convert a geo-types geometry to its GeoJSON representation.
+    pub(super) fn from_geo(geometry: &geo_types::Geometry<f64>) -> Self {
+        use geo_types::Geometry as G;
+
+        let (type_, coordinates) = match geometry {
+            G::Point(p) => ("Point", coord_to_value(p.0)),
+            G::MultiPoint(mp) => (
+                "MultiPoint",
+                Value::Array(mp.iter().map(|p| coord_to_value(p.0)).collect()),
+            ),
+            G::Line(line) => (
+                "LineString",
+                Value::Array(vec![coord_to_value(line.start), coord_to_value(line.end)]),
+            ),
+            G::LineString(ls) => ("LineString", line_string_to_value(ls)),
+            G::MultiLineString(mls) => (
+                "MultiLineString",
+                Value::Array(mls.iter().map(line_string_to_value).collect()),
+            ),
+            G::Polygon(poly) => ("Polygon", polygon_to_value(poly)),
+            G::MultiPolygon(mp) => (
+                "MultiPolygon",
+                Value::Array(mp.iter().map(polygon_to_value).collect()),
+            ),
+            G::Rect(rect) => ("Polygon", polygon_to_value(&rect.to_polygon())),
+            G::Triangle(tri) => ("Polygon", polygon_to_value(&tri.to_polygon())),
+            G::GeometryCollection(gc) => {
+                return Self {
+                    type_: "GeometryCollection".to_string(),
+                    coordinates: Value::Null,
+                    geometries: Some(gc.iter().map(Self::from_geo).collect()),
+                };
+            },
+        };
+
+        Self {
+            type_: type_.to_string(),
+            coordinates,
+            geometries: None,
+        }
+    }
+
+    /// Convert to a geo-types geometry.
+    ///
+    /// Returns an error if the type is unsupported or coordinates are
+    /// malformed.
+    ///
+    /// Polygon rings must be closed and have at least four positions, as
+    /// RFC 7946 requires.
+    pub(super) fn to_geo(&self) -> Result<geo_types::Geometry<f64>> {
+        self.convert()
+            .ok_or_else(|| Error::InvalidFormat(format!("invalid {} geometry", self.type_)))
+    }
+
+    fn convert(&self) -> Option<geo_types::Geometry<f64>> {
+        let coords = &self.coordinates;
+        let geometry = match self.type_.as_str() {
+            "Point" => geo_types::Point(value_to_coord(coords)?).into(),
+            "MultiPoint" => geo_types::MultiPoint(
+                coords
+                    .as_array()?
+                    .iter()
+                    .map(|c| value_to_coord(c).map(geo_types::Point))
+                    .collect::<Option<_>>()?,
+            )
+            .into(),
+            "LineString" => value_to_line_string(coords)?.into(),
+            "MultiLineString" => geo_types::MultiLineString(
+                coords
+                    .as_array()?
+                    .iter()
+                    .map(value_to_line_string)
+                    .collect::<Option<_>>()?,
+            )
+            .into(),
+            "Polygon" => value_to_polygon(coords)?.into(),
+            "MultiPolygon" => geo_types::MultiPolygon(
+                coords
+                    .as_array()?
+                    .iter()
+                    .map(value_to_polygon)
+                    .collect::<Option<_>>()?,
+            )
+            .into(),
+            "GeometryCollection" => {
+                geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection(
+                    self.geometries
+                        .as_ref()?
+                        .iter()
+                        .map(Self::convert)
+                        .collect::<Option<_>>()?,
+                ))
+            },
+            _ => return None,
+        };
+        Some(geometry)
+    }
+}
+
+fn coord_to_value(coord: geo_types::Coord<f64>) -> Value {
+    serde_json::json!([coord.x, coord.y])
+}
+
+fn line_string_to_value(ls: &geo_types::LineString<f64>) -> Value {
+    Value::Array(ls.coords().map(|c| coord_to_value(*c)).collect())
+}
+
+fn polygon_to_value(poly: &geo_types::Polygon<f64>) -> Value {
+    let rings = std::iter::once(poly.exterior())
+        .chain(poly.interiors())
+        .map(line_string_to_value)
+        .collect();
+    Value::Array(rings)
+}
+
+fn value_to_coord(value: &Value) -> Option<geo_types::Coord<f64>> {
+    let arr = value.as_array()?;
+    if arr.len() < 2 {
+        return None;
+    }
+    Some(geo_types::coord! { x: arr[0].as_f64()?, y: arr[1].as_f64()? })
+}
+
+fn value_to_line_string(value: &Value) -> Option<geo_types::LineString<f64>> {
+    value
+        .as_array()?
+        .iter()
+        .map(value_to_coord)
+        .collect::<Option<Vec<_>>>()
+        .map(geo_types::LineString::new)
+}
+
+fn value_to_polygon(value: &Value) -> Option<geo_types::Polygon<f64>> {
+    let mut rings = value
+        .as_array()?
+        .iter()
+        .map(|ring| value_to_line_string(ring).filter(|ls| ls.0.len() >= 4 && ls.is_closed()))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter();
+    let exterior = rings.next()?;
+    Some(geo_types::Polygon::new(exterior, rings.collect()))
 }
 
 impl Format for GeoJsonFormat {
@@ -142,24 +327,61 @@ impl Format for GeoJsonFormat {
             if let Some(desc) = props.get("description").and_then(|v| v.as_str()) {
                 builder = builder.description(desc);
             }
+            if self.options.include_metadata {
+                for (key, value) in props {
+                    if !RESERVED_COLLECTION_PROPERTIES.contains(&key.as_str()) {
+                        builder = builder.metadata(key, MetadataValue::from_json(value));
+                    }
+                }
+            }
         }
 
         // Convert each feature to an event
-        for feature in fc.features {
-            if feature.geometry.type_ != "Point" {
-                continue; // Skip non-point geometries
+        let mut ids = ImportIds::new(self.options.id_strategy);
+        for (index, feature) in fc.features.into_iter().enumerate() {
+            let mut event_builder = EventBuilder::new().id_strategy(self.options.id_strategy);
+            let explicit_id = feature
+                .id
+                .as_ref()
+                .and_then(Value::as_str)
+                .and_then(|id| EventId::parse(id).ok());
+            let has_id = explicit_id.is_some();
+            if let Some(id) = explicit_id {
+                event_builder = event_builder.id(id);
             }
 
-            let coords = &feature.geometry.coordinates;
-            if coords.len() < 2 {
-                continue; // Invalid coordinates
-            }
-
-            let lon = coords[0];
-            let lat = coords[1];
-            let mut location = Location::new(lat, lon);
-            if let Some(elev) = coords.get(2).copied() {
-                location.elevation = Some(elev);
+            let invalid_geometry = || {
+                Error::InvalidFormat(format!(
+                    "invalid {} geometry in feature {}",
+                    feature.geometry.type_, index
+                ))
+            };
+            if feature.geometry.type_ == "Point" {
+                let coords = feature
+                    .geometry
+                    .coordinates
+                    .as_array()
+                    .and_then(|arr| arr.iter().map(Value::as_f64).collect::<Option<Vec<_>>>())
+                    .filter(|coords| coords.len() >= 2);
+                let coords = match coords {
+                    Some(coords) => coords,
+                    None if self.options.skip_invalid_geometry => continue,
+                    None => return Err(invalid_geometry()),
+                };
+
+                let lon = coords[0];
+                let lat = coords[1];
+                let mut location = Location::new(lat, lon);
+                if let Some(elev) = coords.get(2).copied() {
+                    location.elevation = Some(elev);
+                }
+                event_builder = event_builder.location(location);
+            } else {
+                match feature.geometry.to_geo() {
+                    Ok(geometry) => event_builder = event_builder.geometry(geometry),
+                    Err(_) if self.options.skip_invalid_geometry => continue,
+                    Err(_) => return Err(invalid_geometry()),
+                }
             }
 
             let props = &feature.properties;
@@ -176,7 +398,18 @@ impl Format for GeoJsonFormat {
             };
 
             // Build the event
-            let mut event_builder = EventBuilder::new().location(location).timestamp(timestamp);
+            event_builder = event_builder.timestamp(timestamp.clone());
+
+            // Extract end time for interval events
+            if let Some(end_str) = props
+                .get(&self.options.end_timestamp_property)
+                .and_then(|v| v.as_str())
+            {
+                let end = Timestamp::parse(end_str)
+                    .map_err(|e| Error::InvalidFormat(format!("invalid end timestamp: {}", e)))?;
+                check_interval(&timestamp, Some(&end))?;
+                event_builder = event_builder.end_timestamp(end);
+            }
 
             // Extract text/description
             if let Some(text) = props
@@ -200,13 +433,7 @@ impl Format for GeoJsonFormat {
                 let source_type = source_obj
                     .get("type")
                     .and_then(|v| v.as_str())
-                    .and_then(|s| match s.to_lowercase().as_str() {
-                        "article" => Some(SourceType::Article),
-                        "report" => Some(SourceType::Report),
-                        "witness" => Some(SourceType::Witness),
-                        "sensor" => Some(SourceType::Sensor),
-                        _ => None,
-                    })
+                    .and_then(SourceType::from_name)
                     .unwrap_or(SourceType::Article);
 
                 let mut source = SourceRef::new(source_type);
@@ -219,7 +446,20 @@ impl Format for GeoJsonFormat {
                 event_builder = event_builder.source(source);
             }
 
-            let event = event_builder.build();
+            // Remaining properties become typed metadata
+            if self.options.include_metadata {
+                for (key, value) in props {
+                    if !self.is_reserved_property(key) {
+                        event_builder = event_builder
+                            .metadata(key.clone(), MetadataValue::from_json(value.clone()));
+                    }
+                }
+            }
+
+            let mut event = event_builder.build();
+            if !has_id {
+                event.id = ids.distinct(event.id);
+            }
             builder = builder.event(event);
         }
 
@@ -230,16 +470,22 @@ impl Format for GeoJsonFormat {
         let mut features = Vec::new();
 
         for event in narrative.events() {
-            let loc = &event.location;
-            let coords = if let Some(elev) = loc.elevation {
-                vec![loc.lon, loc.lat, elev]
-            } else {
-                vec![loc.lon, loc.lat]
-            };
-
-            let geometry = Geometry {
-                type_: "Point".to_string(),
-                coordinates: coords,
+            let geometry = match event.geometry.as_ref().map(Geometry::from_geo) {
+                Some(geometry) => geometry,
+                None => {
+                    let loc = &event.location;
+                    let coords = if let Some(elev) = loc.elevation {
+                        vec![loc.lon, loc.lat, elev]
+                    } else {
+                        vec![loc.lon, loc.lat]
+                    };
+
+                    Geometry {
+                        type_: "Point".to_string(),
+                        coordinates: coords.into(),
+                        geometries: None,
+                    }
+                },
             };
 
             let mut properties = Map::new();
@@ -247,9 +493,17 @@ impl Format for GeoJsonFormat {
             // Add timestamp
             properties.insert(
                 self.options.timestamp_property.clone(),
-                Value::String(event.timestamp.to_rfc3339()),
+                Value::String(event.timestamp.format_with_precision()),
             );
 
+            // Add end time for interval events
+            if let Some(end) = &event.end_timestamp {
+                properties.insert(
+                    self.options.end_timestamp_property.clone(),
+                    Value::String(end.format_with_precision()),
+                );
+            }
+
             // Add text if present
             properties.insert(
                 self.options.text_property.clone(),
@@ -283,6 +537,15 @@ impl Format for GeoJsonFormat {
                 properties.insert("source".to_string(), Value::Object(source_obj));
             }
 
+            // Add metadata as extra properties
+            if self.options.include_metadata {
+                for (key, value) in &event.metadata {
+                    if !self.is_reserved_property(key) {
+                        properties.insert(key.clone(), value.to_json());
+                    }
+                }
+            }
+
             let feature = Feature {
                 type_: "Feature".to_string(),
                 geometry,
@@ -303,6 +566,13 @@ impl Format for GeoJsonFormat {
         if let Some(desc) = &narrative.metadata.description {
             fc_properties.insert("description".to_string(), Value::String(desc.clone()));
         }
+        if self.options.include_metadata {
+            for (key, value) in &narrative.metadata.extra {
+                if !RESERVED_COLLECTION_PROPERTIES.contains(&key.as_str()) {
+                    fc_properties.insert(key.clone(), value.to_json());
+                }
+            }
+        }
 
         let fc = FeatureCollection {
             type_: "FeatureCollection".to_string(),
@@ -323,6 +593,7 @@ impl Format for GeoJsonFormat {
 mod tests {
     use super::*;
     use crate::core::Event;
+    use geo_types::line_string;
 
     #[test]
     fn test_geojson_import_basic() {
@@ -353,6 +624,36 @@ mod tests {
         assert_eq!(event.text.as_str(), "Event at NYC");
     }
 
+    #[test]
+    fn test_geojson_metadata_roundtrip() {
+        let event = EventBuilder::new()
+            .location(Location::new(38.3, 142.4))
+            .timestamp(Timestamp::parse("2011-03-11T05:46:24Z").unwrap())
+            .metadata("magnitude", 9.1)
+            .metadata("deaths", 19_759)
+            .metadata("tsunami", true)
+            .metadata("epicenter", Location::new(38.297, 142.373))
+            .metadata("agencies", vec!["JMA".into(), "USGS".into()])
+            .build();
+        let narrative = NarrativeBuilder::new()
+            .title("Tohoku")
+            .metadata("catalog", "ComCat")
+            .event(event.clone())
+            .build();
+
+        let format = GeoJsonFormat::new();
+        let geojson = format.export_str(&narrative).unwrap();
+        let value: Value = serde_json::from_str(&geojson).unwrap();
+        assert_eq!(value["features"][0]["properties"]["magnitude"], 9.1);
+
+        let restored = format.import_str(&geojson).unwrap();
+        assert_eq!(restored.events()[0].metadata, event.metadata);
+        assert_eq!(
+            restored.metadata.extra.get("catalog"),
+            Some(&MetadataValue::from("ComCat"))
+        );
+    }
+
     #[test]
     fn test_geojson_roundtrip() {
         let event = Event::builder()
@@ -373,6 +674,127 @@ mod tests {
 
         assert_eq!(imported.events().len(), 1);
         assert_eq!(imported.title, "Test Narrative");
+        assert_eq!(imported.events()[0].id, narrative.events()[0].id);
+    }
+
+    #[test]
+    fn test_geojson_invalid_geometry() {
+        // The ring is not closed and has too few positions
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": {"type": "Point", "coordinates": [2.35, 48.85]},
+                    "properties": {"timestamp": "2024-01-01T00:00:00Z", "text": "Paris"}
+                },
+                {
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]]
+                    },
+                    "properties": {"timestamp": "2024-01-02T00:00:00Z", "text": "Broken"}
+                }
+            ]
+        }"#;
+
+        let err = GeoJsonFormat::new().import_str(geojson).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid Polygon geometry in feature 1"),
+            "{err}"
+        );
+
+        let format = GeoJsonFormat::with_options(GeoJsonOptions {
+            skip_invalid_geometry: true,
+            ..Default::default()
+        });
+        let narrative = format.import_str(geojson).unwrap();
+        assert_eq!(narrative.events().len(), 1);
+        assert_eq!(narrative.events()[0].text, "Paris");
+
+        let point = geojson.replace("[2.35, 48.85]", "[2.35]");
+        let err = GeoJsonFormat::new().import_str(&point).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid Point geometry in feature 0"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_geojson_content_ids() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Test event")
+            .build();
+        let narrative = Narrative::builder().event(event).build();
+
+        let format = GeoJsonFormat::with_options(GeoJsonOptions {
+            include_ids: false,
+            id_strategy: IdStrategy::Content,
+            ..Default::default()
+        });
+        let exported = format.export_str(&narrative).unwrap();
+        let first = format.import_str(&exported).unwrap();
+        let second = format.import_str(&exported).unwrap();
+
+        assert_eq!(first.events()[0].id, second.events()[0].id);
+        assert_eq!(first.events()[0].id, narrative.events()[0].content_id());
+
+        // A repeated feature gets its own ID
+        let mut twice = narrative.clone();
+        twice.add_event(narrative.events()[0].clone());
+        let imported = format
+            .import_str(&format.export_str(&twice).unwrap())
+            .unwrap();
+        assert_eq!(imported.events()[0].id, first.events()[0].id);
+        assert_ne!(imported.events()[1].id, imported.events()[0].id);
+    }
+
+    #[test]
+    fn test_geojson_interval_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-01-17T14:30:00Z").unwrap())
+            .text("Strike")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = GeoJsonFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported.contains("end_timestamp"));
+
+        let imported = format.import_str(&exported).unwrap();
+        assert_eq!(imported.events()[0].end_timestamp, event.end_timestamp);
+
+        // An interval that ends before it starts is rejected
+        let reversed = exported.replace("2024-01-17T14:30:00", "2024-01-13T14:30:00");
+        assert!(format.import_str(&reversed).is_err());
+    }
+
+    #[test]
+    fn test_geojson_time_zone_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(-33.8688, 151.2093))
+            .timestamp(
+                Timestamp::parse("2024-01-15T14:30:00Z")
+                    .unwrap()
+                    .with_time_zone(chrono_tz::Australia::Sydney),
+            )
+            .text("Harbour")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = GeoJsonFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        let imported = format.import_str(&exported).unwrap();
+        assert_eq!(imported.events()[0].timestamp, event.timestamp);
     }
 
     #[test]
@@ -399,4 +821,99 @@ mod tests {
         let event = &narrative.events()[0];
         assert_eq!(event.location.elevation, Some(100.5));
     }
+
+    #[test]
+    fn test_geojson_import_polygon() {
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [[[0.0, 0.0], [4.0, 0.0], [4.0, 2.0], [0.0, 2.0], [0.0, 0.0]]]
+                    },
+                    "properties": {
+                        "text": "Flood extent",
+                        "timestamp": "2024-01-15T14:30:00Z"
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "MultiLineString",
+                        "coordinates": [[[0.0, 0.0], [1.0, 1.0]], [[2.0, 2.0], [3.0, 3.0]]]
+                    },
+                    "properties": {
+                        "timestamp": "2024-01-15T15:00:00Z"
+                    }
+                }
+            ]
+        }"#;
+
+        let format = GeoJsonFormat::new();
+        let narrative = format.import_str(geojson).unwrap();
+
+        assert_eq!(narrative.events().len(), 2);
+        let flood = &narrative.events()[0];
+        assert!(matches!(
+            flood.geometry,
+            Some(geo_types::Geometry::Polygon(_))
+        ));
+        assert!((flood.location.lat - 1.0).abs() < 1e-9);
+        assert!((flood.location.lon - 2.0).abs() < 1e-9);
+        assert!(matches!(
+            narrative.events()[1].geometry,
+            Some(geo_types::Geometry::MultiLineString(_))
+        ));
+    }
+
+    #[test]
+    fn test_geojson_geometry_roundtrip() {
+        let route = line_string![
+            (x: -74.006, y: 40.7128),
+            (x: -73.9857, y: 40.7484),
+        ];
+        let event = Event::builder()
+            .geometry(route.into())
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("March")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = GeoJsonFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported.contains("LineString"));
+
+        let imported = format.import_str(&exported).unwrap();
+        assert_eq!(imported.events()[0].geometry, event.geometry);
+    }
+
+    #[test]
+    fn test_geojson_geometry_collection_roundtrip() {
+        let route = line_string![
+            (x: -74.006, y: 40.7128),
+            (x: -73.9857, y: 40.7484),
+        ];
+        let collection = geo_types::GeometryCollection(vec![
+            geo_types::Point::new(-74.006, 40.7128).into(),
+            route.into(),
+        ]);
+        let event = Event::builder()
+            .geometry(geo_types::Geometry::GeometryCollection(collection))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("March and rally")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = GeoJsonFormat::new();
+        let exported = format.export_str(&narrative).unwrap();
+        assert!(exported.contains("GeometryCollection"));
+        assert!(exported.contains("geometries"));
+
+        let imported = format.import_str(&exported).unwrap();
+        assert_eq!(imported.events()[0].geometry, event.geometry);
+    }
 }
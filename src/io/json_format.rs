@@ -4,11 +4,18 @@
 //! with all metadata preserved.
 
 use super::format::Format;
+use super::geojson::Geometry;
+use super::schema::{self, SchemaViolation};
 use crate::core::{
-    Event, Location, Narrative, NarrativeMetadata, SourceRef, SourceType, Timestamp,
+    check_interval, ActorRef, ActorRegistry, Event, EventId, IdStrategy, ImportIds, Location,
+    MetadataValue, Narrative, NarrativeMetadata, PlaceHierarchy, Section, SourceRef, SourceType,
+    Timestamp,
 };
 use crate::{Error, Result};
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{Read, Write};
 
 /// Custom JSON format handler.
@@ -44,6 +51,12 @@ use std::io::{Read, Write};
 pub struct JsonFormat {
     /// Whether to pretty-print the JSON output
     pub pretty: bool,
+
+    /// How to assign IDs to imported events that have none
+    pub id_strategy: IdStrategy,
+
+    /// Whether to validate documents against [`JsonFormat::schema`] before import
+    pub validate: bool,
 }
 
 impl JsonFormat {
@@ -54,14 +67,93 @@ impl JsonFormat {
 
     /// Create a new JSON format handler with pretty printing enabled.
     pub fn pretty() -> Self {
-        Self { pretty: true }
+        Self {
+            pretty: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets how imported events without an ID get one.
+    pub fn with_id_strategy(mut self, strategy: IdStrategy) -> Self {
+        self.id_strategy = strategy;
+        self
+    }
+
+    /// Sets whether imports are validated against the schema first.
+    ///
+    /// With validation on, a document that does not match the schema
+    /// fails with [`Error::SchemaValidation`], listing every violation
+    /// with its JSON pointer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use spatial_narrative::io::{Format, JsonFormat};
+    /// use spatial_narrative::Error;
+    ///
+    /// let json = r#"{
+    ///     "version": "1.0",
+    ///     "metadata": {},
+    ///     "events": [{
+    ///         "location": {"lat": 95.0, "lon": -74.0},
+    ///         "timestamp": "2024-01-15T14:30:00Z",
+    ///         "text": "Out of range",
+    ///         "tags": [],
+    ///         "metadata": {}
+    ///     }]
+    /// }"#;
+    ///
+    /// let format = JsonFormat::new().with_validation(true);
+    /// match format.import_str(json) {
+    ///     Err(Error::SchemaValidation(violations)) => {
+    ///         assert_eq!(violations[0].pointer, "/events/0/location/lat");
+    ///     },
+    ///     _ => panic!("expected a schema violation"),
+    /// }
+    /// ```
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Returns the JSON Schema (draft 7) describing documents in this format.
+    ///
+    /// The schema is generated from the same structures used to read and
+    /// write the format, and can be published or fed to code generators
+    /// such as `json-schema-to-typescript`.
+    pub fn schema() -> serde_json::Value {
+        SCHEMA.clone()
+    }
+
+    /// Validates a parsed document against [`JsonFormat::schema`].
+    ///
+    /// Returns every violation found; an empty list means the document is valid.
+    pub fn validate(document: &serde_json::Value) -> Vec<SchemaViolation> {
+        schema::validate(&SCHEMA, document)
     }
 }
 
-/// JSON representation of a narrative with version info.
-#[derive(Debug, Serialize, Deserialize)]
+/// Identifier of the published schema: the raw URL of the schema file.
+const SCHEMA_ID: &str = "https://raw.githubusercontent.com/jwilliamsresearch/spatial-narrative/main/schema/narrative.schema.json";
+
+/// The schema, generated once and shared by every validation.
+static SCHEMA: Lazy<serde_json::Value> = Lazy::new(|| {
+    let settings = schemars::gen::SchemaSettings::draft07().with(|s| {
+        s.option_add_null_type = true;
+    });
+    let mut root = settings
+        .into_generator()
+        .into_root_schema_for::<NarrativeJson>();
+    root.schema.metadata().id = Some(SCHEMA_ID.to_string());
+    serde_json::to_value(root).unwrap_or_default()
+});
+
+/// A narrative in the spatial-narrative native JSON format.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "Narrative")]
 struct NarrativeJson {
     /// Format version for future compatibility
+    #[schemars(regex(pattern = r"^1\."))]
     version: String,
 
     /// Narrative metadata
@@ -69,32 +161,67 @@ struct NarrativeJson {
 
     /// Events in the narrative
     events: Vec<EventJson>,
+
+    /// Actors referenced by events
+    #[serde(default, skip_serializing_if = "ActorRegistry::is_empty")]
+    actors: ActorRegistry,
+
+    /// Chapters and sections
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sections: Vec<Section>,
+
+    /// Telling order of event IDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    discourse: Vec<EventId>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Descriptive metadata for a narrative.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "NarrativeMetadata")]
 struct NarrativeMetadataJson {
     created: Option<String>,
     modified: Option<String>,
     author: Option<String>,
     description: Option<String>,
     category: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    extra: HashMap<String, MetadataValue>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// An event: something that happened at a place and time.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "Event")]
 struct EventJson {
+    /// Event UUID; generated on import when empty or missing
+    #[serde(default)]
     id: String,
     location: LocationJson,
+    /// Extent of the event, for events that are not a single point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    geometry: Option<Geometry>,
+    /// When the event happened; RFC 3339, partial or fuzzy dates
     timestamp: String,
+    /// End of an interval event, in the same form as `timestamp`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end_timestamp: Option<String>,
     text: String,
     tags: Vec<String>,
     #[serde(default)]
     sources: Vec<SourceRefJson>,
+    /// Arbitrary key-value metadata
+    #[schemars(with = "HashMap<String, MetadataValue>")]
     metadata: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    actors: Vec<ActorRef>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A geographic location in WGS84 degrees.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "Location")]
 struct LocationJson {
+    #[schemars(range(min = -90, max = 90))]
     lat: f64,
+    #[schemars(range(min = -180, max = 180))]
     lon: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
     elevation: Option<f64>,
@@ -102,9 +229,13 @@ struct LocationJson {
     uncertainty_meters: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hierarchy: Option<PlaceHierarchy>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A reference to a source supporting an event.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "SourceRef")]
 struct SourceRefJson {
     source_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -115,11 +246,34 @@ struct SourceRefJson {
     url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    publisher: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pages: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    doi: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    accessed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    /// Credibility score from 0.0 to 1.0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0, max = 1))]
+    credibility: Option<f64>,
 }
 
 impl Format for JsonFormat {
     fn import<R: Read>(&self, reader: R) -> Result<Narrative> {
-        let json: NarrativeJson = serde_json::from_reader(reader)?;
+        let json: NarrativeJson = if self.validate {
+            let document: serde_json::Value = serde_json::from_reader(reader)?;
+            let violations = Self::validate(&document);
+            if !violations.is_empty() {
+                return Err(Error::SchemaValidation(violations));
+            }
+            serde_json::from_value(document)?
+        } else {
+            serde_json::from_reader(reader)?
+        };
 
         // Check version compatibility (for now, we only support 1.0)
         if !json.version.starts_with("1.") {
@@ -146,10 +300,11 @@ impl Format for JsonFormat {
             author: json.metadata.author,
             description: json.metadata.description,
             category: json.metadata.category,
-            extra: std::collections::HashMap::new(),
+            extra: json.metadata.extra,
         };
 
         let mut events = Vec::new();
+        let mut ids = ImportIds::new(self.id_strategy);
 
         for event_json in json.events {
             let location = Location {
@@ -158,24 +313,26 @@ impl Format for JsonFormat {
                 elevation: event_json.location.elevation,
                 uncertainty_meters: event_json.location.uncertainty_meters,
                 name: event_json.location.name,
+                hierarchy: event_json.location.hierarchy,
             };
 
             // Validate location
             location.validate()?;
 
             let timestamp = Timestamp::parse(&event_json.timestamp)?;
+            let end_timestamp = event_json
+                .end_timestamp
+                .as_deref()
+                .map(Timestamp::parse)
+                .transpose()?;
+            check_interval(&timestamp, end_timestamp.as_ref())?;
 
             let sources: Vec<SourceRef> = event_json
                 .sources
                 .into_iter()
                 .map(|s| {
-                    let source_type = match s.source_type.as_str() {
-                        "article" => SourceType::Article,
-                        "report" => SourceType::Report,
-                        "witness" => SourceType::Witness,
-                        "sensor" => SourceType::Sensor,
-                        _ => SourceType::Other,
-                    };
+                    let source_type =
+                        SourceType::from_name(&s.source_type).unwrap_or(SourceType::Other);
 
                     SourceRef {
                         source_type,
@@ -183,19 +340,39 @@ impl Format for JsonFormat {
                         title: s.title,
                         author: s.author,
                         date: s.date.and_then(|d| Timestamp::parse(&d).ok()),
-                        notes: None,
+                        publisher: s.publisher,
+                        pages: s.pages,
+                        doi: s.doi,
+                        accessed: s.accessed.and_then(|d| Timestamp::parse(&d).ok()),
+                        notes: s.notes,
+                        credibility: s.credibility,
                     }
                 })
                 .collect();
 
-            let event = Event {
-                id: crate::core::EventId::parse(&event_json.id)?,
+            let id = match event_json.id.trim() {
+                "" => None,
+                id => Some(crate::core::EventId::parse(id)?),
+            };
+            let mut event = Event {
+                id: crate::core::EventId::default(),
                 location,
+                geometry: event_json
+                    .geometry
+                    .as_ref()
+                    .map(Geometry::to_geo)
+                    .transpose()?,
                 timestamp,
+                end_timestamp,
                 text: event_json.text,
                 tags: event_json.tags,
                 sources,
                 metadata: serde_json::from_value(event_json.metadata).unwrap_or_default(),
+                actors: event_json.actors,
+            };
+            event.id = match id {
+                Some(id) => id,
+                None => ids.distinct(self.id_strategy.id_for(&event)),
             };
             events.push(event);
         }
@@ -206,6 +383,9 @@ impl Format for JsonFormat {
             events,
             metadata,
             tags: Vec::new(),
+            actors: json.actors,
+            sections: json.sections,
+            discourse: json.discourse,
         })
     }
 
@@ -216,6 +396,7 @@ impl Format for JsonFormat {
             author: narrative.metadata.author.clone(),
             description: narrative.metadata.description.clone(),
             category: narrative.metadata.category.clone(),
+            extra: narrative.metadata.extra.clone(),
         };
 
         let events: Vec<EventJson> = narrative
@@ -228,12 +409,18 @@ impl Format for JsonFormat {
                     elevation: event.location.elevation,
                     uncertainty_meters: event.location.uncertainty_meters,
                     name: event.location.name.clone(),
+                    hierarchy: event.location.hierarchy.clone(),
                 };
 
                 EventJson {
                     id: event.id.to_string(),
                     location,
-                    timestamp: event.timestamp.to_rfc3339(),
+                    geometry: event.geometry.as_ref().map(Geometry::from_geo),
+                    timestamp: event.timestamp.format_with_precision(),
+                    end_timestamp: event
+                        .end_timestamp
+                        .as_ref()
+                        .map(|t| t.format_with_precision()),
                     text: event.text.clone(),
                     tags: event.tags.clone(),
                     sources: event
@@ -244,11 +431,18 @@ impl Format for JsonFormat {
                             title: s.title.clone(),
                             author: s.author.clone(),
                             url: s.url.clone(),
-                            date: s.date.as_ref().map(|ts| ts.to_rfc3339()),
+                            date: s.date.as_ref().map(|ts| ts.format_with_precision()),
+                            publisher: s.publisher.clone(),
+                            pages: s.pages.clone(),
+                            doi: s.doi.clone(),
+                            accessed: s.accessed.as_ref().map(|ts| ts.format_with_precision()),
+                            notes: s.notes.clone(),
+                            credibility: s.credibility,
                         })
                         .collect(),
                     metadata: serde_json::to_value(&event.metadata)
                         .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
+                    actors: event.actors.clone(),
                 }
             })
             .collect();
@@ -257,6 +451,9 @@ impl Format for JsonFormat {
             version: "1.0".to_string(),
             metadata,
             events,
+            actors: narrative.actors.clone(),
+            sections: narrative.sections.clone(),
+            discourse: narrative.discourse.clone(),
         };
 
         if self.pretty {
@@ -272,6 +469,7 @@ impl Format for JsonFormat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use geo_types::polygon;
 
     #[test]
     fn test_json_roundtrip() {
@@ -297,6 +495,136 @@ mod tests {
         assert_eq!(restored.events()[0].tags, vec!["tag1"]);
     }
 
+    #[test]
+    fn test_json_geometry_roundtrip() {
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+            (x: 0.0, y: 1.0),
+        ];
+        let event = Event::builder()
+            .geometry(polygon.into())
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Perimeter")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        assert_eq!(restored.events()[0].geometry, event.geometry);
+        assert_eq!(restored.events()[0].location, event.location);
+
+        // Malformed geometry is an error rather than dropped
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["events"][0]["geometry"]["coordinates"] = serde_json::json!([[1.0]]);
+        let err = format.import_str(&value.to_string()).unwrap_err();
+        assert!(
+            err.to_string().contains("invalid Polygon geometry"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_json_interval_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-01-20T09:00:00Z").unwrap())
+            .text("Ship in port")
+            .build();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        assert_eq!(restored.events()[0].end_timestamp, event.end_timestamp);
+
+        // An interval that ends before it starts is rejected
+        let reversed = json.replace("2024-01-20T09:00:00", "2024-01-10T09:00:00");
+        assert!(format.import_str(&reversed).is_err());
+    }
+
+    #[test]
+    fn test_json_historical_dates_roundtrip() {
+        let ides = Event::builder()
+            .location(Location::new(41.8925, 12.4853))
+            .timestamp(Timestamp::parse("15 March 44 BCE (Julian)").unwrap())
+            .text("Assassination of Caesar")
+            .build();
+        let founding = Event::builder()
+            .location(Location::new(41.9, 12.5))
+            .timestamp(Timestamp::parse("c. 753 BC").unwrap())
+            .text("Founding of Rome")
+            .build();
+
+        let narrative = Narrative::builder().event(ides).event(founding).build();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        for (original, restored) in narrative.events().iter().zip(restored.events()) {
+            assert_eq!(original.timestamp, restored.timestamp);
+        }
+        assert_eq!(restored.events()[0].timestamp.calendar_date(), (-43, 3, 15));
+    }
+
+    #[test]
+    fn test_json_time_zone_roundtrip() {
+        let mut event = Event::builder()
+            .location(Location::new(48.8566, 2.3522))
+            .timestamp(Timestamp::parse("2024-07-14T08:00:00Z").unwrap())
+            .end_timestamp(Timestamp::parse("2024-07-14T10:00:00+02:00").unwrap())
+            .text("Parade")
+            .build();
+        event.infer_time_zone();
+
+        let narrative = Narrative::builder().event(event.clone()).build();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        assert!(json.contains("2024-07-14T10:00:00+02:00[Europe/Paris]"));
+
+        let restored = format.import_str(&json).unwrap();
+        assert_eq!(restored.events()[0].timestamp, event.timestamp);
+        assert_eq!(restored.events()[0].end_timestamp, event.end_timestamp);
+        assert_eq!(restored.events()[0].timestamp.local_hour(), 10);
+    }
+
+    #[test]
+    fn test_json_typed_metadata_roundtrip() {
+        let event = Event::builder()
+            .location(Location::new(38.3, 142.4))
+            .timestamp(Timestamp::parse("2011-03-11T05:46:24Z").unwrap())
+            .metadata("magnitude", 9.1)
+            .metadata("deaths", 19_759)
+            .metadata("tsunami", true)
+            .metadata("epicenter", Location::new(38.297, 142.373))
+            .metadata(
+                "reported",
+                Timestamp::parse("2011-03-11T06:00:00Z").unwrap(),
+            )
+            .metadata("agencies", vec!["JMA".into(), "USGS".into()])
+            .metadata("extra", serde_json::json!({"depth_km": 29}))
+            .build();
+
+        let mut narrative = Narrative::builder().event(event.clone()).build();
+        narrative.metadata.extra.insert("version".into(), 3.into());
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        assert_eq!(restored.events()[0].metadata, event.metadata);
+        assert_eq!(restored.metadata.extra, narrative.metadata.extra);
+    }
+
     #[test]
     fn test_json_version_check() {
         let json = r#"{
@@ -318,6 +646,95 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_json_missing_ids() {
+        let json = r#"{
+            "version": "1.0",
+            "metadata": {},
+            "events": [{
+                "location": {"lat": 40.7128, "lon": -74.006},
+                "timestamp": "2024-01-15T14:30:00Z",
+                "text": "No ID",
+                "tags": [],
+                "metadata": {}
+            }]
+        }"#;
+
+        let format = JsonFormat::new().with_id_strategy(IdStrategy::Content);
+        let first = format.import_str(json).unwrap();
+        let second = format.import_str(json).unwrap();
+        assert_eq!(first.events()[0].id, second.events()[0].id);
+        assert_eq!(first.events()[0].id, first.events()[0].content_id());
+
+        // Identical events without IDs are told apart
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let repeat = value["events"][0].clone();
+        value["events"].as_array_mut().unwrap().push(repeat);
+        let imported = format.import_str(&value.to_string()).unwrap();
+        assert_eq!(imported.events()[0].id, first.events()[0].id);
+        assert_ne!(imported.events()[1].id, imported.events()[0].id);
+    }
+
+    #[test]
+    fn test_json_schema_matches_published() {
+        let published: serde_json::Value =
+            serde_json::from_str(include_str!("../../schema/narrative.schema.json")).unwrap();
+        assert_eq!(
+            JsonFormat::schema(),
+            published,
+            "regenerate with `cargo run --example json_schema > schema/narrative.schema.json`"
+        );
+    }
+
+    #[test]
+    fn test_json_schema_validation() {
+        let event = Event::builder()
+            .location(Location::new(40.7128, -74.006))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .text("Valid")
+            .source(SourceRef::article("https://example.com"))
+            .metadata("deaths", 3)
+            .build();
+        let narrative = Narrative::builder().event(event).build();
+        let json = JsonFormat::new().export_str(&narrative).unwrap();
+        let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(JsonFormat::validate(&document), vec![]);
+
+        let invalid = r#"{
+            "version": "1.0",
+            "metadata": {"author": 7},
+            "events": [{
+                "location": {"lat": 40.0, "lon": 200.0},
+                "timestamp": "2024-01-15T14:30:00Z",
+                "text": "Invalid",
+                "tags": "war",
+                "metadata": {}
+            }]
+        }"#;
+        // Without validation the first serde error is all we get
+        assert!(matches!(
+            JsonFormat::new().import_str(invalid),
+            Err(Error::Json(_))
+        ));
+
+        let format = JsonFormat::new().with_validation(true);
+        let Err(Error::SchemaValidation(violations)) = format.import_str(invalid) else {
+            panic!("expected schema violations");
+        };
+        let pointers: Vec<&str> = violations.iter().map(|v| v.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/events/0/location/lon",
+                "/events/0/tags",
+                "/metadata/author"
+            ]
+        );
+
+        // Valid documents import as usual
+        assert_eq!(format.import_str(&json).unwrap().events().len(), 1);
+    }
+
     #[test]
     fn test_json_with_source() {
         let mut source = SourceRef::new(SourceType::Article);
@@ -347,4 +764,46 @@ mod tests {
             Some("https://example.com".to_string())
         );
     }
+
+    #[test]
+    fn test_json_with_actors() {
+        use crate::core::{Actor, ActorKind};
+
+        let ranger = Actor::new("Park Service", ActorKind::Organization).with_alias("NPS");
+        let event = Event::builder()
+            .location(Location::new(44.4, -110.6))
+            .timestamp(Timestamp::parse("2024-01-15T14:30:00Z").unwrap())
+            .actor_with_role(ranger.id.clone(), "responder")
+            .build();
+        let id = ranger.id.clone();
+        let narrative = Narrative::builder().actor(ranger).event(event).build();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        assert_eq!(restored.actors.find_by_name("nps").unwrap().id, id);
+        assert_eq!(restored.events_with_role(&id, "responder").len(), 1);
+    }
+
+    #[test]
+    fn test_json_with_sections_and_discourse() {
+        let a = Event::new(Location::new(1.0, 2.0), Timestamp::now(), "A");
+        let b = Event::new(Location::new(3.0, 4.0), Timestamp::now(), "B");
+        let chapter = Section::new("Chapter 1")
+            .with_description("Opening")
+            .with_events([a.id.clone(), b.id.clone()])
+            .with_section(Section::new("Scene").with_event(b.id.clone()));
+        let b_id = b.id.clone();
+        let mut narrative = Narrative::builder().events([a, b]).section(chapter).build();
+        narrative.set_discourse_order([b_id.clone()]).unwrap();
+
+        let format = JsonFormat::new();
+        let json = format.export_str(&narrative).unwrap();
+        let restored = format.import_str(&json).unwrap();
+
+        assert_eq!(restored.sections, narrative.sections);
+        assert_eq!(restored.discourse, vec![b_id]);
+        assert_eq!(restored.sections[0].events(&restored).len(), 2);
+    }
 }
@@ -8,6 +8,7 @@
 //! - [`GeoJsonFormat`] - Standard geographic data format
 //! - [`CsvFormat`] - Tabular data with configurable columns
 //! - [`JsonFormat`] - Custom JSON format optimized for narratives
+//! - BibTeX and CSL-JSON citations for sources ([`CitationEntry`], [`Bibliography`])
 //! - GPX - GPS exchange format (optional feature, TODO)
 //!
 //! # Example
@@ -38,12 +39,16 @@
 //! let json = json_format.export_str(&narrative).unwrap();
 //! ```
 
+mod citation;
 mod csv_format;
 mod format;
 mod geojson;
 mod json_format;
+mod schema;
 
+pub use citation::{citation_key, parse_bibtex, parse_csl_json, Bibliography, CitationEntry};
 pub use csv_format::{CsvFormat, CsvOptions};
 pub use format::Format;
 pub use geojson::{GeoJsonFormat, GeoJsonOptions};
 pub use json_format::JsonFormat;
+pub use schema::SchemaViolation;
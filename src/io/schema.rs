@@ -0,0 +1,433 @@
+//! JSON Schema validation with JSON pointer error reporting.
+//!
+//! Supports the draft 7 keywords used by the schemas this crate
+//! generates: `$ref`, `type`, `enum`, `const`, numeric and string
+//! bounds, `pattern`, the `uuid` format, object and array keywords,
+//! and `allOf`/`anyOf`/`oneOf`/`not`.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+/// Compiled `pattern` keywords, shared across validations; `None` for a
+/// pattern that does not compile.
+static PATTERNS: Lazy<Mutex<HashMap<String, Option<Regex>>>> = Lazy::new(Default::default);
+
+/// Returns the compiled regex for a `pattern` keyword.
+fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let mut patterns = PATTERNS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(re) = patterns.get(pattern) {
+        return re.clone();
+    }
+    let re = Regex::new(pattern).ok();
+    patterns.insert(pattern.to_string(), re.clone());
+    re
+}
+
+/// A place where a document does not match its schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaViolation {
+    /// JSON pointer (RFC 6901) to the offending value; empty for the document root.
+    pub pointer: String,
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(f, "{}: {}", pointer, self.message)
+    }
+}
+
+/// Validates a document against a schema, returning every violation found.
+pub(crate) fn validate(schema: &Value, document: &Value) -> Vec<SchemaViolation> {
+    let mut validator = Validator {
+        root: schema,
+        violations: Vec::new(),
+    };
+    validator.check(schema, document, &mut String::new());
+    validator.violations
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+    violations: Vec<SchemaViolation>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, pointer: &str, message: impl Into<String>) {
+        self.violations.push(SchemaViolation {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn check(&mut self, schema: &'a Value, value: &Value, pointer: &mut String) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => return self.report(pointer, "no value is allowed here"),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match self.resolve(reference) {
+                Some(target) => self.check(target, value, pointer),
+                None => self.report(
+                    pointer,
+                    format!("unresolvable schema reference {}", reference),
+                ),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            if !type_matches(expected, value) {
+                return self.report(
+                    pointer,
+                    format!(
+                        "expected {}, found {}",
+                        describe_type(expected),
+                        type_name(value)
+                    ),
+                );
+            }
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            if !allowed.contains(value) {
+                self.report(
+                    pointer,
+                    format!("{} is not one of {}", value, Value::from(allowed.clone())),
+                );
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if expected != value {
+                self.report(pointer, format!("expected {}, found {}", expected, value));
+            }
+        }
+
+        match value {
+            Value::Number(n) => self.check_number(schema, n.as_f64().unwrap_or(f64::NAN), pointer),
+            Value::String(s) => self.check_string(schema, s, pointer),
+            Value::Array(items) => self.check_array(schema, items, pointer),
+            Value::Object(object) => self.check_object(schema, object, pointer),
+            _ => {},
+        }
+
+        self.check_combinators(schema, value, pointer);
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let path = reference.strip_prefix('#')?;
+        self.root.pointer(path)
+    }
+
+    fn check_number(&mut self, schema: &Map<String, Value>, n: f64, pointer: &str) {
+        let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+        if let Some(min) = bound("minimum") {
+            if n < min {
+                self.report(
+                    pointer,
+                    format!("{} is less than the minimum of {}", n, min),
+                );
+            }
+        }
+        if let Some(max) = bound("maximum") {
+            if n > max {
+                self.report(
+                    pointer,
+                    format!("{} is greater than the maximum of {}", n, max),
+                );
+            }
+        }
+        if let Some(min) = bound("exclusiveMinimum") {
+            if n <= min {
+                self.report(pointer, format!("{} must be greater than {}", n, min));
+            }
+        }
+        if let Some(max) = bound("exclusiveMaximum") {
+            if n >= max {
+                self.report(pointer, format!("{} must be less than {}", n, max));
+            }
+        }
+    }
+
+    fn check_string(&mut self, schema: &Map<String, Value>, s: &str, pointer: &str) {
+        let length = s.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if length < min {
+                self.report(pointer, format!("must be at least {} characters long", min));
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if length > max {
+                self.report(pointer, format!("must be at most {} characters long", max));
+            }
+        }
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            if let Some(re) = pattern_regex(pattern) {
+                if !re.is_match(s) {
+                    self.report(
+                        pointer,
+                        format!("{:?} does not match the pattern {}", s, pattern),
+                    );
+                }
+            }
+        }
+        if schema.get("format").and_then(Value::as_str) == Some("uuid")
+            && uuid::Uuid::parse_str(s).is_err()
+        {
+            self.report(pointer, format!("{:?} is not a valid UUID", s));
+        }
+    }
+
+    fn check_array(
+        &mut self,
+        schema: &'a Map<String, Value>,
+        items: &[Value],
+        pointer: &mut String,
+    ) {
+        let count = items.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if count < min {
+                self.report(pointer, format!("must have at least {} items", min));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if count > max {
+                self.report(pointer, format!("must have at most {} items", max));
+            }
+        }
+        if schema.get("uniqueItems").and_then(Value::as_bool) == Some(true) {
+            let duplicated = items
+                .iter()
+                .enumerate()
+                .any(|(i, item)| items[..i].contains(item));
+            if duplicated {
+                self.report(pointer, "items must be unique");
+            }
+        }
+
+        match schema.get("items") {
+            Some(Value::Array(tuple)) => {
+                for (i, (item, item_schema)) in items.iter().zip(tuple).enumerate() {
+                    self.check_child(item_schema, item, pointer, &i.to_string());
+                }
+            },
+            Some(item_schema) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.check_child(item_schema, item, pointer, &i.to_string());
+                }
+            },
+            None => {},
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        schema: &'a Map<String, Value>,
+        object: &Map<String, Value>,
+        pointer: &mut String,
+    ) {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(key) {
+                    self.report(pointer, format!("missing required property {:?}", key));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, value) in object {
+            match properties.and_then(|p| p.get(key)) {
+                Some(property_schema) => self.check_child(property_schema, value, pointer, key),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        self.report(pointer, format!("unknown property {:?}", key));
+                    },
+                    Some(extra_schema) => self.check_child(extra_schema, value, pointer, key),
+                    None => {},
+                },
+            }
+        }
+    }
+
+    fn check_combinators(
+        &mut self,
+        schema: &'a Map<String, Value>,
+        value: &Value,
+        pointer: &mut String,
+    ) {
+        if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+            for sub in all {
+                self.check(sub, value, pointer);
+            }
+        }
+
+        for (keyword, exactly_one) in [("anyOf", false), ("oneOf", true)] {
+            let Some(options) = schema.get(keyword).and_then(Value::as_array) else {
+                continue;
+            };
+            let results: Vec<Vec<SchemaViolation>> = options
+                .iter()
+                .map(|option| self.trial(option, value, pointer))
+                .collect();
+            let matching = results.iter().filter(|r| r.is_empty()).count();
+
+            if matching == 0 {
+                // When only one option has the right type, its errors are the useful ones
+                let mut candidates = options
+                    .iter()
+                    .zip(results)
+                    .filter(|(option, _)| self.could_match_type(option, value));
+                match (candidates.next(), candidates.next()) {
+                    (Some((_, violations)), None) => self.violations.extend(violations),
+                    _ => self.report(pointer, "does not match any of the allowed schemas"),
+                }
+            } else if exactly_one && matching > 1 {
+                self.report(pointer, "matches more than one of the allowed schemas");
+            }
+        }
+
+        if let Some(not) = schema.get("not") {
+            if self.trial(not, value, pointer).is_empty() {
+                self.report(pointer, "matches a schema it must not match");
+            }
+        }
+    }
+
+    /// Validates against a sub-schema without recording the violations.
+    fn trial(
+        &mut self,
+        schema: &'a Value,
+        value: &Value,
+        pointer: &mut String,
+    ) -> Vec<SchemaViolation> {
+        let saved = std::mem::take(&mut self.violations);
+        self.check(schema, value, pointer);
+        std::mem::replace(&mut self.violations, saved)
+    }
+
+    /// Whether a schema's top-level `type`, following references, admits the value.
+    fn could_match_type(&self, schema: &'a Value, value: &Value) -> bool {
+        let Some(schema) = schema.as_object() else {
+            return schema.as_bool().unwrap_or(true);
+        };
+        if let Some(target) = schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|r| self.resolve(r))
+        {
+            if !self.could_match_type(target, value) {
+                return false;
+            }
+        }
+        schema.get("type").map_or(true, |t| type_matches(t, value))
+    }
+
+    fn check_child(&mut self, schema: &'a Value, value: &Value, pointer: &mut String, key: &str) {
+        let len = pointer.len();
+        pointer.push('/');
+        pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+        self.check(schema, value, pointer);
+        pointer.truncate(len);
+    }
+}
+
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    match expected {
+        Value::String(name) => is_type(name, value),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(name, value)),
+        _ => true,
+    }
+}
+
+fn is_type(name: &str, value: &Value) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        },
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn describe_type(expected: &Value) -> String {
+    match expected {
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        other => other.as_str().unwrap_or("a valid value").to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_reports_pointers() {
+        let schema = json!({
+            "type": "object",
+            "required": ["points"],
+            "properties": {
+                "points": {"type": "array", "items": {"$ref": "#/definitions/Point"}},
+                "name": {"anyOf": [{"$ref": "#/definitions/Name"}, {"type": "null"}]}
+            },
+            "definitions": {
+                "Point": {
+                    "type": "object",
+                    "required": ["lat"],
+                    "properties": {"lat": {"type": "number", "minimum": -90, "maximum": 90}}
+                },
+                "Name": {"type": "string", "minLength": 1}
+            }
+        });
+
+        assert!(validate(&schema, &json!({"points": [{"lat": 1.0}], "name": null})).is_empty());
+
+        let violations = validate(
+            &schema,
+            &json!({"points": [{"lat": 1.0}, {"lat": 91}, {}], "name": ""}),
+        );
+        let pointers: Vec<&str> = violations.iter().map(|v| v.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/name", "/points/1/lat", "/points/2"]);
+        assert_eq!(
+            violations[1].to_string(),
+            "/points/1/lat: 91 is greater than the maximum of 90"
+        );
+
+        let violations = validate(&schema, &json!([]));
+        assert_eq!(violations[0].to_string(), "/: expected object, found array");
+    }
+}
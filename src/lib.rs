@@ -40,6 +40,7 @@
 //! ## Modules
 //!
 //! - [`core`] - Fundamental types: `Location`, `Timestamp`, `Event`, `Narrative`
+//! - [`corpus`] - Collections of narratives sharing one event pool
 //! - [`index`] - Spatial and temporal indexing for efficient queries
 //! - [`graph`] - Graph representation of narratives
 //! - [`analysis`] - Metrics, clustering, and movement analysis
@@ -50,6 +51,7 @@
 
 pub mod analysis;
 pub mod core;
+pub mod corpus;
 pub mod graph;
 pub mod index;
 pub mod io;
@@ -65,7 +67,7 @@ pub mod prelude {
     pub use crate::core::{
         Event, EventBuilder, EventId, GeoBounds, Location, LocationBuilder, Narrative,
         NarrativeBuilder, NarrativeId, NarrativeMetadata, SourceRef, SourceType, SpatialEntity,
-        TemporalEntity, TemporalPrecision, TimeRange, Timestamp,
+        TemporalEntity, TemporalMatch, TemporalPrecision, TimeRange, Timestamp,
     };
     pub use crate::error::{Error, Result};
 
@@ -25,7 +25,8 @@
 //! }
 //! ```
 
-use crate::core::Location;
+use super::gazetteer_admin::ADMIN;
+use crate::core::{Location, PlaceHierarchy};
 use std::collections::HashMap;
 
 #[cfg(feature = "geocoding")]
@@ -130,13 +131,38 @@ struct NominatimResponse {
     lat: String,
     lon: String,
     display_name: Option<String>,
+    #[serde(default)]
+    address: Option<NominatimAddress>,
+}
+
+#[cfg(feature = "geocoding")]
+#[derive(Debug, Deserialize)]
+struct NominatimAddress {
+    country: Option<String>,
+    state: Option<String>,
+    county: Option<String>,
+    city: Option<String>,
+    town: Option<String>,
+    village: Option<String>,
+}
+
+#[cfg(feature = "geocoding")]
+impl NominatimAddress {
+    fn hierarchy(self) -> PlaceHierarchy {
+        PlaceHierarchy {
+            country: self.country,
+            admin1: self.state,
+            admin2: self.county,
+            locality: self.city.or(self.town).or(self.village),
+        }
+    }
 }
 
 #[cfg(feature = "geocoding")]
 impl Gazetteer for GazetteerNominatim {
     fn lookup(&self, name: &str) -> Option<Location> {
         let url = format!(
-            "{}/search?q={}&format=json&limit=1",
+            "{}/search?q={}&format=json&addressdetails=1&limit=1",
             self.base_url,
             urlencoding::encode(name)
         );
@@ -148,13 +174,21 @@ impl Gazetteer for GazetteerNominatim {
             .send()
             .ok()?;
 
-        let results: Vec<NominatimResponse> = response.json().ok()?;
-        let result = results.first()?;
+        let mut results: Vec<NominatimResponse> = response.json().ok()?;
+        if results.is_empty() {
+            return None;
+        }
+        let result = results.swap_remove(0);
 
         let lat: f64 = result.lat.parse().ok()?;
         let lon: f64 = result.lon.parse().ok()?;
 
-        Some(Location::new(lat, lon))
+        let mut location = Location::new(lat, lon);
+        location.hierarchy = result
+            .address
+            .map(NominatimAddress::hierarchy)
+            .filter(|h| !h.is_empty());
+        Some(location)
     }
 
     fn contains(&self, name: &str) -> bool {
@@ -226,13 +260,19 @@ struct GeoNamesEntry {
     lat: String,
     lng: String,
     name: String,
+    #[serde(rename = "countryName", default)]
+    country_name: Option<String>,
+    #[serde(rename = "adminName1", default)]
+    admin_name1: Option<String>,
+    #[serde(rename = "adminName2", default)]
+    admin_name2: Option<String>,
 }
 
 #[cfg(feature = "geocoding")]
 impl Gazetteer for GazetteerGeoNames {
     fn lookup(&self, name: &str) -> Option<Location> {
         let url = format!(
-            "http://api.geonames.org/searchJSON?q={}&maxRows=1&username={}",
+            "http://api.geonames.org/searchJSON?q={}&maxRows=1&style=FULL&username={}",
             urlencoding::encode(name),
             urlencoding::encode(&self.username)
         );
@@ -245,7 +285,15 @@ impl Gazetteer for GazetteerGeoNames {
         let lat: f64 = entry.lat.parse().ok()?;
         let lon: f64 = entry.lng.parse().ok()?;
 
-        Some(Location::new(lat, lon))
+        // GeoNames reports missing levels as empty strings
+        let level = |v: &Option<String>| v.clone().filter(|s| !s.is_empty());
+        let hierarchy = PlaceHierarchy {
+            country: level(&entry.country_name),
+            admin1: level(&entry.admin_name1),
+            admin2: level(&entry.admin_name2),
+            locality: Some(entry.name.clone()),
+        };
+        Some(Location::new(lat, lon).with_hierarchy(hierarchy))
     }
 
     fn contains(&self, name: &str) -> bool {
@@ -481,6 +529,10 @@ pub struct GazetteerEntry {
     pub name: String,
     /// Country or region
     pub country: String,
+    /// First-level subdivision (state, province), if known
+    pub admin1: Option<String>,
+    /// Second-level subdivision (county, district), if known
+    pub admin2: Option<String>,
     /// Latitude
     pub lat: f64,
     /// Longitude
@@ -491,6 +543,46 @@ pub struct GazetteerEntry {
     pub aliases: Vec<String>,
 }
 
+impl GazetteerEntry {
+    /// Returns the entry's place hierarchy, with the entry as the locality.
+    pub fn hierarchy(&self) -> PlaceHierarchy {
+        PlaceHierarchy {
+            country: Some(self.country.clone()).filter(|c| !c.is_empty()),
+            admin1: self.admin1.clone(),
+            admin2: self.admin2.clone(),
+            locality: Some(self.name.clone()),
+        }
+    }
+
+    /// Estimates how far the place extends from its centroid, in meters.
+    ///
+    /// Treats the place as a disk populated at about 5,000 people per km²,
+    /// with a floor of 500 m. Returns `None` when the population is unknown.
+    pub fn uncertainty_radius(&self) -> Option<f64> {
+        const PEOPLE_PER_KM2: f64 = 5_000.0;
+        const MIN_RADIUS_M: f64 = 500.0;
+
+        if self.population == 0 {
+            return None;
+        }
+        let area_km2 = self.population as f64 / PEOPLE_PER_KM2;
+        let radius_m = (area_km2 / std::f64::consts::PI).sqrt() * 1_000.0;
+        Some(radius_m.max(MIN_RADIUS_M))
+    }
+
+    /// Returns the entry as a named location with its place hierarchy.
+    ///
+    /// The location's uncertainty is set from
+    /// [`uncertainty_radius`](Self::uncertainty_radius), since a
+    /// centroid stands for the whole place.
+    pub fn to_location(&self) -> Location {
+        let mut location = Location::new(self.lat, self.lon).with_hierarchy(self.hierarchy());
+        location.name = Some(self.name.clone());
+        location.uncertainty_meters = self.uncertainty_radius();
+        location
+    }
+}
+
 /// Built-in gazetteer with major world locations.
 ///
 /// Contains approximately 200+ major cities, countries, and landmarks.
@@ -540,7 +632,8 @@ impl BuiltinGazetteer {
     // Licensed under Creative Commons Attribution 4.0 International (CC BY 4.0)
     // https://creativecommons.org/licenses/by/4.0/
     //
-    // Contains 2500+ world cities selected by population from cities1000.txt
+    // Contains 2500+ world cities selected by population from cities1000.txt,
+    // with their administrative divisions in gazetteer_admin.rs
     // DO NOT EDIT MANUALLY - regenerate with scripts/generate_gazetteer.py
     fn load_default_entries(&mut self) {
         let cities = [
@@ -19923,10 +20016,19 @@ impl BuiltinGazetteer {
             ("Maracanaú", "Brazil", -3.8767, -38.6256, 234509, vec![]),
         ];
 
+        let divisions: HashMap<(&str, &str), (&str, &str)> = ADMIN
+            .iter()
+            .map(|&(name, country, admin1, admin2)| ((name, country), (admin1, admin2)))
+            .collect();
+        let division = |name: &str| Some(name.to_string()).filter(|n| !n.is_empty());
+
         for (name, country, lat, lon, pop, aliases) in cities {
+            let (admin1, admin2) = divisions.get(&(name, country)).copied().unwrap_or_default();
             self.add_entry(GazetteerEntry {
                 name: name.to_string(),
                 country: country.to_string(),
+                admin1: division(admin1),
+                admin2: division(admin2),
                 lat,
                 lon,
                 population: pop,
@@ -19948,7 +20050,7 @@ impl Gazetteer for BuiltinGazetteer {
         self.name_to_canonical
             .get(&lower)
             .and_then(|canonical| self.entries.get(canonical))
-            .map(|entry| Location::new(entry.lat, entry.lon))
+            .map(GazetteerEntry::to_location)
     }
 
     fn contains(&self, name: &str) -> bool {
@@ -20043,6 +20145,42 @@ mod tests {
         let berlin = gazetteer.lookup("Berlin").unwrap();
         assert!((berlin.lat - 52.52).abs() < 0.01);
         assert!((berlin.lon - 13.405).abs() < 0.01);
+
+        let hierarchy = berlin.hierarchy.unwrap();
+        assert_eq!(hierarchy.country.as_deref(), Some("Germany"));
+        assert_eq!(hierarchy.locality.as_deref(), Some("Berlin"));
+
+        // City centroids carry the city's extent as uncertainty
+        let radius = berlin.uncertainty_meters.unwrap();
+        assert!(radius > 5_000.0 && radius < 50_000.0, "radius {}", radius);
+    }
+
+    #[test]
+    fn test_builtin_admin_divisions() {
+        let gazetteer = BuiltinGazetteer::new();
+        assert!(!ADMIN.is_empty(), "no administrative divisions");
+
+        let lyon = gazetteer.lookup("Lyon").unwrap().hierarchy.unwrap();
+        assert_eq!(lyon.country.as_deref(), Some("France"));
+        assert_eq!(lyon.admin1.as_deref(), Some("Auvergne-Rhône-Alpes"));
+        assert_eq!(lyon.admin2.as_deref(), Some("Rhône"));
+
+        let manchester = gazetteer.lookup("Manchester").unwrap().hierarchy.unwrap();
+        assert_eq!(manchester.country.as_deref(), Some("United Kingdom"));
+        assert_eq!(manchester.admin1.as_deref(), Some("England"));
+        assert_eq!(manchester.admin2.as_deref(), Some("Greater Manchester"));
+
+        // Cities pick up their generated divisions (names shared across
+        // countries keep only the last city, so match on country too)
+        for &(name, country, admin1, admin2) in ADMIN {
+            let Some(entry) = gazetteer.entries.get(&name.to_lowercase()) else {
+                panic!("division row for unknown city {name}");
+            };
+            if entry.country == country {
+                assert_eq!(entry.admin1.as_deref().unwrap_or(""), admin1, "{name}");
+                assert_eq!(entry.admin2.as_deref().unwrap_or(""), admin2, "{name}");
+            }
+        }
     }
 
     #[test]
@@ -0,0 +1,28 @@
+// Administrative divisions of the built-in gazetteer's cities.
+// Seed rows for major cities, entered by hand from GeoNames
+// (https://www.geonames.org) admin1CodesASCII.txt and admin2Codes.txt names.
+// Run scripts/generate_gazetteer.py to replace them with the generated table
+// for every city.
+// Licensed under Creative Commons Attribution 4.0 International (CC BY 4.0)
+
+/// Divisions as `(name, country, admin1, admin2)`; empty when unknown.
+#[rustfmt::skip]
+pub(super) const ADMIN: &[(&str, &str, &str, &str)] = &[
+    ("Shanghai", "China", "Shanghai", ""),
+    ("Tokyo", "Japan", "Tokyo", ""),
+    ("Mumbai", "India", "Maharashtra", ""),
+    ("New York City", "United States", "New York", ""),
+    ("London", "United Kingdom", "England", "Greater London"),
+    ("Madrid", "Spain", "Madrid", "Madrid"),
+    ("Los Angeles", "United States", "California", "Los Angeles County"),
+    ("Rome", "Italy", "Lazio", "Rome"),
+    ("Paris", "France", "Île-de-France", "Paris"),
+    ("Chicago", "United States", "Illinois", "Cook County"),
+    ("Toronto", "Canada", "Ontario", ""),
+    ("Barcelona", "Spain", "Catalonia", "Barcelona"),
+    ("Munich", "Germany", "Bavaria", "Upper Bavaria"),
+    ("Milan", "Italy", "Lombardy", "Milan"),
+    ("Marseille", "France", "Provence-Alpes-Côte d'Azur", "Bouches-du-Rhône"),
+    ("Manchester", "United Kingdom", "England", "Greater Manchester"),
+    ("Lyon", "France", "Auvergne-Rhône-Alpes", "Rhône"),
+];
@@ -83,6 +83,7 @@
 //! ```
 
 mod gazetteer;
+mod gazetteer_admin;
 mod geoparser;
 mod mention;
 